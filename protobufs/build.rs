@@ -24,7 +24,10 @@ use std::fs::{
     create_dir_all,
     read_dir,
 };
-use std::path::Path;
+use std::path::{
+    Path,
+    PathBuf,
+};
 
 const DERIVE_EQ_HASH: &str = "#[derive(Eq, Hash)]";
 const DERIVE_EQ_HASH_COPY: &str = "#[derive(Copy, Eq, Hash)]";
@@ -40,14 +43,17 @@ fn main() -> anyhow::Result<()> {
         anyhow::bail!("Folder {SERVICES_FOLDER} does not exist; do you need to `git submodule update --init`?");
     }
 
-    let services: Vec<_> = read_dir(services_path)?
-        .filter_map(|entry| {
-            let entry = entry.ok()?;
-            entry.file_type().ok()?.is_file().then(|| entry.path())
-        })
-        .collect();
+    // The contents of this folder will be copied and modified before they are used for code
+    // generation, so the cargo directive has to be set on the original folder.
+    println!("cargo:rerun-if-changed={SERVICES_FOLDER}");
+
+    let services_tmp_path = Path::new(&env::var("OUT_DIR")?).join("services_src");
 
-    let mut cfg = tonic_build::configure().build_server(cfg!(feature = "server"));
+    let services = copy_services(services_path, &services_tmp_path)?;
+
+    let mut cfg = tonic_build::configure()
+        .build_server(cfg!(feature = "server"))
+        .emit_rerun_if_changed(false);
 
     // most of the protobufs in "basic types" should be Eq + Hash + Copy
     // any protobufs that would typically be used as parameter, that meet the requirements of those
@@ -98,7 +104,7 @@ fn main() -> anyhow::Result<()> {
      "]"#,
     );
 
-    cfg.compile(&services, &["./protobufs/services/"])?;
+    cfg.compile(&services, &[services_tmp_path.as_path(), services_path])?;
 
     // NOTE: prost generates rust doc comments and fails to remove the leading * line
     remove_useless_comments(&Path::new(&env::var("OUT_DIR")?).join("proto.rs"))?;
@@ -125,7 +131,7 @@ fn main() -> anyhow::Result<()> {
                 "./protobufs/mirror/consensus_service.proto",
                 "./protobufs/mirror/mirror_network_service.proto",
             ],
-            &["./protobufs/mirror/", "./protobufs/services/"],
+            &[Path::new("./protobufs/mirror/"), services_tmp_path.as_path(), services_path],
         )?;
 
     remove_useless_comments(&mirror_out_dir.join("proto.rs"))?;
@@ -142,7 +148,7 @@ fn main() -> anyhow::Result<()> {
 
     cfg.out_dir(&streams_out_dir).compile(
         &["./protobufs/streams/account_balance_file.proto"],
-        &["./protobufs/streams/", "./protobufs/services/"],
+        &[Path::new("./protobufs/streams/"), services_tmp_path.as_path(), services_path],
     )?;
 
     // see note wrt services.
@@ -188,8 +194,11 @@ fn main() -> anyhow::Result<()> {
         .services_same("FreezeType")
         .services_same("LiveHash")
         .services_same("NftRemoveAllowance")
+        .services_same("NodeCreateTransactionBody")
+        .services_same("NodeDeleteTransactionBody")
         .services_same("NodeStake")
         .services_same("NodeStakeUpdateTransactionBody")
+        .services_same("NodeUpdateTransactionBody")
         .services_same("RoyaltyFee")
         .services_same("SchedulableTransactionBody")
         .services_same("ScheduleCreateTransactionBody")
@@ -197,8 +206,11 @@ fn main() -> anyhow::Result<()> {
         .services_same("ScheduleSignTransactionBody")
         .services_same("SystemDeleteTransactionBody")
         .services_same("SystemUndeleteTransactionBody")
+        .services_same("TokenAirdropTransactionBody")
         .services_same("TokenAssociateTransactionBody")
         .services_same("TokenBurnTransactionBody")
+        .services_same("TokenCancelAirdropTransactionBody")
+        .services_same("TokenClaimAirdropTransactionBody")
         .services_same("TokenCreateTransactionBody")
         .services_same("TokenDeleteTransactionBody")
         .services_same("TokenDissociateTransactionBody")
@@ -207,6 +219,8 @@ fn main() -> anyhow::Result<()> {
         .services_same("TokenGrantKycTransactionBody")
         .services_same("TokenMintTransactionBody")
         .services_same("TokenPauseTransactionBody")
+        .services_same("TokenReference")
+        .services_same("TokenRejectTransactionBody")
         .services_same("TokenRevokeKycTransactionBody")
         .services_same("TokenUnfreezeAccountTransactionBody")
         .services_same("TokenUnpauseTransactionBody")
@@ -221,7 +235,7 @@ fn main() -> anyhow::Result<()> {
 
     cfg.out_dir(&sdk_out_dir).compile(
         &["./protobufs/sdk/transaction_list.proto"],
-        &["./protobufs/sdk/", "./protobufs/services/"],
+        &[Path::new("./protobufs/sdk/"), services_tmp_path.as_path(), services_path],
     )?;
 
     // see note wrt services.
@@ -230,6 +244,39 @@ fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Copy the services protobufs from `from` into `to`, returning the paths of the copies.
+///
+/// The address book service (HIP-869) is declared in the `com.hedera.hapi.node.addressbook`
+/// package rather than `proto`, which would put its messages in a module of their own;
+/// it's rewritten into `proto` so that it's generated into `services` like everything else.
+fn copy_services(from: &Path, to: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    const ADDRESS_BOOK_PACKAGE: &str = "com.hedera.hapi.node.addressbook";
+
+    create_dir_all(to)?;
+
+    let mut services = Vec::new();
+
+    for entry in read_dir(from)? {
+        let entry = entry?;
+
+        if !entry.file_type()?.is_file() {
+            continue;
+        }
+
+        let contents = fs::read_to_string(entry.path())?
+            .replace(&format!("package {ADDRESS_BOOK_PACKAGE};"), "package proto;")
+            .replace(&format!("{ADDRESS_BOOK_PACKAGE}."), "");
+
+        let path = to.join(entry.file_name());
+
+        fs::write(&path, contents)?;
+
+        services.push(path);
+    }
+
+    Ok(services)
+}
+
 fn remove_useless_comments(path: &Path) -> anyhow::Result<()> {
     let mut contents = fs::read_to_string(path)?;
 
@@ -322,5 +369,7 @@ mod builder {
             .services_path("TokenAssociation", "TokenAssociation")
             .services_path("ContractID", "ContractId")
             .services_path("StakingInfo", "StakingInfo")
+            .services_path("PendingAirdropId", "PendingAirdropId")
+            .services_path("PendingAirdropValue", "PendingAirdropValue")
     }
 }
@@ -34,6 +34,8 @@ use crate::{
 
 /// Response from [`AccountBalanceQuery`][crate::AccountBalanceQuery].
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct AccountBalance {
     /// The account that is being referenced.
     pub account_id: AccountId,
@@ -44,11 +46,13 @@ pub struct AccountBalance {
     /// Token balances for the referenced account.
     #[deprecated = "use a mirror query"]
     #[allow(deprecated)]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::sorted_map"))]
     pub tokens: HashMap<TokenId, u64>,
 
     /// Token decimals for the referenced account.
     #[deprecated = "use a mirror query"]
     #[allow(deprecated)]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::sorted_map"))]
     pub token_decimals: HashMap<TokenId, u32>,
 }
 
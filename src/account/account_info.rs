@@ -39,6 +39,8 @@ use crate::{
 
 /// Response from [`AccountInfoQuery`][crate::AccountInfoQuery].
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct AccountInfo {
     /// The account that is being referenced.
     pub account_id: AccountId,
@@ -87,9 +89,11 @@ pub struct AccountInfo {
     pub is_receiver_signature_required: bool,
 
     /// The time at which this account is set to expire.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::option_timestamp"))]
     pub expiration_time: Option<OffsetDateTime>,
 
     /// The duration for expiration time will extend every this many seconds.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::option_duration"))]
     pub auto_renew_period: Option<Duration>,
 
     /// The memo associated with the account.
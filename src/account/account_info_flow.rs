@@ -57,17 +57,29 @@ pub async fn verify_signature(
     key.verify(msg, signature)
 }
 
-/// Returns `Ok(())` if the given account's public key has signed the given transaction.
+/// Returns `Ok(())` if the given account's key has signed the given transaction.
+///
+/// The account's key may be any [`Key`], see [`Key::verify_transaction`] for how key lists are handled.
+///
 /// # Errors
-/// - [`Error::SignatureVerify`] if the private key associated with the account's public key did _not_ sign this transaction,
-///   or the signature associated was invalid.
+/// - [`Error::SignatureVerify`] if the signatures on this transaction don't satisfy the account's key,
+///   or a signature associated with the key was invalid.
 /// - See [`AccountInfoQuery::execute`]
 pub async fn verify_transaction_signature<D: TransactionExecute>(
     client: &Client,
     account_id: AccountId,
     transaction: &mut Transaction<D>,
 ) -> crate::Result<()> {
-    let key = query_pk(client, account_id).await?;
+    let key = AccountInfoQuery::new().account_id(account_id).execute(client).await?.key;
+
+    let report = key.verify_transaction(transaction)?;
+
+    if report.is_satisfied {
+        return Ok(());
+    }
 
-    key.verify_transaction(transaction)
+    Err(Error::signature_verify(format!(
+        "`{account_id}`: transaction is missing signatures for {:?}",
+        report.missing
+    )))
 }
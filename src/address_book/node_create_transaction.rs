@@ -0,0 +1,482 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::net::SocketAddrV4;
+
+use hedera_proto::services;
+use hedera_proto::services::address_book_service_client::AddressBookServiceClient;
+use tonic::transport::Channel;
+
+use crate::ledger_id::RefLedgerId;
+use crate::node_address::{
+    service_endpoint_from_protobuf,
+    service_endpoint_to_protobuf,
+};
+use crate::protobuf::{
+    FromProtobuf,
+    ToProtobuf,
+};
+use crate::transaction::{
+    AnyTransactionData,
+    ChunkInfo,
+    ToSchedulableTransactionDataProtobuf,
+    ToTransactionDataProtobuf,
+    TransactionData,
+    TransactionExecute,
+};
+use crate::{
+    AccountId,
+    BoxGrpcFuture,
+    Error,
+    Key,
+    NodeAddress,
+    Transaction,
+    ValidateChecksums,
+};
+
+/// Add a new consensus node to the network address book.
+///
+/// This transaction must be signed by the Hedera council, as well as by the `admin_key` of the new node.
+///
+/// The new node isn't active in the network until the network is upgraded. On success, the
+/// receipt contains the `node_id` assigned to the new node.
+pub type NodeCreateTransaction = Transaction<NodeCreateTransactionData>;
+
+#[derive(Debug, Clone, Default)]
+pub struct NodeCreateTransactionData {
+    /// The account to be paid for queries and transactions sent to the node.
+    account_id: Option<AccountId>,
+
+    /// A short description of the node.
+    description: String,
+
+    /// The node's gossip endpoints.
+    gossip_endpoints: Vec<SocketAddrV4>,
+
+    /// The node's gRPC service endpoints.
+    service_endpoints: Vec<SocketAddrV4>,
+
+    /// The node's DER encoded certificate used to sign gossip events.
+    gossip_ca_certificate: Vec<u8>,
+
+    /// The SHA-384 hash of the node's gRPC TLS certificate.
+    grpc_certificate_hash: Vec<u8>,
+
+    /// The key that must sign updates to the node.
+    admin_key: Option<Key>,
+}
+
+impl NodeCreateTransaction {
+    /// Returns the account to be paid for queries and transactions sent to the node.
+    #[must_use]
+    pub fn get_account_id(&self) -> Option<AccountId> {
+        self.data().account_id
+    }
+
+    /// Sets the account to be paid for queries and transactions sent to the node.
+    pub fn account_id(&mut self, account_id: AccountId) -> &mut Self {
+        self.data_mut().account_id = Some(account_id);
+        self
+    }
+
+    /// Returns the description of the node.
+    #[must_use]
+    pub fn get_description(&self) -> &str {
+        &self.data().description
+    }
+
+    /// Sets a short description of the node, up to 100 bytes.
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        self.data_mut().description = description.into();
+        self
+    }
+
+    /// Returns the node's gossip endpoints.
+    #[must_use]
+    pub fn get_gossip_endpoints(&self) -> &[SocketAddrV4] {
+        &self.data().gossip_endpoints
+    }
+
+    /// Sets the node's gossip endpoints, used for communication between consensus nodes.
+    ///
+    /// The first endpoint is the node's internal address, the second (if any) its external address.
+    pub fn gossip_endpoints(
+        &mut self,
+        gossip_endpoints: impl IntoIterator<Item = SocketAddrV4>,
+    ) -> &mut Self {
+        self.data_mut().gossip_endpoints = gossip_endpoints.into_iter().collect();
+        self
+    }
+
+    /// Returns the node's gRPC service endpoints.
+    #[must_use]
+    pub fn get_service_endpoints(&self) -> &[SocketAddrV4] {
+        &self.data().service_endpoints
+    }
+
+    /// Sets the node's gRPC service endpoints, used by clients to submit transactions and queries.
+    pub fn service_endpoints(
+        &mut self,
+        service_endpoints: impl IntoIterator<Item = SocketAddrV4>,
+    ) -> &mut Self {
+        self.data_mut().service_endpoints = service_endpoints.into_iter().collect();
+        self
+    }
+
+    /// Returns the node's DER encoded gossip certificate.
+    #[must_use]
+    pub fn get_gossip_ca_certificate(&self) -> &[u8] {
+        &self.data().gossip_ca_certificate
+    }
+
+    /// Sets the node's DER encoded certificate, used to sign gossip events.
+    pub fn gossip_ca_certificate(&mut self, gossip_ca_certificate: Vec<u8>) -> &mut Self {
+        self.data_mut().gossip_ca_certificate = gossip_ca_certificate;
+        self
+    }
+
+    /// Returns the SHA-384 hash of the node's gRPC TLS certificate.
+    #[must_use]
+    pub fn get_grpc_certificate_hash(&self) -> &[u8] {
+        &self.data().grpc_certificate_hash
+    }
+
+    /// Sets the SHA-384 hash of the node's gRPC TLS certificate.
+    pub fn grpc_certificate_hash(&mut self, grpc_certificate_hash: Vec<u8>) -> &mut Self {
+        self.data_mut().grpc_certificate_hash = grpc_certificate_hash;
+        self
+    }
+
+    /// Returns the key that must sign updates to the node.
+    #[must_use]
+    pub fn get_admin_key(&self) -> Option<&Key> {
+        self.data().admin_key.as_ref()
+    }
+
+    /// Sets the key that must sign updates to the node.
+    pub fn admin_key(&mut self, admin_key: impl Into<Key>) -> &mut Self {
+        self.data_mut().admin_key = Some(admin_key.into());
+        self
+    }
+
+    /// Sets the account, description, endpoints, gRPC certificate hash and admin key of the node
+    /// from `address`.
+    pub fn node_address(&mut self, address: &NodeAddress) -> &mut Self {
+        let data = self.data_mut();
+
+        data.account_id = Some(address.node_account_id);
+        data.description = address.description.clone();
+        data.gossip_endpoints = address.gossip_endpoints.clone();
+        data.service_endpoints = address.service_endpoints.clone();
+        data.grpc_certificate_hash = address.grpc_certificate_hash.clone();
+        data.admin_key = address.admin_key.clone();
+
+        self
+    }
+}
+
+impl TransactionData for NodeCreateTransactionData {}
+
+impl TransactionExecute for NodeCreateTransactionData {
+    fn execute(
+        &self,
+        channel: Channel,
+        request: services::Transaction,
+    ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
+        Box::pin(async { AddressBookServiceClient::new(channel).create_node(request).await })
+    }
+}
+
+impl ValidateChecksums for NodeCreateTransactionData {
+    fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
+        self.account_id.validate_checksums(ledger_id)
+    }
+}
+
+impl ToTransactionDataProtobuf for NodeCreateTransactionData {
+    fn to_transaction_data_protobuf(
+        &self,
+        chunk_info: &ChunkInfo,
+    ) -> services::transaction_body::Data {
+        let _ = chunk_info.assert_single_transaction();
+
+        services::transaction_body::Data::NodeCreate(self.to_protobuf())
+    }
+}
+
+impl ToSchedulableTransactionDataProtobuf for NodeCreateTransactionData {
+    fn to_schedulable_transaction_data_protobuf(
+        &self,
+    ) -> services::schedulable_transaction_body::Data {
+        services::schedulable_transaction_body::Data::NodeCreate(self.to_protobuf())
+    }
+}
+
+impl From<NodeCreateTransactionData> for AnyTransactionData {
+    fn from(transaction: NodeCreateTransactionData) -> Self {
+        Self::NodeCreate(transaction)
+    }
+}
+
+impl FromProtobuf<services::NodeCreateTransactionBody> for NodeCreateTransactionData {
+    fn from_protobuf(pb: services::NodeCreateTransactionBody) -> crate::Result<Self> {
+        Ok(Self {
+            account_id: Option::from_protobuf(pb.account_id)?,
+            description: pb.description,
+            gossip_endpoints: pb
+                .gossip_endpoint
+                .into_iter()
+                .map(service_endpoint_from_protobuf)
+                .collect::<crate::Result<_>>()?,
+            service_endpoints: pb
+                .service_endpoint
+                .into_iter()
+                .map(service_endpoint_from_protobuf)
+                .collect::<crate::Result<_>>()?,
+            gossip_ca_certificate: pb.gossip_ca_certificate,
+            grpc_certificate_hash: pb.grpc_certificate_hash,
+            admin_key: Option::from_protobuf(pb.admin_key)?,
+        })
+    }
+}
+
+impl ToProtobuf for NodeCreateTransactionData {
+    type Protobuf = services::NodeCreateTransactionBody;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::NodeCreateTransactionBody {
+            account_id: self.account_id.to_protobuf(),
+            description: self.description.clone(),
+            gossip_endpoint: self
+                .gossip_endpoints
+                .iter()
+                .map(service_endpoint_to_protobuf)
+                .collect(),
+            service_endpoint: self
+                .service_endpoints
+                .iter()
+                .map(service_endpoint_to_protobuf)
+                .collect(),
+            gossip_ca_certificate: self.gossip_ca_certificate.clone(),
+            grpc_certificate_hash: self.grpc_certificate_hash.clone(),
+            admin_key: self.admin_key.to_protobuf(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{
+        Ipv4Addr,
+        SocketAddrV4,
+    };
+
+    use expect_test::expect_file;
+    use hedera_proto::services;
+
+    use crate::address_book::NodeCreateTransactionData;
+    use crate::protobuf::{
+        FromProtobuf,
+        ToProtobuf,
+    };
+    use crate::transaction::test_helpers::{
+        check_body,
+        transaction_body,
+        unused_private_key,
+        TEST_ACCOUNT_ID,
+    };
+    use crate::{
+        AnyTransaction,
+        Key,
+        NodeCreateTransaction,
+    };
+
+    const TEST_DESCRIPTION: &str = "test description";
+    const TEST_GOSSIP_ENDPOINT: SocketAddrV4 =
+        SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 50111);
+    const TEST_SERVICE_ENDPOINT: SocketAddrV4 =
+        SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 2), 50211);
+    const TEST_GOSSIP_CA_CERTIFICATE: [u8; 4] = [1, 2, 3, 4];
+    const TEST_GRPC_CERTIFICATE_HASH: [u8; 4] = [5, 6, 7, 8];
+
+    fn make_transaction() -> NodeCreateTransaction {
+        let mut tx = NodeCreateTransaction::new_for_tests();
+
+        tx.account_id(TEST_ACCOUNT_ID)
+            .description(TEST_DESCRIPTION)
+            .gossip_endpoints([TEST_GOSSIP_ENDPOINT])
+            .service_endpoints([TEST_SERVICE_ENDPOINT])
+            .gossip_ca_certificate(TEST_GOSSIP_CA_CERTIFICATE.to_vec())
+            .grpc_certificate_hash(TEST_GRPC_CERTIFICATE_HASH.to_vec())
+            .admin_key(unused_private_key().public_key())
+            .freeze()
+            .unwrap();
+
+        tx
+    }
+
+    #[test]
+    fn serialize() {
+        let tx = make_transaction();
+
+        let tx = transaction_body(tx);
+
+        let tx = check_body(tx);
+
+        expect_file!["./snapshots/node_create_transaction/serialize.txt"].assert_debug_eq(&tx);
+    }
+
+    #[test]
+    fn to_from_bytes() {
+        let tx = make_transaction();
+
+        let tx2 = AnyTransaction::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+
+        let tx = transaction_body(tx);
+        let tx2 = transaction_body(tx2);
+
+        assert_eq!(tx, tx2);
+    }
+
+    #[test]
+    fn from_proto_body() {
+        let tx = services::NodeCreateTransactionBody {
+            account_id: Some(TEST_ACCOUNT_ID.to_protobuf()),
+            description: TEST_DESCRIPTION.to_owned(),
+            gossip_endpoint: Vec::from([services::ServiceEndpoint {
+                ip_address_v4: Vec::from([127, 0, 0, 1]),
+                port: 50111,
+                domain_name: String::new(),
+            }]),
+            service_endpoint: Vec::new(),
+            gossip_ca_certificate: TEST_GOSSIP_CA_CERTIFICATE.to_vec(),
+            grpc_certificate_hash: TEST_GRPC_CERTIFICATE_HASH.to_vec(),
+            admin_key: Some(unused_private_key().public_key().to_protobuf()),
+        };
+
+        let data = NodeCreateTransactionData::from_protobuf(tx).unwrap();
+
+        assert_eq!(data.account_id, Some(TEST_ACCOUNT_ID));
+        assert_eq!(data.description, TEST_DESCRIPTION);
+        assert_eq!(data.gossip_endpoints, [TEST_GOSSIP_ENDPOINT]);
+        assert!(data.service_endpoints.is_empty());
+        assert_eq!(data.gossip_ca_certificate, TEST_GOSSIP_CA_CERTIFICATE);
+        assert_eq!(data.grpc_certificate_hash, TEST_GRPC_CERTIFICATE_HASH);
+        assert_eq!(data.admin_key, Some(Key::from(unused_private_key().public_key())));
+    }
+
+    #[test]
+    fn get_set_account_id() {
+        let mut tx = NodeCreateTransaction::new();
+        tx.account_id(TEST_ACCOUNT_ID);
+
+        assert_eq!(tx.get_account_id(), Some(TEST_ACCOUNT_ID));
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_account_id_frozen_panic() {
+        make_transaction().account_id(TEST_ACCOUNT_ID);
+    }
+
+    #[test]
+    fn get_set_description() {
+        let mut tx = NodeCreateTransaction::new();
+        tx.description(TEST_DESCRIPTION);
+
+        assert_eq!(tx.get_description(), TEST_DESCRIPTION);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_description_frozen_panic() {
+        make_transaction().description(TEST_DESCRIPTION);
+    }
+
+    #[test]
+    fn get_set_gossip_endpoints() {
+        let mut tx = NodeCreateTransaction::new();
+        tx.gossip_endpoints([TEST_GOSSIP_ENDPOINT]);
+
+        assert_eq!(tx.get_gossip_endpoints(), &[TEST_GOSSIP_ENDPOINT]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_gossip_endpoints_frozen_panic() {
+        make_transaction().gossip_endpoints([TEST_GOSSIP_ENDPOINT]);
+    }
+
+    #[test]
+    fn get_set_service_endpoints() {
+        let mut tx = NodeCreateTransaction::new();
+        tx.service_endpoints([TEST_SERVICE_ENDPOINT]);
+
+        assert_eq!(tx.get_service_endpoints(), &[TEST_SERVICE_ENDPOINT]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_service_endpoints_frozen_panic() {
+        make_transaction().service_endpoints([TEST_SERVICE_ENDPOINT]);
+    }
+
+    #[test]
+    fn get_set_gossip_ca_certificate() {
+        let mut tx = NodeCreateTransaction::new();
+        tx.gossip_ca_certificate(TEST_GOSSIP_CA_CERTIFICATE.to_vec());
+
+        assert_eq!(tx.get_gossip_ca_certificate(), TEST_GOSSIP_CA_CERTIFICATE);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_gossip_ca_certificate_frozen_panic() {
+        make_transaction().gossip_ca_certificate(TEST_GOSSIP_CA_CERTIFICATE.to_vec());
+    }
+
+    #[test]
+    fn get_set_grpc_certificate_hash() {
+        let mut tx = NodeCreateTransaction::new();
+        tx.grpc_certificate_hash(TEST_GRPC_CERTIFICATE_HASH.to_vec());
+
+        assert_eq!(tx.get_grpc_certificate_hash(), TEST_GRPC_CERTIFICATE_HASH);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_grpc_certificate_hash_frozen_panic() {
+        make_transaction().grpc_certificate_hash(TEST_GRPC_CERTIFICATE_HASH.to_vec());
+    }
+
+    #[test]
+    fn get_set_admin_key() {
+        let mut tx = NodeCreateTransaction::new();
+        tx.admin_key(unused_private_key().public_key());
+
+        assert_eq!(tx.get_admin_key(), Some(&unused_private_key().public_key().into()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_admin_key_frozen_panic() {
+        make_transaction().admin_key(unused_private_key().public_key());
+    }
+}
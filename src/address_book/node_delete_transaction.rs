@@ -0,0 +1,200 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::services;
+use hedera_proto::services::address_book_service_client::AddressBookServiceClient;
+use tonic::transport::Channel;
+
+use crate::ledger_id::RefLedgerId;
+use crate::protobuf::{
+    FromProtobuf,
+    ToProtobuf,
+};
+use crate::transaction::{
+    AnyTransactionData,
+    ChunkInfo,
+    ToSchedulableTransactionDataProtobuf,
+    ToTransactionDataProtobuf,
+    TransactionData,
+    TransactionExecute,
+};
+use crate::{
+    BoxGrpcFuture,
+    Error,
+    Transaction,
+    ValidateChecksums,
+};
+
+/// Remove a consensus node from the network address book.
+///
+/// This transaction must be signed by the Hedera council, or by the node's `admin_key`.
+///
+/// The node keeps participating in the network until the network is upgraded.
+pub type NodeDeleteTransaction = Transaction<NodeDeleteTransactionData>;
+
+#[derive(Debug, Clone, Default)]
+pub struct NodeDeleteTransactionData {
+    /// The ID of the node to delete.
+    node_id: u64,
+}
+
+impl NodeDeleteTransaction {
+    /// Returns the ID of the node to delete.
+    #[must_use]
+    pub fn get_node_id(&self) -> u64 {
+        self.data().node_id
+    }
+
+    /// Sets the ID of the node to delete.
+    pub fn node_id(&mut self, node_id: u64) -> &mut Self {
+        self.data_mut().node_id = node_id;
+        self
+    }
+}
+
+impl TransactionData for NodeDeleteTransactionData {}
+
+impl TransactionExecute for NodeDeleteTransactionData {
+    fn execute(
+        &self,
+        channel: Channel,
+        request: services::Transaction,
+    ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
+        Box::pin(async { AddressBookServiceClient::new(channel).delete_node(request).await })
+    }
+}
+
+impl ValidateChecksums for NodeDeleteTransactionData {
+    fn validate_checksums(&self, _ledger_id: &RefLedgerId) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl ToTransactionDataProtobuf for NodeDeleteTransactionData {
+    fn to_transaction_data_protobuf(
+        &self,
+        chunk_info: &ChunkInfo,
+    ) -> services::transaction_body::Data {
+        let _ = chunk_info.assert_single_transaction();
+
+        services::transaction_body::Data::NodeDelete(self.to_protobuf())
+    }
+}
+
+impl ToSchedulableTransactionDataProtobuf for NodeDeleteTransactionData {
+    fn to_schedulable_transaction_data_protobuf(
+        &self,
+    ) -> services::schedulable_transaction_body::Data {
+        services::schedulable_transaction_body::Data::NodeDelete(self.to_protobuf())
+    }
+}
+
+impl From<NodeDeleteTransactionData> for AnyTransactionData {
+    fn from(transaction: NodeDeleteTransactionData) -> Self {
+        Self::NodeDelete(transaction)
+    }
+}
+
+impl FromProtobuf<services::NodeDeleteTransactionBody> for NodeDeleteTransactionData {
+    fn from_protobuf(pb: services::NodeDeleteTransactionBody) -> crate::Result<Self> {
+        Ok(Self { node_id: pb.node_id })
+    }
+}
+
+impl ToProtobuf for NodeDeleteTransactionData {
+    type Protobuf = services::NodeDeleteTransactionBody;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::NodeDeleteTransactionBody { node_id: self.node_id }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect_file;
+    use hedera_proto::services;
+
+    use crate::address_book::NodeDeleteTransactionData;
+    use crate::protobuf::FromProtobuf;
+    use crate::transaction::test_helpers::{
+        check_body,
+        transaction_body,
+    };
+    use crate::{
+        AnyTransaction,
+        NodeDeleteTransaction,
+    };
+
+    const TEST_NODE_ID: u64 = 1;
+
+    fn make_transaction() -> NodeDeleteTransaction {
+        let mut tx = NodeDeleteTransaction::new_for_tests();
+
+        tx.node_id(TEST_NODE_ID).freeze().unwrap();
+
+        tx
+    }
+
+    #[test]
+    fn serialize() {
+        let tx = make_transaction();
+
+        let tx = transaction_body(tx);
+
+        let tx = check_body(tx);
+
+        expect_file!["./snapshots/node_delete_transaction/serialize.txt"].assert_debug_eq(&tx);
+    }
+
+    #[test]
+    fn to_from_bytes() {
+        let tx = make_transaction();
+
+        let tx2 = AnyTransaction::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+
+        let tx = transaction_body(tx);
+        let tx2 = transaction_body(tx2);
+
+        assert_eq!(tx, tx2);
+    }
+
+    #[test]
+    fn from_proto_body() {
+        let tx = services::NodeDeleteTransactionBody { node_id: TEST_NODE_ID };
+
+        let data = NodeDeleteTransactionData::from_protobuf(tx).unwrap();
+
+        assert_eq!(data.node_id, TEST_NODE_ID);
+    }
+
+    #[test]
+    fn get_set_node_id() {
+        let mut tx = NodeDeleteTransaction::new();
+        tx.node_id(TEST_NODE_ID);
+
+        assert_eq!(tx.get_node_id(), TEST_NODE_ID);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_node_id_frozen_panic() {
+        make_transaction().node_id(TEST_NODE_ID);
+    }
+}
@@ -0,0 +1,503 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::net::SocketAddrV4;
+
+use hedera_proto::services;
+use hedera_proto::services::address_book_service_client::AddressBookServiceClient;
+use tonic::transport::Channel;
+
+use crate::ledger_id::RefLedgerId;
+use crate::node_address::{
+    service_endpoint_from_protobuf,
+    service_endpoint_to_protobuf,
+};
+use crate::protobuf::{
+    FromProtobuf,
+    ToProtobuf,
+};
+use crate::transaction::{
+    AnyTransactionData,
+    ChunkInfo,
+    ToSchedulableTransactionDataProtobuf,
+    ToTransactionDataProtobuf,
+    TransactionData,
+    TransactionExecute,
+};
+use crate::{
+    AccountId,
+    BoxGrpcFuture,
+    Error,
+    Key,
+    Transaction,
+    ValidateChecksums,
+};
+
+/// Modify the attributes of a consensus node in the network address book.
+///
+/// This transaction must be signed by the node's current `admin_key`, and by the new `admin_key`
+/// if it's being changed. Any field that isn't set is left unchanged.
+///
+/// Changes don't take effect until the network is upgraded.
+pub type NodeUpdateTransaction = Transaction<NodeUpdateTransactionData>;
+
+#[derive(Debug, Clone, Default)]
+pub struct NodeUpdateTransactionData {
+    /// The ID of the node to update.
+    node_id: u64,
+
+    /// The new account to be paid for queries and transactions sent to the node.
+    account_id: Option<AccountId>,
+
+    /// The new description of the node.
+    description: Option<String>,
+
+    /// The new gossip endpoints of the node.
+    gossip_endpoints: Vec<SocketAddrV4>,
+
+    /// The new gRPC service endpoints of the node.
+    service_endpoints: Vec<SocketAddrV4>,
+
+    /// The node's new DER encoded certificate used to sign gossip events.
+    gossip_ca_certificate: Option<Vec<u8>>,
+
+    /// The new SHA-384 hash of the node's gRPC TLS certificate.
+    grpc_certificate_hash: Option<Vec<u8>>,
+
+    /// The new key that must sign updates to the node.
+    admin_key: Option<Key>,
+}
+
+impl NodeUpdateTransaction {
+    /// Returns the ID of the node to update.
+    #[must_use]
+    pub fn get_node_id(&self) -> u64 {
+        self.data().node_id
+    }
+
+    /// Sets the ID of the node to update.
+    pub fn node_id(&mut self, node_id: u64) -> &mut Self {
+        self.data_mut().node_id = node_id;
+        self
+    }
+
+    /// Returns the new account to be paid for queries and transactions sent to the node.
+    #[must_use]
+    pub fn get_account_id(&self) -> Option<AccountId> {
+        self.data().account_id
+    }
+
+    /// Sets the new account to be paid for queries and transactions sent to the node.
+    pub fn account_id(&mut self, account_id: AccountId) -> &mut Self {
+        self.data_mut().account_id = Some(account_id);
+        self
+    }
+
+    /// Returns the new description of the node.
+    #[must_use]
+    pub fn get_description(&self) -> Option<&str> {
+        self.data().description.as_deref()
+    }
+
+    /// Sets the new description of the node, up to 100 bytes.
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        self.data_mut().description = Some(description.into());
+        self
+    }
+
+    /// Returns the new gossip endpoints of the node.
+    #[must_use]
+    pub fn get_gossip_endpoints(&self) -> &[SocketAddrV4] {
+        &self.data().gossip_endpoints
+    }
+
+    /// Sets the new gossip endpoints of the node.
+    ///
+    /// If empty, the node's gossip endpoints are left unchanged.
+    pub fn gossip_endpoints(
+        &mut self,
+        gossip_endpoints: impl IntoIterator<Item = SocketAddrV4>,
+    ) -> &mut Self {
+        self.data_mut().gossip_endpoints = gossip_endpoints.into_iter().collect();
+        self
+    }
+
+    /// Returns the new gRPC service endpoints of the node.
+    #[must_use]
+    pub fn get_service_endpoints(&self) -> &[SocketAddrV4] {
+        &self.data().service_endpoints
+    }
+
+    /// Sets the new gRPC service endpoints of the node.
+    ///
+    /// If empty, the node's service endpoints are left unchanged.
+    pub fn service_endpoints(
+        &mut self,
+        service_endpoints: impl IntoIterator<Item = SocketAddrV4>,
+    ) -> &mut Self {
+        self.data_mut().service_endpoints = service_endpoints.into_iter().collect();
+        self
+    }
+
+    /// Returns the node's new DER encoded gossip certificate.
+    #[must_use]
+    pub fn get_gossip_ca_certificate(&self) -> Option<&[u8]> {
+        self.data().gossip_ca_certificate.as_deref()
+    }
+
+    /// Sets the node's new DER encoded certificate, used to sign gossip events.
+    pub fn gossip_ca_certificate(&mut self, gossip_ca_certificate: Vec<u8>) -> &mut Self {
+        self.data_mut().gossip_ca_certificate = Some(gossip_ca_certificate);
+        self
+    }
+
+    /// Returns the new SHA-384 hash of the node's gRPC TLS certificate.
+    #[must_use]
+    pub fn get_grpc_certificate_hash(&self) -> Option<&[u8]> {
+        self.data().grpc_certificate_hash.as_deref()
+    }
+
+    /// Sets the new SHA-384 hash of the node's gRPC TLS certificate.
+    pub fn grpc_certificate_hash(&mut self, grpc_certificate_hash: Vec<u8>) -> &mut Self {
+        self.data_mut().grpc_certificate_hash = Some(grpc_certificate_hash);
+        self
+    }
+
+    /// Returns the new key that must sign updates to the node.
+    #[must_use]
+    pub fn get_admin_key(&self) -> Option<&Key> {
+        self.data().admin_key.as_ref()
+    }
+
+    /// Sets the new key that must sign updates to the node.
+    pub fn admin_key(&mut self, admin_key: impl Into<Key>) -> &mut Self {
+        self.data_mut().admin_key = Some(admin_key.into());
+        self
+    }
+}
+
+impl TransactionData for NodeUpdateTransactionData {}
+
+impl TransactionExecute for NodeUpdateTransactionData {
+    fn execute(
+        &self,
+        channel: Channel,
+        request: services::Transaction,
+    ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
+        Box::pin(async { AddressBookServiceClient::new(channel).update_node(request).await })
+    }
+}
+
+impl ValidateChecksums for NodeUpdateTransactionData {
+    fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
+        self.account_id.validate_checksums(ledger_id)
+    }
+}
+
+impl ToTransactionDataProtobuf for NodeUpdateTransactionData {
+    fn to_transaction_data_protobuf(
+        &self,
+        chunk_info: &ChunkInfo,
+    ) -> services::transaction_body::Data {
+        let _ = chunk_info.assert_single_transaction();
+
+        services::transaction_body::Data::NodeUpdate(self.to_protobuf())
+    }
+}
+
+impl ToSchedulableTransactionDataProtobuf for NodeUpdateTransactionData {
+    fn to_schedulable_transaction_data_protobuf(
+        &self,
+    ) -> services::schedulable_transaction_body::Data {
+        services::schedulable_transaction_body::Data::NodeUpdate(self.to_protobuf())
+    }
+}
+
+impl From<NodeUpdateTransactionData> for AnyTransactionData {
+    fn from(transaction: NodeUpdateTransactionData) -> Self {
+        Self::NodeUpdate(transaction)
+    }
+}
+
+impl FromProtobuf<services::NodeUpdateTransactionBody> for NodeUpdateTransactionData {
+    fn from_protobuf(pb: services::NodeUpdateTransactionBody) -> crate::Result<Self> {
+        Ok(Self {
+            node_id: pb.node_id,
+            account_id: Option::from_protobuf(pb.account_id)?,
+            description: pb.description,
+            gossip_endpoints: pb
+                .gossip_endpoint
+                .into_iter()
+                .map(service_endpoint_from_protobuf)
+                .collect::<crate::Result<_>>()?,
+            service_endpoints: pb
+                .service_endpoint
+                .into_iter()
+                .map(service_endpoint_from_protobuf)
+                .collect::<crate::Result<_>>()?,
+            gossip_ca_certificate: pb.gossip_ca_certificate,
+            grpc_certificate_hash: pb.grpc_certificate_hash,
+            admin_key: Option::from_protobuf(pb.admin_key)?,
+        })
+    }
+}
+
+impl ToProtobuf for NodeUpdateTransactionData {
+    type Protobuf = services::NodeUpdateTransactionBody;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::NodeUpdateTransactionBody {
+            node_id: self.node_id,
+            account_id: self.account_id.to_protobuf(),
+            description: self.description.clone(),
+            gossip_endpoint: self
+                .gossip_endpoints
+                .iter()
+                .map(service_endpoint_to_protobuf)
+                .collect(),
+            service_endpoint: self
+                .service_endpoints
+                .iter()
+                .map(service_endpoint_to_protobuf)
+                .collect(),
+            gossip_ca_certificate: self.gossip_ca_certificate.clone(),
+            grpc_certificate_hash: self.grpc_certificate_hash.clone(),
+            admin_key: self.admin_key.to_protobuf(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{
+        Ipv4Addr,
+        SocketAddrV4,
+    };
+
+    use expect_test::expect_file;
+    use hedera_proto::services;
+
+    use crate::address_book::NodeUpdateTransactionData;
+    use crate::protobuf::{
+        FromProtobuf,
+        ToProtobuf,
+    };
+    use crate::transaction::test_helpers::{
+        check_body,
+        transaction_body,
+        unused_private_key,
+        TEST_ACCOUNT_ID,
+    };
+    use crate::{
+        AnyTransaction,
+        Key,
+        NodeUpdateTransaction,
+    };
+
+    const TEST_NODE_ID: u64 = 1;
+    const TEST_DESCRIPTION: &str = "test description";
+    const TEST_GOSSIP_ENDPOINT: SocketAddrV4 =
+        SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 50111);
+    const TEST_SERVICE_ENDPOINT: SocketAddrV4 =
+        SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 2), 50211);
+    const TEST_GOSSIP_CA_CERTIFICATE: [u8; 4] = [1, 2, 3, 4];
+    const TEST_GRPC_CERTIFICATE_HASH: [u8; 4] = [5, 6, 7, 8];
+
+    fn make_transaction() -> NodeUpdateTransaction {
+        let mut tx = NodeUpdateTransaction::new_for_tests();
+
+        tx.node_id(TEST_NODE_ID)
+            .account_id(TEST_ACCOUNT_ID)
+            .description(TEST_DESCRIPTION)
+            .gossip_endpoints([TEST_GOSSIP_ENDPOINT])
+            .service_endpoints([TEST_SERVICE_ENDPOINT])
+            .gossip_ca_certificate(TEST_GOSSIP_CA_CERTIFICATE.to_vec())
+            .grpc_certificate_hash(TEST_GRPC_CERTIFICATE_HASH.to_vec())
+            .admin_key(unused_private_key().public_key())
+            .freeze()
+            .unwrap();
+
+        tx
+    }
+
+    #[test]
+    fn serialize() {
+        let tx = make_transaction();
+
+        let tx = transaction_body(tx);
+
+        let tx = check_body(tx);
+
+        expect_file!["./snapshots/node_update_transaction/serialize.txt"].assert_debug_eq(&tx);
+    }
+
+    #[test]
+    fn to_from_bytes() {
+        let tx = make_transaction();
+
+        let tx2 = AnyTransaction::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+
+        let tx = transaction_body(tx);
+        let tx2 = transaction_body(tx2);
+
+        assert_eq!(tx, tx2);
+    }
+
+    #[test]
+    fn from_proto_body() {
+        let tx = services::NodeUpdateTransactionBody {
+            node_id: TEST_NODE_ID,
+            account_id: Some(TEST_ACCOUNT_ID.to_protobuf()),
+            description: Some(TEST_DESCRIPTION.to_owned()),
+            gossip_endpoint: Vec::new(),
+            service_endpoint: Vec::from([services::ServiceEndpoint {
+                ip_address_v4: Vec::from([127, 0, 0, 2]),
+                port: 50211,
+                domain_name: String::new(),
+            }]),
+            gossip_ca_certificate: None,
+            grpc_certificate_hash: Some(TEST_GRPC_CERTIFICATE_HASH.to_vec()),
+            admin_key: Some(unused_private_key().public_key().to_protobuf()),
+        };
+
+        let data = NodeUpdateTransactionData::from_protobuf(tx).unwrap();
+
+        assert_eq!(data.node_id, TEST_NODE_ID);
+        assert_eq!(data.account_id, Some(TEST_ACCOUNT_ID));
+        assert_eq!(data.description.as_deref(), Some(TEST_DESCRIPTION));
+        assert!(data.gossip_endpoints.is_empty());
+        assert_eq!(data.service_endpoints, [TEST_SERVICE_ENDPOINT]);
+        assert_eq!(data.gossip_ca_certificate, None);
+        assert_eq!(data.grpc_certificate_hash.as_deref(), Some(&TEST_GRPC_CERTIFICATE_HASH[..]));
+        assert_eq!(data.admin_key, Some(Key::from(unused_private_key().public_key())));
+    }
+
+    #[test]
+    fn get_set_node_id() {
+        let mut tx = NodeUpdateTransaction::new();
+        tx.node_id(TEST_NODE_ID);
+
+        assert_eq!(tx.get_node_id(), TEST_NODE_ID);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_node_id_frozen_panic() {
+        make_transaction().node_id(TEST_NODE_ID);
+    }
+
+    #[test]
+    fn get_set_account_id() {
+        let mut tx = NodeUpdateTransaction::new();
+        tx.account_id(TEST_ACCOUNT_ID);
+
+        assert_eq!(tx.get_account_id(), Some(TEST_ACCOUNT_ID));
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_account_id_frozen_panic() {
+        make_transaction().account_id(TEST_ACCOUNT_ID);
+    }
+
+    #[test]
+    fn get_set_description() {
+        let mut tx = NodeUpdateTransaction::new();
+        tx.description(TEST_DESCRIPTION);
+
+        assert_eq!(tx.get_description(), Some(TEST_DESCRIPTION));
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_description_frozen_panic() {
+        make_transaction().description(TEST_DESCRIPTION);
+    }
+
+    #[test]
+    fn get_set_gossip_endpoints() {
+        let mut tx = NodeUpdateTransaction::new();
+        tx.gossip_endpoints([TEST_GOSSIP_ENDPOINT]);
+
+        assert_eq!(tx.get_gossip_endpoints(), &[TEST_GOSSIP_ENDPOINT]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_gossip_endpoints_frozen_panic() {
+        make_transaction().gossip_endpoints([TEST_GOSSIP_ENDPOINT]);
+    }
+
+    #[test]
+    fn get_set_service_endpoints() {
+        let mut tx = NodeUpdateTransaction::new();
+        tx.service_endpoints([TEST_SERVICE_ENDPOINT]);
+
+        assert_eq!(tx.get_service_endpoints(), &[TEST_SERVICE_ENDPOINT]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_service_endpoints_frozen_panic() {
+        make_transaction().service_endpoints([TEST_SERVICE_ENDPOINT]);
+    }
+
+    #[test]
+    fn get_set_gossip_ca_certificate() {
+        let mut tx = NodeUpdateTransaction::new();
+        tx.gossip_ca_certificate(TEST_GOSSIP_CA_CERTIFICATE.to_vec());
+
+        assert_eq!(tx.get_gossip_ca_certificate(), Some(&TEST_GOSSIP_CA_CERTIFICATE[..]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_gossip_ca_certificate_frozen_panic() {
+        make_transaction().gossip_ca_certificate(TEST_GOSSIP_CA_CERTIFICATE.to_vec());
+    }
+
+    #[test]
+    fn get_set_grpc_certificate_hash() {
+        let mut tx = NodeUpdateTransaction::new();
+        tx.grpc_certificate_hash(TEST_GRPC_CERTIFICATE_HASH.to_vec());
+
+        assert_eq!(tx.get_grpc_certificate_hash(), Some(&TEST_GRPC_CERTIFICATE_HASH[..]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_grpc_certificate_hash_frozen_panic() {
+        make_transaction().grpc_certificate_hash(TEST_GRPC_CERTIFICATE_HASH.to_vec());
+    }
+
+    #[test]
+    fn get_set_admin_key() {
+        let mut tx = NodeUpdateTransaction::new();
+        tx.admin_key(unused_private_key().public_key());
+
+        assert_eq!(tx.get_admin_key(), Some(&unused_private_key().public_key().into()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_admin_key_frozen_panic() {
+        make_transaction().admin_key(unused_private_key().public_key());
+    }
+}
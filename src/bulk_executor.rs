@@ -0,0 +1,342 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use futures_core::Stream;
+use futures_util::future::BoxFuture;
+use futures_util::{
+    FutureExt,
+    StreamExt,
+};
+
+use crate::transaction::TransactionExecute;
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    Transaction,
+    TransactionId,
+    TransactionReceipt,
+    TransactionRecord,
+    TransactionResponse,
+};
+
+/// Submits many transactions concurrently and streams back their receipts or records.
+///
+/// Transactions without explicit node account IDs are spread across every healthy node in the client's network,
+/// and each transaction is retried with the client's backoff and [`max_attempts`](Client::max_attempts).
+///
+/// The returned streams are lazy: at most [`concurrency`](Self::concurrency) transactions are in flight at once,
+/// and no more are submitted until the stream is polled again.
+#[derive(Debug, Clone)]
+pub struct BulkExecutor {
+    concurrency: usize,
+    validate_status: bool,
+}
+
+impl Default for BulkExecutor {
+    fn default() -> Self {
+        Self { concurrency: Self::DEFAULT_CONCURRENCY, validate_status: true }
+    }
+}
+
+impl BulkExecutor {
+    /// The number of transactions in flight at once, unless set with [`concurrency`](Self::concurrency).
+    pub const DEFAULT_CONCURRENCY: usize = 32;
+
+    /// Create a new `BulkExecutor` with the default settings.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the maximum number of transactions in flight at once.
+    #[must_use]
+    pub fn get_concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    /// Sets the maximum number of transactions in flight at once.
+    ///
+    /// A limit of `0` is treated as `1`.
+    pub fn concurrency(&mut self, limit: usize) -> &mut Self {
+        self.concurrency = limit.max(1);
+        self
+    }
+
+    /// Returns whether a failing receipt status is reported as an error.
+    #[must_use]
+    pub fn get_validate_status(&self) -> bool {
+        self.validate_status
+    }
+
+    /// Sets whether a failing receipt status is reported as an error.
+    ///
+    /// Defaults to `true`, see [`TransactionResponse::validate_status`].
+    pub fn validate_status(&mut self, validate: bool) -> &mut Self {
+        self.validate_status = validate;
+        self
+    }
+
+    /// Execute every transaction in `transactions`, yielding each transaction's ID and receipt as they complete.
+    ///
+    /// Results are yielded in completion order, not submission order.
+    ///
+    /// An item is [`Error::NoPayerAccountOrTransactionId`] if its transaction doesn't have a transaction ID
+    /// and one can't be generated, because `client` has no operator or the transaction is already frozen.
+    pub fn receipts<'a, D, I>(
+        &self,
+        client: &'a Client,
+        transactions: I,
+    ) -> impl Stream<Item = crate::Result<(TransactionId, crate::Result<TransactionReceipt>)>> + Send + 'a
+    where
+        D: TransactionExecute + 'a,
+        I: IntoIterator<Item = Transaction<D>>,
+        I::IntoIter: Send + 'a,
+    {
+        self.stream(client, transactions, |response, client| {
+            async move { response.get_receipt(client).await }.boxed()
+        })
+    }
+
+    /// Execute every transaction in `transactions`, yielding each transaction's ID and record as they complete.
+    ///
+    /// Results are yielded in completion order, not submission order.
+    ///
+    /// An item is [`Error::NoPayerAccountOrTransactionId`] if its transaction doesn't have a transaction ID
+    /// and one can't be generated, because `client` has no operator or the transaction is already frozen.
+    pub fn records<'a, D, I>(
+        &self,
+        client: &'a Client,
+        transactions: I,
+    ) -> impl Stream<Item = crate::Result<(TransactionId, crate::Result<TransactionRecord>)>> + Send + 'a
+    where
+        D: TransactionExecute + 'a,
+        I: IntoIterator<Item = Transaction<D>>,
+        I::IntoIter: Send + 'a,
+    {
+        self.stream(client, transactions, |response, client| {
+            async move { response.get_record(client).await }.boxed()
+        })
+    }
+
+    fn stream<'a, D, I, T>(
+        &self,
+        client: &'a Client,
+        transactions: I,
+        fetch: fn(TransactionResponse, &'a Client) -> BoxFuture<'a, crate::Result<T>>,
+    ) -> impl Stream<Item = crate::Result<(TransactionId, crate::Result<T>)>> + Send + 'a
+    where
+        D: TransactionExecute + 'a,
+        I: IntoIterator<Item = Transaction<D>>,
+        I::IntoIter: Send + 'a,
+        T: Send + 'a,
+    {
+        let validate_status = self.validate_status;
+        let node_ids = node_ids(client);
+        let operator = client.get_operator_account_id();
+
+        // transactions are only taken from `transactions` as the stream is polled.
+        futures_util::stream::iter(transactions.into_iter().enumerate())
+            .map(move |(index, mut transaction)| {
+                if !transaction.is_frozen() {
+                    if transaction.get_node_account_ids().is_none() && !node_ids.is_empty() {
+                        transaction.node_account_ids(rotate(&node_ids, index));
+                    }
+
+                    if let (None, Some(payer)) = (transaction.get_transaction_id(), operator) {
+                        transaction.transaction_id(TransactionId::generate(payer));
+                    }
+                }
+
+                // every result is keyed by its transaction ID, so a transaction without one can't be executed.
+                let transaction_id = transaction.get_transaction_id();
+
+                async move {
+                    let transaction_id =
+                        transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?;
+
+                    let mut response = match transaction.execute(client).await {
+                        Ok(it) => it,
+                        Err(e) => return Ok((transaction_id, Err(e))),
+                    };
+
+                    response.validate_status(validate_status);
+
+                    // the transaction ID may have been regenerated if the original expired.
+                    Ok((response.transaction_id, fetch(response, client).await))
+                }
+            })
+            .buffer_unordered(self.concurrency)
+    }
+}
+
+/// The nodes to spread transactions over, as picked when freezing a transaction.
+fn node_ids(client: &Client) -> Vec<AccountId> {
+    let network = client.net().0.load();
+
+    let healthy: Vec<_> = network.healthy_node_ids().collect();
+
+    if healthy.is_empty() {
+        network.pinned_node_ids().collect()
+    } else {
+        healthy
+    }
+}
+
+/// Picks the nodes for the transaction at `index`.
+///
+/// Every transaction gets the same number of nodes as [`Transaction::freeze_with`] would pick,
+/// but consecutive transactions start at consecutive nodes, so that the load is spread evenly.
+fn rotate(node_ids: &[AccountId], index: usize) -> Vec<AccountId> {
+    let count = (node_ids.len() + 2) / 3;
+
+    node_ids.iter().cycle().skip(index % node_ids.len()).take(count).copied().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rotate;
+    use crate::AccountId;
+
+    fn nodes(nums: impl IntoIterator<Item = u64>) -> Vec<AccountId> {
+        nums.into_iter().map(|num| AccountId::new(0, 0, num)).collect()
+    }
+
+    #[test]
+    fn rotate_spreads_nodes() {
+        let node_ids = nodes(3..=9);
+
+        assert_eq!(rotate(&node_ids, 0), nodes([3, 4, 5]));
+        assert_eq!(rotate(&node_ids, 1), nodes([4, 5, 6]));
+        assert_eq!(rotate(&node_ids, 6), nodes([9, 3, 4]));
+        assert_eq!(rotate(&node_ids, 7), nodes([3, 4, 5]));
+    }
+
+    #[test]
+    fn rotate_single_node() {
+        let node_ids = nodes([3]);
+
+        assert_eq!(rotate(&node_ids, 0), nodes([3]));
+        assert_eq!(rotate(&node_ids, 5), nodes([3]));
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod mock_tests {
+    use std::collections::HashSet;
+
+    use assert_matches::assert_matches;
+    use futures_util::StreamExt;
+
+    use crate::mock::test_helpers::{
+        client,
+        make_transfer,
+    };
+    use crate::{
+        BulkExecutor,
+        Error,
+        MockReply,
+        MockServer,
+        Status,
+        TransferTransaction,
+    };
+
+    fn make_transfers(count: usize) -> Vec<TransferTransaction> {
+        (0..count).map(|_| make_transfer()).collect()
+    }
+
+    #[tokio::test]
+    async fn receipts() {
+        let server = MockServer::start().await.unwrap();
+        let client = client(&server);
+
+        // busy nodes are retried with the client's backoff.
+        server.push_transaction_reply(MockReply::PreCheck(Status::Busy));
+
+        let results: Vec<_> = BulkExecutor::new()
+            .concurrency(4)
+            .receipts(&client, make_transfers(10))
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        assert_eq!(results.len(), 10);
+
+        let ids: HashSet<_> = results.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids.len(), 10);
+
+        for (_, receipt) in results {
+            assert_eq!(receipt.unwrap().status, Status::Success);
+        }
+    }
+
+    #[tokio::test]
+    async fn failures_are_reported_per_transaction() {
+        let server = MockServer::start().await.unwrap();
+        let client = client(&server);
+
+        server.push_transaction_reply(MockReply::PreCheck(Status::InsufficientPayerBalance));
+
+        let results: Vec<_> = BulkExecutor::new()
+            .concurrency(1)
+            .receipts(&client, make_transfers(3))
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        assert_matches!(
+            results[0].1,
+            Err(Error::TransactionPreCheckStatus { status: Status::InsufficientPayerBalance, .. })
+        );
+        assert!(results[1..].iter().all(|(_, it)| it.is_ok()));
+    }
+
+    #[tokio::test]
+    async fn no_operator() {
+        let server = MockServer::start().await.unwrap();
+        let client = server.client();
+
+        let results: Vec<_> =
+            BulkExecutor::new().receipts(&client, [make_transfer()]).collect().await;
+
+        assert_matches!(results[..], [Err(Error::NoPayerAccountOrTransactionId)]);
+    }
+
+    #[tokio::test]
+    async fn transactions_are_taken_lazily() {
+        let server = MockServer::start().await.unwrap();
+        let client = client(&server);
+
+        let taken = std::sync::atomic::AtomicUsize::new(0);
+
+        let transactions = (0..10).map(|_| {
+            taken.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            make_transfer()
+        });
+
+        let mut stream =
+            std::pin::pin!(BulkExecutor::new().concurrency(2).receipts(&client, transactions));
+
+        stream.next().await.unwrap().unwrap().1.unwrap();
+
+        // only enough transactions to fill the concurrency limit (and replace the finished one) were taken.
+        assert!(taken.load(std::sync::atomic::Ordering::Relaxed) <= 3);
+    }
+}
@@ -19,81 +19,474 @@
  */
 
 use std::collections::HashMap;
-use std::str::FromStr;
+use std::fmt;
+use std::path::Path;
+use std::time::Duration;
 
 use crate::signer::AnySigner;
 use crate::{
     AccountId,
+    Error,
+    Hbar,
+    LedgerId,
     PrivateKey,
 };
 
-struct FromStrProxy<T>(T);
-
-impl<'de, T: FromStr> serde::Deserialize<'de> for FromStrProxy<T>
-where
-    T::Err: std::fmt::Display,
-{
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        use serde::de::Error;
-        String::deserialize(deserializer)
-            .and_then(|it| it.parse().map_err(D::Error::custom))
-            .map(Self)
+/// Configuration for a [`Client`](crate::Client).
+///
+/// Every setting other than `network` is optional, settings that aren't present keep the
+/// defaults of the network the client is created for.
+///
+/// Durations are given in milliseconds and hbar amounts in tinybars.
+///
+/// # Examples
+///
+/// ```
+/// use hedera::ClientConfig;
+///
+/// let config = ClientConfig::from_json(
+///     r#"{
+///         "network": "testnet",
+///         "operator": {
+///             "accountId": "0.0.5006",
+///             "privateKey": "302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e10"
+///         },
+///         "maxAttempts": 5,
+///         "requestTimeout": 30000
+///     }"#,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(config.max_attempts, Some(5));
+/// ```
+#[derive(Debug, Clone, serde_derive::Serialize, serde_derive::Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct ClientConfig {
+    /// The account that pays for and signs requests.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operator: Option<OperatorConfig>,
+
+    /// The consensus nodes to send requests to.
+    pub network: NetworkConfig,
+
+    /// The mirror nodes to use, defaults to the mirror network matching `network` when that's a name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mirror_network: Option<MirrorNetworkConfig>,
+
+    /// The ledger ID of the network, used for checksum validation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ledger_id: Option<LedgerId>,
+
+    /// The maximum number of attempts for a request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_attempts: Option<usize>,
+
+    /// The initial backoff between attempts of a request.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::serde_impls::option_millis"
+    )]
+    pub min_backoff: Option<Duration>,
+
+    /// The maximum backoff between attempts of a request.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::serde_impls::option_millis"
+    )]
+    pub max_backoff: Option<Duration>,
+
+    /// The maximum amount of time that will be spent on a request.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::serde_impls::option_millis"
+    )]
+    pub request_timeout: Option<Duration>,
+
+    /// The maximum amount of time that will be spent on a single gRPC call.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::serde_impls::option_millis"
+    )]
+    pub grpc_timeout: Option<Duration>,
+
+    /// The maximum transaction fee used when a transaction doesn't set one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_max_transaction_fee: Option<Hbar>,
+
+    /// The maximum query payment used when a query doesn't set one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_max_query_payment: Option<Hbar>,
+
+    /// Whether entity ID checksums are validated before requests are sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_validate_checksums: Option<bool>,
+
+    /// How often the network is updated from the address book.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::serde_impls::option_millis"
+    )]
+    pub network_update_period: Option<Duration>,
+
+    /// Whether consensus nodes are connected to over TLS.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transport_security: Option<bool>,
+}
+
+impl ClientConfig {
+    /// Create a new config for the given network with every other setting left unset.
+    #[must_use]
+    pub fn new(network: NetworkConfig) -> Self {
+        Self {
+            operator: None,
+            network,
+            mirror_network: None,
+            ledger_id: None,
+            max_attempts: None,
+            min_backoff: None,
+            max_backoff: None,
+            request_timeout: None,
+            grpc_timeout: None,
+            default_max_transaction_fee: None,
+            default_max_query_payment: None,
+            auto_validate_checksums: None,
+            network_update_period: None,
+            transport_security: None,
+        }
     }
+
+    /// Parse a config from JSON.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `json` isn't a valid config, including when it has unknown keys.
+    pub fn from_json(json: &str) -> crate::Result<Self> {
+        serde_json::from_str(json).map_err(Error::basic_parse)
+    }
+
+    /// Returns this config as JSON.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        // a config has string keys and no fallible fields.
+        serde_json::to_string_pretty(self).unwrap()
+    }
+
+    /// Read a JSON config from the file at `path`.
+    ///
+    /// # Errors
+    /// - [`Error::ClientConfig`] if the file can't be read.
+    /// - [`Error::BasicParse`] if the file isn't a valid config.
+    pub fn from_file(path: impl AsRef<Path>) -> crate::Result<Self> {
+        let path = path.as_ref();
+
+        let json = std::fs::read_to_string(path).map_err(|e| {
+            Error::client_config(format!("failed to read `{}`: {e}", path.display()))
+        })?;
+
+        Self::from_json(&json)
+    }
+
+    /// Create a config from environment variables.
+    ///
+    /// - `HEDERA_CONFIG_FILE`: the path of a JSON config, when set every other variable is ignored.
+    /// - `HEDERA_NETWORK`: the name of the network (required otherwise).
+    /// - `OPERATOR_ACCOUNT_ID` and `OPERATOR_KEY`: the operator, either both or neither must be set.
+    ///
+    /// # Errors
+    /// - [`Error::ClientConfig`] if a required variable is missing or a variable can't be parsed.
+    /// - Any error from [`from_file`](Self::from_file) when `HEDERA_CONFIG_FILE` is set.
+    pub fn from_env() -> crate::Result<Self> {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> crate::Result<Self> {
+        if let Some(path) = var("HEDERA_CONFIG_FILE") {
+            return Self::from_file(path);
+        }
+
+        let network = var("HEDERA_NETWORK")
+            .ok_or_else(|| Error::client_config("`HEDERA_NETWORK` is not set"))?;
+
+        let network = serde_json::from_value(serde_json::Value::String(network))
+            .map(NetworkConfig::Name)
+            .map_err(|e| Error::client_config(format!("invalid `HEDERA_NETWORK`: {e}")))?;
+
+        let operator = match (var("OPERATOR_ACCOUNT_ID"), var("OPERATOR_KEY")) {
+            (Some(account_id), Some(private_key)) => Some(OperatorConfig {
+                account_id: account_id.parse().map_err(|e| {
+                    Error::client_config(format!("invalid `OPERATOR_ACCOUNT_ID`: {e}"))
+                })?,
+                private_key,
+                password: None,
+            }),
+            (None, None) => None,
+            _ => {
+                return Err(Error::client_config(
+                    "`OPERATOR_ACCOUNT_ID` and `OPERATOR_KEY` must be set together",
+                ))
+            }
+        };
+
+        Ok(Self { operator, ..Self::new(network) })
+    }
+}
+
+/// The operator of a [`ClientConfig`].
+///
+/// `privateKey` is either a DER or raw hex encoded key, or a PEM encoded key which may be encrypted with `password`.
+///
+/// For compatibility with older configs `account_id` and `private_key` are also accepted.
+#[derive(Clone, serde_derive::Serialize, serde_derive::Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct OperatorConfig {
+    /// The operator's account.
+    #[serde(alias = "account_id")]
+    pub account_id: AccountId,
+
+    /// The operator's private key.
+    #[serde(alias = "private_key")]
+    pub private_key: String,
+
+    /// The password of an encrypted PEM `private_key`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
 }
 
-#[derive(serde_derive::Deserialize)]
-pub(super) struct Operator {
-    account_id: FromStrProxy<AccountId>,
-    private_key: FromStrProxy<PrivateKey>,
+// configs tend to end up in logs, so keep the secrets out of them.
+impl fmt::Debug for OperatorConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OperatorConfig")
+            .field("account_id", &self.account_id)
+            .field("private_key", &"<redacted>")
+            .field("password", &self.password.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl OperatorConfig {
+    /// Create an operator config for `account_id` with the string form of `private_key`.
+    #[must_use]
+    pub fn new(account_id: AccountId, private_key: &PrivateKey) -> Self {
+        Self { account_id, private_key: private_key.to_string(), password: None }
+    }
+
+    fn parse_private_key(&self) -> crate::Result<PrivateKey> {
+        let private_key = self.private_key.as_str();
+
+        match (private_key.trim_start().starts_with("-----BEGIN"), &self.password) {
+            (true, Some(password)) => PrivateKey::from_pem_with_password(private_key, password),
+            (true, None) => PrivateKey::from_pem(private_key),
+            (false, None) => private_key.parse(),
+            (false, Some(_)) => {
+                Err(Error::client_config("`password` is only supported for PEM encoded keys"))
+            }
+        }
+    }
 }
 
-impl From<Operator> for super::Operator {
-    fn from(value: Operator) -> Self {
-        Self { account_id: value.account_id.0, signer: AnySigner::PrivateKey(value.private_key.0) }
+impl TryFrom<&OperatorConfig> for super::Operator {
+    type Error = Error;
+
+    fn try_from(value: &OperatorConfig) -> crate::Result<Self> {
+        Ok(Self {
+            account_id: value.account_id,
+            signer: AnySigner::PrivateKey(value.parse_private_key()?),
+        })
     }
 }
 
-#[derive(serde_derive::Deserialize)]
+/// The consensus network of a [`ClientConfig`].
+///
+/// Either a network name or a map of node addresses to node account IDs.
+#[derive(Debug, Clone, serde_derive::Serialize, serde_derive::Deserialize)]
 #[serde(untagged)]
-pub(super) enum Either<L, R> {
-    Left(L),
-    Right(R),
+#[non_exhaustive]
+pub enum NetworkConfig {
+    /// A well known network.
+    Name(NetworkName),
+
+    /// The address and account ID of each node.
+    Nodes(HashMap<String, AccountId>),
 }
 
-#[derive(serde_derive::Deserialize)]
+/// The mirror network of a [`ClientConfig`].
+///
+/// Either a network name or a list of mirror node addresses.
+#[derive(Debug, Clone, serde_derive::Serialize, serde_derive::Deserialize)]
+#[serde(untagged)]
+#[non_exhaustive]
+pub enum MirrorNetworkConfig {
+    /// The mirror network of a well known network.
+    Name(NetworkName),
+
+    /// The addresses of the mirror nodes.
+    Addresses(Vec<String>),
+}
+
+/// A well known network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde_derive::Serialize, serde_derive::Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub(crate) enum NetworkName {
+#[non_exhaustive]
+pub enum NetworkName {
+    /// Hedera mainnet.
     Mainnet,
+
+    /// Hedera testnet.
     Testnet,
+
+    /// Hedera previewnet.
     Previewnet,
-}
 
-#[derive(serde_derive::Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub(super) struct ClientConfigInner {
-    operator: Option<Operator>,
-    network: Either<HashMap<String, FromStrProxy<AccountId>>, NetworkName>,
-    mirror_network: Option<Either<Vec<String>, NetworkName>>,
+    /// A local node, with consensus at `127.0.0.1:50211` and mirror at `127.0.0.1:5600`.
+    Localhost,
 }
 
-impl From<ClientConfigInner> for ClientConfig {
-    fn from(value: ClientConfigInner) -> Self {
-        Self {
-            operator: value.operator.map(Into::into),
-            network: match value.network {
-                Either::Left(it) => Either::Left(it.into_iter().map(|(k, v)| (k, v.0)).collect()),
-                Either::Right(it) => Either::Right(it),
-            },
-            mirror_network: value.mirror_network,
-        }
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use expect_test::expect;
+
+    use super::{
+        ClientConfig,
+        NetworkConfig,
+        NetworkName,
+    };
+    use crate::{
+        AccountId,
+        Client,
+        Error,
+        Hbar,
+        LedgerId,
+    };
+
+    const PRIVATE_KEY: &str = "302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e10";
+
+    #[test]
+    fn legacy_config() {
+        let config = ClientConfig::from_json(&format!(
+            r#"{{
+                "operator": {{ "account_id": "0.0.5006", "private_key": "{PRIVATE_KEY}" }},
+                "network": {{ "127.0.0.1:50211": "0.0.3" }},
+                "mirrorNetwork": ["127.0.0.1:5600"]
+            }}"#
+        ))
+        .unwrap();
+
+        let operator = config.operator.as_ref().unwrap();
+
+        assert_eq!(operator.account_id, AccountId::new(0, 0, 5006));
+        assert!(matches!(&config.network, NetworkConfig::Nodes(it) if it.len() == 1));
     }
-}
 
-pub(super) struct ClientConfig {
-    pub(super) operator: Option<super::Operator>,
-    pub(super) network: Either<HashMap<String, AccountId>, NetworkName>,
-    pub(super) mirror_network: Option<Either<Vec<String>, NetworkName>>,
+    #[test]
+    fn debug_redacts_secrets() {
+        let config = ClientConfig::from_json(&format!(
+            r#"{{
+                "operator": {{ "accountId": "0.0.5006", "privateKey": "{PRIVATE_KEY}", "password": "hunter2" }},
+                "network": "testnet"
+            }}"#
+        ))
+        .unwrap();
+
+        let debug = format!("{config:?}");
+
+        assert!(debug.contains("0.0.5006"));
+        assert!(!debug.contains(PRIVATE_KEY));
+        assert!(!debug.contains("hunter2"));
+    }
+
+    #[test]
+    fn unknown_keys_are_rejected() {
+        let err =
+            ClientConfig::from_json(r#"{ "network": "testnet", "maxAtempts": 3 }"#).unwrap_err();
+
+        assert!(matches!(err, Error::BasicParse(_)));
+        assert!(err.to_string().contains("unknown field `maxAtempts`"), "{err}");
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut config = ClientConfig::new(NetworkConfig::Nodes(HashMap::from([(
+            "127.0.0.1:50211".to_owned(),
+            AccountId::new(0, 0, 3),
+        )])));
+
+        config.ledger_id = Some(LedgerId::testnet());
+        config.max_attempts = Some(3);
+        config.request_timeout = Some(Duration::from_secs(30));
+        config.default_max_transaction_fee = Some(Hbar::new(5));
+        config.transport_security = Some(false);
+
+        let json = config.to_json();
+
+        expect![[r#"
+            {
+              "network": {
+                "127.0.0.1:50211": "0.0.3"
+              },
+              "ledgerId": "testnet",
+              "maxAttempts": 3,
+              "requestTimeout": 30000,
+              "defaultMaxTransactionFee": 500000000,
+              "transportSecurity": false
+            }"#]]
+        .assert_eq(&json);
+
+        assert_eq!(ClientConfig::from_json(&json).unwrap().to_json(), json);
+    }
+
+    #[test]
+    fn from_vars() {
+        let vars = HashMap::from([
+            ("HEDERA_NETWORK", "previewnet"),
+            ("OPERATOR_ACCOUNT_ID", "0.0.5006"),
+            ("OPERATOR_KEY", PRIVATE_KEY),
+        ]);
+
+        let config =
+            ClientConfig::from_vars(|name| vars.get(name).map(|it| (*it).to_owned())).unwrap();
+
+        assert!(matches!(config.network, NetworkConfig::Name(NetworkName::Previewnet)));
+        assert_eq!(config.operator.unwrap().account_id, AccountId::new(0, 0, 5006));
+
+        let err = ClientConfig::from_vars(|name| {
+            (name == "OPERATOR_KEY").then(|| PRIVATE_KEY.to_owned())
+        })
+        .unwrap_err();
+
+        assert!(matches!(err, Error::ClientConfig(_)));
+    }
+
+    #[test]
+    fn client_settings() {
+        let client = Client::from_config(&format!(
+            r#"{{
+                "network": {{ "127.0.0.1:50211": "0.0.3" }},
+                "operator": {{ "accountId": "0.0.5006", "privateKey": "{PRIVATE_KEY}" }},
+                "maxAttempts": 4,
+                "minBackoff": 100,
+                "maxBackoff": 2000,
+                "grpcTimeout": 5000,
+                "defaultMaxQueryPayment": 100000000,
+                "autoValidateChecksums": true
+            }}"#
+        ))
+        .unwrap();
+
+        assert_eq!(client.get_operator_account_id(), Some(AccountId::new(0, 0, 5006)));
+        assert_eq!(client.max_attempts(), 4);
+        assert_eq!(client.min_backoff(), Duration::from_millis(100));
+        assert_eq!(client.max_backoff(), Duration::from_secs(2));
+        assert_eq!(client.grpc_timeout(), Some(Duration::from_secs(5)));
+        assert_eq!(client.default_max_query_payment(), Some(Hbar::new(1)));
+        assert!(client.auto_validate_checksums());
+    }
 }
@@ -40,10 +40,23 @@ pub(crate) use operator::Operator;
 use parking_lot::RwLock;
 use tokio::sync::watch;
 use triomphe::Arc;
+use unsize::{
+    CoerceUnsize,
+    Coercion,
+};
 
 use self::network::managed::ManagedNetwork;
-use self::network::mirror::MirrorNetwork;
 pub(crate) use self::network::mirror::MirrorNetworkData;
+use self::network::mirror::{
+    self,
+    MirrorNetwork,
+};
+pub use self::network::status::{
+    NetworkStatus,
+    NodeStatus,
+};
+pub(crate) use self::network::tls::is_certificate_hash_mismatch;
+use crate::execute_policy::ExecutePolicy;
 use crate::ping_query::PingQuery;
 use crate::signer::AnySigner;
 use crate::{
@@ -53,13 +66,26 @@ use crate::{
     Hbar,
     LedgerId,
     NodeAddressBook,
+    NodeSelector,
     PrivateKey,
     PublicKey,
+    RequestHook,
+    RetryPolicy,
+    Signer,
 };
 
 #[cfg(feature = "serde")]
 mod config;
 
+#[cfg(feature = "serde")]
+pub use config::{
+    ClientConfig,
+    MirrorNetworkConfig,
+    NetworkConfig,
+    NetworkName,
+    OperatorConfig,
+};
+
 mod network;
 mod operator;
 
@@ -156,6 +182,8 @@ impl ClientBuilder {
             regenerate_transaction_ids: AtomicBool::new(regenerate_transaction_ids),
             network_update_tx,
             backoff: RwLock::new(backoff),
+            policy: RwLock::new(ExecutePolicy::default()),
+            request_hook: RwLock::new(None),
         }))
     }
 }
@@ -170,6 +198,8 @@ struct ClientInner {
     regenerate_transaction_ids: AtomicBool,
     network_update_tx: watch::Sender<Option<Duration>>,
     backoff: RwLock<ClientBackoff>,
+    policy: RwLock<ExecutePolicy>,
+    request_hook: RwLock<Option<Arc<dyn RequestHook>>>,
 }
 
 /// Managed client for use on the Hedera network.
@@ -184,53 +214,118 @@ impl fmt::Debug for Client {
 }
 
 impl Client {
+    /// Create a client from the given config.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if a node address can't be parsed.
+    /// - [`Error::KeyParse`] if the operator's private key can't be parsed.
+    /// - [`Error::ClientConfig`] if the operator has a `password` for a key that isn't PEM encoded.
     #[cfg(feature = "serde")]
-    fn from_config_data(config: config::ClientConfig) -> crate::Result<Self> {
-        let config::ClientConfig { operator, network, mirror_network } = config;
-
+    pub fn from_client_config(config: &ClientConfig) -> crate::Result<Self> {
         // fixme: check to ensure net and mirror net are the same when they're a network name (no other SDK actually checks this though)
-        let client = match network {
-            config::Either::Left(network) => Client::for_network(network)?,
-            config::Either::Right(it) => match it {
-                config::NetworkName::Mainnet => Client::for_mainnet(),
-                config::NetworkName::Testnet => Client::for_testnet(),
-                config::NetworkName::Previewnet => Client::for_previewnet(),
-            },
+        let client = match &config.network {
+            NetworkConfig::Nodes(network) => Client::for_network(network.clone())?,
+            NetworkConfig::Name(it) => Self::for_network_name(*it),
         };
 
-        let mirror_network = mirror_network.map(|mirror_network| match mirror_network {
-            config::Either::Left(mirror_network) => {
-                MirrorNetwork::from_addresses(mirror_network.into_iter().map(Cow::Owned).collect())
-            }
-            config::Either::Right(it) => match it {
-                config::NetworkName::Mainnet => MirrorNetwork::mainnet(),
-                config::NetworkName::Testnet => MirrorNetwork::testnet(),
-                config::NetworkName::Previewnet => MirrorNetwork::previewnet(),
+        let mirror_network = config.mirror_network.as_ref().map(|it| match it {
+            MirrorNetworkConfig::Addresses(mirror_network) => MirrorNetwork::from_addresses(
+                mirror_network.iter().cloned().map(Cow::Owned).collect(),
+            ),
+            MirrorNetworkConfig::Name(it) => match it {
+                NetworkName::Mainnet => MirrorNetwork::mainnet(),
+                NetworkName::Testnet => MirrorNetwork::testnet(),
+                NetworkName::Previewnet => MirrorNetwork::previewnet(),
+                NetworkName::Localhost => {
+                    MirrorNetwork::from_addresses(vec![Cow::Borrowed(mirror::LOCALHOST)])
+                }
             },
         });
 
-        if let Some(operator) = operator {
-            client.0.operator.store(Some(Arc::new(operator)));
+        if let Some(operator) = &config.operator {
+            client.0.operator.store(Some(Arc::new(Operator::try_from(operator)?)));
         }
 
         if let Some(mirror_network) = mirror_network {
             client.set_mirror_network(mirror_network.load().addresses());
         }
 
+        if let Some(ledger_id) = &config.ledger_id {
+            client.set_ledger_id(Some(ledger_id.clone()));
+        }
+
+        {
+            let mut backoff = client.0.backoff.write();
+
+            if let Some(max_attempts) = config.max_attempts {
+                backoff.max_attempts = max_attempts;
+            }
+
+            if let Some(min_backoff) = config.min_backoff {
+                backoff.initial_backoff = min_backoff;
+            }
+
+            if let Some(max_backoff) = config.max_backoff {
+                backoff.max_backoff = max_backoff;
+            }
+
+            backoff.request_timeout = config.request_timeout.or(backoff.request_timeout);
+            backoff.grpc_timeout = config.grpc_timeout.or(backoff.grpc_timeout);
+        }
+
+        if let Some(fee) = config.default_max_transaction_fee {
+            client.set_default_max_transaction_fee(fee);
+        }
+
+        if let Some(payment) = config.default_max_query_payment {
+            client.set_default_max_query_payment(payment);
+        }
+
+        if let Some(value) = config.auto_validate_checksums {
+            client.set_auto_validate_checksums(value);
+        }
+
+        if let Some(period) = config.network_update_period {
+            client.set_network_update_period(Some(period));
+        }
+
+        if let Some(transport_security) = config.transport_security {
+            client.set_transport_security(transport_security);
+        }
+
         Ok(client)
     }
 
     /// Create a client from the given json config.
     ///
+    /// See [`ClientConfig`] for the accepted settings.
+    ///
     /// # Errors
     /// - [`Error::BasicParse`] if an error occurs parsing the configuration.
+    /// - Any error from [`from_client_config`](Self::from_client_config).
     #[cfg(feature = "serde")]
     pub fn from_config(json: &str) -> crate::Result<Self> {
-        let config = serde_json::from_str::<config::ClientConfigInner>(json)
-            .map_err(crate::Error::basic_parse)?
-            .into();
+        Self::from_client_config(&ClientConfig::from_json(json)?)
+    }
 
-        Self::from_config_data(config)
+    /// Create a client from the json config in the file at `path`.
+    ///
+    /// # Errors
+    /// - Any error from [`ClientConfig::from_file`].
+    /// - Any error from [`from_client_config`](Self::from_client_config).
+    #[cfg(feature = "serde")]
+    pub fn from_config_file(path: impl AsRef<std::path::Path>) -> crate::Result<Self> {
+        Self::from_client_config(&ClientConfig::from_file(path)?)
+    }
+
+    /// Create a client from environment variables, see [`ClientConfig::from_env`].
+    ///
+    /// # Errors
+    /// - Any error from [`ClientConfig::from_env`].
+    /// - Any error from [`from_client_config`](Self::from_client_config).
+    #[cfg(feature = "serde")]
+    pub fn from_env() -> crate::Result<Self> {
+        Self::from_client_config(&ClientConfig::from_env()?)
     }
 
     /// Returns the addresses for the configured mirror network.
@@ -320,6 +415,22 @@ impl Client {
         self.net().update_from_address_book(&address_book);
     }
 
+    /// Sets whether or not to connect to consensus nodes over TLS.
+    ///
+    /// When enabled, each node's certificate is checked against the certificate hash from the address book,
+    /// a node that doesn't have a certificate hash (such as one from [`set_network`](Self::set_network)) can't be connected to.
+    ///
+    /// Defaults to `false`.
+    pub fn set_transport_security(&self, transport_security: bool) {
+        self.net().set_transport_security(transport_security);
+    }
+
+    /// Returns whether or not consensus nodes are connected to over TLS.
+    #[must_use]
+    pub fn transport_security(&self) -> bool {
+        self.net().0.load().transport_security()
+    }
+
     /// Updates the network to use the given addresses.
     ///
     /// Note: This is only really useful if you used `for_network`, because the network can auto-update.
@@ -344,6 +455,57 @@ impl Client {
         self.net().0.load().addresses()
     }
 
+    /// Returns a snapshot of the health of each node in the network.
+    ///
+    /// This includes why a node isn't being used for requests (its last error and when it will be readmitted).
+    #[must_use]
+    pub fn network_status(&self) -> NetworkStatus {
+        self.net().0.load().status()
+    }
+
+    /// Marks the node as unhealthy, so that it isn't used for requests until it's readmitted.
+    ///
+    /// A node that's marked unhealthy repeatedly backs off for longer each time, just like when it returns an error.
+    ///
+    /// # Errors
+    /// - [`Error::NodeAccountUnknown`] if the node isn't in the network.
+    pub fn mark_node_unhealthy(&self, node_account_id: AccountId) -> crate::Result<()> {
+        let network = self.net().0.load();
+
+        let index = network.node_indexes_for_ids(&[node_account_id])?[0];
+
+        network.mark_node_unhealthy(index);
+
+        Ok(())
+    }
+
+    /// Removes the node from the network.
+    ///
+    /// If network auto-updating is enabled the node will be added back by the next update.
+    ///
+    /// # Errors
+    /// - [`Error::NodeAccountUnknown`] if the node isn't in the network.
+    pub fn remove_node(&self, node_account_id: AccountId) -> crate::Result<()> {
+        self.net().remove_node(node_account_id)
+    }
+
+    /// Restricts requests to the given nodes.
+    ///
+    /// This only applies to requests that don't explicitly set their node account IDs.
+    /// Passing an empty list removes the restriction.
+    ///
+    /// # Errors
+    /// - [`Error::NodeAccountUnknown`] if any of the nodes isn't in the network.
+    pub fn set_pinned_nodes(&self, node_account_ids: &[AccountId]) -> crate::Result<()> {
+        self.net().set_pinned_nodes(Some(node_account_ids))
+    }
+
+    /// Returns the nodes that requests are restricted to, if any.
+    #[must_use]
+    pub fn pinned_nodes(&self) -> Option<Vec<AccountId>> {
+        self.network_status().pinned_nodes
+    }
+
     /// Returns the max number of times a node can be retried before removing it from the network.
     pub fn max_node_attempts(&self) -> Option<NonZeroUsize> {
         self.net().0.load().max_node_attempts()
@@ -385,18 +547,30 @@ impl Client {
             "mainnet" => Ok(Self::for_mainnet()),
             "testnet" => Ok(Self::for_testnet()),
             "previewnet" => Ok(Self::for_previewnet()),
-            "localhost" => {
-                let mut network: HashMap<String, AccountId> = HashMap::new();
-                network.insert("127.0.0.1:50211".to_string(), AccountId::new(0, 0, 3));
-
-                let client = Client::for_network(network).unwrap();
-                client.set_mirror_network(["127.0.0.1:5600".to_string()]);
-                Ok(client)
-            }
+            "localhost" => Ok(Self::for_localhost()),
             _ => Err(Error::basic_parse(format!("Unknown network name {name}"))),
         }
     }
 
+    fn for_localhost() -> Self {
+        let mut network: HashMap<String, AccountId> = HashMap::new();
+        network.insert("127.0.0.1:50211".to_string(), AccountId::new(0, 0, 3));
+
+        let client = Client::for_network(network).unwrap();
+        client.set_mirror_network([mirror::LOCALHOST.to_owned()]);
+        client
+    }
+
+    #[cfg(feature = "serde")]
+    fn for_network_name(name: NetworkName) -> Self {
+        match name {
+            NetworkName::Mainnet => Self::for_mainnet(),
+            NetworkName::Testnet => Self::for_testnet(),
+            NetworkName::Previewnet => Self::for_previewnet(),
+            NetworkName::Localhost => Self::for_localhost(),
+        }
+    }
+
     // optimized function to avoid allocations/pointer chasing.
     // this shouldn't be exposed because it exposes repr.
     pub(crate) fn ledger_id_internal(&self) -> arc_swap::Guard<Option<Arc<LedgerId>>> {
@@ -464,6 +638,19 @@ impl Client {
         })));
     }
 
+    /// Sets the account that will, by default, be paying for transactions and queries built with
+    /// this client.
+    ///
+    /// The operator account ID is used to generate the default transaction ID for all transactions
+    /// executed with this client.
+    ///
+    /// The operator signer is used to sign all transactions executed by this client.
+    pub fn set_operator_signer<S: Signer + 'static>(&self, id: AccountId, signer: S) {
+        self.0
+            .operator
+            .store(Some(Arc::new(Operator { account_id: id, signer: AnySigner::signer(signer) })));
+    }
+
     /// Gets a reference to the configured network.
     pub(crate) fn net(&self) -> &Network {
         &self.0.network.primary
@@ -521,6 +708,17 @@ impl Client {
         self.0.backoff.write().request_timeout = timeout;
     }
 
+    /// Returns the maximum amount of time that will be spent on a single gRPC call.
+    #[must_use]
+    pub fn grpc_timeout(&self) -> Option<Duration> {
+        self.backoff().grpc_timeout
+    }
+
+    /// Sets the maximum amount of time that will be spent on a single gRPC call.
+    pub fn set_grpc_timeout(&self, timeout: Option<Duration>) {
+        self.0.backoff.write().grpc_timeout = timeout;
+    }
+
     /// Returns the maximum number of attempts for a request.
     #[must_use]
     pub fn max_attempts(&self) -> usize {
@@ -541,8 +739,8 @@ impl Client {
 
     /// Sets the initial backoff for a request being executed.
     #[doc(alias = "set_initial_backoff")]
-    pub fn set_min_backoff(&self, max_backoff: Duration) {
-        self.0.backoff.write().max_backoff = max_backoff;
+    pub fn set_min_backoff(&self, min_backoff: Duration) {
+        self.0.backoff.write().initial_backoff = min_backoff;
     }
 
     /// Returns the maximum amount of time a request will wait between attempts.
@@ -556,6 +754,58 @@ impl Client {
         self.0.backoff.write().max_backoff = max_backoff;
     }
 
+    #[must_use]
+    pub(crate) fn retry_policy(&self) -> Option<Arc<dyn RetryPolicy>> {
+        self.0.policy.read().retry_policy.clone()
+    }
+
+    /// Sets the retry policy used by requests that don't set their own.
+    pub fn set_retry_policy(&self, policy: impl RetryPolicy + 'static) {
+        self.0.policy.write().retry_policy = Some(ExecutePolicy::arc_retry_policy(policy));
+    }
+
+    /// Removes the retry policy set with [`set_retry_policy`](Self::set_retry_policy).
+    ///
+    /// Requests that don't set their own policy go back to an exponential backoff
+    /// between [`min_backoff`](Self::min_backoff) and [`max_backoff`](Self::max_backoff).
+    pub fn clear_retry_policy(&self) {
+        self.0.policy.write().retry_policy = None;
+    }
+
+    #[must_use]
+    pub(crate) fn node_selector(&self) -> Option<Arc<dyn NodeSelector>> {
+        self.0.policy.read().node_selector.clone()
+    }
+
+    /// Sets the node selector used by requests that don't set their own.
+    pub fn set_node_selector(&self, selector: impl NodeSelector + 'static) {
+        self.0.policy.write().node_selector = Some(ExecutePolicy::arc_node_selector(selector));
+    }
+
+    /// Removes the node selector set with [`set_node_selector`](Self::set_node_selector).
+    ///
+    /// Requests that don't set their own selector go back to picking random healthy nodes.
+    pub fn clear_node_selector(&self) {
+        self.0.policy.write().node_selector = None;
+    }
+
+    #[must_use]
+    pub(crate) fn request_hook(&self) -> Option<Arc<dyn RequestHook>> {
+        self.0.request_hook.read().clone()
+    }
+
+    /// Sets the hook called after every attempt to send a request to a node.
+    ///
+    /// This is useful for recording metrics, see [`RequestHook`].
+    pub fn set_request_hook(&self, hook: impl RequestHook + 'static) {
+        *self.0.request_hook.write() = Some(Arc::new(hook).unsize(Coercion!(to dyn RequestHook)));
+    }
+
+    /// Removes the hook set with [`set_request_hook`](Self::set_request_hook).
+    pub fn clear_request_hook(&self) {
+        *self.0.request_hook.write() = None;
+    }
+
     #[must_use]
     pub(crate) fn backoff(&self) -> ClientBackoff {
         *self.0.backoff.read()
@@ -616,7 +866,7 @@ impl Client {
     /// Note that network updates will not affect any in-flight requests.
     pub fn set_network_update_period(&self, period: Option<Duration>) {
         self.0.network_update_tx.send_if_modified(|place| {
-            let changed = *place == period;
+            let changed = *place != period;
             if changed {
                 *place = period;
             }
@@ -637,3 +887,32 @@ impl Client {
         self.load_operator().as_deref().map(|it| it.signer.public_key())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::Client;
+
+    #[tokio::test]
+    async fn set_min_backoff() {
+        let client = Client::for_testnet();
+        let max_backoff = client.max_backoff();
+
+        client.set_min_backoff(Duration::from_millis(50));
+
+        assert_eq!(client.min_backoff(), Duration::from_millis(50));
+        assert_eq!(client.max_backoff(), max_backoff);
+    }
+
+    #[tokio::test]
+    async fn set_network_update_period() {
+        let client = Client::for_testnet();
+
+        client.set_network_update_period(Some(Duration::from_secs(60)));
+        assert_eq!(client.network_update_period(), Some(Duration::from_secs(60)));
+
+        client.set_network_update_period(None);
+        assert_eq!(client.network_update_period(), None);
+    }
+}
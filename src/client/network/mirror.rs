@@ -38,6 +38,8 @@ pub(crate) const TESTNET: &str = "testnet.mirrornode.hedera.com:443";
 
 pub(crate) const PREVIEWNET: &str = "previewnet.mirrornode.hedera.com:443";
 
+pub(crate) const LOCALHOST: &str = "127.0.0.1:5600";
+
 #[derive(Default)]
 pub(crate) struct MirrorNetwork(ArcSwap<MirrorNetworkData>);
 
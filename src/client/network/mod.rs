@@ -20,14 +20,17 @@
 
 pub(super) mod managed;
 pub(super) mod mirror;
+pub(super) mod status;
+pub(super) mod tls;
 
 use std::borrow::Cow;
 use std::collections::{
     BTreeSet,
     HashMap,
+    HashSet,
 };
 use std::fmt;
-use std::net::Ipv4Addr;
+use std::net::SocketAddrV4;
 use std::num::NonZeroUsize;
 use std::str::FromStr;
 use std::time::{
@@ -39,12 +42,18 @@ use backoff::backoff::Backoff;
 use once_cell::sync::OnceCell;
 use parking_lot::RwLock;
 use rand::thread_rng;
+use time::OffsetDateTime;
 use tonic::transport::{
     Channel,
     Endpoint,
+    Uri,
 };
 use triomphe::Arc;
 
+use self::status::{
+    NetworkStatus,
+    NodeStatus,
+};
 use crate::{
     AccountId,
     ArcSwap,
@@ -164,6 +173,26 @@ impl Network {
         // todo: skip the updating whem `map` is the same and `connections` is the same.
         self.rcu(|old| NetworkData::with_address_book(old, address_book));
     }
+
+    pub(crate) fn set_transport_security(&self, transport_security: bool) {
+        if self.0.load().transport_security == transport_security {
+            return;
+        }
+
+        self.rcu(|old| old.with_transport_security(transport_security));
+    }
+
+    pub(crate) fn remove_node(&self, node_account_id: AccountId) -> crate::Result<()> {
+        self.try_rcu(|old| old.without_node(node_account_id))?;
+
+        Ok(())
+    }
+
+    pub(crate) fn set_pinned_nodes(&self, nodes: Option<&[AccountId]>) -> crate::Result<()> {
+        self.try_rcu(|old| old.with_pinned_nodes(nodes))?;
+
+        Ok(())
+    }
 }
 
 impl From<NetworkData> for Network {
@@ -179,8 +208,11 @@ pub(crate) struct NetworkData {
     node_ids: Box<[AccountId]>,
     backoff: RwLock<NodeBackoff>,
     // Health stuff has to be in an Arc because it needs to stick around even if the map changes.
-    health: Box<[Arc<parking_lot::RwLock<NodeHealth>>]>,
+    health: Box<[Arc<parking_lot::RwLock<NodeState>>]>,
     connections: Box<[NodeConnection]>,
+    transport_security: bool,
+    // when set, only these nodes are picked for requests that don't have explicit nodes.
+    pinned: Option<HashSet<AccountId>>,
 }
 
 impl NetworkData {
@@ -209,6 +241,8 @@ impl NetworkData {
             health: health.into_boxed_slice(),
             connections: connections.into_boxed_slice(),
             backoff: NodeBackoff::default().into(),
+            transport_security: false,
+            pinned: None,
         }
     }
 
@@ -221,12 +255,11 @@ impl NetworkData {
         let mut health = Vec::with_capacity(address_book.len());
 
         for (i, address) in address_book.iter().enumerate() {
-            let new: BTreeSet<_> = address
-                .service_endpoints
-                .iter()
-                .filter(|it| it.port() == NodeConnection::PLAINTEXT_PORT)
-                .map(|it| (*it.ip()).into())
-                .collect();
+            let new: BTreeSet<_> =
+                address.service_endpoints.iter().copied().map(HostAndPort::from).collect();
+
+            let certificate_hash = (!address.tls_certificate_hash.is_empty())
+                .then(|| address.tls_certificate_hash.clone());
 
             // if the node is the exact same we want to reuse everything (namely the connections and `healthy`).
             // if the node has different routes then we still want to reuse `healthy` but replace the channel with a new channel.
@@ -234,18 +267,18 @@ impl NetworkData {
             // and, last but not least, if the node doesn't exist in `new` we want to get rid of it.
             let upsert = match old.map.get(&address.node_account_id) {
                 Some(&account) => {
+                    let old_connection = &old.connections[account];
                     let connection =
-                        match old.connections[account].addresses.symmetric_difference(&new).count()
-                        {
-                            0 => old.connections[account].clone(),
-                            _ => NodeConnection { addresses: new, channel: OnceCell::new() },
+                        match old_connection.addresses.symmetric_difference(&new).count() {
+                            0 if old_connection.certificate_hash == certificate_hash => {
+                                old_connection.clone()
+                            }
+                            _ => NodeConnection::new(new, certificate_hash),
                         };
 
                     (old.health[account].clone(), connection)
                 }
-                None => {
-                    (Arc::default(), NodeConnection { addresses: new, channel: OnceCell::new() })
-                }
+                None => (Arc::default(), NodeConnection::new(new, certificate_hash)),
             };
 
             map.insert(address.node_account_id, i);
@@ -254,12 +287,16 @@ impl NetworkData {
             connections.push(upsert.1);
         }
 
+        let pinned = old.retain_pinned(&map);
+
         Self {
             map,
             node_ids: node_ids.into_boxed_slice(),
             health: health.into_boxed_slice(),
             connections: connections.into_boxed_slice(),
             backoff: NodeBackoff::default().into(),
+            transport_security: old.transport_security,
+            pinned,
         }
     }
 
@@ -283,10 +320,12 @@ impl NetworkData {
                     entry.insert(next_index);
                     node_ids.push(*node);
                     // fixme: keep the channel around more.
-                    connections.push(NodeConnection {
-                        addresses: BTreeSet::from([address]),
-                        channel: OnceCell::new(),
-                    });
+                    connections.push(NodeConnection::new(
+                        BTreeSet::from([address]),
+                        self.map
+                            .get(node)
+                            .and_then(|it| self.connections[*it].certificate_hash.clone()),
+                    ));
 
                     health.push(match self.map.get(node) {
                         Some(it) => self.health[*it].clone(),
@@ -296,15 +335,103 @@ impl NetworkData {
             };
         }
 
+        let pinned = self.retain_pinned(&map);
+
         Ok(Self {
             map,
             node_ids: node_ids.into_boxed_slice(),
             health: health.into_boxed_slice(),
             connections: connections.into_boxed_slice(),
             backoff: NodeBackoff::default().into(),
+            transport_security: self.transport_security,
+            pinned,
         })
     }
 
+    fn with_transport_security(&self, transport_security: bool) -> Self {
+        Self {
+            map: self.map.clone(),
+            node_ids: self.node_ids.clone(),
+            backoff: RwLock::new(*self.backoff.read()),
+            health: self.health.clone(),
+            // channels are made for one kind of transport, so they can't be reused.
+            connections: self
+                .connections
+                .iter()
+                .map(|it| NodeConnection::new(it.addresses.clone(), it.certificate_hash.clone()))
+                .collect(),
+            transport_security,
+            pinned: self.pinned.clone(),
+        }
+    }
+
+    fn without_node(&self, node_account_id: AccountId) -> crate::Result<Self> {
+        let removed = *self
+            .map
+            .get(&node_account_id)
+            .ok_or_else(|| Error::NodeAccountUnknown(Box::new(node_account_id)))?;
+
+        let keep = |index: &usize| *index != removed;
+
+        let node_ids: Box<[AccountId]> =
+            (0..self.node_ids.len()).filter(keep).map(|index| self.node_ids[index]).collect();
+
+        let map = node_ids.iter().enumerate().map(|(index, id)| (*id, index)).collect();
+        let pinned = self.retain_pinned(&map);
+
+        Ok(Self {
+            map,
+            node_ids,
+            backoff: RwLock::new(*self.backoff.read()),
+            health: (0..self.health.len())
+                .filter(keep)
+                .map(|index| self.health[index].clone())
+                .collect(),
+            connections: (0..self.connections.len())
+                .filter(keep)
+                .map(|index| self.connections[index].clone())
+                .collect(),
+            transport_security: self.transport_security,
+            pinned,
+        })
+    }
+
+    /// Returns the pinned nodes that are still in `map`.
+    ///
+    /// If none of them are left, every node is unpinned rather than leaving nothing to pick from.
+    fn retain_pinned(&self, map: &HashMap<AccountId, usize>) -> Option<HashSet<AccountId>> {
+        let pinned: HashSet<_> =
+            self.pinned.as_ref()?.iter().copied().filter(|it| map.contains_key(it)).collect();
+
+        (!pinned.is_empty()).then_some(pinned)
+    }
+
+    fn with_pinned_nodes(&self, nodes: Option<&[AccountId]>) -> crate::Result<Self> {
+        let pinned = match nodes {
+            Some(nodes) if !nodes.is_empty() => {
+                // make sure every node exists, so that a typo doesn't quietly pin nothing.
+                self.node_indexes_for_ids(nodes)?;
+
+                Some(nodes.iter().copied().collect())
+            }
+            _ => None,
+        };
+
+        Ok(Self {
+            map: self.map.clone(),
+            node_ids: self.node_ids.clone(),
+            backoff: RwLock::new(*self.backoff.read()),
+            health: self.health.clone(),
+            connections: self.connections.clone(),
+            transport_security: self.transport_security,
+            pinned,
+        })
+    }
+
+    pub(crate) fn transport_security(&self) -> bool {
+        self.transport_security
+    }
+
     pub(crate) fn node_ids(&self) -> &[AccountId] {
         &self.node_ids
     }
@@ -358,30 +485,53 @@ impl NetworkData {
     pub(crate) fn mark_node_unhealthy(&self, node_index: usize) {
         let now = Instant::now();
 
-        self.health[node_index].write().mark_unhealthy(*self.backoff.read(), now);
+        self.health[node_index].write().health.mark_unhealthy(*self.backoff.read(), now);
     }
 
     pub(crate) fn mark_node_healthy(&self, node_index: usize) {
-        self.health[node_index].write().mark_healthy(Instant::now());
+        self.health[node_index].write().health.mark_healthy(Instant::now());
+    }
+
+    /// Records how long the most recent request to the node took, for [`status`](Self::status).
+    pub(crate) fn record_node_latency(&self, node_index: usize, latency: Duration) {
+        self.health[node_index].write().latency = Some(latency);
+    }
+
+    /// Records the most recent error the node returned, for [`status`](Self::status).
+    pub(crate) fn record_node_error(&self, node_index: usize, error: String) {
+        self.health[node_index].write().last_error = Some(error);
     }
 
     pub(crate) fn is_node_healthy(&self, node_index: usize, now: Instant) -> bool {
         // a healthy node has a healthiness before now.
 
-        self.health[node_index].read().is_healthy(now)
+        self.health[node_index].read().health.is_healthy(now)
     }
 
     pub(crate) fn node_recently_pinged(&self, node_index: usize, now: Instant) -> bool {
-        self.health[node_index].read().recently_pinged(now)
+        self.health[node_index].read().health.recently_pinged(now)
+    }
+
+    fn is_node_pinned(&self, node_index: usize) -> bool {
+        self.pinned.as_ref().map_or(true, |it| it.contains(&self.node_ids[node_index]))
     }
 
     pub(crate) fn healthy_node_indexes(&self, time: Instant) -> impl Iterator<Item = usize> + '_ {
-        (0..self.node_ids.len()).filter(move |index| self.is_node_healthy(*index, time))
+        (0..self.node_ids.len())
+            .filter(move |index| self.is_node_pinned(*index) && self.is_node_healthy(*index, time))
     }
 
     pub(crate) fn healthy_node_ids(&self) -> impl Iterator<Item = AccountId> + '_ {
         self.healthy_node_indexes(Instant::now()).map(|it| self.node_ids[it])
     }
+
+    /// Returns the nodes that may be picked for a request, healthy or not (that is, the pinned nodes, if any).
+    pub(crate) fn pinned_node_ids(&self) -> impl Iterator<Item = AccountId> + '_ {
+        (0..self.node_ids.len())
+            .filter(|index| self.is_node_pinned(*index))
+            .map(|index| self.node_ids[index])
+    }
+
     pub(crate) fn random_node_ids(&self) -> Vec<AccountId> {
         let mut node_ids: Vec<_> = self.healthy_node_ids().collect();
         // self.remove_dead_nodes();
@@ -389,7 +539,7 @@ impl NetworkData {
         if node_ids.is_empty() {
             log::warn!("No healthy nodes, randomly picking some unhealthy ones");
             // hack, slowpath, don't care perf, fix this better later tho.
-            node_ids = self.node_ids.to_vec();
+            node_ids = self.pinned_node_ids().collect();
         }
 
         let node_sample_amount = (node_ids.len() + 2) / 3;
@@ -403,11 +553,52 @@ impl NetworkData {
     pub(crate) fn channel(&self, index: usize) -> (AccountId, Channel) {
         let id = self.node_ids[index];
 
-        let channel = self.connections[index].channel();
+        let channel = self.connections[index].channel(self.transport_security);
 
         (id, channel)
     }
 
+    pub(crate) fn status(&self) -> NetworkStatus {
+        let now = Instant::now();
+        let now_utc = OffsetDateTime::now_utc();
+
+        let nodes = (0..self.node_ids.len())
+            .map(|index| {
+                let state = self.health[index].read();
+
+                let (readmitted_at, unhealthy_attempts) = match &state.health {
+                    NodeHealth::Unhealthy { healthy_at, attempts, .. } => {
+                        ((*healthy_at > now).then(|| now_utc + (*healthy_at - now)), *attempts)
+                    }
+                    _ => (None, 0),
+                };
+
+                NodeStatus {
+                    node_account_id: self.node_ids[index],
+                    is_healthy: state.health.is_healthy(now),
+                    readmitted_at,
+                    unhealthy_attempts,
+                    latency: state.latency,
+                    last_error: state.last_error.clone(),
+                    addresses: self.connections[index]
+                        .addresses
+                        .iter()
+                        .map(|it| it.for_transport(self.transport_security).to_string())
+                        .collect(),
+                    is_pinned: self.pinned.is_some() && self.is_node_pinned(index),
+                }
+            })
+            .collect();
+
+        NetworkStatus {
+            nodes,
+            transport_security: self.transport_security,
+            pinned_nodes: self.pinned.as_ref().map(|pinned| {
+                self.node_ids.iter().copied().filter(|it| pinned.contains(it)).collect()
+            }),
+        }
+    }
+
     pub(crate) fn addresses(&self) -> HashMap<String, AccountId> {
         self.map
             .iter()
@@ -418,6 +609,14 @@ impl NetworkData {
     }
 }
 
+/// Everything tracked about a node, which is kept when the network changes.
+#[derive(Default)]
+struct NodeState {
+    health: NodeHealth,
+    latency: Option<Duration>,
+    last_error: Option<String>,
+}
+
 #[derive(Default)]
 enum NodeHealth {
     /// The node has never been used, so we don't know anything about it.
@@ -541,6 +740,18 @@ impl HostAndPort {
     const fn from_static(host: &'static str) -> Self {
         Self { host: Cow::Borrowed(host), port: NodeConnection::PLAINTEXT_PORT }
     }
+
+    /// Returns the address to use for the given transport.
+    ///
+    /// Nodes serve TLS on the port after the plaintext one, so the well known plaintext port maps to the well known TLS port.
+    fn for_transport(&self, transport_security: bool) -> Self {
+        let port = match (transport_security, self.port) {
+            (true, NodeConnection::PLAINTEXT_PORT) => NodeConnection::TLS_PORT,
+            (_, port) => port,
+        };
+
+        Self { host: self.host.clone(), port }
+    }
 }
 
 impl FromStr for HostAndPort {
@@ -562,45 +773,202 @@ impl fmt::Display for HostAndPort {
     }
 }
 
-impl From<Ipv4Addr> for HostAndPort {
-    fn from(value: Ipv4Addr) -> Self {
-        Self { host: Cow::Owned(value.to_string()), port: NodeConnection::PLAINTEXT_PORT }
+impl From<SocketAddrV4> for HostAndPort {
+    fn from(value: SocketAddrV4) -> Self {
+        // the TLS endpoint is derived from the plaintext one when it's needed.
+        let port = match value.port() {
+            NodeConnection::TLS_PORT => NodeConnection::PLAINTEXT_PORT,
+            port => port,
+        };
+
+        Self { host: Cow::Owned(value.ip().to_string()), port }
     }
 }
 
 #[derive(Clone)]
 struct NodeConnection {
     addresses: BTreeSet<HostAndPort>,
+    // hash of the node's TLS certificate, from the address book.
+    certificate_hash: Option<Vec<u8>>,
     channel: OnceCell<Channel>,
 }
 
 impl NodeConnection {
     const PLAINTEXT_PORT: u16 = 50211;
+    const TLS_PORT: u16 = 50212;
+
+    fn new(addresses: BTreeSet<HostAndPort>, certificate_hash: Option<Vec<u8>>) -> Self {
+        Self { addresses, certificate_hash, channel: OnceCell::new() }
+    }
 
     fn new_static(addresses: &[&'static str]) -> NodeConnection {
-        Self {
-            addresses: addresses.iter().copied().map(HostAndPort::from_static).collect(),
-            channel: OnceCell::default(),
-        }
+        Self::new(addresses.iter().copied().map(HostAndPort::from_static).collect(), None)
+    }
+
+    fn endpoint(address: &HostAndPort) -> Endpoint {
+        Endpoint::from_shared(format!("tcp://{address}"))
+            .unwrap()
+            .keep_alive_timeout(Duration::from_secs(10))
+            .keep_alive_while_idle(true)
+            .tcp_keepalive(Some(Duration::from_secs(10)))
+            .connect_timeout(Duration::from_secs(10))
     }
 
-    pub(crate) fn channel(&self) -> Channel {
+    pub(crate) fn channel(&self, transport_security: bool) -> Channel {
         let channel = self
             .channel
-            .get_or_init(|| {
-                let addresses = self.addresses.iter().map(|it| {
-                    Endpoint::from_shared(format!("tcp://{it}"))
-                        .unwrap()
-                        .keep_alive_timeout(Duration::from_secs(10))
-                        .keep_alive_while_idle(true)
-                        .tcp_keepalive(Some(Duration::from_secs(10)))
-                        .connect_timeout(Duration::from_secs(10))
-                });
-
-                Channel::balance_list(addresses)
+            .get_or_init(|| match transport_security {
+                true => self.tls_channel(),
+                false => Channel::balance_list(self.addresses.iter().map(Self::endpoint)),
             })
             .clone();
 
         channel
     }
+
+    fn tls_channel(&self) -> Channel {
+        let addresses: Arc<[HostAndPort]> =
+            self.addresses.iter().map(|it| it.for_transport(true)).collect();
+
+        // a node without a known certificate hash can't be verified, so it'll be rejected when connecting.
+        let config = tls::client_config(self.certificate_hash.clone().unwrap_or_default());
+
+        // tonic can't load balance with a custom connector,
+        // so instead the connector tries each of the node's addresses in turn.
+        let endpoint = match addresses.first() {
+            Some(it) => Self::endpoint(it),
+            None => Self::endpoint(&HostAndPort::from_static("0.0.0.0")),
+        };
+
+        endpoint.connect_with_connector_lazy(tower::service_fn(move |_: Uri| {
+            let addresses = Arc::clone(&addresses);
+            let config = config.clone();
+
+            async move { tls::connect(&addresses, config).await }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use super::NetworkData;
+    use crate::{
+        AccountId,
+        Error,
+    };
+
+    fn network() -> NetworkData {
+        NetworkData::from_addresses(&HashMap::from([
+            ("127.0.0.1:50211".to_owned(), AccountId::new(0, 0, 3)),
+            ("127.0.0.1:50212".to_owned(), AccountId::new(0, 0, 4)),
+            ("127.0.0.1:50213".to_owned(), AccountId::new(0, 0, 5)),
+        ]))
+        .unwrap()
+    }
+
+    fn index(network: &NetworkData, node: u64) -> usize {
+        network.node_indexes_for_ids(&[AccountId::new(0, 0, node)]).unwrap()[0]
+    }
+
+    #[test]
+    fn status() {
+        let network = network();
+
+        let node_3 = index(&network, 3);
+
+        network.record_node_latency(node_3, Duration::from_millis(30));
+        network.record_node_error(node_3, "pre-check status Busy".to_owned());
+        network.mark_node_unhealthy(node_3);
+
+        let status = network.status();
+
+        assert_eq!(status.nodes.len(), 3);
+        assert_eq!(status.healthy_nodes().count(), 2);
+        assert_eq!(status.pinned_nodes, None);
+
+        let node = status.node(AccountId::new(0, 0, 3)).unwrap();
+
+        assert!(!node.is_healthy);
+        assert!(node.readmitted_at.is_some());
+        assert_eq!(node.unhealthy_attempts, 1);
+        assert_eq!(node.latency, Some(Duration::from_millis(30)));
+        assert_eq!(node.last_error.as_deref(), Some("pre-check status Busy"));
+        assert_eq!(node.addresses, ["127.0.0.1:50211"]);
+
+        network.mark_node_healthy(node_3);
+
+        let node = network.status().node(AccountId::new(0, 0, 3)).cloned().unwrap();
+
+        assert!(node.is_healthy);
+        assert_eq!(node.readmitted_at, None);
+        // the last error sticks around after the node recovers.
+        assert!(node.last_error.is_some());
+    }
+
+    #[test]
+    fn pinned_nodes() {
+        let network = network().with_pinned_nodes(Some(&[AccountId::new(0, 0, 4)])).unwrap();
+
+        assert_eq!(network.healthy_node_ids().collect::<Vec<_>>(), [AccountId::new(0, 0, 4)]);
+        assert_eq!(network.random_node_ids(), [AccountId::new(0, 0, 4)]);
+
+        let status = network.status();
+
+        assert_eq!(status.pinned_nodes, Some(vec![AccountId::new(0, 0, 4)]));
+        assert!(status.node(AccountId::new(0, 0, 4)).unwrap().is_pinned);
+        assert!(!status.node(AccountId::new(0, 0, 3)).unwrap().is_pinned);
+
+        // unpinning.
+        let network = network.with_pinned_nodes(Some(&[])).unwrap();
+
+        assert_eq!(network.healthy_node_ids().count(), 3);
+
+        assert!(matches!(
+            network.with_pinned_nodes(Some(&[AccountId::new(0, 0, 99)])),
+            Err(Error::NodeAccountUnknown(_))
+        ));
+    }
+
+    #[test]
+    fn remove_node() {
+        let network = network();
+
+        network.mark_node_unhealthy(index(&network, 5));
+
+        let network = network.without_node(AccountId::new(0, 0, 4)).unwrap();
+
+        let mut node_ids = network.node_ids().to_vec();
+        node_ids.sort_by_key(|it| it.num);
+
+        assert_eq!(node_ids, [AccountId::new(0, 0, 3), AccountId::new(0, 0, 5)]);
+        assert_eq!(network.addresses().len(), 2);
+
+        // the remaining nodes keep their health.
+        assert!(!network.is_node_healthy(index(&network, 5), std::time::Instant::now()));
+
+        assert!(matches!(
+            network.without_node(AccountId::new(0, 0, 4)),
+            Err(Error::NodeAccountUnknown(_))
+        ));
+    }
+
+    #[test]
+    fn remove_pinned_node() {
+        let network = network()
+            .with_pinned_nodes(Some(&[AccountId::new(0, 0, 3), AccountId::new(0, 0, 4)]))
+            .unwrap();
+
+        let network = network.without_node(AccountId::new(0, 0, 4)).unwrap();
+
+        assert_eq!(network.status().pinned_nodes, Some(vec![AccountId::new(0, 0, 3)]));
+
+        // with every pinned node gone, the rest of the network is usable again.
+        let network = network.without_node(AccountId::new(0, 0, 3)).unwrap();
+
+        assert_eq!(network.status().pinned_nodes, None);
+        assert_eq!(network.healthy_node_ids().collect::<Vec<_>>(), [AccountId::new(0, 0, 5)]);
+    }
 }
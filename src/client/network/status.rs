@@ -0,0 +1,86 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::time::Duration;
+
+use time::OffsetDateTime;
+
+use crate::AccountId;
+
+/// A snapshot of the consensus network as seen by a [`Client`](crate::Client).
+///
+/// Returned by [`Client::network_status`](crate::Client::network_status).
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct NetworkStatus {
+    /// The status of each node in the network.
+    pub nodes: Vec<NodeStatus>,
+
+    /// Whether consensus nodes are connected to over TLS.
+    pub transport_security: bool,
+
+    /// The nodes that requests are restricted to, if any,
+    /// see [`Client::set_pinned_nodes`](crate::Client::set_pinned_nodes).
+    pub pinned_nodes: Option<Vec<AccountId>>,
+}
+
+impl NetworkStatus {
+    /// Returns the status of the node with the given account ID.
+    #[must_use]
+    pub fn node(&self, node_account_id: AccountId) -> Option<&NodeStatus> {
+        self.nodes.iter().find(|it| it.node_account_id == node_account_id)
+    }
+
+    /// Returns the nodes that are currently healthy.
+    pub fn healthy_nodes(&self) -> impl Iterator<Item = &NodeStatus> {
+        self.nodes.iter().filter(|it| it.is_healthy)
+    }
+}
+
+/// The status of a single consensus node, see [`NetworkStatus`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct NodeStatus {
+    /// The account ID of the node.
+    pub node_account_id: AccountId,
+
+    /// Whether requests will be sent to this node.
+    ///
+    /// A node that returned an error (like `BUSY`) is unhealthy until it's readmitted.
+    pub is_healthy: bool,
+
+    /// When an unhealthy node will be readmitted.
+    pub readmitted_at: Option<OffsetDateTime>,
+
+    /// The number of times in a row this node has been marked unhealthy.
+    pub unhealthy_attempts: usize,
+
+    /// The latency of the most recent request to this node.
+    pub latency: Option<Duration>,
+
+    /// The most recent error returned by this node.
+    pub last_error: Option<String>,
+
+    /// The addresses used to connect to this node.
+    pub addresses: Vec<String>,
+
+    /// Whether this node is one of the pinned nodes.
+    pub is_pinned: bool,
+}
@@ -0,0 +1,357 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::error::Error as StdError;
+use std::fmt;
+// rustls takes and returns `std::sync::Arc`, so `triomphe::Arc` can't be used in this module.
+#[allow(clippy::disallowed_types)]
+use std::sync::Arc;
+
+use sha2::{
+    Digest,
+    Sha384,
+};
+use tokio::net::TcpStream;
+use tokio_rustls::client::TlsStream;
+use tokio_rustls::rustls::client::danger::{
+    HandshakeSignatureValid,
+    ServerCertVerified,
+    ServerCertVerifier,
+};
+use tokio_rustls::rustls::crypto::{
+    self,
+    CryptoProvider,
+};
+use tokio_rustls::rustls::pki_types::{
+    CertificateDer,
+    ServerName,
+    UnixTime,
+};
+use tokio_rustls::rustls::{
+    self,
+    CertificateError,
+    ClientConfig,
+    DigitallySignedStruct,
+    OtherError,
+    SignatureScheme,
+};
+use tokio_rustls::TlsConnector;
+
+use super::HostAndPort;
+use crate::error::BoxStdError;
+
+/// A node presented a TLS certificate that doesn't hash to the value in the address book.
+#[derive(Debug)]
+pub(crate) struct CertificateHashMismatch;
+
+impl fmt::Display for CertificateHashMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("node certificate does not match the certificate hash from the address book")
+    }
+}
+
+impl StdError for CertificateHashMismatch {}
+
+/// Returns `true` if `error` (or anything in its source chain) is a [`CertificateHashMismatch`].
+pub(crate) fn is_certificate_hash_mismatch(error: &(dyn StdError + 'static)) -> bool {
+    std::iter::successors(Some(error), |it| it.source())
+        .any(|it| it.is::<CertificateHashMismatch>())
+}
+
+/// Hashes a DER encoded certificate the same way as the address book.
+///
+/// That is, the SHA-384 hash of the certificate in PEM format.
+pub(crate) fn certificate_hash(certificate: &[u8]) -> [u8; 48] {
+    let pem = pem::encode_config(
+        &pem::Pem::new("CERTIFICATE", certificate),
+        pem::EncodeConfig::new().set_line_ending(pem::LineEnding::LF),
+    );
+
+    Sha384::digest(pem.as_bytes()).into()
+}
+
+/// Checks the certificate a node presents against the node's certificate hash.
+///
+/// Node certificates are self-signed, so there's no chain or host name to check,
+/// the pinned hash from the address book *is* the trust anchor.
+#[derive(Debug)]
+#[allow(clippy::disallowed_types)]
+struct CertificateHashVerifier {
+    expected_hash: Vec<u8>,
+    provider: Arc<CryptoProvider>,
+}
+
+impl CertificateHashVerifier {
+    fn matches(&self, certificate: &[u8]) -> bool {
+        let hash = certificate_hash(certificate);
+
+        // the address book stores the hash as hex characters, but some tools hand out the raw digest.
+        self.expected_hash == hash
+            || self.expected_hash.eq_ignore_ascii_case(hex::encode(hash).as_bytes())
+    }
+}
+
+impl ServerCertVerifier for CertificateHashVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        if !self.matches(end_entity) {
+            return Err(rustls::Error::InvalidCertificate(CertificateError::Other(OtherError(
+                Arc::new(CertificateHashMismatch),
+            ))));
+        }
+
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        crypto::verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        crypto::verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.provider.signature_verification_algorithms.supported_schemes()
+    }
+}
+
+/// Creates a TLS config that only accepts a certificate with the given hash.
+#[allow(clippy::disallowed_types)]
+pub(super) fn client_config(expected_hash: Vec<u8>) -> Arc<ClientConfig> {
+    let provider = Arc::new(crypto::ring::default_provider());
+
+    let mut config = ClientConfig::builder()
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(CertificateHashVerifier {
+            expected_hash,
+            provider,
+        }))
+        .with_no_client_auth();
+
+    // gRPC is http2 only.
+    config.alpn_protocols = Vec::from([b"h2".to_vec()]);
+
+    Arc::new(config)
+}
+
+/// Connects to the first of `addresses` that accepts a connection and performs a TLS handshake with it.
+///
+/// All of the addresses belong to the same node, so they share a certificate.
+#[allow(clippy::disallowed_types)]
+pub(super) async fn connect(
+    addresses: &[HostAndPort],
+    config: Arc<ClientConfig>,
+) -> Result<TlsStream<TcpStream>, BoxStdError> {
+    let connector = TlsConnector::from(config);
+
+    let mut last_error: Option<BoxStdError> = None;
+
+    for address in addresses {
+        let stream = match TcpStream::connect((&*address.host, address.port)).await {
+            Ok(it) => it,
+            Err(e) => {
+                last_error = Some(e.into());
+                continue;
+            }
+        };
+
+        let server_name = ServerName::try_from(&*address.host)?.to_owned();
+
+        match connector.connect(server_name, stream).await {
+            Ok(it) => return Ok(it),
+            // a certificate mismatch means every address is going to fail the same way.
+            Err(e) if is_rustls_hash_mismatch(&e) => return Err(Box::new(CertificateHashMismatch)),
+            Err(e) => last_error = Some(e.into()),
+        }
+    }
+
+    Err(last_error.unwrap_or_else(|| "node has no addresses".into()))
+}
+
+fn is_rustls_hash_mismatch(error: &std::io::Error) -> bool {
+    let Some(error) = error.get_ref().and_then(|it| it.downcast_ref::<rustls::Error>()) else {
+        return false;
+    };
+
+    match error {
+        rustls::Error::InvalidCertificate(CertificateError::Other(OtherError(it))) => {
+            it.is::<CertificateHashMismatch>()
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::collections::HashMap;
+    #[allow(clippy::disallowed_types)]
+    use std::sync::Arc;
+
+    use assert_matches::assert_matches;
+    use tokio::net::TcpListener;
+    use tokio_rustls::rustls::pki_types::{
+        CertificateDer,
+        PrivateKeyDer,
+        PrivatePkcs8KeyDer,
+    };
+    use tokio_rustls::rustls::ServerConfig;
+    use tokio_rustls::TlsAcceptor;
+
+    use super::{
+        certificate_hash,
+        client_config,
+        connect,
+        is_certificate_hash_mismatch,
+    };
+    use crate::client::network::HostAndPort;
+    use crate::{
+        AccountBalanceQuery,
+        AccountId,
+        Client,
+        Error,
+    };
+
+    const CERT: &str = include_str!("test-cert.pem");
+    const KEY: &str = include_str!("test-key.pem");
+
+    fn cert_der() -> Vec<u8> {
+        pem::parse(CERT).unwrap().into_contents()
+    }
+
+    // spawns a server that accepts (at most) one TLS connection with the self-signed test certificate.
+    async fn spawn_server() -> HostAndPort {
+        let config = ServerConfig::builder()
+            .with_no_client_auth()
+            .with_single_cert(
+                Vec::from([CertificateDer::from(cert_der())]),
+                PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(
+                    pem::parse(KEY).unwrap().into_contents(),
+                )),
+            )
+            .unwrap();
+
+        let acceptor = TlsAcceptor::from(Arc::new(config));
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let _ = acceptor.accept(stream).await;
+        });
+
+        HostAndPort { host: Cow::Borrowed("127.0.0.1"), port }
+    }
+
+    #[test]
+    fn hash_matches_address_book_format() {
+        // sha384 of the PEM file (which uses `\n` line endings).
+        assert_eq!(
+            hex::encode(certificate_hash(&cert_der())),
+            "3f4d95ef7ec15f219f34d0b3f7e1c3fa9ab91728868f28c369f519f3c8dfc83c99d77543657edd3097a87300175f2356"
+        );
+    }
+
+    #[tokio::test]
+    async fn connect_matching_hash() {
+        let address = spawn_server().await;
+
+        let expected = hex::encode(certificate_hash(&cert_der())).into_bytes();
+
+        connect(&[address], client_config(expected)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn connect_matching_raw_hash() {
+        let address = spawn_server().await;
+
+        let expected = certificate_hash(&cert_der()).to_vec();
+
+        connect(&[address], client_config(expected)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn connect_mismatched_hash() {
+        let address = spawn_server().await;
+
+        let err = connect(&[address], client_config(Vec::from([0; 48]))).await.unwrap_err();
+
+        assert!(is_certificate_hash_mismatch(&*err));
+    }
+
+    #[tokio::test]
+    async fn connect_missing_hash() {
+        let address = spawn_server().await;
+
+        let err = connect(&[address], client_config(Vec::new())).await.unwrap_err();
+
+        assert!(is_certificate_hash_mismatch(&*err));
+    }
+
+    #[tokio::test]
+    async fn execute_mismatched_hash() {
+        let address = spawn_server().await;
+        let node_account_id = AccountId::new(0, 0, 3);
+
+        // nodes that aren't from an address book have no certificate hash, so the test certificate can't match.
+        let client =
+            Client::for_network(HashMap::from([(address.to_string(), node_account_id)])).unwrap();
+        client.set_transport_security(true);
+        client.set_max_attempts(1);
+
+        let err = AccountBalanceQuery::new()
+            .account_id(AccountId::new(0, 0, 1001))
+            .node_account_ids([node_account_id])
+            .execute(&client)
+            .await
+            .unwrap_err();
+
+        assert_matches!(err, Error::TimedOut(err) => {
+            assert_matches!(*err, Error::NodeCertificateMismatch(id) if *id == node_account_id);
+        });
+    }
+}
@@ -1,7 +1,6 @@
 use crate::signer::AnySigner;
 use crate::{
     AccountId,
-    PublicKey,
     TransactionId,
 };
 
@@ -12,11 +11,6 @@ pub(crate) struct Operator {
 }
 
 impl Operator {
-    #[must_use]
-    pub(crate) fn sign(&self, body_bytes: &[u8]) -> (PublicKey, Vec<u8>) {
-        self.signer.sign(body_bytes)
-    }
-
     #[must_use]
     pub(crate) fn generate_transaction_id(&self) -> TransactionId {
         TransactionId::generate(self.account_id)
@@ -0,0 +1,311 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::Error;
+
+/// The type of a Solidity ABI parameter.
+///
+/// Parses from (and formats as) the canonical type name used in function signatures,
+/// for example `uint256`, `bytes32[]` or `(address,string)[2]`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AbiType {
+    /// `address`
+    Address,
+
+    /// `bool`
+    Bool,
+
+    /// `intN`, with the size in bits.
+    Int(usize),
+
+    /// `uintN`, with the size in bits.
+    Uint(usize),
+
+    /// `bytesN`, with the size in bytes.
+    FixedBytes(usize),
+
+    /// `bytes`
+    Bytes,
+
+    /// `string`
+    String,
+
+    /// `function`: an address followed by a function selector.
+    Function,
+
+    /// `T[]`
+    Array(Box<AbiType>),
+
+    /// `T[N]`
+    FixedArray(Box<AbiType>, usize),
+
+    /// `(T1,T2,...)`
+    Tuple(Vec<AbiType>),
+}
+
+impl AbiType {
+    /// Returns `true` if values of this type are encoded out of line (after the static part of the enclosing tuple).
+    #[must_use]
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            Self::Bytes | Self::String | Self::Array(_) => true,
+            Self::FixedArray(inner, _) => inner.is_dynamic(),
+            Self::Tuple(types) => types.iter().any(Self::is_dynamic),
+            _ => false,
+        }
+    }
+
+    /// Checks that this type can be encoded.
+    ///
+    /// `from_str` never produces an invalid type, but the variants can be constructed directly.
+    pub(crate) fn validate(&self) -> crate::Result<()> {
+        let valid = match self {
+            Self::Int(bits) | Self::Uint(bits) => *bits > 0 && *bits <= 256 && *bits % 8 == 0,
+            Self::FixedBytes(len) => *len > 0 && *len <= 32,
+            Self::Array(inner) | Self::FixedArray(inner, _) => return inner.validate(),
+            Self::Tuple(types) => return types.iter().try_for_each(Self::validate),
+            _ => true,
+        };
+
+        match valid {
+            true => Ok(()),
+            false => Err(Error::Abi(format!("invalid ABI type `{self}`").into())),
+        }
+    }
+
+    /// Returns the size of the in-place encoding of a value of this type.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if the size overflows a `usize`.
+    pub(crate) fn head_size(&self) -> crate::Result<usize> {
+        if self.is_dynamic() {
+            return Ok(32);
+        }
+
+        let too_large = || Error::Abi(format!("`{self}` is too large to encode").into());
+
+        match self {
+            Self::FixedArray(inner, len) => {
+                inner.head_size()?.checked_mul(*len).ok_or_else(too_large)
+            }
+            Self::Tuple(types) => types.iter().try_fold(0_usize, |size, ty| {
+                size.checked_add(ty.head_size()?).ok_or_else(too_large)
+            }),
+            _ => Ok(32),
+        }
+    }
+}
+
+impl fmt::Display for AbiType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Address => f.write_str("address"),
+            Self::Bool => f.write_str("bool"),
+            Self::Int(bits) => write!(f, "int{bits}"),
+            Self::Uint(bits) => write!(f, "uint{bits}"),
+            Self::FixedBytes(len) => write!(f, "bytes{len}"),
+            Self::Bytes => f.write_str("bytes"),
+            Self::String => f.write_str("string"),
+            Self::Function => f.write_str("function"),
+            Self::Array(inner) => write!(f, "{inner}[]"),
+            Self::FixedArray(inner, len) => write!(f, "{inner}[{len}]"),
+            Self::Tuple(types) => {
+                f.write_str("(")?;
+
+                for (i, ty) in types.iter().enumerate() {
+                    if i != 0 {
+                        f.write_str(",")?;
+                    }
+
+                    write!(f, "{ty}")?;
+                }
+
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+impl FromStr for AbiType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if let Some(rest) = s.strip_suffix(']') {
+            let (inner, len) = rest
+                .rsplit_once('[')
+                .ok_or_else(|| Error::basic_parse(format!("invalid ABI type `{s}`")))?;
+
+            let inner = Box::new(inner.parse()?);
+
+            return match len {
+                "" => Ok(Self::Array(inner)),
+                len => Ok(Self::FixedArray(inner, len.parse().map_err(Error::basic_parse)?)),
+            };
+        }
+
+        if let Some(rest) = s.strip_prefix('(') {
+            let rest = rest
+                .strip_suffix(')')
+                .ok_or_else(|| Error::basic_parse(format!("invalid ABI type `{s}`")))?;
+
+            return split_tuple(rest)
+                .map(str::parse)
+                .collect::<crate::Result<_>>()
+                .map(Self::Tuple);
+        }
+
+        let sized = |prefix: &str, default: Option<usize>, valid: fn(usize) -> bool| {
+            let size = match &s[prefix.len()..] {
+                "" => default,
+                size => size.parse().ok(),
+            };
+
+            size.filter(|it| valid(*it))
+                .ok_or_else(|| Error::basic_parse(format!("invalid ABI type `{s}`")))
+        };
+
+        let valid_bits = |bits: usize| bits > 0 && bits <= 256 && bits % 8 == 0;
+
+        match s {
+            "address" => Ok(Self::Address),
+            "bool" => Ok(Self::Bool),
+            "bytes" => Ok(Self::Bytes),
+            "string" => Ok(Self::String),
+            "function" => Ok(Self::Function),
+            _ if s.starts_with("uint") => sized("uint", Some(256), valid_bits).map(Self::Uint),
+            _ if s.starts_with("int") => sized("int", Some(256), valid_bits).map(Self::Int),
+            _ if s.starts_with("bytes") => {
+                sized("bytes", None, |len| len > 0 && len <= 32).map(Self::FixedBytes)
+            }
+            _ => Err(Error::basic_parse(format!("unsupported ABI type `{s}`"))),
+        }
+    }
+}
+
+/// Splits the contents of a tuple type on the commas that aren't nested in another tuple.
+fn split_tuple(s: &str) -> impl Iterator<Item = &str> {
+    let mut depth = 0_usize;
+    let mut start = 0;
+    let mut parts = Vec::new();
+
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    if !s.is_empty() {
+        parts.push(&s[start..]);
+    }
+
+    parts.into_iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use assert_matches::assert_matches;
+
+    use super::AbiType;
+    use crate::Error;
+
+    #[test]
+    fn parse_elementary() {
+        assert_eq!(AbiType::from_str("address").unwrap(), AbiType::Address);
+        assert_eq!(AbiType::from_str("uint").unwrap(), AbiType::Uint(256));
+        assert_eq!(AbiType::from_str("int48").unwrap(), AbiType::Int(48));
+        assert_eq!(AbiType::from_str("bytes32").unwrap(), AbiType::FixedBytes(32));
+        assert_eq!(AbiType::from_str("bytes").unwrap(), AbiType::Bytes);
+    }
+
+    #[test]
+    fn parse_nested() {
+        let ty = AbiType::from_str("(address,(string,uint8[])[])[2]").unwrap();
+
+        assert_eq!(
+            ty,
+            AbiType::FixedArray(
+                Box::new(AbiType::Tuple(Vec::from([
+                    AbiType::Address,
+                    AbiType::Array(Box::new(AbiType::Tuple(Vec::from([
+                        AbiType::String,
+                        AbiType::Array(Box::new(AbiType::Uint(8))),
+                    ])))),
+                ]))),
+                2
+            )
+        );
+
+        assert_eq!(ty.to_string(), "(address,(string,uint8[])[])[2]");
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert_matches!(AbiType::from_str("uint7"), Err(_));
+        assert_matches!(AbiType::from_str("bytes33"), Err(_));
+        assert_matches!(AbiType::from_str("mapping"), Err(_));
+        assert_matches!(AbiType::from_str("uint8[x]"), Err(_));
+    }
+
+    #[test]
+    fn dynamic() {
+        assert!(!AbiType::from_str("(uint256,bytes32)[2]").unwrap().is_dynamic());
+        assert!(AbiType::from_str("(uint256,bytes)").unwrap().is_dynamic());
+        assert!(AbiType::from_str("uint256[]").unwrap().is_dynamic());
+    }
+
+    #[test]
+    fn head_size() {
+        assert_eq!(AbiType::from_str("(uint256,bytes32)[2]").unwrap().head_size().unwrap(), 128);
+        assert_eq!(AbiType::from_str("(uint256,bytes)[2]").unwrap().head_size().unwrap(), 32);
+
+        let huge = AbiType::FixedArray(Box::new(AbiType::Uint(256)), usize::MAX);
+        assert_matches!(huge.head_size(), Err(Error::Abi(_)));
+    }
+
+    #[test]
+    fn validate() {
+        assert_matches!(AbiType::from_str("(int8,uint256,bytes32[])").unwrap().validate(), Ok(()));
+
+        for ty in [
+            AbiType::Int(0),
+            AbiType::Uint(512),
+            AbiType::Int(12),
+            AbiType::FixedBytes(0),
+            AbiType::FixedBytes(40),
+            AbiType::Array(Box::new(AbiType::Uint(7))),
+            AbiType::Tuple(Vec::from([AbiType::Bool, AbiType::FixedBytes(33)])),
+        ] {
+            assert_matches!(ty.validate(), Err(Error::Abi(_)), "{ty:?}");
+        }
+    }
+}
@@ -0,0 +1,530 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::str::FromStr;
+
+use num_bigint::{
+    BigInt,
+    BigUint,
+    Sign,
+};
+
+use crate::contract::AbiType;
+use crate::ethereum::SolidityAddress;
+use crate::Error;
+
+const WORD: usize = 32;
+
+/// A Solidity ABI value.
+///
+/// Values nest the same way their [`AbiType`]s do, so a `(address,uint256)[]` is an
+/// [`Array`](Self::Array) of [`Tuple`](Self::Tuple)s.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AbiValue {
+    /// An `address`.
+    Address([u8; 20]),
+
+    /// A `bool`.
+    Bool(bool),
+
+    /// An `intN`.
+    Int(BigInt),
+
+    /// A `uintN`.
+    Uint(BigUint),
+
+    /// A `bytesN`.
+    FixedBytes(Vec<u8>),
+
+    /// A `bytes`.
+    Bytes(Vec<u8>),
+
+    /// A `string`.
+    String(String),
+
+    /// A `function`: a 20 byte address followed by a 4 byte function selector.
+    Function([u8; 24]),
+
+    /// A `T[]` or `T[N]`.
+    Array(Vec<AbiValue>),
+
+    /// A `(T1,T2,...)`.
+    Tuple(Vec<AbiValue>),
+}
+
+impl AbiValue {
+    /// Create an [`Address`](Self::Address) from a hex encoded EVM address, with or without a `0x` prefix.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `address` isn't a valid EVM address.
+    pub fn address(address: &str) -> crate::Result<Self> {
+        Ok(Self::Address(SolidityAddress::from_str(address)?.0 .0))
+    }
+
+    /// Returns the value as an `address`, if it is one.
+    #[must_use]
+    pub fn as_address(&self) -> Option<&[u8; 20]> {
+        match self {
+            Self::Address(it) => Some(it),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a `bool`, if it is one.
+    #[must_use]
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(it) => Some(*it),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as an `intN`, if it is one.
+    #[must_use]
+    pub fn as_int(&self) -> Option<&BigInt> {
+        match self {
+            Self::Int(it) => Some(it),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a `uintN`, if it is one.
+    #[must_use]
+    pub fn as_uint(&self) -> Option<&BigUint> {
+        match self {
+            Self::Uint(it) => Some(it),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a `bytes` or `bytesN`, if it is one.
+    #[must_use]
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::Bytes(it) | Self::FixedBytes(it) => Some(it),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a `string`, if it is one.
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(it) => Some(it),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements of an array or the fields of a tuple.
+    #[must_use]
+    pub fn as_slice(&self) -> Option<&[AbiValue]> {
+        match self {
+            Self::Array(it) | Self::Tuple(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+
+impl From<bool> for AbiValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<&str> for AbiValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl From<String> for AbiValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<BigInt> for AbiValue {
+    fn from(value: BigInt) -> Self {
+        Self::Int(value)
+    }
+}
+
+impl From<BigUint> for AbiValue {
+    fn from(value: BigUint) -> Self {
+        Self::Uint(value)
+    }
+}
+
+macro_rules! impl_from_int {
+    ($variant:ident: $($ty:ty),*) => {
+        $(
+            impl From<$ty> for AbiValue {
+                fn from(value: $ty) -> Self {
+                    Self::$variant(value.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(Uint: u8, u16, u32, u64, u128);
+impl_from_int!(Int: i8, i16, i32, i64, i128);
+
+fn abi_error(message: impl Into<String>) -> Error {
+    Error::Abi(message.into().into())
+}
+
+/// Encodes `values` as the tuple `types`.
+pub(crate) fn encode(types: &[AbiType], values: &[AbiValue]) -> crate::Result<Vec<u8>> {
+    types.iter().try_for_each(AbiType::validate)?;
+
+    encode_tuple(types, values)
+}
+
+fn encode_tuple(types: &[AbiType], values: &[AbiValue]) -> crate::Result<Vec<u8>> {
+    if types.len() != values.len() {
+        return Err(abi_error(format!("expected {} values, got {}", types.len(), values.len())));
+    }
+
+    let head_len = types.iter().try_fold(0_usize, |len, ty| {
+        len.checked_add(ty.head_size()?).ok_or_else(|| abi_error("tuple is too large to encode"))
+    })?;
+
+    let mut head = Vec::with_capacity(head_len);
+    let mut tail = Vec::new();
+
+    for (ty, value) in types.iter().zip(values) {
+        if ty.is_dynamic() {
+            head.extend_from_slice(&encode_usize(head_len + tail.len()));
+            tail.extend(encode_value(ty, value)?);
+        } else {
+            head.extend(encode_value(ty, value)?);
+        }
+    }
+
+    head.append(&mut tail);
+
+    Ok(head)
+}
+
+fn encode_value(ty: &AbiType, value: &AbiValue) -> crate::Result<Vec<u8>> {
+    match (ty, value) {
+        (AbiType::Address, AbiValue::Address(address)) => Ok(left_pad(address).to_vec()),
+        (AbiType::Bool, AbiValue::Bool(value)) => Ok(encode_usize(usize::from(*value)).to_vec()),
+        (AbiType::Uint(bits), AbiValue::Uint(value)) => {
+            if value.bits() > *bits as u64 {
+                return Err(abi_error(format!("value `{value}` doesn't fit in a `uint{bits}`")));
+            }
+
+            Ok(left_pad(&value.to_bytes_be()).to_vec())
+        }
+        (AbiType::Int(bits), AbiValue::Int(value)) => {
+            let bytes = value.to_signed_bytes_be();
+
+            // `bits` includes the sign bit, so the magnitude must fit in `bits - 1`.
+            if value.bits() >= *bits as u64 && *value != -(BigInt::from(1) << (bits - 1)) {
+                return Err(abi_error(format!("value `{value}` doesn't fit in an `int{bits}`")));
+            }
+
+            let mut word = if value.sign() == Sign::Minus { [0xff; WORD] } else { [0; WORD] };
+            word[WORD - bytes.len()..].copy_from_slice(&bytes);
+
+            Ok(word.to_vec())
+        }
+        (AbiType::FixedBytes(len), AbiValue::FixedBytes(bytes)) => {
+            if bytes.len() != *len {
+                return Err(abi_error(format!(
+                    "expected {len} bytes for a `bytes{len}`, got {}",
+                    bytes.len()
+                )));
+            }
+
+            Ok(right_pad(bytes))
+        }
+        (AbiType::Function, AbiValue::Function(function)) => Ok(right_pad(function)),
+        (AbiType::Bytes, AbiValue::Bytes(bytes)) => Ok(encode_dynamic_bytes(bytes)),
+        (AbiType::String, AbiValue::String(value)) => Ok(encode_dynamic_bytes(value.as_bytes())),
+        (AbiType::Array(inner), AbiValue::Array(values)) => {
+            let types = vec![(**inner).clone(); values.len()];
+
+            let mut out = encode_usize(values.len()).to_vec();
+            out.extend(encode_tuple(&types, values)?);
+
+            Ok(out)
+        }
+        (AbiType::FixedArray(inner, len), AbiValue::Array(values)) => {
+            if values.len() != *len {
+                return Err(abi_error(format!(
+                    "expected {len} elements for a `{ty}`, got {}",
+                    values.len()
+                )));
+            }
+
+            encode_tuple(&vec![(**inner).clone(); *len], values)
+        }
+        (AbiType::Tuple(types), AbiValue::Tuple(values)) => encode_tuple(types, values),
+        (ty, value) => Err(abi_error(format!("cannot encode `{value:?}` as a `{ty}`"))),
+    }
+}
+
+/// Decodes the tuple `types` from `data`.
+pub(crate) fn decode(types: &[AbiType], data: &[u8]) -> crate::Result<Vec<AbiValue>> {
+    types.iter().try_for_each(AbiType::validate)?;
+
+    decode_tuple(types, data, 0)
+}
+
+fn decode_tuple(types: &[AbiType], data: &[u8], base: usize) -> crate::Result<Vec<AbiValue>> {
+    let mut values = Vec::with_capacity(types.len());
+    let mut offset = base;
+
+    for ty in types {
+        if ty.is_dynamic() {
+            let tail_offset = read_usize(data, offset)?;
+            let at =
+                base.checked_add(tail_offset).ok_or_else(|| abi_error("offset out of bounds"))?;
+
+            values.push(decode_value(ty, data, at)?);
+        } else {
+            values.push(decode_value(ty, data, offset)?);
+        }
+
+        offset =
+            offset.checked_add(ty.head_size()?).ok_or_else(|| abi_error("offset out of bounds"))?;
+    }
+
+    Ok(values)
+}
+
+fn decode_value(ty: &AbiType, data: &[u8], at: usize) -> crate::Result<AbiValue> {
+    match ty {
+        AbiType::Address => Ok(AbiValue::Address(read_word(data, at)?[12..].try_into().unwrap())),
+        AbiType::Bool => Ok(AbiValue::Bool(read_word(data, at)?.iter().any(|it| *it != 0))),
+        AbiType::Uint(_) => Ok(AbiValue::Uint(BigUint::from_bytes_be(read_word(data, at)?))),
+        AbiType::Int(_) => Ok(AbiValue::Int(BigInt::from_signed_bytes_be(read_word(data, at)?))),
+        AbiType::FixedBytes(len) => Ok(AbiValue::FixedBytes(read_word(data, at)?[..*len].to_vec())),
+        AbiType::Function => Ok(AbiValue::Function(read_word(data, at)?[..24].try_into().unwrap())),
+        AbiType::Bytes => Ok(AbiValue::Bytes(read_dynamic_bytes(data, at)?.to_vec())),
+        AbiType::String => String::from_utf8(read_dynamic_bytes(data, at)?.to_vec())
+            .map(AbiValue::String)
+            .map_err(|it| Error::Abi(it.into())),
+        AbiType::Array(inner) => {
+            let len = read_usize(data, at)?;
+            let at = at + WORD;
+
+            // every element takes at least one word, so this also bounds the allocation below.
+            if len > data.len().saturating_sub(at) / WORD {
+                return Err(abi_error("array length out of bounds"));
+            }
+
+            decode_tuple(&vec![(**inner).clone(); len], data, at).map(AbiValue::Array)
+        }
+        AbiType::FixedArray(inner, len) => {
+            // same as above, the length just comes from the type instead of the data.
+            if *len > data.len().saturating_sub(at) / WORD {
+                return Err(abi_error("array length out of bounds"));
+            }
+
+            decode_tuple(&vec![(**inner).clone(); *len], data, at).map(AbiValue::Array)
+        }
+        AbiType::Tuple(types) => decode_tuple(types, data, at).map(AbiValue::Tuple),
+    }
+}
+
+fn read_word(data: &[u8], at: usize) -> crate::Result<&[u8]> {
+    data.get(at..)
+        .and_then(|it| it.get(..WORD))
+        .ok_or_else(|| abi_error(format!("unexpected end of data at offset {at}")))
+}
+
+fn read_usize(data: &[u8], at: usize) -> crate::Result<usize> {
+    let word = read_word(data, at)?;
+
+    let (high, low) = word.split_at(WORD - std::mem::size_of::<usize>());
+
+    if high.iter().any(|it| *it != 0) {
+        return Err(abi_error(format!("length or offset at {at} is too large")));
+    }
+
+    Ok(usize::from_be_bytes(low.try_into().unwrap()))
+}
+
+fn read_dynamic_bytes(data: &[u8], at: usize) -> crate::Result<&[u8]> {
+    let len = read_usize(data, at)?;
+
+    data.get(at + WORD..)
+        .and_then(|it| it.get(..len))
+        .ok_or_else(|| abi_error(format!("unexpected end of data at offset {at}")))
+}
+
+fn encode_usize(value: usize) -> [u8; WORD] {
+    left_pad(&value.to_be_bytes())
+}
+
+fn left_pad(bytes: &[u8]) -> [u8; WORD] {
+    let mut word = [0; WORD];
+    word[WORD - bytes.len()..].copy_from_slice(bytes);
+    word
+}
+
+fn right_pad(bytes: &[u8]) -> Vec<u8> {
+    let mut out = bytes.to_vec();
+    out.resize(bytes.len().div_ceil(WORD) * WORD, 0);
+    out
+}
+
+fn encode_dynamic_bytes(bytes: &[u8]) -> Vec<u8> {
+    let mut out = encode_usize(bytes.len()).to_vec();
+    out.extend(right_pad(bytes));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use assert_matches::assert_matches;
+    use hex_literal::hex;
+    use num_bigint::BigInt;
+
+    use super::{
+        decode,
+        encode,
+    };
+    use crate::contract::{
+        AbiType,
+        AbiValue,
+    };
+    use crate::{
+        ContractFunctionParameters,
+        Error,
+    };
+
+    fn types(s: &str) -> Vec<AbiType> {
+        match AbiType::from_str(s).unwrap() {
+            AbiType::Tuple(types) => types,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn matches_function_parameters() {
+        let values = [
+            AbiValue::from("hello"),
+            AbiValue::from(-5_i64),
+            AbiValue::Array(Vec::from([AbiValue::from(1_u32), AbiValue::from(2_u32)])),
+            AbiValue::from(true),
+        ];
+
+        let expected = ContractFunctionParameters::new()
+            .add_string("hello")
+            .add_int64(-5)
+            .add_uint32_array(&[1, 2])
+            .add_bool(true)
+            .to_bytes(None);
+
+        assert_eq!(encode(&types("(string,int64,uint32[],bool)"), &values).unwrap(), expected);
+    }
+
+    #[test]
+    fn round_trip_nested() {
+        let types = types("((address,string)[],uint8[2],bytes,bytes4)");
+
+        let values = Vec::from([
+            AbiValue::Array(Vec::from([
+                AbiValue::Tuple(Vec::from([
+                    AbiValue::Address(hex!("11223344556677889900aabbccddeeff00112233")),
+                    AbiValue::from("first"),
+                ])),
+                AbiValue::Tuple(Vec::from([AbiValue::Address([0; 20]), AbiValue::from("second")])),
+            ])),
+            AbiValue::Array(Vec::from([AbiValue::from(1_u8), AbiValue::from(2_u8)])),
+            AbiValue::Bytes(Vec::from([1, 2, 3])),
+            AbiValue::FixedBytes(Vec::from([4, 5, 6, 7])),
+        ]);
+
+        let encoded = encode(&types, &values).unwrap();
+
+        assert_eq!(decode(&types, &encoded).unwrap(), values);
+    }
+
+    #[test]
+    fn int_bounds() {
+        let ty = [AbiType::Int(8)];
+
+        assert_matches!(encode(&ty, &[AbiValue::from(127_i32)]), Ok(_));
+        assert_matches!(encode(&ty, &[AbiValue::from(-128_i32)]), Ok(_));
+        assert_matches!(encode(&ty, &[AbiValue::from(128_i32)]), Err(Error::Abi(_)));
+        assert_matches!(encode(&ty, &[AbiValue::from(-129_i32)]), Err(Error::Abi(_)));
+
+        let encoded = encode(&ty, &[AbiValue::from(-1_i32)]).unwrap();
+        assert_eq!(decode(&ty, &encoded).unwrap(), [AbiValue::Int(BigInt::from(-1))]);
+    }
+
+    #[test]
+    fn uint_bounds() {
+        let ty = [AbiType::Uint(8)];
+
+        assert_matches!(encode(&ty, &[AbiValue::from(255_u32)]), Ok(_));
+        assert_matches!(encode(&ty, &[AbiValue::from(256_u32)]), Err(Error::Abi(_)));
+    }
+
+    #[test]
+    fn type_mismatch() {
+        assert_matches!(encode(&[AbiType::Bool], &[AbiValue::from("true")]), Err(Error::Abi(_)));
+        assert_matches!(encode(&[AbiType::Bool], &[]), Err(Error::Abi(_)));
+    }
+
+    #[test]
+    fn decode_truncated() {
+        let types = types("(string)");
+        let mut encoded = encode(&types, &[AbiValue::from("hello")]).unwrap();
+        encoded.truncate(64);
+
+        assert_matches!(decode(&types, &encoded), Err(Error::Abi(_)));
+    }
+
+    #[test]
+    fn decode_huge_array_length() {
+        let mut data = [0; 64];
+        data[31] = 32;
+        data[32..].fill(0x11);
+        data[32..56].fill(0);
+
+        assert_matches!(decode(&types("(uint256[])"), &data), Err(Error::Abi(_)));
+    }
+
+    #[test]
+    fn invalid_types() {
+        let value = [AbiValue::from(1_u8)];
+
+        assert_matches!(encode(&[AbiType::Int(0)], &value), Err(Error::Abi(_)));
+        assert_matches!(encode(&[AbiType::Uint(512)], &value), Err(Error::Abi(_)));
+        assert_matches!(decode(&[AbiType::Int(512)], &[0; 64]), Err(Error::Abi(_)));
+        assert_matches!(decode(&[AbiType::FixedBytes(40)], &[0; 64]), Err(Error::Abi(_)));
+
+        let huge = AbiType::FixedArray(Box::new(AbiType::Uint(256)), usize::MAX);
+        assert_matches!(
+            encode(&[huge.clone()], &[AbiValue::Array(Vec::new())]),
+            Err(Error::Abi(_))
+        );
+        assert_matches!(decode(&[huge], &[0; 64]), Err(Error::Abi(_)));
+    }
+}
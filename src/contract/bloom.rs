@@ -0,0 +1,94 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use sha3::{
+    Digest,
+    Keccak256,
+};
+
+/// Size of an EVM log bloom filter in bytes (2048 bits).
+const BLOOM_LEN: usize = 256;
+
+/// Returns the topic an event with the given `signature` is logged under (the keccak256 hash of the signature).
+pub(crate) fn event_topic(signature: &str) -> [u8; 32] {
+    Keccak256::digest(signature.as_bytes()).into()
+}
+
+/// Returns the three (byte index, bit mask) pairs `item` sets in a bloom filter.
+fn bloom_bits(item: &[u8]) -> [(usize, u8); 3] {
+    let hash = Keccak256::digest(item);
+
+    [0, 2, 4].map(|i| {
+        let bit = ((usize::from(hash[i]) << 8) | usize::from(hash[i + 1])) & (BLOOM_LEN * 8 - 1);
+
+        (BLOOM_LEN - 1 - bit / 8, 1 << (bit % 8))
+    })
+}
+
+/// Returns `false` if `item` is definitely not in `bloom`, and `true` if it might be.
+///
+/// A `bloom` that isn't 256 bytes long can't rule anything out, so this returns `true` for it.
+pub(crate) fn bloom_may_contain(bloom: &[u8], item: &[u8]) -> bool {
+    if bloom.len() != BLOOM_LEN {
+        return true;
+    }
+
+    bloom_bits(item).iter().all(|(index, mask)| bloom[*index] & mask != 0)
+}
+
+#[cfg(test)]
+pub(crate) fn bloom_add(bloom: &mut [u8; BLOOM_LEN], item: &[u8]) {
+    for (index, mask) in bloom_bits(item) {
+        bloom[index] |= mask;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::{
+        bloom_add,
+        bloom_may_contain,
+        event_topic,
+    };
+
+    #[test]
+    fn transfer_topic() {
+        assert_eq!(
+            event_topic("Transfer(address,address,uint256)"),
+            hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")
+        );
+    }
+
+    #[test]
+    fn may_contain() {
+        let mut bloom = [0; 256];
+        bloom_add(&mut bloom, &event_topic("Transfer(address,address,uint256)"));
+
+        assert!(bloom_may_contain(&bloom, &event_topic("Transfer(address,address,uint256)")));
+        assert!(!bloom_may_contain(&bloom, &event_topic("Approval(address,address,uint256)")));
+    }
+
+    #[test]
+    fn empty_bloom_may_contain_anything() {
+        assert!(bloom_may_contain(&[], b"anything"));
+    }
+}
@@ -0,0 +1,762 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::slice;
+
+use crate::contract::abi_value::{
+    decode,
+    encode,
+};
+use crate::contract::bloom::event_topic;
+use crate::contract::contract_function_selector::ContractFunctionSelector;
+use crate::contract::{
+    AbiType,
+    AbiValue,
+};
+use crate::{
+    ContractLogInfo,
+    Error,
+};
+
+/// A Solidity contract ABI, as produced by `solc --abi` or found in the `abi` field of a build artifact.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractAbi {
+    constructor: Option<ContractAbiFunction>,
+    functions: Vec<ContractAbiFunction>,
+    events: Vec<ContractAbiEvent>,
+}
+
+/// A function (or constructor) in a [`ContractAbi`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractAbiFunction {
+    /// The name of the function, empty for a constructor.
+    pub name: String,
+
+    /// The parameters of the function.
+    pub inputs: Vec<ContractAbiParam>,
+
+    /// The values returned by the function.
+    pub outputs: Vec<ContractAbiParam>,
+
+    /// The state mutability of the function: `pure`, `view`, `nonpayable` or `payable`.
+    pub state_mutability: String,
+}
+
+/// A named parameter of a [`ContractAbiFunction`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractAbiParam {
+    /// The name of the parameter, may be empty.
+    pub name: String,
+
+    /// The type of the parameter.
+    pub ty: AbiType,
+}
+
+/// An event in a [`ContractAbi`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractAbiEvent {
+    /// The name of the event.
+    pub name: String,
+
+    /// The parameters of the event.
+    pub inputs: Vec<ContractAbiEventParam>,
+
+    /// Whether the event is anonymous, meaning its signature isn't logged as the first topic.
+    pub anonymous: bool,
+}
+
+/// A named parameter of a [`ContractAbiEvent`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractAbiEventParam {
+    /// The name of the parameter, may be empty.
+    pub name: String,
+
+    /// The type of the parameter.
+    pub ty: AbiType,
+
+    /// Whether the parameter is logged as a topic instead of in the log's data.
+    pub indexed: bool,
+}
+
+/// A [`ContractLogInfo`] decoded with its [`ContractAbiEvent`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractEventLog {
+    /// The name of the event.
+    pub name: String,
+
+    /// The fields of the event, in declaration order.
+    pub fields: Vec<ContractEventField>,
+}
+
+/// A field of a [`ContractEventLog`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractEventField {
+    /// The name of the field, may be empty.
+    pub name: String,
+
+    /// The value of the field.
+    ///
+    /// Indexed `string`, `bytes`, array and tuple fields are logged as the keccak256 hash of their value,
+    /// so for those this is the 32 byte hash as a [`AbiValue::FixedBytes`].
+    pub value: AbiValue,
+
+    /// Whether the field was logged as a topic.
+    pub indexed: bool,
+}
+
+impl ContractEventLog {
+    /// Returns the value of the field named `name`.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&AbiValue> {
+        self.fields.iter().find(|it| it.name == name).map(|it| &it.value)
+    }
+}
+
+impl ContractAbi {
+    /// Create a `ContractAbi` from its constructor, functions and events.
+    #[must_use]
+    pub fn new(
+        constructor: Option<ContractAbiFunction>,
+        functions: Vec<ContractAbiFunction>,
+        events: Vec<ContractAbiEvent>,
+    ) -> Self {
+        Self { constructor, functions, events }
+    }
+
+    /// Parse a `ContractAbi` from JSON.
+    ///
+    /// Accepts either the ABI itself (a JSON array) or a build artifact with an `abi` field.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `json` isn't a valid ABI.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> crate::Result<Self> {
+        let entries = match serde_json::from_str(json).map_err(Error::basic_parse)? {
+            AbiJson::Artifact { abi } => abi,
+            AbiJson::Entries(entries) => entries,
+        };
+
+        let mut constructor = None;
+        let mut functions = Vec::new();
+        let mut events = Vec::new();
+
+        for entry in entries {
+            match entry.kind.as_str() {
+                "function" => functions.push(entry.try_into()?),
+                "constructor" => constructor = Some(entry.try_into()?),
+                "event" => events.push(entry.try_into()?),
+                _ => {}
+            }
+        }
+
+        Ok(Self { constructor, functions, events })
+    }
+
+    /// Returns the constructor of the contract, if it declares one.
+    #[must_use]
+    pub fn constructor(&self) -> Option<&ContractAbiFunction> {
+        self.constructor.as_ref()
+    }
+
+    /// Returns all the functions of the contract.
+    #[must_use]
+    pub fn functions(&self) -> &[ContractAbiFunction] {
+        &self.functions
+    }
+
+    /// Returns the function named `name`.
+    ///
+    /// `name` can also be a full signature, such as `transfer(address,uint256)`, to pick an overload.
+    /// Otherwise the first function with the name is returned.
+    #[must_use]
+    pub fn function(&self, name: &str) -> Option<&ContractAbiFunction> {
+        if name.contains('(') {
+            return self.functions.iter().find(|it| it.signature() == name);
+        }
+
+        self.functions.iter().find(|it| it.name == name)
+    }
+
+    /// Returns all the events of the contract.
+    #[must_use]
+    pub fn events(&self) -> &[ContractAbiEvent] {
+        &self.events
+    }
+
+    /// Returns the event named `name`.
+    ///
+    /// `name` can also be a full signature, such as `Transfer(address,address,uint256)`.
+    #[must_use]
+    pub fn event(&self, name: &str) -> Option<&ContractAbiEvent> {
+        if name.contains('(') {
+            return self.events.iter().find(|it| it.signature() == name);
+        }
+
+        self.events.iter().find(|it| it.name == name)
+    }
+
+    /// Decode `log` with the (non-anonymous) event whose topic matches the log's first topic.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if no event matches the log, or the log isn't a valid encoding of the event.
+    pub fn decode_log(&self, log: &ContractLogInfo) -> crate::Result<ContractEventLog> {
+        let topic = log.topics.first().map(Vec::as_slice);
+
+        self.events
+            .iter()
+            .filter(|it| !it.anonymous)
+            .find(|it| topic == Some(it.topic().as_slice()))
+            .ok_or_else(|| Error::Abi("no event matches the log's topic".into()))?
+            .decode_log(log)
+    }
+
+    /// Encode a call to the function `name` with `args`, for use as the parameters of a
+    /// [`ContractExecuteTransaction`](crate::ContractExecuteTransaction) or [`ContractCallQuery`](crate::ContractCallQuery).
+    ///
+    /// If the function is overloaded, the overload that takes `args.len()` parameters is used.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if there's no such function, or `args` don't match its parameters.
+    pub fn encode_call(&self, name: &str, args: &[AbiValue]) -> crate::Result<Vec<u8>> {
+        let function = if name.contains('(') {
+            self.function(name)
+        } else {
+            self.functions.iter().find(|it| it.name == name && it.inputs.len() == args.len())
+        };
+
+        function
+            .ok_or_else(|| {
+                Error::Abi(format!("no function `{name}` taking {} arguments", args.len()).into())
+            })?
+            .encode_call(args)
+    }
+
+    /// Encode `args` as the constructor parameters of the contract, for use with
+    /// [`ContractCreateTransaction::constructor_parameters`](crate::ContractCreateTransaction::constructor_parameters).
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `args` don't match the constructor's parameters.
+    pub fn encode_constructor(&self, args: &[AbiValue]) -> crate::Result<Vec<u8>> {
+        match &self.constructor {
+            Some(constructor) => encode(&constructor.input_types(), args),
+            None => encode(&[], args),
+        }
+    }
+}
+
+impl ContractAbiFunction {
+    /// Returns the signature of the function, for example `transfer(address,uint256)`.
+    #[must_use]
+    pub fn signature(&self) -> String {
+        format!("{}{}", self.name, AbiType::Tuple(self.input_types()))
+    }
+
+    /// Returns the 4 byte selector of the function.
+    #[must_use]
+    pub fn selector(&self) -> [u8; 4] {
+        let mut selector = ContractFunctionSelector::new(&self.name);
+
+        for input in &self.inputs {
+            selector.add_param_type(&input.ty.to_string());
+        }
+
+        selector.finish()
+    }
+
+    /// Encode a call to this function with `args`.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `args` don't match the function's parameters.
+    pub fn encode_call(&self, args: &[AbiValue]) -> crate::Result<Vec<u8>> {
+        let mut out = self.selector().to_vec();
+        out.extend(encode(&self.input_types(), args)?);
+        Ok(out)
+    }
+
+    /// Decode the values returned by this function from `data`.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `data` isn't a valid encoding of the function's outputs.
+    pub fn decode_output(&self, data: &[u8]) -> crate::Result<Vec<AbiValue>> {
+        decode(&self.outputs.iter().map(|it| it.ty.clone()).collect::<Vec<_>>(), data)
+    }
+
+    fn input_types(&self) -> Vec<AbiType> {
+        self.inputs.iter().map(|it| it.ty.clone()).collect()
+    }
+}
+
+impl ContractAbiEvent {
+    /// Returns the signature of the event, for example `Transfer(address,address,uint256)`.
+    #[must_use]
+    pub fn signature(&self) -> String {
+        let types = self.inputs.iter().map(|it| it.ty.clone()).collect();
+
+        format!("{}{}", self.name, AbiType::Tuple(types))
+    }
+
+    /// Returns the topic the event is logged under (the keccak256 hash of its signature).
+    #[must_use]
+    pub fn topic(&self) -> [u8; 32] {
+        event_topic(&self.signature())
+    }
+
+    /// Decode the fields of this event from `log`.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `log` isn't an instance of this event.
+    pub fn decode_log(&self, log: &ContractLogInfo) -> crate::Result<ContractEventLog> {
+        let mut topics = log.topics.iter();
+
+        if !self.anonymous && topics.next().map(Vec::as_slice) != Some(self.topic().as_slice()) {
+            return Err(Error::Abi(format!("log isn't a `{}` event", self.signature()).into()));
+        }
+
+        let data_types: Vec<_> =
+            self.inputs.iter().filter(|it| !it.indexed).map(|it| it.ty.clone()).collect();
+
+        let mut data_values = decode(&data_types, &log.data)?.into_iter();
+
+        let mut fields = Vec::with_capacity(self.inputs.len());
+
+        for input in &self.inputs {
+            let value = if input.indexed {
+                let topic = topics.next().ok_or_else(|| {
+                    Error::Abi(format!("log is missing the topic for `{}`", input.name).into())
+                })?;
+
+                match input.ty {
+                    AbiType::Bytes
+                    | AbiType::String
+                    | AbiType::Array(_)
+                    | AbiType::FixedArray(..)
+                    | AbiType::Tuple(_) => AbiValue::FixedBytes(topic.clone()),
+                    _ => decode(slice::from_ref(&input.ty), topic)?.remove(0),
+                }
+            } else {
+                // `decode` returns exactly one value per type.
+                data_values.next().unwrap()
+            };
+
+            fields.push(ContractEventField {
+                name: input.name.clone(),
+                value,
+                indexed: input.indexed,
+            });
+        }
+
+        Ok(ContractEventLog { name: self.name.clone(), fields })
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde_derive::Deserialize)]
+#[serde(untagged)]
+enum AbiJson {
+    Artifact { abi: Vec<EntryJson> },
+    Entries(Vec<EntryJson>),
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde_derive::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EntryJson {
+    #[serde(rename = "type", default = "function_kind")]
+    kind: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    inputs: Vec<ParamJson>,
+    #[serde(default)]
+    outputs: Vec<ParamJson>,
+    #[serde(default = "nonpayable")]
+    state_mutability: String,
+    #[serde(default)]
+    anonymous: bool,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde_derive::Deserialize)]
+struct ParamJson {
+    #[serde(default)]
+    name: String,
+    #[serde(rename = "type")]
+    ty: String,
+    #[serde(default)]
+    components: Vec<ParamJson>,
+    #[serde(default)]
+    indexed: bool,
+}
+
+#[cfg(feature = "serde")]
+fn function_kind() -> String {
+    String::from("function")
+}
+
+#[cfg(feature = "serde")]
+fn nonpayable() -> String {
+    String::from("nonpayable")
+}
+
+#[cfg(feature = "serde")]
+impl ParamJson {
+    /// Returns the canonical type name, expanding `tuple` into its components.
+    fn canonical_type(&self) -> String {
+        match self.ty.strip_prefix("tuple") {
+            Some(suffix) => {
+                let components: Vec<_> =
+                    self.components.iter().map(ParamJson::canonical_type).collect();
+
+                format!("({}){suffix}", components.join(","))
+            }
+            None => self.ty.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<ParamJson> for ContractAbiParam {
+    type Error = Error;
+
+    fn try_from(param: ParamJson) -> crate::Result<Self> {
+        let ty = param.canonical_type().parse()?;
+
+        Ok(Self { name: param.name, ty })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<ParamJson> for ContractAbiEventParam {
+    type Error = Error;
+
+    fn try_from(param: ParamJson) -> crate::Result<Self> {
+        let ty = param.canonical_type().parse()?;
+
+        Ok(Self { name: param.name, ty, indexed: param.indexed })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<EntryJson> for ContractAbiEvent {
+    type Error = Error;
+
+    fn try_from(entry: EntryJson) -> crate::Result<Self> {
+        Ok(Self {
+            name: entry.name,
+            inputs: entry
+                .inputs
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<crate::Result<_>>()?,
+            anonymous: entry.anonymous,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<EntryJson> for ContractAbiFunction {
+    type Error = Error;
+
+    fn try_from(entry: EntryJson) -> crate::Result<Self> {
+        Ok(Self {
+            name: entry.name,
+            inputs: entry
+                .inputs
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<crate::Result<_>>()?,
+            outputs: entry
+                .outputs
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<crate::Result<_>>()?,
+            state_mutability: entry.state_mutability,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use assert_matches::assert_matches;
+    use hex_literal::hex;
+    use num_bigint::BigUint;
+    use sha3::{
+        Digest,
+        Keccak256,
+    };
+
+    use crate::contract::{
+        AbiType,
+        AbiValue,
+    };
+    use crate::{
+        ContractAbi,
+        ContractFunctionParameters,
+        ContractId,
+        ContractLogInfo,
+        Error,
+    };
+
+    const ABI: &str = r#"[
+        {
+            "type": "constructor",
+            "inputs": [{ "name": "message", "type": "string" }],
+            "stateMutability": "nonpayable"
+        },
+        {
+            "type": "function",
+            "name": "transfer",
+            "inputs": [
+                { "name": "to", "type": "address" },
+                { "name": "amount", "type": "uint256" }
+            ],
+            "outputs": [{ "name": "", "type": "bool" }],
+            "stateMutability": "nonpayable"
+        },
+        {
+            "type": "function",
+            "name": "transfer",
+            "inputs": [{ "name": "to", "type": "address" }],
+            "outputs": [],
+            "stateMutability": "payable"
+        },
+        {
+            "type": "function",
+            "name": "getOrders",
+            "inputs": [],
+            "outputs": [{
+                "name": "orders",
+                "type": "tuple[]",
+                "components": [
+                    { "name": "owner", "type": "address" },
+                    { "name": "items", "type": "string[]" }
+                ]
+            }],
+            "stateMutability": "view"
+        },
+        {
+            "type": "event",
+            "name": "Transfer",
+            "anonymous": false,
+            "inputs": [
+                { "name": "from", "type": "address", "indexed": true },
+                { "name": "to", "type": "address", "indexed": true },
+                { "name": "value", "type": "uint256", "indexed": false }
+            ]
+        },
+        {
+            "type": "event",
+            "name": "Renamed",
+            "inputs": [
+                { "name": "name", "type": "string", "indexed": true },
+                { "name": "data", "type": "bytes", "indexed": false }
+            ]
+        }
+    ]"#;
+
+    const FROM: [u8; 20] = [0x11; 20];
+    const TO: [u8; 20] = [0x22; 20];
+
+    fn word(bytes: &[u8]) -> Vec<u8> {
+        let mut word = vec![0; 32 - bytes.len()];
+        word.extend_from_slice(bytes);
+        word
+    }
+
+    fn transfer_log() -> ContractLogInfo {
+        ContractLogInfo {
+            contract_id: ContractId::new(0, 0, 1001),
+            bloom: Vec::new(),
+            topics: Vec::from([
+                hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef").to_vec(),
+                word(&FROM),
+                word(&TO),
+            ]),
+            data: word(&[0x01, 0x00]),
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let abi = ContractAbi::from_json(ABI).unwrap();
+
+        assert_eq!(abi.functions().len(), 3);
+        assert_eq!(abi.constructor().unwrap().inputs[0].ty, AbiType::String);
+
+        let get_orders = abi.function("getOrders").unwrap();
+        assert_eq!(get_orders.state_mutability, "view");
+        assert_eq!(get_orders.outputs[0].ty.to_string(), "(address,string[])[]");
+    }
+
+    #[test]
+    fn parse_artifact() {
+        let abi = ContractAbi::from_json(&format!(r#"{{ "contractName": "Test", "abi": {ABI} }}"#))
+            .unwrap();
+
+        assert_eq!(abi.functions().len(), 3);
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert_matches!(ContractAbi::from_json("{}"), Err(Error::BasicParse(_)));
+        assert_matches!(
+            ContractAbi::from_json(
+                r#"[{ "type": "function", "name": "f", "inputs": [{ "type": "uint7" }] }]"#
+            ),
+            Err(Error::BasicParse(_))
+        );
+    }
+
+    #[test]
+    fn function_by_signature() {
+        let abi = ContractAbi::from_json(ABI).unwrap();
+
+        assert_eq!(abi.function("transfer(address)").unwrap().state_mutability, "payable");
+        assert_eq!(abi.function("transfer(address,uint256)").unwrap().inputs.len(), 2);
+    }
+
+    #[test]
+    fn encode_call() {
+        let abi = ContractAbi::from_json(ABI).unwrap();
+
+        let address = "11223344556677889900aabbccddeeff00112233";
+
+        let encoded = abi
+            .encode_call(
+                "transfer",
+                &[AbiValue::address(address).unwrap(), AbiValue::from(100_u64)],
+            )
+            .unwrap();
+
+        let expected = ContractFunctionParameters::new()
+            .add_address(address)
+            .add_uint256(BigUint::from(100_u64))
+            .to_bytes(Some("transfer"));
+
+        assert_eq!(encoded, expected);
+    }
+
+    #[test]
+    fn encode_call_unknown() {
+        let abi = ContractAbi::from_json(ABI).unwrap();
+
+        assert_matches!(abi.encode_call("transfer", &[]), Err(Error::Abi(_)));
+        assert_matches!(abi.encode_call("approve", &[]), Err(Error::Abi(_)));
+    }
+
+    #[test]
+    fn encode_constructor() {
+        let abi = ContractAbi::from_json(ABI).unwrap();
+
+        assert_eq!(
+            abi.encode_constructor(&[AbiValue::from("hello")]).unwrap(),
+            ContractFunctionParameters::new().add_string("hello").to_bytes(None)
+        );
+    }
+
+    #[test]
+    fn decode_output() {
+        let abi = ContractAbi::from_json(ABI).unwrap();
+        let get_orders = abi.function("getOrders").unwrap();
+
+        let orders = AbiValue::Array(Vec::from([AbiValue::Tuple(Vec::from([
+            AbiValue::Address([0x11; 20]),
+            AbiValue::Array(Vec::from([AbiValue::from("apple"), AbiValue::from("pear")])),
+        ]))]));
+
+        let encoded = crate::contract::abi_value::encode(
+            &[get_orders.outputs[0].ty.clone()],
+            &[orders.clone()],
+        )
+        .unwrap();
+
+        assert_eq!(get_orders.decode_output(&encoded).unwrap(), [orders]);
+    }
+
+    #[test]
+    fn event_topic() {
+        let abi = ContractAbi::from_json(ABI).unwrap();
+        let transfer = abi.event("Transfer").unwrap();
+
+        assert_eq!(transfer.signature(), "Transfer(address,address,uint256)");
+        assert_eq!(
+            transfer.topic(),
+            hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")
+        );
+    }
+
+    #[test]
+    fn decode_log() {
+        let abi = ContractAbi::from_json(ABI).unwrap();
+
+        let log = abi.decode_log(&transfer_log()).unwrap();
+
+        assert_eq!(log.name, "Transfer");
+        assert_eq!(log.get("from"), Some(&AbiValue::Address(FROM)));
+        assert_eq!(log.get("to"), Some(&AbiValue::Address(TO)));
+        assert_eq!(log.get("value"), Some(&AbiValue::from(256_u32)));
+        assert!(log.fields[0].indexed);
+        assert!(!log.fields[2].indexed);
+    }
+
+    #[test]
+    fn decode_log_indexed_dynamic() {
+        let abi = ContractAbi::from_json(ABI).unwrap();
+        let renamed = abi.event("Renamed").unwrap();
+
+        let name_hash = Keccak256::digest(b"new name").to_vec();
+
+        let log = ContractLogInfo {
+            contract_id: ContractId::new(0, 0, 1001),
+            bloom: Vec::new(),
+            topics: Vec::from([renamed.topic().to_vec(), name_hash.clone()]),
+            data: crate::contract::abi_value::encode(
+                &[AbiType::Bytes],
+                &[AbiValue::Bytes(Vec::from([1, 2, 3]))],
+            )
+            .unwrap(),
+        };
+
+        let log = renamed.decode_log(&log).unwrap();
+
+        assert_eq!(log.get("name"), Some(&AbiValue::FixedBytes(name_hash)));
+        assert_eq!(log.get("data"), Some(&AbiValue::Bytes(Vec::from([1, 2, 3]))));
+    }
+
+    #[test]
+    fn decode_log_wrong_event() {
+        let abi = ContractAbi::from_json(ABI).unwrap();
+
+        assert_matches!(
+            abi.event("Renamed").unwrap().decode_log(&transfer_log()),
+            Err(Error::Abi(_))
+        );
+    }
+
+    #[test]
+    fn decode_log_missing_topic() {
+        let abi = ContractAbi::from_json(ABI).unwrap();
+
+        let mut log = transfer_log();
+        log.topics.pop();
+
+        assert_matches!(abi.decode_log(&log), Err(Error::Abi(_)));
+    }
+}
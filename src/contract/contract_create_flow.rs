@@ -35,6 +35,7 @@ use crate::{
     Key,
     PrivateKey,
     PublicKey,
+    Signer,
     TransactionResponse,
 };
 
@@ -299,6 +300,15 @@ impl ContractCreateFlow {
         self
     }
 
+    /// Sets the signer for use in the ``ContractCreateTransaction``
+    ///
+    /// Important: Only *one* signer is allowed.
+    pub fn sign_with_signer<S: Signer + 'static>(&mut self, signer: S) -> &mut Self {
+        self.contract_data.signer = Some(AnySigner::signer(signer));
+
+        self
+    }
+
     /// Generates the required transactions and executes them all.
     pub async fn execute(&self, client: &Client) -> crate::Result<TransactionResponse> {
         self.execute_with_optional_timeout(client, None).await
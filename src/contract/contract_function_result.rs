@@ -27,9 +27,15 @@ use num_bigint::{
     BigUint,
 };
 
+use crate::contract::bloom::{
+    bloom_may_contain,
+    event_topic,
+};
 use crate::protobuf::ToProtobuf;
 use crate::{
+    AbiValue,
     AccountId,
+    ContractAbiFunction,
     ContractId,
     ContractLogInfo,
     ContractNonceInfo,
@@ -38,6 +44,8 @@ use crate::{
 
 /// The result returned by a call to a smart contract function.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ContractFunctionResult {
     /// The smart contract instance whose function was called.
     pub contract_id: ContractId,
@@ -46,12 +54,14 @@ pub struct ContractFunctionResult {
     pub evm_address: Option<ContractId>,
 
     /// The raw bytes returned by the function.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub bytes: Vec<u8>,
 
     /// Message if there was an error during smart contract execution.
     pub error_message: Option<String>,
 
     /// Bloom filter for record.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub bloom: Vec<u8>,
 
     /// Units of gas used to execute contract.
@@ -64,6 +74,7 @@ pub struct ContractFunctionResult {
     pub hbar_amount: u64,
 
     /// The parameters passed into the contract call.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub contract_function_parameters_bytes: Vec<u8>,
 
     /// The account that is the "sender." If not present it is the accountId from the transactionId.
@@ -223,6 +234,31 @@ impl ContractFunctionResult {
     pub fn get_i256(&self, index: usize) -> Option<BigInt> {
         self.get_bytes32(index).map(|it| BigInt::from_signed_bytes_be(it))
     }
+
+    /// Returns the logs emitted by the (non-anonymous) event with the given `signature`,
+    /// for example `Transfer(address,address,uint256)`.
+    pub fn logs_for_event(&self, signature: &str) -> impl Iterator<Item = &ContractLogInfo> {
+        let topic = event_topic(signature);
+
+        self.logs.iter().filter(move |it| it.has_topic(&topic))
+    }
+
+    /// Returns `false` if no log in this result can have been emitted by the event with the given `signature`,
+    /// and `true` if one might have been, according to the result's [`bloom`](Self::bloom).
+    #[must_use]
+    pub fn bloom_may_contain_event(&self, signature: &str) -> bool {
+        bloom_may_contain(&self.bloom, &event_topic(signature))
+    }
+
+    /// Decode the whole result as the values returned by `function`.
+    ///
+    /// Unlike the positional getters, this handles tuples, nested arrays and any other dynamic types.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`](crate::Error::Abi) if the result isn't a valid encoding of the function's outputs.
+    pub fn decode(&self, function: &ContractAbiFunction) -> crate::Result<Vec<AbiValue>> {
+        function.decode_output(&self.bytes)
+    }
 }
 
 impl FromProtobuf<services::ContractFunctionResult> for ContractFunctionResult {
@@ -328,7 +364,10 @@ mod tests {
         ToProtobuf,
     };
     use crate::{
+        AbiValue,
         AccountId,
+        ContractAbiFunction,
+        ContractAbiParam,
         ContractFunctionResult,
         ContractId,
         ContractNonceInfo,
@@ -472,6 +511,69 @@ mod tests {
         )
     }
 
+    #[test]
+    fn decode() {
+        let result = services::ContractFunctionResult {
+            contract_id: Some(ContractId::from(3).to_protobuf()),
+            contract_call_result: CALL_RESULT.to_vec(),
+            ..Default::default()
+        };
+
+        let result = ContractFunctionResult::from_protobuf(result).unwrap();
+
+        let function = ContractAbiFunction {
+            name: "getResults".to_owned(),
+            inputs: Vec::new(),
+            outputs: ["uint32", "int256", "address", "uint256", "string", "string"]
+                .into_iter()
+                .map(|ty| ContractAbiParam { name: String::new(), ty: ty.parse().unwrap() })
+                .collect(),
+            state_mutability: "view".to_owned(),
+        };
+
+        let values = result.decode(&function).unwrap();
+
+        assert_eq!(values[0], AbiValue::Uint(BigUint::from(u32::MAX)));
+        assert_eq!(values[1], AbiValue::Int((BigInt::from(1) << 255) - 1));
+        assert_eq!(values[2], AbiValue::Address(hex!("11223344556677889900aabbccddeeff00112233")));
+        assert_eq!(values[3], AbiValue::Uint((BigUint::from(1_u8) << 256) - 1_u32));
+        assert_eq!(values[4], AbiValue::from("Hello, world!"));
+        assert_eq!(values[5], AbiValue::from("Hello, world, again!"));
+    }
+
+    #[test]
+    fn logs_for_event() {
+        const TRANSFER: &str = "Transfer(address,address,uint256)";
+
+        let log = |signature: &str| ContractLogInfo {
+            contract_id: ContractId::from(3),
+            bloom: Vec::new(),
+            topics: Vec::from([event_topic(signature).to_vec()]),
+            data: Vec::new(),
+        };
+
+        let mut bloom = [0; 256];
+        bloom_add(&mut bloom, &event_topic(TRANSFER));
+
+        let result = ContractFunctionResult {
+            bloom: bloom.to_vec(),
+            logs: Vec::from([
+                log(TRANSFER),
+                log("Approval(address,address,uint256)"),
+                log(TRANSFER),
+            ]),
+            ..ContractFunctionResult::from_protobuf(services::ContractFunctionResult {
+                contract_id: Some(ContractId::from(3).to_protobuf()),
+                ..Default::default()
+            })
+            .unwrap()
+        };
+
+        assert_eq!(result.logs_for_event(TRANSFER).count(), 2);
+        assert!(result.bloom_may_contain_event(TRANSFER));
+        assert!(!result.bloom_may_contain_event("Approval(address,address,uint256)"));
+    }
+
     #[test]
     fn str_array_results() {
         let result = services::ContractFunctionResult {
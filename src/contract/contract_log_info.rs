@@ -1,25 +1,38 @@
 use hedera_proto::services;
 
+use crate::contract::bloom::{
+    bloom_may_contain,
+    event_topic,
+};
 use crate::protobuf::{
     FromProtobuf,
     ToProtobuf,
 };
-use crate::ContractId;
+use crate::{
+    ContractAbiEvent,
+    ContractEventLog,
+    ContractId,
+};
 
 /// The log information for an event returned by a smart contract function call.
 /// One function call may return several such events.
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ContractLogInfo {
     /// Address of the contract that emitted the event.
     pub contract_id: ContractId,
 
     /// Bloom filter for this log.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub bloom: Vec<u8>,
 
     /// A list of topics this log is relevent to.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex_list"))]
     pub topics: Vec<Vec<u8>>,
 
     /// The log's data payload.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub data: Vec<u8>,
 }
 
@@ -38,6 +51,32 @@ impl ContractLogInfo {
     pub fn to_bytes(&self) -> Vec<u8> {
         ToProtobuf::to_bytes(self)
     }
+
+    /// Returns `true` if this log was emitted by the (non-anonymous) event with the given `signature`,
+    /// for example `Transfer(address,address,uint256)`.
+    #[must_use]
+    pub fn is_event(&self, signature: &str) -> bool {
+        self.has_topic(&event_topic(signature))
+    }
+
+    /// Returns `false` if `item` (an address or topic) is definitely not in this log's [`bloom`](Self::bloom),
+    /// and `true` if it might be.
+    #[must_use]
+    pub fn bloom_may_contain(&self, item: &[u8]) -> bool {
+        bloom_may_contain(&self.bloom, item)
+    }
+
+    /// Decode the fields of this log with `event`.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`](crate::Error::Abi) if this log isn't an instance of `event`.
+    pub fn decode(&self, event: &ContractAbiEvent) -> crate::Result<ContractEventLog> {
+        event.decode_log(self)
+    }
+
+    pub(crate) fn has_topic(&self, topic: &[u8; 32]) -> bool {
+        self.topics.first().is_some_and(|it| it[..] == topic[..])
+    }
 }
 
 impl FromProtobuf<services::ContractLoginfo> for ContractLogInfo {
@@ -73,6 +112,10 @@ mod tests {
     use hedera_proto::services;
     use prost::Message;
 
+    use crate::contract::bloom::{
+        bloom_add,
+        event_topic,
+    };
     use crate::protobuf::{
         FromProtobuf,
         ToProtobuf,
@@ -166,6 +209,27 @@ mod tests {
         .assert_debug_eq(&ContractLogInfo::from_protobuf(make_info()).unwrap().to_protobuf())
     }
 
+    #[test]
+    fn is_event() {
+        let mut info = ContractLogInfo::from_protobuf(make_info()).unwrap();
+        info.topics[0] = event_topic("Transfer(address,address,uint256)").to_vec();
+
+        assert!(info.is_event("Transfer(address,address,uint256)"));
+        assert!(!info.is_event("Approval(address,address,uint256)"));
+    }
+
+    #[test]
+    fn bloom_may_contain() {
+        let mut bloom = [0; 256];
+        bloom_add(&mut bloom, b"topic");
+
+        let mut info = ContractLogInfo::from_protobuf(make_info()).unwrap();
+        info.bloom = bloom.to_vec();
+
+        assert!(info.bloom_may_contain(b"topic"));
+        assert!(!info.bloom_may_contain(b"other topic"));
+    }
+
     #[test]
     fn from_bytes() {
         expect![[r#"
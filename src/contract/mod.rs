@@ -18,6 +18,10 @@
  * ‍
  */
 
+mod abi_type;
+mod abi_value;
+mod bloom;
+mod contract_abi;
 mod contract_bytecode_query;
 mod contract_call_query;
 mod contract_create_flow;
@@ -35,6 +39,18 @@ mod contract_nonce_info;
 mod contract_update_transaction;
 mod delegate_contract_id;
 
+pub use abi_type::AbiType;
+pub use abi_value::AbiValue;
+pub(crate) use bloom::event_topic;
+pub use contract_abi::{
+    ContractAbi,
+    ContractAbiEvent,
+    ContractAbiEventParam,
+    ContractAbiFunction,
+    ContractAbiParam,
+    ContractEventField,
+    ContractEventLog,
+};
 pub use contract_bytecode_query::ContractBytecodeQuery;
 pub(crate) use contract_bytecode_query::ContractBytecodeQueryData;
 pub use contract_call_query::ContractCallQuery;
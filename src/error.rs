@@ -25,6 +25,7 @@ use crate::entity_id::Checksum;
 use crate::{
     AccountId,
     Hbar,
+    RequiredSigner,
     Status,
     TransactionId,
 };
@@ -183,6 +184,10 @@ pub enum Error {
     #[error("node account `{0}` was not found in the configured network")]
     NodeAccountUnknown(Box<AccountId>),
 
+    /// The TLS certificate presented by a node didn't match the certificate hash in the address book.
+    #[error("node `{0}` presented a TLS certificate that doesn't match the address book")]
+    NodeCertificateMismatch(Box<AccountId>),
+
     /// Received an unrecognized status code from the Hedera Network.
     ///
     /// This can happen when the SDK is outdated, try updating your SDK.
@@ -202,6 +207,60 @@ pub enum Error {
     /// Failed to verify a signature.
     #[error("failed to verify a signature: {0}")]
     SignatureVerify(#[source] BoxStdError),
+
+    /// Failed to encode or decode values with a Solidity ABI.
+    #[error("ABI error: {0}")]
+    Abi(#[source] BoxStdError),
+
+    /// Failed to estimate a fee from a fee schedule.
+    #[error("failed to estimate fee: {0}")]
+    FeeEstimate(#[source] BoxStdError),
+
+    /// A request to the mirror node REST API failed, or its response couldn't be decoded.
+    #[cfg(feature = "mirror-rest")]
+    #[error("mirror node REST request failed: {0}")]
+    MirrorRest(#[source] BoxStdError),
+
+    /// The mirror node REST API responded to a request with an unsuccessful HTTP status code.
+    #[cfg(feature = "mirror-rest")]
+    #[error("mirror node REST API responded to `{path}` with HTTP status {status}")]
+    MirrorRestStatus {
+        /// The HTTP status code.
+        status: u16,
+        /// The path of the request, relative to the mirror node's base URL.
+        path: String,
+    },
+
+    /// Failed to sign a transaction.
+    ///
+    /// [`Signer`](crate::Signer) implementations should return this when they fail.
+    #[error("failed to sign: {0}")]
+    Signature(#[source] BoxStdError),
+
+    /// Failed to merge the signatures of two [`SignatureCollection`](crate::SignatureCollection)s.
+    #[error("failed to merge signatures: {0}")]
+    SignatureMerge(#[source] BoxStdError),
+
+    /// The key of a [`RequiredSigner`] isn't published by the network, so it can't be fetched.
+    #[error("the key of required signer `{0:?}` can't be fetched from the network")]
+    RequiredSignerKeyUnavailable(Box<RequiredSigner>),
+
+    /// A message from [`TopicMessageQuery`](crate::TopicMessageQuery) failed running hash verification.
+    #[error("failed to verify topic message: {0}")]
+    TopicMessageVerify(#[from] TopicMessageVerifyError),
+
+    /// Failed to load a [`ClientConfig`](crate::ClientConfig), other than by parsing it.
+    #[cfg(feature = "serde")]
+    #[error("failed to load client config: {0}")]
+    ClientConfig(#[source] BoxStdError),
+
+    /// A [`TopicMessageCheckpointStore`](crate::TopicMessageCheckpointStore) failed to load or save a checkpoint.
+    #[error("failed to load or save a topic message checkpoint: {0}")]
+    TopicMessageCheckpoint(#[source] BoxStdError),
+
+    /// Failed to encrypt or decrypt a [`TopicMessageEnvelope`](crate::TopicMessageEnvelope).
+    #[error("failed to encrypt or decrypt a topic message: {0}")]
+    TopicMessageEnvelope(#[source] BoxStdError),
 }
 
 impl Error {
@@ -221,9 +280,104 @@ impl Error {
         Self::BasicParse(error.into())
     }
 
+    pub(crate) fn fee_estimate(error: impl Into<BoxStdError>) -> Self {
+        Self::FeeEstimate(error.into())
+    }
+
+    #[cfg(feature = "mirror-rest")]
+    pub(crate) fn mirror_rest(error: impl Into<BoxStdError>) -> Self {
+        Self::MirrorRest(error.into())
+    }
+
     pub(crate) fn signature_verify(error: impl Into<BoxStdError>) -> Self {
         Self::SignatureVerify(error.into())
     }
+
+    pub(crate) fn signature_merge(error: impl Into<BoxStdError>) -> Self {
+        Self::SignatureMerge(error.into())
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn client_config(error: impl Into<BoxStdError>) -> Self {
+        Self::ClientConfig(error.into())
+    }
+
+    pub(crate) fn topic_message_checkpoint(error: impl Into<BoxStdError>) -> Self {
+        Self::TopicMessageCheckpoint(error.into())
+    }
+
+    pub(crate) fn topic_message_envelope(error: impl Into<BoxStdError>) -> Self {
+        Self::TopicMessageEnvelope(error.into())
+    }
+}
+
+/// A topic message that failed running hash verification,
+/// see [`TopicMessageQuery::verify_running_hash`](crate::TopicMessageQuery::verify_running_hash).
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum TopicMessageVerifyError {
+    /// A message was skipped, either the mirror node dropped it or messages arrived out of order.
+    #[error("sequence gap: expected message `{expected}`, found `{actual}`")]
+    SequenceGap {
+        /// The sequence number that was expected.
+        expected: u64,
+        /// The sequence number that was actually found.
+        actual: u64,
+    },
+
+    /// The running hash of a message doesn't match the one computed from the previous message.
+    #[error(
+        "running hash mismatch for message `{sequence_number}`: expected `{}`, found `{}`",
+        hex::encode(expected),
+        hex::encode(actual)
+    )]
+    RunningHashMismatch {
+        /// The sequence number of the message.
+        sequence_number: u64,
+        /// The running hash that was computed.
+        expected: Vec<u8>,
+        /// The running hash that the mirror node returned.
+        actual: Vec<u8>,
+    },
+
+    /// The payer of a message isn't known, so its running hash can't be computed.
+    ///
+    /// The mirror node only returns the payer of messages that were submitted with chunk info.
+    #[error("the payer of message `{sequence_number}` is unknown")]
+    UnknownPayer {
+        /// The sequence number of the message.
+        sequence_number: u64,
+    },
+
+    /// The running hash of a message uses a version other than 3.
+    #[error("message `{sequence_number}` has unsupported running hash version `{version}`")]
+    UnsupportedVersion {
+        /// The sequence number of the message.
+        sequence_number: u64,
+        /// The running hash version of the message.
+        version: u64,
+    },
+
+    /// A running hash isn't 48 bytes long, so it can't be a SHA-384 running hash.
+    #[error("running hash of message `{sequence_number}` is {length} bytes long, expected 48")]
+    InvalidRunningHash {
+        /// The sequence number of the message the running hash belongs to.
+        sequence_number: u64,
+        /// The length of the running hash.
+        length: usize,
+    },
+
+    /// A message came after a message with the largest possible sequence number.
+    #[error("message `{sequence_number}` follows the largest possible sequence number")]
+    SequenceNumberOverflow {
+        /// The sequence number of the message.
+        sequence_number: u64,
+    },
+
+    /// Running hash verification was requested, but no topic ID was set,
+    /// so the running hash can't be computed.
+    #[error("cannot verify running hashes without a topic ID")]
+    MissingTopicId,
 }
 
 /// Failed to parse a mnemonic.
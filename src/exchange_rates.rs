@@ -25,6 +25,8 @@ use crate::protobuf::FromProtobuf;
 
 /// The current and next exchange rates between [`Hbar`](crate::HbarUnit::Hbar) and USD-cents.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ExchangeRates {
     /// The current exchange rate between [`Hbar`](crate::HbarUnit::Hbar) and USD-cents.
     pub current_rate: ExchangeRate,
@@ -54,6 +56,8 @@ impl FromProtobuf<services::ExchangeRateSet> for ExchangeRates {
 
 /// Denotes a conversion between Hbars and cents (USD).
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ExchangeRate {
     /// Denotes [`Hbar`](crate::HbarUnit::Hbar) equivalent to cents (USD).
     pub hbars: u32,
@@ -62,6 +66,7 @@ pub struct ExchangeRate {
     pub cents: u32,
 
     /// Expiration time of this exchange rate.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::timestamp"))]
     pub expiration_time: OffsetDateTime,
 }
 
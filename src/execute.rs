@@ -22,6 +22,10 @@ use std::any::type_name;
 use std::borrow::Cow;
 use std::error::Error as StdError;
 use std::ops::ControlFlow;
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
 use std::time::{
     Duration,
     Instant,
@@ -41,7 +45,12 @@ use tonic::transport::Channel;
 use triomphe::Arc;
 
 use crate::client::NetworkData;
+use crate::execute_policy::{
+    ExecutePolicy,
+    PolicyBackoff,
+};
 use crate::ping_query::PingQuery;
+use crate::request_hook::request_type_name;
 use crate::{
     client,
     retry,
@@ -49,6 +58,11 @@ use crate::{
     BoxGrpcFuture,
     Client,
     Error,
+    NodeSelector,
+    RequestAttempt,
+    RequestHook,
+    RetryDecision,
+    RetryPolicy,
     Status,
     TransactionId,
     ValidateChecksums,
@@ -87,6 +101,11 @@ pub(crate) trait Execute: ValidateChecksums {
         None
     }
 
+    /// Get the _explicit_ retry policy and node selector for this request, which take priority over the client's.
+    fn execute_policy(&self) -> Option<&ExecutePolicy> {
+        None
+    }
+
     /// Check whether to retry an pre-check status.
     fn should_retry_pre_check(&self, _status: Status) -> bool {
         false
@@ -102,11 +121,13 @@ pub(crate) trait Execute: ValidateChecksums {
     ///
     /// A created request is cached per node until any request returns
     /// `TransactionExpired`; in which case, the request cache is cleared.
-    fn make_request(
-        &self,
-        transaction_id: Option<&TransactionId>,
+    ///
+    /// This is async so that signatures can come from a [`Signer`](crate::Signer).
+    fn make_request<'a>(
+        &'a self,
+        transaction_id: Option<&'a TransactionId>,
         node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)>;
+    ) -> BoxFuture<'a, crate::Result<(Self::GrpcRequest, Self::Context)>>;
 
     /// Execute the created GRPC request against the provided GRPC channel.
     fn execute(
@@ -140,10 +161,40 @@ struct ExecuteContext {
     // When `Some` the `transaction_id` will be regenerated when expired.
     operator_account_id: Option<AccountId>,
     network: Arc<NetworkData>,
-    backoff_config: ExponentialBackoff,
+    backoff_config: ExecuteBackoff,
     max_attempts: usize,
     // timeout for a single grpc request.
     grpc_timeout: Option<Duration>,
+    retry_policy: Option<Arc<dyn RetryPolicy>>,
+    node_selector: Option<Arc<dyn NodeSelector>>,
+    request_hook: Option<Arc<dyn RequestHook>>,
+    // short name of the request, for the request hook.
+    request_type: &'static str,
+    // number of nodes tried so far, across all retries.
+    attempts: AtomicUsize,
+}
+
+/// Either the default exponential backoff, or one from a user provided [`RetryPolicy`].
+#[derive(Clone)]
+enum ExecuteBackoff {
+    Exponential(ExponentialBackoff),
+    Policy(PolicyBackoff),
+}
+
+impl backoff::backoff::Backoff for ExecuteBackoff {
+    fn reset(&mut self) {
+        match self {
+            Self::Exponential(it) => it.reset(),
+            Self::Policy(it) => it.reset(),
+        }
+    }
+
+    fn next_backoff(&mut self) -> Option<Duration> {
+        match self {
+            Self::Exponential(it) => it.next_backoff(),
+            Self::Policy(it) => it.next_backoff(),
+        }
+    }
 }
 
 pub(crate) async fn execute<E>(
@@ -178,28 +229,57 @@ where
             .or_else(|| client.load_operator().as_ref().map(|it| it.account_id))
     };
 
+    let policy = executable.execute_policy();
+    let retry_policy =
+        policy.and_then(|it| it.retry_policy.clone()).or_else(|| client.retry_policy());
+    let node_selector =
+        policy.and_then(|it| it.node_selector.clone()).or_else(|| client.node_selector());
+
     let backoff = client.backoff();
-    let mut backoff_builder = ExponentialBackoffBuilder::new();
+    let timeout = timeout.or(backoff.request_timeout);
 
-    backoff_builder
-        .with_initial_interval(backoff.initial_backoff)
-        .with_max_interval(backoff.max_backoff);
+    let max_attempts =
+        retry_policy.as_ref().and_then(|it| it.max_attempts()).unwrap_or(backoff.max_attempts);
 
-    if let Some(timeout) = timeout.or(backoff.request_timeout) {
-        backoff_builder.with_max_elapsed_time(Some(timeout));
-    }
+    let backoff_config = match &retry_policy {
+        Some(policy) => ExecuteBackoff::Policy(PolicyBackoff::new(policy.clone(), timeout)),
+        None => {
+            let mut backoff_builder = ExponentialBackoffBuilder::new();
+
+            backoff_builder
+                .with_initial_interval(backoff.initial_backoff)
+                .with_max_interval(backoff.max_backoff);
+
+            if let Some(timeout) = timeout {
+                backoff_builder.with_max_elapsed_time(Some(timeout));
+            }
+
+            ExecuteBackoff::Exponential(backoff_builder.build())
+        }
+    };
+
+    let ctx = ExecuteContext {
+        max_attempts,
+        backoff_config,
+        operator_account_id,
+        network: client.net().0.load_full(),
+        grpc_timeout: backoff.grpc_timeout,
+        retry_policy,
+        node_selector,
+        request_hook: client.request_hook(),
+        request_type: request_type_name(type_name::<E>()),
+        attempts: AtomicUsize::new(0),
+    };
 
-    execute_inner(
-        &ExecuteContext {
-            max_attempts: backoff.max_attempts,
-            backoff_config: backoff_builder.build(),
-            operator_account_id,
-            network: client.net().0.load_full(),
-            grpc_timeout: backoff.grpc_timeout,
-        },
-        executable,
-    )
-    .await
+    let fut = execute_inner(&ctx, executable);
+
+    #[cfg(feature = "tracing")]
+    let fut = tracing::Instrument::instrument(
+        fut,
+        tracing::debug_span!("execute", request_type = ctx.request_type),
+    );
+
+    fut.await
 }
 
 async fn execute_inner<E>(ctx: &ExecuteContext, executable: &E) -> crate::Result<E::Response>
@@ -208,12 +288,18 @@ where
 {
     fn recurse_ping(ctx: &ExecuteContext, index: usize) -> BoxFuture<'_, bool> {
         Box::pin(async move {
+            // the ping has to go to the node being checked, and shouldn't be failed by the request's policies.
             let ctx = ExecuteContext {
                 operator_account_id: None,
                 network: Arc::clone(&ctx.network),
                 backoff_config: ctx.backoff_config.clone(),
                 max_attempts: ctx.max_attempts,
                 grpc_timeout: ctx.grpc_timeout,
+                retry_policy: None,
+                node_selector: None,
+                request_hook: ctx.request_hook.clone(),
+                request_type: request_type_name(type_name::<PingQuery>()),
+                attempts: AtomicUsize::new(0),
             };
             let ping_query = PingQuery::new(ctx.network.node_ids()[index]);
 
@@ -247,8 +333,13 @@ where
         loop {
             let mut last_error: Option<Error> = None;
 
-            let random_node_indexes = random_node_indexes(&ctx.network, explicit_node_indexes)
-                .ok_or(retry::Error::EmptyTransient)?;
+            let random_node_indexes = match &ctx.node_selector {
+                Some(selector) => {
+                    selected_node_indexes(&ctx.network, &**selector, explicit_node_indexes)
+                }
+                None => random_node_indexes(&ctx.network, explicit_node_indexes),
+            }
+            .ok_or(retry::Error::EmptyTransient)?;
 
             let random_node_indexes = {
                 let random_node_indexes = &random_node_indexes;
@@ -325,6 +416,15 @@ fn map_tonic_error(
 
     const MIME_HTML: &[u8] = b"text/html";
 
+    // the node isn't who the address book says it is, so don't talk to it for a while.
+    if client::is_certificate_hash_mismatch(&status) {
+        network.mark_node_unhealthy(node_index);
+
+        return retry::Error::Transient(Error::NodeCertificateMismatch(Box::new(
+            network.node_ids()[node_index],
+        )));
+    }
+
     match status.code() {
         // if the node says it isn't available, then we should just try again with a different node.
         tonic::Code::Unavailable | tonic::Code::ResourceExhausted => {
@@ -381,20 +481,68 @@ async fn execute_single<E: Execute + Sync>(
 
     let (request, context) = executable
         .make_request(transaction_id.as_ref(), node_account_id)
+        .await
         .map_err(crate::retry::Error::Permanent)?;
 
+    let attempt = ctx.attempts.fetch_add(1, Ordering::Relaxed) + 1;
+
     let fut = executable.execute(channel, request);
 
+    #[cfg(feature = "tracing")]
+    let fut = tracing::Instrument::instrument(
+        fut,
+        tracing::debug_span!("attempt", node_account_id = %node_account_id, attempt),
+    );
+
+    let start = Instant::now();
+
     let response = match ctx.grpc_timeout {
-        Some(it) => match tokio::time::timeout(it, fut).await {
-            Ok(it) => it,
-            Err(_) => {
-                return Ok(ControlFlow::Continue(crate::Error::GrpcStatus(
-                    tonic::Status::deadline_exceeded("explicitly given grpc timeout was exceeded"),
-                )))
-            }
-        },
-        None => fut.await,
+        Some(it) => tokio::time::timeout(it, fut).await.ok(),
+        None => Some(fut.await),
+    };
+
+    let latency = start.elapsed();
+
+    ctx.network.record_node_latency(node_index, latency);
+
+    match &response {
+        Some(Ok(_)) => {}
+        Some(Err(status)) => ctx
+            .network
+            .record_node_error(node_index, format!("{:?}: {}", status.code(), status.message())),
+        None => ctx.network.record_node_error(node_index, "grpc timeout was exceeded".to_owned()),
+    }
+
+    if let Some(selector) = &ctx.node_selector {
+        selector.record(node_account_id, latency, matches!(response, Some(Ok(_))));
+    }
+
+    if let Some(hook) = &ctx.request_hook {
+        let (grpc_status, status) = match &response {
+            Some(Ok(response)) => (
+                tonic::Code::Ok,
+                E::response_pre_check_status(response.get_ref())
+                    .ok()
+                    .and_then(|status| Status::try_from(status).ok()),
+            ),
+            Some(Err(status)) => (status.code(), None),
+            None => (tonic::Code::DeadlineExceeded, None),
+        };
+
+        hook.on_attempt(&RequestAttempt {
+            request_type: ctx.request_type,
+            node_account_id,
+            attempt,
+            latency,
+            grpc_status,
+            status,
+        });
+    }
+
+    let Some(response) = response else {
+        return Ok(ControlFlow::Continue(crate::Error::GrpcStatus(
+            tonic::Status::deadline_exceeded("explicitly given grpc timeout was exceeded"),
+        )));
     };
 
     let response = response.map(tonic::Response::into_inner).map_err(|status| {
@@ -420,6 +568,22 @@ async fn execute_single<E: Execute + Sync>(
         })
         .map_err(retry::Error::Permanent)?;
 
+    // a user provided retry policy gets the first say on how to handle a failing status.
+    let decision = match status {
+        Status::Ok => None,
+        _ => ctx.retry_policy.as_ref().and_then(|it| it.classify_pre_check(status)),
+    };
+
+    if let Some(decision) = decision {
+        let error = executable.make_error_pre_check(status, transaction_id.as_ref());
+
+        return match decision {
+            RetryDecision::NextNode => Ok(ControlFlow::Continue(error)),
+            RetryDecision::Backoff => Err(retry::Error::Transient(error)),
+            RetryDecision::Fail => Err(retry::Error::Permanent(error)),
+        };
+    }
+
     match status {
         Status::Ok if executable.should_retry(&response) => Err(retry::Error::Transient(
             executable.make_error_pre_check(status, transaction_id.as_ref()),
@@ -431,6 +595,8 @@ async fn execute_single<E: Execute + Sync>(
             .map_err(retry::Error::Permanent),
 
         Status::Busy | Status::PlatformNotActive => {
+            ctx.network.record_node_error(node_index, format!("pre-check status {status:?}"));
+
             // NOTE: this is a "busy" node
             // try the next node in our allowed list, immediately
             Ok(ControlFlow::Continue(
@@ -468,6 +634,42 @@ async fn execute_single<E: Execute + Sync>(
     }
 }
 
+/// Like [`random_node_indexes`], but the order (and amount) of nodes comes from `selector`.
+fn selected_node_indexes(
+    network: &client::NetworkData,
+    selector: &dyn NodeSelector,
+    explicit_node_indexes: Option<&[usize]>,
+) -> Option<Vec<usize>> {
+    let now = Instant::now();
+
+    let candidates: Vec<_> = match explicit_node_indexes {
+        Some(indexes) => {
+            let tmp: Vec<_> = indexes
+                .iter()
+                .copied()
+                .filter(|index| network.is_node_healthy(*index, now))
+                .collect();
+
+            if tmp.is_empty() {
+                indexes.to_vec()
+            } else {
+                tmp
+            }
+        }
+        None => network.healthy_node_indexes(now).collect(),
+    };
+
+    let candidate_ids: Vec<_> = candidates.iter().map(|it| network.node_ids()[*it]).collect();
+
+    let selected: Vec<_> = selector
+        .select(&candidate_ids)
+        .into_iter()
+        .filter_map(|id| candidate_ids.iter().position(|it| *it == id).map(|it| candidates[it]))
+        .collect();
+
+    (!selected.is_empty()).then_some(selected)
+}
+
 // todo: return an iterator.
 fn random_node_indexes(
     network: &client::NetworkData,
@@ -0,0 +1,308 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fmt;
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use triomphe::Arc;
+use unsize::{
+    CoerceUnsize,
+    Coercion,
+};
+
+use crate::{
+    AccountId,
+    Status,
+};
+
+/// What to do after a node answers a request with a failing pre-check status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RetryDecision {
+    /// Try the request on the next node, without waiting.
+    NextNode,
+
+    /// Wait for the next backoff, then try the request again.
+    Backoff,
+
+    /// Give up, and return the pre-check status as an error.
+    Fail,
+}
+
+/// Decides when, and how many times, requests are retried.
+///
+/// A policy can be set for every request with [`Client::set_retry_policy`](crate::Client::set_retry_policy),
+/// or for a single request with [`Transaction::retry_policy`](crate::Transaction::retry_policy)
+/// and [`Query::retry_policy`](crate::Query::retry_policy).
+pub trait RetryPolicy: Send + Sync {
+    /// Returns how long to wait before the next attempt, or `None` to give up.
+    ///
+    /// `attempt` is the number of attempts that have failed so far (starting at `1`),
+    /// and `elapsed` is the time since the first attempt.
+    ///
+    /// The client's [`request_timeout`](crate::Client::request_timeout) still applies.
+    fn backoff(&self, attempt: usize, elapsed: Duration) -> Option<Duration>;
+
+    /// Returns the maximum number of attempts, or `None` to use the client's [`max_attempts`](crate::Client::max_attempts).
+    fn max_attempts(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns how to handle a failing pre-check `status`, or `None` to handle it as usual.
+    ///
+    /// Usually [`Status::Busy`] and [`Status::PlatformNotActive`] move on to the next node,
+    /// an expired transaction gets a new transaction ID (if allowed), some statuses specific to the request
+    /// are retried after a backoff, and any other status fails the request.
+    #[allow(unused_variables)]
+    fn classify_pre_check(&self, status: Status) -> Option<RetryDecision> {
+        None
+    }
+}
+
+/// Picks which nodes a request is sent to, and in what order.
+///
+/// A selector can be set for every request with [`Client::set_node_selector`](crate::Client::set_node_selector),
+/// or for a single request with [`Transaction::node_selector`](crate::Transaction::node_selector)
+/// and [`Query::node_selector`](crate::Query::node_selector).
+pub trait NodeSelector: Send + Sync {
+    /// Returns the nodes to try out of `candidates`, in order.
+    ///
+    /// `candidates` are the request's explicit node account IDs (if set), otherwise every healthy node in the network.
+    ///
+    /// Nodes that aren't in `candidates` are ignored,
+    /// and if no nodes are returned the request waits for the next backoff before asking again.
+    fn select(&self, candidates: &[AccountId]) -> Vec<AccountId>;
+
+    /// Called after every call to a node, with how long the call took and whether the node answered.
+    ///
+    /// A node that answers with a failing pre-check status still counts as answering.
+    #[allow(unused_variables)]
+    fn record(&self, node_account_id: AccountId, latency: Duration, answered: bool) {}
+}
+
+/// The policies set on a single request, which take priority over the client's.
+#[derive(Clone, Default)]
+pub(crate) struct ExecutePolicy {
+    pub(crate) retry_policy: Option<Arc<dyn RetryPolicy>>,
+    pub(crate) node_selector: Option<Arc<dyn NodeSelector>>,
+}
+
+impl ExecutePolicy {
+    pub(crate) fn arc_retry_policy<P: RetryPolicy + 'static>(policy: P) -> Arc<dyn RetryPolicy> {
+        Arc::new(policy).unsize(Coercion!(to dyn RetryPolicy))
+    }
+
+    pub(crate) fn arc_node_selector<S: NodeSelector + 'static>(
+        selector: S,
+    ) -> Arc<dyn NodeSelector> {
+        Arc::new(selector).unsize(Coercion!(to dyn NodeSelector))
+    }
+}
+
+impl fmt::Debug for ExecutePolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExecutePolicy")
+            .field("retry_policy", &self.retry_policy.is_some())
+            .field("node_selector", &self.node_selector.is_some())
+            .finish()
+    }
+}
+
+/// Adapts a [`RetryPolicy`] to the `backoff` crate.
+#[derive(Clone)]
+pub(crate) struct PolicyBackoff {
+    policy: Arc<dyn RetryPolicy>,
+    attempt: usize,
+    start: Instant,
+    max_elapsed: Option<Duration>,
+}
+
+impl PolicyBackoff {
+    pub(crate) fn new(policy: Arc<dyn RetryPolicy>, max_elapsed: Option<Duration>) -> Self {
+        Self { policy, attempt: 0, start: Instant::now(), max_elapsed }
+    }
+}
+
+impl backoff::backoff::Backoff for PolicyBackoff {
+    fn reset(&mut self) {
+        self.attempt = 0;
+        self.start = Instant::now();
+    }
+
+    fn next_backoff(&mut self) -> Option<Duration> {
+        self.attempt += 1;
+
+        let elapsed = self.start.elapsed();
+
+        if self.max_elapsed.is_some_and(|it| elapsed >= it) {
+            return None;
+        }
+
+        self.policy.backoff(self.attempt, elapsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use backoff::backoff::Backoff;
+
+    use super::{
+        ExecutePolicy,
+        PolicyBackoff,
+        RetryPolicy,
+    };
+
+    struct Linear;
+
+    impl RetryPolicy for Linear {
+        fn backoff(&self, attempt: usize, _elapsed: Duration) -> Option<Duration> {
+            (attempt < 3).then(|| Duration::from_millis(attempt as u64 * 10))
+        }
+    }
+
+    #[test]
+    fn policy_backoff() {
+        let mut backoff = PolicyBackoff::new(ExecutePolicy::arc_retry_policy(Linear), None);
+
+        assert_eq!(backoff.next_backoff(), Some(Duration::from_millis(10)));
+        assert_eq!(backoff.next_backoff(), Some(Duration::from_millis(20)));
+        assert_eq!(backoff.next_backoff(), None);
+
+        backoff.reset();
+
+        assert_eq!(backoff.next_backoff(), Some(Duration::from_millis(10)));
+    }
+
+    #[test]
+    fn policy_backoff_honors_timeout() {
+        let mut backoff =
+            PolicyBackoff::new(ExecutePolicy::arc_retry_policy(Linear), Some(Duration::ZERO));
+
+        assert_eq!(backoff.next_backoff(), None);
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod mock_tests {
+    use std::time::Duration;
+
+    use assert_matches::assert_matches;
+    use parking_lot::Mutex;
+    use triomphe::Arc;
+
+    use super::{
+        NodeSelector,
+        RetryDecision,
+        RetryPolicy,
+    };
+    use crate::mock::test_helpers::{
+        client,
+        make_transfer,
+        transfer_count,
+    };
+    use crate::{
+        AccountId,
+        Error,
+        MockReply,
+        MockServer,
+        Status,
+    };
+
+    /// Retries immediately, and gives up on busy nodes.
+    struct FailBusy;
+
+    impl RetryPolicy for FailBusy {
+        fn backoff(&self, _attempt: usize, _elapsed: Duration) -> Option<Duration> {
+            Some(Duration::ZERO)
+        }
+
+        fn classify_pre_check(&self, status: Status) -> Option<RetryDecision> {
+            (status == Status::Busy).then_some(RetryDecision::Fail)
+        }
+    }
+
+    /// Retries immediately.
+    struct Immediate;
+
+    impl RetryPolicy for Immediate {
+        fn backoff(&self, _attempt: usize, _elapsed: Duration) -> Option<Duration> {
+            Some(Duration::ZERO)
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct Recording(Arc<Mutex<Vec<AccountId>>>);
+
+    impl NodeSelector for Recording {
+        fn select(&self, candidates: &[AccountId]) -> Vec<AccountId> {
+            candidates.to_vec()
+        }
+
+        fn record(&self, node_account_id: AccountId, _latency: Duration, answered: bool) {
+            assert!(answered);
+            self.0.lock().push(node_account_id);
+        }
+    }
+
+    #[tokio::test]
+    async fn client_retry_policy() {
+        let server = MockServer::start().await.unwrap();
+        let client = client(&server);
+        client.set_retry_policy(FailBusy);
+
+        server.push_transaction_reply(MockReply::PreCheck(Status::Busy));
+
+        let res = make_transfer().execute(&client).await;
+
+        assert_matches!(res, Err(Error::TransactionPreCheckStatus { status: Status::Busy, .. }));
+        assert_eq!(transfer_count(&server), 1);
+    }
+
+    #[tokio::test]
+    async fn request_retry_policy_overrides_client() {
+        let server = MockServer::start().await.unwrap();
+        let client = client(&server);
+        client.set_retry_policy(FailBusy);
+
+        server.push_transaction_reply(MockReply::PreCheck(Status::Busy));
+
+        make_transfer().retry_policy(Immediate).execute(&client).await.unwrap();
+
+        assert_eq!(transfer_count(&server), 2);
+    }
+
+    #[tokio::test]
+    async fn node_selector() {
+        let server = MockServer::start().await.unwrap();
+        let client = client(&server);
+
+        let selector = Recording::default();
+        client.set_node_selector(selector.clone());
+
+        make_transfer().execute(&client).await.unwrap();
+
+        assert_eq!(*selector.0.lock(), [MockServer::NODE_ACCOUNT_ID]);
+    }
+}
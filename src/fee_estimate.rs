@@ -0,0 +1,726 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+use hedera_proto::services;
+use prost::Message;
+
+use crate::query::QueryExecute;
+use crate::transaction::TransactionExecute;
+use crate::{
+    AccountId,
+    Error,
+    ExchangeRate,
+    ExchangeRates,
+    FeeComponents,
+    FeeData,
+    FeeDataType,
+    FeeSchedule,
+    FeeSchedules,
+    Hbar,
+    Query,
+    RequestType,
+    Transaction,
+    TransactionId,
+};
+
+/// Fee schedule prices are expressed in thousandths of a tinycent.
+const FEE_DIVISOR_FACTOR: u128 = 1000;
+
+const NO_USAGE: FeeComponents = FeeComponents {
+    min: 0,
+    max: 0,
+    constant: 0,
+    bandwidth_byte: 0,
+    verification: 0,
+    storage_byte_hour: 0,
+    ram_byte_hour: 0,
+    contract_transaction_gas: 0,
+    transfer_volume_hbar: 0,
+    response_memory_byte: 0,
+    response_disk_byte: 0,
+};
+
+/// An estimated fee for a transaction or query, split into the parts that make up the total.
+///
+/// See [`FeeEstimator`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct FeeEstimate {
+    /// The fee paid to the node that submits the request.
+    pub node: Hbar,
+
+    /// The fee paid to the network for gossip, consensus, and storing the record.
+    pub network: Hbar,
+
+    /// The fee paid for the service-specific work done by the request.
+    pub service: Hbar,
+}
+
+impl FeeEstimate {
+    /// Returns the sum of the node, network, and service fees.
+    #[must_use]
+    pub fn total(&self) -> Hbar {
+        self.node + self.network + self.service
+    }
+}
+
+/// Estimates fees offline from a [`FeeSchedule`] and an [`ExchangeRate`].
+///
+/// The estimate follows the consensus node's fee formula for the bytes, signatures,
+/// and gas of the request, but doesn't account for usage that's only known to the network
+/// (such as storage, custom fees, or the size of a query response),
+/// so the actual fee charged may differ.
+#[derive(Debug, Clone)]
+pub struct FeeEstimator {
+    schedule: FeeSchedule,
+    exchange_rate: ExchangeRate,
+}
+
+/// The resources a single request uses.
+struct Usage {
+    request_type: RequestType,
+    kind: FeeDataType,
+    bytes: u64,
+    signatures: u64,
+    gas: u64,
+}
+
+impl FeeEstimator {
+    /// Create a new estimator that uses the given fee `schedule` and `exchange_rate`.
+    #[must_use]
+    pub fn new(schedule: FeeSchedule, exchange_rate: ExchangeRate) -> Self {
+        Self { schedule, exchange_rate }
+    }
+
+    /// Create a new estimator from the current fee schedule and current exchange rate.
+    ///
+    /// Returns `None` if `schedules` has no current fee schedule.
+    #[must_use]
+    pub fn from_current(schedules: &FeeSchedules, exchange_rates: &ExchangeRates) -> Option<Self> {
+        let schedule = schedules.current.clone()?;
+
+        Some(Self::new(schedule, exchange_rates.current_rate.clone()))
+    }
+
+    /// Returns the fee schedule used by this estimator.
+    #[must_use]
+    pub fn schedule(&self) -> &FeeSchedule {
+        &self.schedule
+    }
+
+    /// Returns the exchange rate used by this estimator.
+    #[must_use]
+    pub fn exchange_rate(&self) -> &ExchangeRate {
+        &self.exchange_rate
+    }
+
+    /// Estimate the fee for a frozen `transaction`.
+    ///
+    /// Chunked transactions are estimated as the sum of every chunk.
+    ///
+    /// # Errors
+    /// - [`Error::NoPayerAccountOrTransactionId`] if the transaction has no transaction ID and no operator.
+    /// - [`Error::FeeEstimate`] if the fee schedule has no fees for the transaction.
+    /// - [`Error::FeeEstimate`] if the exchange rate has zero cents.
+    ///
+    /// # Panics
+    /// If `transaction` isn't frozen.
+    pub fn estimate_transaction<D: TransactionExecute>(
+        &self,
+        transaction: &Transaction<D>,
+    ) -> crate::Result<FeeEstimate> {
+        assert!(transaction.is_frozen(), "Transaction must be frozen to estimate its fee");
+
+        let sources = transaction.make_sources()?;
+
+        let mut estimate =
+            FeeEstimate { node: Hbar::ZERO, network: Hbar::ZERO, service: Hbar::ZERO };
+
+        for chunk in sources.chunks() {
+            // every node gets the same body (other than the node account ID), so the first is representative.
+            let chunk_transaction = &chunk.transactions()[0];
+            let signed = &chunk.signed_transactions()[0];
+
+            let body = services::TransactionBody::decode(&*signed.body_bytes)
+                .map_err(Error::from_protobuf)?;
+
+            let data =
+                body.data.ok_or_else(|| Error::from_protobuf("unexpected missing `data`"))?;
+
+            let signatures = signed.sig_map.as_ref().map_or(0, |it| it.sig_pair.len());
+
+            let (request_type, kind, gas) = transaction_usage(&data);
+
+            let chunk_estimate = self.estimate(&Usage {
+                request_type,
+                kind,
+                bytes: chunk_transaction.encoded_len() as u64,
+                // the payer will always need to sign, even if they haven't yet.
+                signatures: signatures.max(1) as u64,
+                gas,
+            })?;
+
+            estimate.node += chunk_estimate.node;
+            estimate.network += chunk_estimate.network;
+            estimate.service += chunk_estimate.service;
+        }
+
+        Ok(estimate)
+    }
+
+    /// Estimate the fee for `query`.
+    ///
+    /// # Errors
+    /// - [`Error::FeeEstimate`] if the fee schedule has no fees for the query.
+    /// - [`Error::FeeEstimate`] if the exchange rate has zero cents.
+    pub fn estimate_query<D: QueryExecute>(&self, query: &Query<D>) -> crate::Result<FeeEstimate> {
+        let header = services::QueryHeader { response_type: 0, payment: None };
+        let pb = query.data.to_query_protobuf(header);
+
+        let Some((request_type, gas)) = pb.query.as_ref().and_then(query_usage) else {
+            return Err(Error::fee_estimate("unsupported query"));
+        };
+
+        self.estimate(&Usage {
+            request_type,
+            kind: FeeDataType::Default,
+            bytes: pb.encoded_len() as u64,
+            signatures: u64::from(query.data.is_payment_required()),
+            gas,
+        })
+    }
+
+    /// Estimate the fee for a non-frozen `transaction`, and set its maximum transaction fee to the estimate.
+    ///
+    /// Node account IDs and a transaction ID are filled in with placeholders for the estimate if they're unset,
+    /// `transaction` itself is only modified by setting its maximum transaction fee.
+    ///
+    /// # Errors
+    /// - [`Error::FeeEstimate`] if the fee schedule has no fees for the transaction.
+    /// - [`Error::FeeEstimate`] if the exchange rate has zero cents.
+    ///
+    /// # Panics
+    /// If `transaction` is frozen.
+    pub fn apply_max_transaction_fee<D: TransactionExecute>(
+        &self,
+        transaction: &mut Transaction<D>,
+    ) -> crate::Result<FeeEstimate> {
+        transaction.require_not_frozen();
+
+        let mut estimated = transaction.clone();
+
+        let node_account_id = match transaction.get_node_account_ids() {
+            Some([first, ..]) => *first,
+            _ => AccountId::new(0, 0, 3),
+        };
+
+        estimated.node_account_ids([node_account_id]);
+
+        if estimated.get_transaction_id().is_none() {
+            estimated.transaction_id(TransactionId::generate(AccountId::new(0, 0, 2)));
+        }
+
+        estimated.freeze()?;
+
+        let estimate = self.estimate_transaction(&estimated)?;
+
+        transaction.max_transaction_fee(estimate.total());
+
+        Ok(estimate)
+    }
+
+    fn estimate(&self, usage: &Usage) -> crate::Result<FeeEstimate> {
+        let fee_data = self.fee_data(&usage.request_type, &usage.kind)?;
+
+        let node_usage = FeeComponents {
+            constant: 1,
+            bandwidth_byte: usage.bytes,
+            verification: usage.signatures,
+            ..NO_USAGE
+        };
+
+        let service_usage =
+            FeeComponents { constant: 1, contract_transaction_gas: usage.gas, ..NO_USAGE };
+
+        Ok(FeeEstimate {
+            node: self.to_hbar(component_fee(&fee_data.node, &node_usage))?,
+            network: self.to_hbar(component_fee(&fee_data.network, &node_usage))?,
+            service: self.to_hbar(component_fee(&fee_data.service, &service_usage))?,
+        })
+    }
+
+    fn fee_data(&self, request_type: &RequestType, kind: &FeeDataType) -> crate::Result<&FeeData> {
+        let schedule = self
+            .schedule
+            .transaction_fee_schedules
+            .iter()
+            .find(|it| it.request_type == *request_type)
+            .ok_or_else(|| {
+                Error::fee_estimate(format!("fee schedule has no fees for `{request_type:?}`"))
+            })?;
+
+        let fees = &schedule.fees;
+
+        #[allow(deprecated)]
+        let fee_data = fees
+            .iter()
+            .find(|it| it.kind == *kind)
+            .or_else(|| fees.iter().find(|it| it.kind == FeeDataType::Default))
+            .or_else(|| fees.first())
+            .or(schedule.fee_data.as_deref());
+
+        fee_data.ok_or_else(|| {
+            Error::fee_estimate(format!("fee schedule has no fees for `{request_type:?}`"))
+        })
+    }
+
+    fn to_hbar(&self, tinycents: u128) -> crate::Result<Hbar> {
+        if self.exchange_rate.cents == 0 {
+            return Err(Error::fee_estimate("exchange rate has zero cents"));
+        }
+
+        let tinybars =
+            tinycents * u128::from(self.exchange_rate.hbars) / u128::from(self.exchange_rate.cents);
+
+        Ok(Hbar::from_tinybars(i64::try_from(tinybars).unwrap_or(i64::MAX)))
+    }
+}
+
+/// Computes the fee for a single component of [`FeeData`] in tinycents.
+fn component_fee(price: &FeeComponents, usage: &FeeComponents) -> u128 {
+    let products = [
+        (price.constant, usage.constant),
+        (price.bandwidth_byte, usage.bandwidth_byte),
+        (price.verification, usage.verification),
+        (price.storage_byte_hour, usage.storage_byte_hour),
+        (price.ram_byte_hour, usage.ram_byte_hour),
+        (price.contract_transaction_gas, usage.contract_transaction_gas),
+        (price.transfer_volume_hbar, usage.transfer_volume_hbar),
+        (price.response_memory_byte, usage.response_memory_byte),
+        (price.response_disk_byte, usage.response_disk_byte),
+    ];
+
+    let total = products
+        .into_iter()
+        .fold(0_u128, |acc, (price, usage)| acc + u128::from(price) * u128::from(usage));
+
+    // note: this intentionally isn't `clamp`, `min` wins over `max` (as it does on the network).
+    let total = total.min(u128::from(price.max)).max(u128::from(price.min));
+
+    total / FEE_DIVISOR_FACTOR
+}
+
+fn to_gas(gas: i64) -> u64 {
+    u64::try_from(gas).unwrap_or(0)
+}
+
+/// Returns the request type, fee subtype, and gas used by a transaction with the given `data`.
+fn transaction_usage(data: &services::transaction_body::Data) -> (RequestType, FeeDataType, u64) {
+    use services::transaction_body::Data;
+
+    let request_type = match data {
+        Data::ContractCall(it) => {
+            return (RequestType::ContractCall, FeeDataType::Default, to_gas(it.gas));
+        }
+        Data::ContractCreateInstance(it) => {
+            return (RequestType::ContractCreate, FeeDataType::Default, to_gas(it.gas));
+        }
+        Data::TokenCreation(it) => {
+            let non_fungible = it.token_type == services::TokenType::NonFungibleUnique as i32;
+            let kind = match (non_fungible, it.custom_fees.is_empty()) {
+                (false, true) => FeeDataType::TokenFungibleCommon,
+                (false, false) => FeeDataType::TokenFungibleCommonWithCustomFees,
+                (true, true) => FeeDataType::TokenNonFungibleUnique,
+                (true, false) => FeeDataType::TokenNonFungibleUniqueWithCustomFees,
+            };
+
+            return (RequestType::TokenCreate, kind, 0);
+        }
+        Data::TokenMint(it) => {
+            return (RequestType::TokenMint, token_kind(!it.metadata.is_empty()), 0);
+        }
+        Data::TokenBurn(it) => {
+            return (RequestType::TokenBurn, token_kind(!it.serial_numbers.is_empty()), 0);
+        }
+        Data::TokenWipe(it) => {
+            return (RequestType::TokenAccountWipe, token_kind(!it.serial_numbers.is_empty()), 0);
+        }
+        Data::CryptoTransfer(it) => {
+            let kind = if it.token_transfers.iter().any(|it| !it.nft_transfers.is_empty()) {
+                FeeDataType::TokenNonFungibleUnique
+            } else if !it.token_transfers.is_empty() {
+                FeeDataType::TokenFungibleCommon
+            } else {
+                FeeDataType::Default
+            };
+
+            return (RequestType::CryptoTransfer, kind, 0);
+        }
+        Data::ScheduleCreate(it) => {
+            let is_contract_call = matches!(
+                it.scheduled_transaction_body.as_ref().and_then(|it| it.data.as_ref()),
+                Some(services::schedulable_transaction_body::Data::ContractCall(_))
+            );
+
+            let kind = if is_contract_call {
+                FeeDataType::ScheduleCreateContractCall
+            } else {
+                FeeDataType::Default
+            };
+
+            return (RequestType::ScheduleCreate, kind, 0);
+        }
+        Data::ContractUpdateInstance(_) => RequestType::ContractUpdate,
+        Data::ContractDeleteInstance(_) => RequestType::ContractDelete,
+        Data::EthereumTransaction(_) => RequestType::EthereumTransaction,
+        Data::CryptoApproveAllowance(_) => RequestType::CryptoApproveAllowance,
+        Data::CryptoDeleteAllowance(_) => RequestType::CryptoDeleteAllowance,
+        Data::CryptoCreateAccount(_) => RequestType::CryptoCreate,
+        Data::CryptoDelete(_) => RequestType::CryptoDelete,
+        Data::CryptoUpdateAccount(_) => RequestType::CryptoUpdate,
+        Data::CryptoAddLiveHash(_) => RequestType::CryptoAddLiveHash,
+        Data::CryptoDeleteLiveHash(_) => RequestType::CryptoDeleteLiveHash,
+        Data::FileAppend(_) => RequestType::FileAppend,
+        Data::FileCreate(_) => RequestType::FileCreate,
+        Data::FileDelete(_) => RequestType::FileDelete,
+        Data::FileUpdate(_) => RequestType::FileUpdate,
+        Data::UtilPrng(_) => RequestType::UtilPrng,
+        Data::SystemDelete(_) => RequestType::SystemDelete,
+        Data::SystemUndelete(_) => RequestType::SystemUndelete,
+        Data::Freeze(_) => RequestType::Freeze,
+        Data::ConsensusCreateTopic(_) => RequestType::ConsensusCreateTopic,
+        Data::ConsensusUpdateTopic(_) => RequestType::ConsensusUpdateTopic,
+        Data::ConsensusDeleteTopic(_) => RequestType::ConsensusDeleteTopic,
+        Data::ConsensusSubmitMessage(_) => RequestType::ConsensusSubmitMessage,
+        Data::TokenFreeze(_) => RequestType::TokenFreezeAccount,
+        Data::TokenUnfreeze(_) => RequestType::TokenUnfreezeAccount,
+        Data::TokenGrantKyc(_) => RequestType::TokenGrantKycToAccount,
+        Data::TokenRevokeKyc(_) => RequestType::TokenRevokeKycFromAccount,
+        Data::TokenDeletion(_) => RequestType::TokenDelete,
+        Data::TokenUpdate(_) => RequestType::TokenUpdate,
+        Data::TokenAssociate(_) => RequestType::TokenAssociateToAccount,
+        Data::TokenDissociate(_) => RequestType::TokenDissociateFromAccount,
+        Data::TokenFeeScheduleUpdate(_) => RequestType::TokenFeeScheduleUpdate,
+        Data::TokenPause(_) => RequestType::TokenPause,
+        Data::TokenUnpause(_) => RequestType::TokenUnpause,
+        Data::TokenUpdateNfts(_) => RequestType::TokenUpdateNfts,
+        Data::TokenAirdrop(_) => RequestType::TokenAirdrop,
+        Data::TokenClaimAirdrop(_) => RequestType::TokenClaimAirdrop,
+        Data::TokenCancelAirdrop(_) => RequestType::TokenCancelAirdrop,
+        Data::TokenReject(_) => RequestType::TokenReject,
+        Data::ScheduleDelete(_) => RequestType::ScheduleDelete,
+        Data::ScheduleSign(_) => RequestType::ScheduleSign,
+        Data::NodeCreate(_) => RequestType::NodeCreate,
+        Data::NodeUpdate(_) => RequestType::NodeUpdate,
+        Data::NodeDelete(_) => RequestType::NodeDelete,
+        Data::NodeStakeUpdate(_) => RequestType::NodeStakeUpdate,
+        Data::UncheckedSubmit(_) => RequestType::UncheckedSubmit,
+    };
+
+    (request_type, FeeDataType::Default, 0)
+}
+
+fn token_kind(non_fungible: bool) -> FeeDataType {
+    if non_fungible {
+        FeeDataType::TokenNonFungibleUnique
+    } else {
+        FeeDataType::TokenFungibleCommon
+    }
+}
+
+/// Returns the request type and gas used by the given `query`.
+fn query_usage(query: &services::query::Query) -> Option<(RequestType, u64)> {
+    use services::query::Query;
+
+    let request_type = match query {
+        Query::ContractCallLocal(it) => {
+            return Some((RequestType::ContractCallLocal, to_gas(it.gas)))
+        }
+        Query::CryptogetAccountBalance(_) => RequestType::CryptoGetAccountBalance,
+        Query::CryptoGetAccountRecords(_) => RequestType::CryptoGetAccountRecords,
+        Query::CryptoGetInfo(_) => RequestType::CryptoGetInfo,
+        Query::CryptoGetProxyStakers(_) => RequestType::CryptoGetStakers,
+        Query::ContractGetInfo(_) => RequestType::ContractGetInfo,
+        Query::ContractGetBytecode(_) => RequestType::ContractGetBytecode,
+        Query::FileGetContents(_) => RequestType::FileGetContents,
+        Query::FileGetInfo(_) => RequestType::FileGetInfo,
+        Query::TransactionGetReceipt(_) => RequestType::TransactionGetReceipt,
+        Query::TransactionGetRecord(_) => RequestType::TransactionGetRecord,
+        Query::ConsensusGetTopicInfo(_) => RequestType::ConsensusGetTopicInfo,
+        Query::NetworkGetVersionInfo(_) => RequestType::GetVersionInfo,
+        Query::TokenGetInfo(_) => RequestType::TokenGetInfo,
+        Query::TokenGetNftInfo(_) => RequestType::TokenGetNftInfo,
+        Query::ScheduleGetInfo(_) => RequestType::ScheduleGetInfo,
+        _ => return None,
+    };
+
+    Some((request_type, 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use time::OffsetDateTime;
+
+    use super::{
+        component_fee,
+        NO_USAGE,
+    };
+    use crate::transaction::test_helpers::{
+        self,
+        TEST_ACCOUNT_ID,
+    };
+    use crate::{
+        AccountId,
+        ContractExecuteTransaction,
+        ContractId,
+        Error,
+        ExchangeRate,
+        ExchangeRates,
+        FeeComponents,
+        FeeData,
+        FeeDataType,
+        FeeEstimate,
+        FeeEstimator,
+        FeeSchedule,
+        FeeSchedules,
+        FileContentsQuery,
+        FileDeleteTransaction,
+        FileId,
+        Hbar,
+        RequestType,
+        TokenId,
+        TokenMintTransaction,
+        TransactionFeeSchedule,
+        TransferTransaction,
+    };
+
+    const MAX_FEE: u64 = 1_000_000_000_000_000;
+
+    fn price(constant: u64) -> FeeComponents {
+        FeeComponents { constant, max: MAX_FEE, ..NO_USAGE }
+    }
+
+    fn fee_data(kind: FeeDataType, node: FeeComponents, service: FeeComponents) -> FeeData {
+        FeeData { node, network: price(20_000_000), service, kind }
+    }
+
+    fn schedule_for(request_type: RequestType, fees: Vec<FeeData>) -> TransactionFeeSchedule {
+        #[allow(deprecated)]
+        TransactionFeeSchedule { request_type, fee_data: None, fees }
+    }
+
+    fn fee_schedule_bytes(transfer_node: FeeComponents) -> Vec<u8> {
+        FeeSchedules {
+            current: Some(FeeSchedule {
+                transaction_fee_schedules: Vec::from([
+                    schedule_for(
+                        RequestType::CryptoTransfer,
+                        Vec::from([fee_data(
+                            FeeDataType::Default,
+                            transfer_node,
+                            price(30_000_000),
+                        )]),
+                    ),
+                    schedule_for(
+                        RequestType::TokenMint,
+                        Vec::from([
+                            fee_data(FeeDataType::Default, price(1_000_000), price(0)),
+                            fee_data(
+                                FeeDataType::TokenNonFungibleUnique,
+                                price(5_000_000),
+                                price(0),
+                            ),
+                        ]),
+                    ),
+                    schedule_for(
+                        RequestType::ContractCall,
+                        Vec::from([fee_data(
+                            FeeDataType::Default,
+                            price(0),
+                            FeeComponents { contract_transaction_gas: 1000, ..price(0) },
+                        )]),
+                    ),
+                    schedule_for(
+                        RequestType::FileGetContents,
+                        Vec::from([fee_data(FeeDataType::Default, price(10_000_000), price(0))]),
+                    ),
+                ]),
+                expiration_time: OffsetDateTime::from_unix_timestamp(1554158542).unwrap(),
+            }),
+            next: None,
+        }
+        .to_bytes()
+    }
+
+    fn exchange_rate(cents: u32) -> ExchangeRate {
+        ExchangeRate {
+            hbars: 1,
+            cents,
+            expiration_time: OffsetDateTime::from_unix_timestamp(1554158542).unwrap(),
+        }
+    }
+
+    fn make_estimator(transfer_node: FeeComponents) -> FeeEstimator {
+        let schedules = FeeSchedules::from_bytes(&fee_schedule_bytes(transfer_node)).unwrap();
+        let rates = ExchangeRates { current_rate: exchange_rate(10), next_rate: exchange_rate(10) };
+
+        FeeEstimator::from_current(&schedules, &rates).unwrap()
+    }
+
+    fn estimator() -> FeeEstimator {
+        make_estimator(price(10_000_000))
+    }
+
+    fn make_transfer() -> TransferTransaction {
+        test_helpers::make_transfer(TEST_ACCOUNT_ID, AccountId::new(0, 0, 3), Hbar::new(1))
+    }
+
+    #[test]
+    fn estimate_transaction() {
+        let mut tx = make_transfer();
+        tx.freeze().unwrap();
+
+        let estimate = estimator().estimate_transaction(&tx).unwrap();
+
+        assert_eq!(
+            estimate,
+            FeeEstimate {
+                node: Hbar::from_tinybars(1000),
+                network: Hbar::from_tinybars(2000),
+                service: Hbar::from_tinybars(3000),
+            }
+        );
+        assert_eq!(estimate.total(), Hbar::from_tinybars(6000));
+    }
+
+    #[test]
+    fn estimate_transaction_counts_bytes() {
+        let estimator = make_estimator(FeeComponents { bandwidth_byte: 100_000, ..price(0) });
+
+        let mut short = make_transfer();
+        short.freeze().unwrap();
+
+        let mut long = make_transfer();
+        long.transaction_memo("a much longer memo than the other transaction has")
+            .freeze()
+            .unwrap();
+
+        let short = estimator.estimate_transaction(&short).unwrap();
+        let long = estimator.estimate_transaction(&long).unwrap();
+
+        assert!(long.node > short.node);
+        assert_eq!(long.network, short.network);
+        assert_eq!(long.service, short.service);
+    }
+
+    #[test]
+    fn estimate_transaction_uses_subtype() {
+        let mut tx = TokenMintTransaction::new_for_tests();
+        tx.token_id(TokenId::new(0, 0, 1234)).metadata([b"nft".to_vec()]).freeze().unwrap();
+
+        let estimate = estimator().estimate_transaction(&tx).unwrap();
+
+        assert_eq!(estimate.node, Hbar::from_tinybars(500));
+    }
+
+    #[test]
+    fn estimate_transaction_falls_back_to_default_subtype() {
+        let mut tx = TokenMintTransaction::new_for_tests();
+        tx.token_id(TokenId::new(0, 0, 1234)).amount(10).freeze().unwrap();
+
+        let estimate = estimator().estimate_transaction(&tx).unwrap();
+
+        assert_eq!(estimate.node, Hbar::from_tinybars(100));
+    }
+
+    #[test]
+    fn estimate_transaction_counts_gas() {
+        let mut tx = ContractExecuteTransaction::new_for_tests();
+        tx.contract_id(ContractId::new(0, 0, 5007)).gas(100_000).freeze().unwrap();
+
+        let estimate = estimator().estimate_transaction(&tx).unwrap();
+
+        assert_eq!(estimate.service, Hbar::from_tinybars(10_000));
+    }
+
+    #[test]
+    fn estimate_transaction_missing_schedule() {
+        let mut tx = FileDeleteTransaction::new_for_tests();
+        tx.file_id(FileId::new(0, 0, 6006)).freeze().unwrap();
+
+        let res = estimator().estimate_transaction(&tx);
+
+        assert_matches!(res, Err(Error::FeeEstimate(_)));
+    }
+
+    #[test]
+    fn estimate_query() {
+        let mut query = FileContentsQuery::new();
+        query.file_id(FileId::new(0, 0, 5005));
+
+        let estimate = estimator().estimate_query(&query).unwrap();
+
+        assert_eq!(estimate.node, Hbar::from_tinybars(1000));
+        assert_eq!(estimate.network, Hbar::from_tinybars(2000));
+        assert_eq!(estimate.service, Hbar::ZERO);
+    }
+
+    #[test]
+    fn apply_max_transaction_fee() {
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfer(TEST_ACCOUNT_ID, Hbar::new(-1))
+            .hbar_transfer(AccountId::new(0, 0, 3), Hbar::new(1));
+
+        let estimate = estimator().apply_max_transaction_fee(&mut tx).unwrap();
+
+        assert_eq!(estimate.total(), Hbar::from_tinybars(6000));
+        assert_eq!(tx.get_max_transaction_fee(), Some(Hbar::from_tinybars(6000)));
+        assert_eq!(tx.get_node_account_ids(), None);
+        assert_eq!(tx.get_transaction_id(), None);
+    }
+
+    #[test]
+    fn zero_cent_exchange_rate() {
+        let mut tx = make_transfer();
+        tx.freeze().unwrap();
+
+        let estimator = FeeEstimator::new(estimator().schedule().clone(), exchange_rate(0));
+
+        assert_matches!(estimator.estimate_transaction(&tx), Err(Error::FeeEstimate(_)));
+    }
+
+    #[test]
+    fn from_current_without_schedule() {
+        let schedules = FeeSchedules { current: None, next: None };
+        let rates = ExchangeRates { current_rate: exchange_rate(10), next_rate: exchange_rate(10) };
+
+        assert!(FeeEstimator::from_current(&schedules, &rates).is_none());
+    }
+
+    #[test]
+    fn component_fee_is_bounded() {
+        let usage = FeeComponents { constant: 1, ..NO_USAGE };
+
+        let capped = FeeComponents { constant: 10_000_000, min: 0, max: 5_000_000, ..NO_USAGE };
+        assert_eq!(component_fee(&capped, &usage), 5000);
+
+        let floored = FeeComponents { constant: 1000, min: 2_000_000, max: MAX_FEE, ..NO_USAGE };
+        assert_eq!(component_fee(&floored, &usage), 2000);
+    }
+}
@@ -409,6 +409,27 @@ pub enum RequestType {
 
     /// Update the metadata of one or more NFT's of a specific token type.
     TokenUpdateNfts,
+
+    /// Airdrop one or more tokens to one or more accounts.
+    TokenAirdrop,
+
+    /// Claim one or more pending airdrops.
+    TokenClaimAirdrop,
+
+    /// Cancel one or more pending airdrops.
+    TokenCancelAirdrop,
+
+    /// Reject tokens, returning them to their treasuries.
+    TokenReject,
+
+    /// Create a consensus node.
+    NodeCreate,
+
+    /// Update a consensus node.
+    NodeUpdate,
+
+    /// Delete a consensus node.
+    NodeDelete,
 }
 
 impl FromProtobuf<services::HederaFunctionality> for RequestType {
@@ -490,6 +511,13 @@ impl FromProtobuf<services::HederaFunctionality> for RequestType {
             HederaFunctionality::UtilPrng => Self::UtilPrng,
             HederaFunctionality::TransactionGetFastRecord => Self::TransactionGetFastRecord,
             HederaFunctionality::TokenUpdateNfts => Self::TokenUpdateNfts,
+            HederaFunctionality::TokenAirdrop => Self::TokenAirdrop,
+            HederaFunctionality::TokenClaimAirdrop => Self::TokenClaimAirdrop,
+            HederaFunctionality::TokenCancelAirdrop => Self::TokenCancelAirdrop,
+            HederaFunctionality::TokenReject => Self::TokenReject,
+            HederaFunctionality::NodeCreate => Self::NodeCreate,
+            HederaFunctionality::NodeUpdate => Self::NodeUpdate,
+            HederaFunctionality::NodeDelete => Self::NodeDelete,
         };
 
         Ok(value)
@@ -577,6 +605,13 @@ impl ToProtobuf for RequestType {
             Self::UtilPrng => HederaFunctionality::UtilPrng,
             Self::TransactionGetFastRecord => HederaFunctionality::TransactionGetFastRecord,
             Self::TokenUpdateNfts => HederaFunctionality::TokenUpdateNfts,
+            Self::TokenAirdrop => HederaFunctionality::TokenAirdrop,
+            Self::TokenClaimAirdrop => HederaFunctionality::TokenClaimAirdrop,
+            Self::TokenCancelAirdrop => HederaFunctionality::TokenCancelAirdrop,
+            Self::TokenReject => HederaFunctionality::TokenReject,
+            Self::NodeCreate => HederaFunctionality::NodeCreate,
+            Self::NodeUpdate => HederaFunctionality::NodeUpdate,
+            Self::NodeDelete => HederaFunctionality::NodeDelete,
         }
     }
 }
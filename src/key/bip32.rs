@@ -0,0 +1,205 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! [BIP-32](https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki) child key derivation for ECDSA(secp256k1) keys,
+//! and the extended key (`xprv`/`xpub`) serialization format.
+
+use hmac::{
+    Hmac,
+    Mac,
+};
+use k256::elliptic_curve::PrimeField;
+use sha2::{
+    Digest,
+    Sha256,
+    Sha512,
+};
+
+use crate::Error;
+
+/// Indexes with this bit set are hardened.
+pub(crate) const HARDENED: u32 = 1 << 31;
+
+/// BIP-32 mainnet version bytes, which encode as `xprv`.
+pub(crate) const ECDSA_PRIVATE_VERSION: [u8; 4] = [0x04, 0x88, 0xad, 0xe4];
+
+/// BIP-32 mainnet version bytes, which encode as `xpub`.
+pub(crate) const ECDSA_PUBLIC_VERSION: [u8; 4] = [0x04, 0x88, 0xb2, 0x1e];
+
+/// SLIP-10 doesn't define version bytes for Ed25519,
+/// and an Ed25519 private key would be indistinguishable from an ECDSA one with BIP-32's,
+/// so these are specific to this SDK, and encode as `eprv`.
+///
+/// There's no public counterpart: SLIP-10 Ed25519 derivation is hardened-only,
+/// so an extended Ed25519 public key couldn't derive anything.
+pub(crate) const ED25519_PRIVATE_VERSION: [u8; 4] = [0x03, 0x12, 0x6f, 0x7e];
+
+const EXTENDED_KEY_LEN: usize = 78;
+
+/// Where an extended key sits in its derivation tree.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Lineage {
+    /// `0` for master keys, `1` for their children, and so on.
+    pub(crate) depth: u8,
+
+    /// The first 4 bytes of the parent key's `HASH160`, or zeros for master keys.
+    pub(crate) parent_fingerprint: [u8; 4],
+
+    /// The index this key was derived with, or `0` for master keys.
+    pub(crate) child_number: u32,
+}
+
+impl Lineage {
+    /// Returns the lineage of the child at `index` of a key with this lineage.
+    ///
+    /// `parent_public_key` is the parent's 33 byte serialized public key
+    /// (SEC1 compressed for ECDSA, `0x00 || key` for Ed25519).
+    pub(crate) fn child(&self, parent_public_key: &[u8], index: u32) -> crate::Result<Self> {
+        let depth = self
+            .depth
+            .checked_add(1)
+            .ok_or_else(|| Error::key_derive("maximum derivation depth reached"))?;
+
+        let hash = ripemd::Ripemd160::digest(Sha256::digest(parent_public_key));
+
+        let mut parent_fingerprint = [0; 4];
+        parent_fingerprint.copy_from_slice(&hash[..4]);
+
+        Ok(Self { depth, parent_fingerprint, child_number: index })
+    }
+}
+
+/// The fields of a serialized extended key.
+pub(crate) struct ExtendedKey {
+    pub(crate) version: [u8; 4],
+    pub(crate) lineage: Lineage,
+    pub(crate) chain_code: [u8; 32],
+
+    /// `0x00 || key` for private keys and Ed25519 public keys, the SEC1 compressed point for ECDSA public keys.
+    pub(crate) key: [u8; 33],
+}
+
+impl ExtendedKey {
+    /// Encodes `self` as a Base58Check string.
+    pub(crate) fn encode(&self) -> String {
+        let mut bytes = Vec::with_capacity(EXTENDED_KEY_LEN);
+
+        bytes.extend_from_slice(&self.version);
+        bytes.push(self.lineage.depth);
+        bytes.extend_from_slice(&self.lineage.parent_fingerprint);
+        bytes.extend_from_slice(&self.lineage.child_number.to_be_bytes());
+        bytes.extend_from_slice(&self.chain_code);
+        bytes.extend_from_slice(&self.key);
+
+        bs58::encode(bytes).with_check().into_string()
+    }
+
+    /// Decodes a Base58Check string.
+    ///
+    /// The version is returned as is, it's up to the caller to check it.
+    pub(crate) fn decode(s: &str) -> crate::Result<Self> {
+        let bytes = bs58::decode(s).with_check(None).into_vec().map_err(Error::key_parse)?;
+
+        if bytes.len() != EXTENDED_KEY_LEN {
+            return Err(Error::key_parse(format!(
+                "extended key must be {EXTENDED_KEY_LEN} bytes, got {}",
+                bytes.len()
+            )));
+        }
+
+        let mut version = [0; 4];
+        let mut parent_fingerprint = [0; 4];
+        let mut child_number = [0; 4];
+        let mut chain_code = [0; 32];
+        let mut key = [0; 33];
+
+        version.copy_from_slice(&bytes[0..4]);
+        let depth = bytes[4];
+        parent_fingerprint.copy_from_slice(&bytes[5..9]);
+        child_number.copy_from_slice(&bytes[9..13]);
+        chain_code.copy_from_slice(&bytes[13..45]);
+        key.copy_from_slice(&bytes[45..]);
+
+        let lineage =
+            Lineage { depth, parent_fingerprint, child_number: u32::from_be_bytes(child_number) };
+
+        if depth == 0 && lineage != Lineage::default() {
+            return Err(Error::key_parse(
+                "master extended key has a parent fingerprint or child number",
+            ));
+        }
+
+        Ok(Self { version, lineage, chain_code, key })
+    }
+}
+
+/// Returns the HMAC-SHA512 of `data` keyed with `key` (usually a chain code),
+/// split into the key material and the child's chain code.
+pub(crate) fn hmac_sha512(key: &[u8], data: &[&[u8]]) -> ([u8; 32], [u8; 32]) {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC can take keys of any size");
+
+    for it in data {
+        mac.update(it);
+    }
+
+    let output = mac.finalize().into_bytes();
+
+    let mut key = [0; 32];
+    let mut chain_code = [0; 32];
+
+    key.copy_from_slice(&output[..32]);
+    chain_code.copy_from_slice(&output[32..]);
+
+    (key, chain_code)
+}
+
+fn parse_tweak(tweak: &[u8; 32]) -> crate::Result<k256::Scalar> {
+    Option::from(k256::Scalar::from_repr((*tweak).into()))
+        .ok_or_else(|| Error::key_derive("derived key is invalid, use the next index instead"))
+}
+
+/// Computes the child private key `parent + tweak`.
+pub(crate) fn ecdsa_child_private_key(
+    parent: &k256::ecdsa::SigningKey,
+    tweak: &[u8; 32],
+) -> crate::Result<k256::ecdsa::SigningKey> {
+    let tweak = parse_tweak(tweak)?;
+
+    let child: Option<k256::NonZeroScalar> =
+        k256::NonZeroScalar::new(tweak + parent.as_nonzero_scalar().as_ref()).into();
+
+    child
+        .map(k256::ecdsa::SigningKey::from)
+        .ok_or_else(|| Error::key_derive("derived key is invalid, use the next index instead"))
+}
+
+/// Computes the child public key `parent + tweak * G`.
+pub(crate) fn ecdsa_child_public_key(
+    parent: &k256::ecdsa::VerifyingKey,
+    tweak: &[u8; 32],
+) -> crate::Result<k256::ecdsa::VerifyingKey> {
+    let tweak = parse_tweak(tweak)?;
+
+    let child =
+        k256::ProjectivePoint::GENERATOR * tweak + k256::ProjectivePoint::from(*parent.as_affine());
+
+    k256::ecdsa::VerifyingKey::from_affine(child.to_affine())
+        .map_err(|_| Error::key_derive("derived key is invalid, use the next index instead"))
+}
@@ -21,13 +21,17 @@
 use hedera_proto::services;
 
 use crate::contract::DelegateContractId;
+use crate::transaction::TransactionExecute;
 use crate::{
+    AnyTransaction,
     ContractId,
     Error,
     FromProtobuf,
     KeyList,
+    KeySignatureReport,
     PublicKey,
     ToProtobuf,
+    Transaction,
 };
 
 /// Any method that can be used to authorize an operation on Hedera.
@@ -54,6 +58,43 @@ impl Key {
     pub fn to_bytes(&self) -> Vec<u8> {
         ToProtobuf::to_bytes(self)
     }
+
+    /// Check the signatures on `transaction` against this key.
+    ///
+    /// Signers added with [`Transaction::sign`] (and friends) count as signed, as do valid signatures already
+    /// present on the transaction (for instance, after [`Transaction::from_bytes`]).
+    ///
+    /// `KeyList`s without a threshold require every key to be satisfied,
+    /// otherwise at least `threshold` of their keys must be satisfied.
+    ///
+    /// This freezes `transaction` if it isn't frozen already, so it can't be modified afterwards.
+    /// Use [`verify_transaction_bytes`](Self::verify_transaction_bytes) to check a transaction without freezing it.
+    ///
+    /// # Errors
+    /// - If the transaction fails to freeze, see [`Transaction::freeze`].
+    pub fn verify_transaction<D: TransactionExecute>(
+        &self,
+        transaction: &mut Transaction<D>,
+    ) -> crate::Result<KeySignatureReport> {
+        transaction.freeze()?;
+
+        Ok(KeySignatureReport::new(self, transaction))
+    }
+
+    /// Check the signatures on signed transaction bytes (from [`Transaction::to_bytes`]) against this key.
+    ///
+    /// Only the signatures present in the bytes are considered, every chunk and node transaction must
+    /// carry a valid signature for a key to count as signed.
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`] if the bytes aren't a valid signed transaction list.
+    pub fn verify_transaction_bytes(&self, bytes: &[u8]) -> crate::Result<KeySignatureReport> {
+        let transaction = AnyTransaction::from_bytes(bytes)?;
+
+        let sources = transaction.sources().expect("`Transaction::from_bytes` always sets sources");
+
+        Ok(KeySignatureReport::from_sources(self, sources))
+    }
 }
 
 impl ToProtobuf for Key {
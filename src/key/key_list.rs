@@ -10,12 +10,15 @@ use crate::Key;
 // todo: Copy over the _entire_ `Vec` API?.
 /// A list of keys with an optional threshold.
 #[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct KeyList {
     // todo: better doc comment?
     /// The list of keys.
     pub keys: Vec<Key>,
 
     /// If [`Some`]: The minimum number of keys that must sign.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
     pub threshold: Option<u32>,
 }
 
@@ -18,16 +18,19 @@
  * ‍
  */
 
+mod bip32;
 #[allow(clippy::module_inception)]
 mod key;
 mod key_list;
 mod private_key;
 mod public_key;
+mod signature_report;
 
 pub use key::Key;
 pub use key_list::KeyList;
 pub use private_key::PrivateKey;
 pub use public_key::PublicKey;
+pub use signature_report::KeySignatureReport;
 
 #[derive(Copy, Clone, Debug)]
 pub(crate) enum KeyKind {
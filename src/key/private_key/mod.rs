@@ -53,6 +53,7 @@ use sha2::Sha512;
 use sha3::Digest;
 use triomphe::Arc;
 
+use crate::key::bip32;
 use crate::signer::AnySigner;
 use crate::{
     AccountId,
@@ -101,15 +102,16 @@ pub struct PrivateKey(Arc<PrivateKeyDataWrapper>);
 struct PrivateKeyDataWrapper {
     data: PrivateKeyData,
     chain_code: Option<[u8; 32]>,
+    lineage: bip32::Lineage,
 }
 
 impl PrivateKeyDataWrapper {
     fn new(inner: PrivateKeyData) -> Self {
-        Self { data: inner, chain_code: None }
+        Self { data: inner, chain_code: None, lineage: bip32::Lineage::default() }
     }
 
-    fn new_derivable(inner: PrivateKeyData, chain_code: [u8; 32]) -> Self {
-        Self { data: inner, chain_code: Some(chain_code) }
+    fn new_derivable(inner: PrivateKeyData, chain_code: [u8; 32], lineage: bip32::Lineage) -> Self {
+        Self { data: inner, chain_code: Some(chain_code), lineage }
     }
 }
 
@@ -158,7 +160,11 @@ impl PrivateKey {
     }
 
     fn new_derivable(key: PrivateKeyData, chain_code: [u8; 32]) -> Self {
-        Self::new(PrivateKeyDataWrapper::new_derivable(key, chain_code))
+        Self::new_child(key, chain_code, bip32::Lineage::default())
+    }
+
+    fn new_child(key: PrivateKeyData, chain_code: [u8; 32], lineage: bip32::Lineage) -> Self {
+        Self::new(PrivateKeyDataWrapper::new_derivable(key, chain_code, lineage))
     }
 
     fn ed25519(key: ed25519_dalek::SigningKey) -> Self {
@@ -190,6 +196,8 @@ impl PrivateKey {
     }
 
     /// Gets the [`PublicKey`] which corresponds to this `PrivateKey`.
+    ///
+    /// The returned key never has a chain code, see [`extended_public_key`](Self::extended_public_key).
     #[must_use]
     pub fn public_key(&self) -> PublicKey {
         match &self.0.data {
@@ -198,6 +206,27 @@ impl PrivateKey {
         }
     }
 
+    /// Gets the [`PublicKey`] which corresponds to this `PrivateKey`, along with this key's chain code,
+    /// so that it can [`derive`](PublicKey::derive) child public keys.
+    ///
+    /// Only share the result where that's intended:
+    /// together with any non-hardened child private key, the chain code reveals this private key.
+    ///
+    /// # Errors
+    /// - [`Error::KeyDerive`] if this is an Ed25519 key (SLIP-10 only supports hardened derivation,
+    ///   so an Ed25519 public key can't derive anything with a chain code).
+    /// - [`Error::KeyDerive`] if this key has no `chain_code` (key is not derivable).
+    pub fn extended_public_key(&self) -> crate::Result<PublicKey> {
+        if self.is_ed25519() {
+            return Err(Error::key_derive("Ed25519 keys don't have extended public keys"));
+        }
+
+        let chain_code =
+            self.0.chain_code.ok_or_else(|| Error::key_derive("key is underivable"))?;
+
+        Ok(self.public_key().with_chain_code(Some(chain_code), self.0.lineage))
+    }
+
     /// Parse a `PrivateKey` from a sequence of bytes.
     ///
     /// # Errors
@@ -618,36 +647,44 @@ impl PrivateKey {
     ) -> crate::Result<Vec<u8>> {
         transaction.freeze()?;
 
-        let sig = transaction.add_signature_signer(&AnySigner::PrivateKey(self.clone()));
-
-        Ok(sig)
+        transaction.add_signature_signer(&AnySigner::PrivateKey(self.clone()))
     }
 
     /// Returns true if calling [`derive`](Self::derive) on `self` would succeed.
     #[must_use]
     pub fn is_derivable(&self) -> bool {
-        self.is_ed25519() && self.0.chain_code.is_some()
+        self.0.chain_code.is_some()
     }
 
     /// Derives a child key based on `index`.
     ///
+    /// Ed25519 keys are derived with [SLIP-10](https://github.com/satoshilabs/slips/blob/master/slip-0010.md),
+    /// which only supports hardened derivation, so every index is hardened.
+    ///
+    /// ECDSA(secp256k1) keys are derived with [BIP-32](https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki),
+    /// where an `index` with the high bit set (a negative `index`, such as `44 | i32::MIN` for `44'`) is hardened.
+    ///
     /// # Errors
-    /// - [`Error::KeyDerive`] if this is an Ecdsa key (unsupported operation)
     /// - [`Error::KeyDerive`] if this key has no `chain_code` (key is not derivable)
+    /// - [`Error::KeyDerive`] if the derived ECDSA key is invalid (which is astronomically unlikely),
+    ///   in which case the next index should be used instead.
     // this is specifically for the two `try_into`s which depend on `split_array_ref`.
     // Any panic would indicate a bug in this crate or a dependency of it, not in user code.
     #[allow(clippy::missing_panics_doc)]
     pub fn derive(&self, index: i32) -> crate::Result<Self> {
-        const HARDEND_MASK: u32 = 1 << 31;
+        const HARDEND_MASK: u32 = bip32::HARDENED;
         let index = index as u32;
 
         let chain_code =
             self.0.chain_code.as_ref().ok_or_else(|| Error::key_derive("key is underivable"))?;
 
+        let parent = self.public_key().to_extended_key_bytes();
+
         match &self.0.data {
             PrivateKeyData::Ed25519(key) => {
                 // force hardened.
                 let index = index | HARDEND_MASK;
+                let lineage = self.0.lineage.child(&parent, index)?;
 
                 let output: [u8; 64] = Hmac::<Sha512>::new_from_slice(chain_code)
                     .expect("HMAC can take keys of any size")
@@ -663,14 +700,108 @@ impl PrivateKey {
 
                 let data = ed25519_dalek::SigningKey::from_bytes(data);
 
-                Ok(Self::new_derivable(data.into(), *chain_code))
+                Ok(Self::new_child(data.into(), *chain_code, lineage))
             }
-            PrivateKeyData::Ecdsa(_) => {
-                Err(Error::key_derive("Ecdsa private keys don't currently support derivation"))
+            PrivateKeyData::Ecdsa(key) => {
+                let index_bytes = index.to_be_bytes();
+                let lineage = self.0.lineage.child(&parent, index)?;
+
+                let (tweak, chain_code) = match index & HARDEND_MASK {
+                    0 => bip32::hmac_sha512(chain_code, &[&parent, &index_bytes]),
+                    _ => bip32::hmac_sha512(
+                        chain_code,
+                        &[&[0], key.to_bytes().as_slice(), &index_bytes],
+                    ),
+                };
+
+                let data = bip32::ecdsa_child_private_key(key, &tweak)?;
+
+                Ok(Self::new_child(data.into(), chain_code, lineage))
             }
         }
     }
 
+    /// Returns this key and its chain code as an extended private key.
+    ///
+    /// ECDSA(secp256k1) keys use the [BIP-32](https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki) `xprv` format.
+    /// SLIP-10 doesn't define a format for Ed25519 keys, so they use the same layout,
+    /// with version bytes specific to this SDK that encode as `eprv`;
+    /// other wallets won't read these.
+    ///
+    /// The extended public key of an ECDSA key is available via
+    /// [`extended_public_key`](Self::extended_public_key) and [`PublicKey::to_xpub`].
+    ///
+    /// # Errors
+    /// - [`Error::KeyDerive`] if this key has no `chain_code` (key is not derivable).
+    pub fn to_xprv(&self) -> crate::Result<String> {
+        let chain_code =
+            self.0.chain_code.ok_or_else(|| Error::key_derive("key is underivable"))?;
+
+        let version = match &self.0.data {
+            PrivateKeyData::Ed25519(_) => bip32::ED25519_PRIVATE_VERSION,
+            PrivateKeyData::Ecdsa(_) => bip32::ECDSA_PRIVATE_VERSION,
+        };
+
+        let mut key = [0; 33];
+        key[1..].copy_from_slice(&self.to_bytes_raw_internal());
+
+        Ok(bip32::ExtendedKey { version, lineage: self.0.lineage, chain_code, key }.encode())
+    }
+
+    /// Parses an extended private key, as returned by [`to_xprv`](Self::to_xprv).
+    ///
+    /// The returned key keeps the chain code, so it can be [`derive`](Self::derive)d.
+    ///
+    /// # Errors
+    /// - [`Error::KeyParse`] if `s` isn't a valid Base58Check encoded extended key.
+    /// - [`Error::KeyParse`] if `s` is an extended public key, or has unknown version bytes.
+    /// - [`Error::KeyParse`] if the key in `s` isn't a valid private key.
+    pub fn from_xprv(s: &str) -> crate::Result<Self> {
+        let extended = bip32::ExtendedKey::decode(s)?;
+
+        let is_ed25519 = match extended.version {
+            bip32::ED25519_PRIVATE_VERSION => true,
+            bip32::ECDSA_PRIVATE_VERSION => false,
+            bip32::ECDSA_PUBLIC_VERSION => {
+                return Err(Error::key_parse(
+                    "expected an extended private key, got an extended public key",
+                ));
+            }
+            _ => return Err(Error::key_parse("unknown extended key version")),
+        };
+
+        if extended.key[0] != 0 {
+            return Err(Error::key_parse("invalid extended private key"));
+        }
+
+        let mut key = [0; 32];
+        key.copy_from_slice(&extended.key[1..]);
+
+        let data: PrivateKeyData = match is_ed25519 {
+            true => ed25519_dalek::SigningKey::from_bytes(&key).into(),
+            false => {
+                k256::ecdsa::SigningKey::from_bytes(&key.into()).map_err(Error::key_parse)?.into()
+            }
+        };
+
+        Ok(Self::new_child(data, extended.chain_code, extended.lineage))
+    }
+
+    /// Creates an ECDSA(secp256k1) `PrivateKey` from a `BIP-32` seed, such as one from a [`Mnemonic`](crate::Mnemonic).
+    ///
+    /// The returned key is the master key, which can be [`derive`](Self::derive)d.
+    ///
+    /// # Errors
+    /// - [`Error::KeyDerive`] if the seed doesn't produce a valid key (which is astronomically unlikely).
+    pub fn from_seed_ecdsa(seed: &[u8]) -> crate::Result<Self> {
+        let (data, chain_code) = bip32::hmac_sha512(b"Bitcoin seed", &[seed]);
+
+        let data = k256::ecdsa::SigningKey::from_bytes(&data.into())
+            .map_err(|_| Error::key_derive("seed produced an invalid key"))?;
+
+        Ok(Self::new_derivable(data.into(), chain_code))
+    }
+
     // todo: what do we do about i32?
     // It's basically just a cast to support them, but, unlike Java, operator overloading doesn't exist.
     /// Derive a `PrivateKey` based on `index`.
@@ -749,6 +880,32 @@ impl PrivateKey {
         Self::from_mnemonic_seed(&seed)
     }
 
+    /// Agrees on a shared secret with an ephemeral public key from [`PublicKey::ephemeral_key_agreement`].
+    ///
+    /// Returns `None` if `ephemeral_public_key` isn't a valid key of the same kind as this key.
+    pub(crate) fn key_agreement(&self, ephemeral_public_key: &[u8]) -> Option<[u8; 32]> {
+        match &self.0.data {
+            PrivateKeyData::Ed25519(key) => {
+                let ephemeral: [u8; 32] = ephemeral_public_key.try_into().ok()?;
+
+                let shared = curve25519_dalek::MontgomeryPoint(ephemeral)
+                    .mul_clamped(key.to_scalar_bytes())
+                    .to_bytes();
+
+                (shared != [0; 32]).then_some(shared)
+            }
+
+            PrivateKeyData::Ecdsa(key) => {
+                let ephemeral = k256::PublicKey::from_sec1_bytes(ephemeral_public_key).ok()?;
+
+                let shared =
+                    k256::ecdh::diffie_hellman(key.as_nonzero_scalar(), ephemeral.as_affine());
+
+                Some((*shared.raw_secret_bytes()).into())
+            }
+        }
+    }
+
     #[must_use]
     pub(crate) fn _kind(&self) -> super::KeyKind {
         match &self.0.data {
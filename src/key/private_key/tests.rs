@@ -10,7 +10,10 @@ use crate::key::private_key::{
     ED25519_OID,
     K256_OID,
 };
-use crate::Error;
+use crate::{
+    Error,
+    PublicKey,
+};
 
 #[test]
 fn ed25519_from_str() {
@@ -408,3 +411,236 @@ fn ecdsa_ec_private_key_no_public_key_der() {
         "03b69a75a5ddb1c0747e995d47555019e5d8a28003ab5202bd92f534361fb4ec8a"
     );
 }
+
+// BIP-32 test vector 1: https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#test-vector-1
+#[test]
+fn ecdsa_derive_bip32_vector_1() {
+    const HARDENED: i32 = i32::MIN;
+
+    // (index, private key, chain code) for `m/0'/1/2'/2/1000000000`.
+    const CHAIN: &[(i32, &str, &str)] = &[
+        (
+            HARDENED,
+            "edb2e14f9ee77d26dd93b4ecede8d16ed408ce149b6cd80b0715a2d911a0afea",
+            "47fdacbd0f1097043b78c63c20c34ef4ed9a111d980047ad16282c7ae6236141",
+        ),
+        (
+            1,
+            "3c6cb8d0f6a264c91ea8b5030fadaa8e538b020f0a387421a12de9319dc93368",
+            "2a7857631386ba23dacac34180dd1983734e444fdbf774041578e9b6adb37c19",
+        ),
+        (
+            2 | HARDENED,
+            "cbce0d719ecf7431d88e6a89fa1483e02e35092af60c042b1df2ff59fa424dca",
+            "04466b9cc8e161e966409ca52986c584f07e9dc81f735db683c3ff6ec7b1503f",
+        ),
+        (
+            2,
+            "0f479245fb19a38a1954c5c7c0ebab2f9bdfd96a17563ef28a6a4b1a2a764ef4",
+            "cfb71883f01676f587d023cc53a35bc7f88f724b1f8c2892ac1275ac822a3edd",
+        ),
+        (
+            1_000_000_000,
+            "471b76e389e528d6de6d816857e012c5455051cad6660850e58372a6c3e6e7c8",
+            "c783e67b921d2beb8f6b389cc646d7263b4145701dadd2161548a8b078e65e9e",
+        ),
+    ];
+
+    let mut key = PrivateKey::from_seed_ecdsa(&hex!("000102030405060708090a0b0c0d0e0f")).unwrap();
+
+    assert_eq!(
+        key.to_string_raw(),
+        "e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35"
+    );
+    assert_eq!(
+        key.0.chain_code.map(hex::encode).as_deref(),
+        Some("873dff81c02f525623fd1fe5167eac3a55a049de3d314bb42ee227ffed37d508")
+    );
+
+    for &(index, data, chain_code) in CHAIN {
+        let child = key.derive(index).unwrap();
+
+        assert_eq!(child.to_string_raw(), data);
+        assert_eq!(child.0.chain_code.map(hex::encode).as_deref(), Some(chain_code));
+
+        // public derivation only works for non-hardened indexes, but should agree with private derivation.
+        match index < 0 {
+            true => {
+                assert_matches!(
+                    key.extended_public_key().unwrap().derive(index),
+                    Err(Error::KeyDerive(_))
+                );
+            }
+            false => {
+                assert_eq!(
+                    key.extended_public_key().unwrap().derive(index).unwrap(),
+                    child.public_key()
+                );
+            }
+        }
+
+        key = child;
+    }
+}
+
+#[test]
+fn ecdsa_derive_underivable() {
+    let key = PrivateKey::generate_ecdsa();
+
+    assert!(!key.is_derivable());
+    assert_matches!(key.derive(0), Err(Error::KeyDerive(_)));
+    assert_matches!(key.public_key().derive(0), Err(Error::KeyDerive(_)));
+    assert_matches!(key.extended_public_key(), Err(Error::KeyDerive(_)));
+}
+
+#[test]
+fn public_key_has_no_chain_code() {
+    let key = PrivateKey::from_seed_ecdsa(&hex!("000102030405060708090a0b0c0d0e0f")).unwrap();
+
+    assert!(!key.public_key().is_derivable());
+    assert_matches!(key.public_key().to_xpub(), Err(Error::KeyDerive(_)));
+
+    assert!(key.extended_public_key().unwrap().is_derivable());
+    assert_eq!(key.extended_public_key().unwrap(), key.public_key());
+}
+
+#[test]
+fn ed25519_public_key_derive_unsupported() {
+    let key = key_with_chain(
+        "302e020100300506032b657004220420a6b9548d7e123ad4c8bc6fee58301e9b96360000df9d03785c07b620569e7728",
+        hex!("cde7f535264f1db4e2ded409396f8c72f8075cc43757bd5a205c97699ea40271"),
+    );
+
+    assert!(key.is_derivable());
+    assert!(!key.public_key().is_derivable());
+    assert_matches!(key.public_key().derive(0), Err(Error::KeyDerive(_)));
+    assert_matches!(key.extended_public_key(), Err(Error::KeyDerive(_)));
+}
+
+// BIP-32 test vector 1: https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#test-vector-1
+#[test]
+fn ecdsa_xprv_bip32_vector_1() {
+    const HARDENED: i32 = i32::MIN;
+
+    // (index, xprv, xpub) for `m/0'/1/2'/2/1000000000`.
+    const CHAIN: &[(i32, &str, &str)] = &[
+        (
+            HARDENED,
+            "xprv9uHRZZhk6KAJC1avXpDAp4MDc3sQKNxDiPvvkX8Br5ngLNv1TxvUxt4cV1rGL5hj6KCesnDYUhd7oWgT11eZG7XnxHrnYeSvkzY7d2bhkJ7",
+            "xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw",
+        ),
+        (
+            1,
+            "xprv9wTYmMFdV23N2TdNG573QoEsfRrWKQgWeibmLntzniatZvR9BmLnvSxqu53Kw1UmYPxLgboyZQaXwTCg8MSY3H2EU4pWcQDnRnrVA1xe8fs",
+            "xpub6ASuArnXKPbfEwhqN6e3mwBcDTgzisQN1wXN9BJcM47sSikHjJf3UFHKkNAWbWMiGj7Wf5uMash7SyYq527Hqck2AxYysAA7xmALppuCkwQ",
+        ),
+        (
+            2 | HARDENED,
+            "xprv9z4pot5VBttmtdRTWfWQmoH1taj2axGVzFqSb8C9xaxKymcFzXBDptWmT7FwuEzG3ryjH4ktypQSAewRiNMjANTtpgP4mLTj34bhnZX7UiM",
+            "xpub6D4BDPcP2GT577Vvch3R8wDkScZWzQzMMUm3PWbmWvVJrZwQY4VUNgqFJPMM3No2dFDFGTsxxpG5uJh7n7epu4trkrX7x7DogT5Uv6fcLW5",
+        ),
+        (
+            2,
+            "xprvA2JDeKCSNNZky6uBCviVfJSKyQ1mDYahRjijr5idH2WwLsEd4Hsb2Tyh8RfQMuPh7f7RtyzTtdrbdqqsunu5Mm3wDvUAKRHSC34sJ7in334",
+            "xpub6FHa3pjLCk84BayeJxFW2SP4XRrFd1JYnxeLeU8EqN3vDfZmbqBqaGJAyiLjTAwm6ZLRQUMv1ZACTj37sR62cfN7fe5JnJ7dh8zL4fiyLHV",
+        ),
+        (
+            1_000_000_000,
+            "xprvA41z7zogVVwxVSgdKUHDy1SKmdb533PjDz7J6N6mV6uS3ze1ai8FHa8kmHScGpWmj4WggLyQjgPie1rFSruoUihUZREPSL39UNdE3BBDu76",
+            "xpub6H1LXWLaKsWFhvm6RVpEL9P4KfRZSW7abD2ttkWP3SSQvnyA8FSVqNTEcYFgJS2UaFcxupHiYkro49S8yGasTvXEYBVPamhGW6cFJodrTHy",
+        ),
+    ];
+
+    let key = PrivateKey::from_seed_ecdsa(&hex!("000102030405060708090a0b0c0d0e0f")).unwrap();
+
+    assert_eq!(
+        key.to_xprv().unwrap(),
+        "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi"
+    );
+    assert_eq!(
+        key.extended_public_key().unwrap().to_xpub().unwrap(),
+        "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8"
+    );
+
+    let mut key = PrivateKey::from_xprv(&key.to_xprv().unwrap()).unwrap();
+    let mut public_key =
+        PublicKey::from_xpub(&key.extended_public_key().unwrap().to_xpub().unwrap()).unwrap();
+
+    for &(index, xprv, xpub) in CHAIN {
+        let child = key.derive(index).unwrap();
+
+        assert_eq!(child.to_xprv().unwrap(), xprv);
+        assert_eq!(child.extended_public_key().unwrap().to_xpub().unwrap(), xpub);
+
+        if index >= 0 {
+            assert_eq!(public_key.derive(index).unwrap().to_xpub().unwrap(), xpub);
+        }
+
+        // continue from the imported keys, so that importing keeps everything derivation needs.
+        key = PrivateKey::from_xprv(xprv).unwrap();
+        public_key = PublicKey::from_xpub(xpub).unwrap();
+
+        assert_eq!(key.to_string_raw(), child.to_string_raw());
+        assert_eq!(public_key, child.public_key());
+    }
+}
+
+// The keys and chain codes are SLIP-10 test vector 1 for ed25519: https://github.com/satoshilabs/slips/blob/master/slip-0010.md#test-vector-1-for-ed25519
+//
+// SLIP-10 doesn't define a serialization, so the `eprv` strings are this crate's own output,
+// kept to catch changes to the format; only the raw keys are checked against the vector.
+#[test]
+fn ed25519_xprv_slip10_vector_1() {
+    let key = key_with_chain(
+        "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7",
+        hex!("90046a93de5380a72b5e45010748567d5ea02bbf6522f979e05c0d8d8ca9fffb"),
+    );
+
+    assert_eq!(
+        key.to_xprv().unwrap(),
+        "eprvLdSdZfLUvnXR67ydqCZhHmWJdBJydAz15Nbp5AikFnJ88jES9S19ZCZM2SbqJ88oCiu8T1oYbZJY46R7HUyqZQTfuoES4nnUmkU24NMyLac"
+    );
+
+    let child = PrivateKey::from_xprv(&key.to_xprv().unwrap()).unwrap().derive(0).unwrap();
+
+    assert_eq!(
+        child.to_string_raw(),
+        "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3"
+    );
+    assert_eq!(
+        child.to_xprv().unwrap(),
+        "eprvLgxNeF1MN72x7iZsjMVq4iRSkenuvqpFRb8fSGWGUKdudFWPq72U6N3eD7NNMRyiHjg3paxboSpHQpLLZdyP7cpUbziVvtBotBQVL3c6oSf"
+    );
+
+    // Ed25519 derivation is hardened-only, so there's no extended public key.
+    assert_matches!(child.extended_public_key(), Err(Error::KeyDerive(_)));
+    assert_matches!(
+        PublicKey::from_xpub(
+            "epubRDeQnf3bWUid1Bbfd1ARU85ZKgJr6B19ztjSinYZtbSJTjmATVr4GrfWPAhUrtmGPduKvem9KiycvzYnSxRNQdRZA7BTBCPqj1SebBn5XTj"
+        ),
+        Err(Error::KeyParse(_))
+    );
+}
+
+#[test]
+fn xprv_errors() {
+    const XPRV: &str = "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi";
+    const XPUB: &str = "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8";
+
+    assert_matches!(PrivateKey::generate_ecdsa().to_xprv(), Err(Error::KeyDerive(_)));
+    assert_matches!(PrivateKey::generate_ecdsa().public_key().to_xpub(), Err(Error::KeyDerive(_)));
+
+    assert_matches!(PrivateKey::from_xprv(XPUB), Err(Error::KeyParse(_)));
+    assert_matches!(PublicKey::from_xpub(XPRV), Err(Error::KeyParse(_)));
+
+    // bad checksum.
+    assert_matches!(PrivateKey::from_xprv(&XPRV.replace("PHi", "PHj")), Err(Error::KeyParse(_)));
+
+    // a master key (depth 0) with a non-zero child number.
+    assert_matches!(
+        PrivateKey::from_xprv(
+            "xprv9s21ZrQH143K63M5oNDqyLrZdN8foVjb5mtJgYMp9P2WmEGCsHwkrkWG2fsiDQHTLDPUGDuv2wpjZCX69eYnxBXoaRBt11Sw9eLswg7FkLM"
+        ),
+        Err(Error::KeyParse(_))
+    );
+}
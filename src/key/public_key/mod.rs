@@ -45,6 +45,7 @@ use pkcs8::ObjectIdentifier;
 use prost::Message;
 use sha2::Digest;
 
+use crate::key::bip32;
 use crate::key::private_key::{
     ED25519_OID,
     K256_OID,
@@ -67,8 +68,11 @@ pub(super) const EC_ALGORITM_OID: ObjectIdentifier =
     ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
 
 /// A public key on the Hedera network.
-#[derive(Clone, Eq, Copy, Hash, PartialEq)]
-pub struct PublicKey(PublicKeyData);
+///
+/// A public key can have a chain code, which allows deriving child public keys.
+/// The chain code (and the rest of the extended key data) isn't part of equality or hashing.
+#[derive(Clone, Copy)]
+pub struct PublicKey(PublicKeyData, Option<[u8; 32]>, bip32::Lineage);
 
 #[derive(Clone, Copy)]
 enum PublicKeyData {
@@ -98,13 +102,49 @@ impl PartialEq for PublicKeyData {
 
 impl Eq for PublicKeyData {}
 
+impl Hash for PublicKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl PartialEq for PublicKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for PublicKey {}
+
 impl PublicKey {
     pub(super) fn ed25519(key: ed25519_dalek::VerifyingKey) -> Self {
-        Self(PublicKeyData::Ed25519(key))
+        Self(PublicKeyData::Ed25519(key), None, bip32::Lineage::default())
     }
 
     pub(super) fn ecdsa(key: k256::ecdsa::VerifyingKey) -> Self {
-        Self(PublicKeyData::Ecdsa(key))
+        Self(PublicKeyData::Ecdsa(key), None, bip32::Lineage::default())
+    }
+
+    pub(super) fn with_chain_code(
+        self,
+        chain_code: Option<[u8; 32]>,
+        lineage: bip32::Lineage,
+    ) -> Self {
+        Self(self.0, chain_code, lineage)
+    }
+
+    /// The key as serialized in extended keys and hashed for fingerprints.
+    pub(super) fn to_extended_key_bytes(&self) -> [u8; 33] {
+        let mut bytes = [0; 33];
+
+        match &self.0 {
+            PublicKeyData::Ed25519(key) => bytes[1..].copy_from_slice(key.as_bytes()),
+            PublicKeyData::Ecdsa(key) => {
+                bytes.copy_from_slice(key.to_encoded_point(true).as_bytes());
+            }
+        }
+
+        bytes
     }
 
     /// Returns `true` if the public key is `Ed25519`.
@@ -374,34 +414,52 @@ impl PublicKey {
         &self,
         sources: &TransactionSources,
     ) -> crate::Result<()> {
+        for signed_transaction in sources.signed_transactions() {
+            if !self.verify_signed_transaction(signed_transaction)? {
+                return Err(Error::signature_verify("signer not in transaction"));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks this key's signature on a single signed transaction.
+    ///
+    /// Returns `Ok(false)` if there's no signature for this key, and `Ok(true)` if any of the signatures for it verify.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if there are signatures for this key, but none of them verify.
+    pub(crate) fn verify_signed_transaction(
+        &self,
+        signed_transaction: &services::SignedTransaction,
+    ) -> crate::Result<bool> {
         use services::signature_pair::Signature;
         let pk_bytes = self.to_bytes_raw();
 
-        for signed_transaction in sources.signed_transactions() {
-            let mut found = false;
-            for sig_pair in
-                signed_transaction.sig_map.as_ref().map_or_else(|| [].as_slice(), |it| &it.sig_pair)
-            {
-                if !pk_bytes.starts_with(&sig_pair.pub_key_prefix) {
-                    continue;
-                }
-
-                found = true;
-                let Some(Signature::EcdsaSecp256k1(sig) | Signature::Ed25519(sig)) =
-                    &sig_pair.signature
-                else {
-                    return Err(Error::signature_verify("Unsupported transaction signature type"));
-                };
+        let mut result = Ok(false);
 
-                self.verify(&signed_transaction.body_bytes, sig)?;
+        // signature prefixes can be ambiguous, so a signature that fails to verify doesn't mean another one won't.
+        for sig_pair in
+            signed_transaction.sig_map.as_ref().map_or_else(|| [].as_slice(), |it| &it.sig_pair)
+        {
+            if !pk_bytes.starts_with(&sig_pair.pub_key_prefix) {
+                continue;
             }
 
-            if !found {
-                return Err(Error::signature_verify("signer not in transaction"));
+            let Some(Signature::EcdsaSecp256k1(sig) | Signature::Ed25519(sig)) =
+                &sig_pair.signature
+            else {
+                result = Err(Error::signature_verify("Unsupported transaction signature type"));
+                continue;
+            };
+
+            match self.verify(&signed_transaction.body_bytes, sig) {
+                Ok(()) => return Ok(true),
+                Err(e) => result = Err(e),
             }
         }
 
-        Ok(())
+        result
     }
 
     /// Returns `Ok(())` if this public key has signed the given transaction.
@@ -426,6 +484,128 @@ impl PublicKey {
         self.verify_transaction_sources(sources)
     }
 
+    /// Generates an ephemeral key pair of the same kind as this key, and agrees on a shared secret with this key.
+    ///
+    /// Ed25519 keys are converted to X25519 first.
+    ///
+    /// Returns the ephemeral public key and the shared secret,
+    /// or `None` if this key can't be used for key agreement (an Ed25519 key of small order).
+    pub(crate) fn ephemeral_key_agreement(&self) -> Option<(Vec<u8>, [u8; 32])> {
+        match &self.0 {
+            PublicKeyData::Ed25519(key) => {
+                use rand::RngCore as _;
+
+                let mut secret = [0; 32];
+                rand::thread_rng().fill_bytes(&mut secret);
+
+                let ephemeral = curve25519_dalek::MontgomeryPoint::mul_base_clamped(secret);
+                let shared = key.to_montgomery().mul_clamped(secret).to_bytes();
+
+                // a key of small order always gives an all zero secret.
+                (shared != [0; 32]).then(|| (ephemeral.to_bytes().to_vec(), shared))
+            }
+
+            PublicKeyData::Ecdsa(key) => {
+                use k256::elliptic_curve::sec1::ToEncodedPoint as _;
+
+                let secret = k256::ecdh::EphemeralSecret::random(&mut rand::thread_rng());
+
+                let ephemeral = secret.public_key().to_encoded_point(true).as_bytes().to_vec();
+                let shared = secret.diffie_hellman(&k256::PublicKey::from(key));
+
+                Some((ephemeral, (*shared.raw_secret_bytes()).into()))
+            }
+        }
+    }
+
+    /// Returns true if calling [`derive`](Self::derive) on `self` would succeed.
+    #[must_use]
+    pub fn is_derivable(&self) -> bool {
+        self.is_ecdsa() && self.1.is_some()
+    }
+
+    /// Derives a child public key based on `index`, without the private key.
+    ///
+    /// This only supports non-hardened [BIP-32](https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki) derivation,
+    /// the result is the public key of [`PrivateKey::derive`](crate::PrivateKey::derive) with the same `index`.
+    ///
+    /// # Errors
+    /// - [`Error::KeyDerive`] if this is an Ed25519 key (SLIP-10 only supports hardened derivation).
+    /// - [`Error::KeyDerive`] if this key has no chain code (key is not derivable).
+    /// - [`Error::KeyDerive`] if `index` is hardened (the high bit is set).
+    /// - [`Error::KeyDerive`] if the derived key is invalid (which is astronomically unlikely),
+    ///   in which case the next index should be used instead.
+    pub fn derive(&self, index: i32) -> crate::Result<Self> {
+        let index = index as u32;
+
+        let PublicKeyData::Ecdsa(key) = &self.0 else {
+            return Err(Error::key_derive("Ed25519 public keys can't be derived"));
+        };
+
+        let chain_code = self.1.as_ref().ok_or_else(|| Error::key_derive("key is underivable"))?;
+
+        if index & bip32::HARDENED != 0 {
+            return Err(Error::key_derive("hardened derivation requires a private key"));
+        }
+
+        let parent = self.to_extended_key_bytes();
+
+        let (tweak, chain_code) = bip32::hmac_sha512(chain_code, &[&parent, &index.to_be_bytes()]);
+
+        let key = bip32::ecdsa_child_public_key(key, &tweak)?;
+
+        Ok(Self::ecdsa(key).with_chain_code(Some(chain_code), self.2.child(&parent, index)?))
+    }
+
+    /// Returns this key and its chain code as an extended public key.
+    ///
+    /// This uses the [BIP-32](https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki) `xpub` format,
+    /// so it's only available for ECDSA(secp256k1) keys: SLIP-10 Ed25519 derivation is hardened-only,
+    /// so an extended Ed25519 public key couldn't derive anything.
+    ///
+    /// # Errors
+    /// - [`Error::KeyDerive`] if this is an Ed25519 key.
+    /// - [`Error::KeyDerive`] if this key has no chain code.
+    pub fn to_xpub(&self) -> crate::Result<String> {
+        if self.is_ed25519() {
+            return Err(Error::key_derive("Ed25519 keys don't have extended public keys"));
+        }
+
+        let chain_code = self.1.ok_or_else(|| Error::key_derive("key is underivable"))?;
+
+        Ok(bip32::ExtendedKey {
+            version: bip32::ECDSA_PUBLIC_VERSION,
+            lineage: self.2,
+            chain_code,
+            key: self.to_extended_key_bytes(),
+        }
+        .encode())
+    }
+
+    /// Parses an extended public key, as returned by [`to_xpub`](Self::to_xpub).
+    ///
+    /// The returned key keeps the chain code, so it can be [`derive`](Self::derive)d.
+    ///
+    /// # Errors
+    /// - [`Error::KeyParse`] if `s` isn't a valid Base58Check encoded extended key.
+    /// - [`Error::KeyParse`] if `s` is an extended private key, or has unknown version bytes.
+    /// - [`Error::KeyParse`] if the key in `s` isn't a valid public key.
+    pub fn from_xpub(s: &str) -> crate::Result<Self> {
+        let extended = bip32::ExtendedKey::decode(s)?;
+
+        let key = match extended.version {
+            bip32::ECDSA_PUBLIC_VERSION => Self::from_bytes_ecdsa(&extended.key)?,
+            bip32::ED25519_PRIVATE_VERSION | bip32::ECDSA_PRIVATE_VERSION => {
+                return Err(Error::key_parse(
+                    "expected an extended public key, got an extended private key",
+                ));
+            }
+            _ => return Err(Error::key_parse("unknown extended key version")),
+        };
+
+        Ok(key.with_chain_code(Some(extended.chain_code), extended.lineage))
+    }
+
     #[must_use]
     pub(crate) fn kind(&self) -> super::KeyKind {
         match &self.0 {
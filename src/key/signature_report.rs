@@ -0,0 +1,347 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use crate::signer::AnySigner;
+use crate::transaction::{
+    TransactionExecute,
+    TransactionSources,
+};
+use crate::{
+    Key,
+    PublicKey,
+    Transaction,
+};
+
+/// The result of checking a transaction's signatures against a [`Key`].
+///
+/// Produced by [`Key::verify_transaction`] and [`Key::verify_transaction_bytes`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeySignatureReport {
+    /// Whether the signatures meet every threshold in the key.
+    pub is_satisfied: bool,
+
+    /// Leaves of the key that have a valid signature.
+    pub signed: Vec<Key>,
+
+    /// Leaves of the key that don't have a valid signature yet.
+    ///
+    /// Contract and delegate contract leaves can never be satisfied by a signature,
+    /// so they always show up here.
+    pub missing: Vec<Key>,
+
+    /// Public keys that provided a signature which failed to verify.
+    ///
+    /// Each of these is also listed in `missing`.
+    pub invalid: Vec<PublicKey>,
+}
+
+impl KeySignatureReport {
+    pub(crate) fn new<D: TransactionExecute>(key: &Key, transaction: &Transaction<D>) -> Self {
+        let mut report = Self {
+            is_satisfied: false,
+            signed: Vec::new(),
+            missing: Vec::new(),
+            invalid: Vec::new(),
+        };
+
+        report.is_satisfied = report.visit(key, &|public_key| {
+            if transaction.signers().map(AnySigner::public_key).any(|it| *public_key == it) {
+                return LeafStatus::Signed;
+            }
+
+            transaction
+                .sources()
+                .map_or(LeafStatus::Missing, |sources| leaf_status(public_key, sources))
+        });
+
+        report
+    }
+
+    /// Like [`new`](Self::new), but only looks at the signatures in `sources`.
+    pub(crate) fn from_sources(key: &Key, sources: &TransactionSources) -> Self {
+        let mut report = Self {
+            is_satisfied: false,
+            signed: Vec::new(),
+            missing: Vec::new(),
+            invalid: Vec::new(),
+        };
+
+        report.is_satisfied = report.visit(key, &|public_key| leaf_status(public_key, sources));
+
+        report
+    }
+
+    fn visit(&mut self, key: &Key, status: &dyn Fn(&PublicKey) -> LeafStatus) -> bool {
+        match key {
+            Key::Single(public_key) => match status(public_key) {
+                LeafStatus::Signed => {
+                    push_unique(&mut self.signed, key);
+                    true
+                }
+                LeafStatus::Missing => {
+                    push_unique(&mut self.missing, key);
+                    false
+                }
+                LeafStatus::Invalid => {
+                    push_unique(&mut self.missing, key);
+                    if !self.invalid.contains(public_key) {
+                        self.invalid.push(*public_key);
+                    }
+                    false
+                }
+            },
+
+            Key::ContractId(_) | Key::DelegateContractId(_) => {
+                push_unique(&mut self.missing, key);
+                false
+            }
+
+            Key::KeyList(list) => {
+                // every child is visited (no short circuiting) so that all leaves get reported.
+                let satisfied = list.keys.iter().filter(|key| self.visit(key, status)).count();
+
+                // `None` means all of the keys are required.
+                let required = list.threshold.map_or(list.keys.len(), |it| it as usize);
+
+                satisfied >= required
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum LeafStatus {
+    Signed,
+    Missing,
+    Invalid,
+}
+
+fn push_unique(leaves: &mut Vec<Key>, key: &Key) {
+    if !leaves.contains(key) {
+        leaves.push(key.clone());
+    }
+}
+
+/// A key is only considered signed if it has a valid signature on _every_ signed transaction.
+fn leaf_status(public_key: &PublicKey, sources: &TransactionSources) -> LeafStatus {
+    let mut status = LeafStatus::Signed;
+
+    for signed_transaction in sources.signed_transactions() {
+        match public_key.verify_signed_transaction(signed_transaction) {
+            Ok(true) => {}
+            Ok(false) => status = LeafStatus::Missing,
+            Err(_) => return LeafStatus::Invalid,
+        }
+    }
+
+    status
+}
+
+#[cfg(test)]
+mod tests {
+    use hedera_proto::services;
+    use prost::Message;
+
+    use crate::transaction::test_helpers::{
+        TEST_NODE_ACCOUNT_IDS,
+        TEST_TX_ID,
+    };
+    use crate::{
+        AnyTransaction,
+        ContractId,
+        Key,
+        KeyList,
+        PrivateKey,
+        TransferTransaction,
+    };
+
+    fn make_transaction(signers: &[&PrivateKey]) -> TransferTransaction {
+        let mut tx = TransferTransaction::new();
+
+        tx.node_account_ids(TEST_NODE_ACCOUNT_IDS).transaction_id(TEST_TX_ID).freeze().unwrap();
+
+        for signer in signers {
+            tx.sign((*signer).clone());
+        }
+
+        tx
+    }
+
+    fn threshold(threshold: Option<u32>, keys: impl IntoIterator<Item = Key>) -> Key {
+        Key::KeyList(KeyList { keys: keys.into_iter().collect(), threshold })
+    }
+
+    #[test]
+    fn single_key() {
+        let key = PrivateKey::generate_ed25519();
+        let other = PrivateKey::generate_ecdsa();
+
+        let mut tx = make_transaction(&[&key]);
+
+        let report = Key::Single(key.public_key()).verify_transaction(&mut tx).unwrap();
+        assert!(report.is_satisfied);
+        assert_eq!(report.signed, [Key::Single(key.public_key())]);
+        assert!(report.missing.is_empty());
+
+        let report = Key::Single(other.public_key()).verify_transaction(&mut tx).unwrap();
+        assert!(!report.is_satisfied);
+        assert_eq!(report.missing, [Key::Single(other.public_key())]);
+    }
+
+    #[test]
+    fn threshold_key() {
+        let keys = [
+            PrivateKey::generate_ed25519(),
+            PrivateKey::generate_ecdsa(),
+            PrivateKey::generate_ed25519(),
+        ];
+
+        let key = threshold(Some(2), keys.iter().map(|it| it.public_key().into()));
+
+        let report = key.verify_transaction(&mut make_transaction(&[&keys[0]])).unwrap();
+        assert!(!report.is_satisfied);
+
+        let report = key.verify_transaction(&mut make_transaction(&[&keys[0], &keys[1]])).unwrap();
+        assert!(report.is_satisfied);
+        assert_eq!(report.missing, [Key::Single(keys[2].public_key())]);
+    }
+
+    #[test]
+    fn nested_key_list() {
+        let keys = [
+            PrivateKey::generate_ed25519(),
+            PrivateKey::generate_ed25519(),
+            PrivateKey::generate_ed25519(),
+        ];
+
+        let key = threshold(
+            None,
+            [
+                keys[0].public_key().into(),
+                threshold(Some(1), [keys[1].public_key().into(), keys[2].public_key().into()]),
+            ],
+        );
+
+        let report = key.verify_transaction(&mut make_transaction(&[&keys[0]])).unwrap();
+        assert!(!report.is_satisfied);
+        assert_eq!(report.signed, [Key::Single(keys[0].public_key())]);
+        assert_eq!(
+            report.missing,
+            [Key::Single(keys[1].public_key()), Key::Single(keys[2].public_key())]
+        );
+
+        let report = key.verify_transaction(&mut make_transaction(&[&keys[0], &keys[2]])).unwrap();
+        assert!(report.is_satisfied);
+    }
+
+    #[test]
+    fn contract_keys_are_never_signed() {
+        let key = PrivateKey::generate_ed25519();
+        let contract = Key::ContractId(ContractId::new(0, 0, 1234));
+
+        let mut tx = make_transaction(&[&key]);
+
+        let report = threshold(Some(1), [key.public_key().into(), contract.clone()])
+            .verify_transaction(&mut tx)
+            .unwrap();
+        assert!(report.is_satisfied);
+        assert_eq!(report.missing, [contract.clone()]);
+
+        let report = threshold(None, [key.public_key().into(), contract])
+            .verify_transaction(&mut tx)
+            .unwrap();
+        assert!(!report.is_satisfied);
+    }
+
+    #[test]
+    fn from_bytes() {
+        let keys = [PrivateKey::generate_ed25519(), PrivateKey::generate_ecdsa()];
+
+        let mut tx = make_transaction(&[&keys[0]]);
+        let forged = keys[1].public_key();
+        tx.sign_with(forged, |_| vec![0; 64]);
+
+        let mut tx = AnyTransaction::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+
+        let key = threshold(None, keys.iter().map(|it| it.public_key().into()));
+
+        let report = key.verify_transaction(&mut tx).unwrap();
+        assert!(!report.is_satisfied);
+        assert_eq!(report.signed, [Key::Single(keys[0].public_key())]);
+        assert_eq!(report.missing, [Key::Single(forged)]);
+        assert_eq!(report.invalid, [forged]);
+    }
+
+    #[test]
+    fn verify_transaction_bytes() {
+        let keys = [PrivateKey::generate_ed25519(), PrivateKey::generate_ecdsa()];
+
+        let mut tx = make_transaction(&[&keys[0]]);
+        let forged = keys[1].public_key();
+        tx.sign_with(forged, |_| vec![0; 64]);
+
+        let bytes = tx.to_bytes().unwrap();
+
+        let report = Key::Single(keys[0].public_key()).verify_transaction_bytes(&bytes).unwrap();
+        assert!(report.is_satisfied);
+
+        let key = threshold(None, keys.iter().map(|it| it.public_key().into()));
+
+        let report = key.verify_transaction_bytes(&bytes).unwrap();
+        assert!(!report.is_satisfied);
+        assert_eq!(report.signed, [Key::Single(keys[0].public_key())]);
+        assert_eq!(report.invalid, [forged]);
+
+        assert!(key.verify_transaction_bytes(&[0xff, 0xff]).is_err());
+    }
+
+    #[test]
+    fn forged_signature_before_valid_one() {
+        let key = PrivateKey::generate_ed25519();
+
+        let mut list = hedera_proto::sdk::TransactionList::decode(
+            &*make_transaction(&[&key]).to_bytes().unwrap(),
+        )
+        .unwrap();
+
+        // put a bogus signature for the same key in front of the real one.
+        for transaction in &mut list.transaction_list {
+            let mut signed =
+                services::SignedTransaction::decode(&*transaction.signed_transaction_bytes)
+                    .unwrap();
+
+            signed.sig_map.as_mut().unwrap().sig_pair.insert(
+                0,
+                services::SignaturePair {
+                    pub_key_prefix: key.public_key().to_bytes_raw(),
+                    signature: Some(services::signature_pair::Signature::Ed25519(vec![0; 64])),
+                },
+            );
+
+            transaction.signed_transaction_bytes = signed.encode_to_vec();
+        }
+
+        let mut tx = AnyTransaction::from_bytes(&list.encode_to_vec()).unwrap();
+
+        let report = Key::Single(key.public_key()).verify_transaction(&mut tx).unwrap();
+        assert!(report.is_satisfied);
+        assert!(report.invalid.is_empty());
+    }
+}
@@ -118,6 +118,8 @@
 mod protobuf;
 
 mod account;
+mod address_book;
+mod bulk_executor;
 mod client;
 mod contract;
 mod downcast;
@@ -126,14 +128,20 @@ mod error;
 mod ethereum;
 mod exchange_rates;
 mod execute;
+mod execute_policy;
+mod fee_estimate;
 mod fee_schedules;
 mod file;
 mod hbar;
 mod key;
 mod ledger_id;
 mod mirror_query;
+#[cfg(feature = "mirror-rest")]
+mod mirror_rest;
 #[cfg(feature = "mnemonic")]
 mod mnemonic;
+#[cfg(feature = "mock")]
+mod mock;
 mod network_version_info;
 mod network_version_info_query;
 mod node_address;
@@ -142,9 +150,13 @@ mod node_address_book_query;
 mod ping_query;
 mod prng_transaction;
 mod query;
+mod request_hook;
+mod required_signers;
 mod retry;
 mod schedule;
 mod semantic_version;
+#[cfg(feature = "serde")]
+mod serde_impls;
 mod signer;
 mod staked_id;
 mod staking_info;
@@ -159,6 +171,7 @@ mod transaction_receipt_query;
 mod transaction_record;
 mod transaction_record_query;
 mod transaction_response;
+mod transaction_summary;
 mod transfer;
 mod transfer_transaction;
 
@@ -179,14 +192,41 @@ pub use account::{
     AllProxyStakers,
     ProxyStaker,
 };
-pub use client::Client;
+pub use address_book::{
+    NodeCreateTransaction,
+    NodeDeleteTransaction,
+    NodeUpdateTransaction,
+};
+pub use bulk_executor::BulkExecutor;
 pub(crate) use client::Operator;
+pub use client::{
+    Client,
+    NetworkStatus,
+    NodeStatus,
+};
+#[cfg(feature = "serde")]
+pub use client::{
+    ClientConfig,
+    MirrorNetworkConfig,
+    NetworkConfig,
+    NetworkName,
+    OperatorConfig,
+};
 pub use contract::{
+    AbiType,
+    AbiValue,
+    ContractAbi,
+    ContractAbiEvent,
+    ContractAbiEventParam,
+    ContractAbiFunction,
+    ContractAbiParam,
     ContractBytecodeQuery,
     ContractCallQuery,
     ContractCreateFlow,
     ContractCreateTransaction,
     ContractDeleteTransaction,
+    ContractEventField,
+    ContractEventLog,
     ContractExecuteTransaction,
     ContractFunctionParameters,
     ContractFunctionResult,
@@ -203,6 +243,7 @@ pub(crate) use entity_id::ValidateChecksums;
 pub use error::{
     Error,
     Result,
+    TopicMessageVerifyError,
 };
 #[cfg(feature = "mnemonic")]
 pub use error::{
@@ -221,6 +262,15 @@ pub use exchange_rates::{
     ExchangeRate,
     ExchangeRates,
 };
+pub use execute_policy::{
+    NodeSelector,
+    RetryDecision,
+    RetryPolicy,
+};
+pub use fee_estimate::{
+    FeeEstimate,
+    FeeEstimator,
+};
 pub use fee_schedules::{
     FeeComponents,
     FeeData,
@@ -250,6 +300,7 @@ pub use hedera_proto::services::ResponseCodeEnum as Status;
 pub use key::{
     Key,
     KeyList,
+    KeySignatureReport,
     PrivateKey,
     PublicKey,
 };
@@ -259,8 +310,22 @@ pub use mirror_query::{
     AnyMirrorQueryResponse,
     MirrorQuery,
 };
+#[cfg(feature = "mirror-rest")]
+pub use mirror_rest::{
+    MirrorContractResult,
+    MirrorNft,
+    MirrorRestClient,
+    MirrorTokenBalance,
+    MirrorTransaction,
+    MirrorTransfer,
+};
 #[cfg(feature = "mnemonic")]
 pub use mnemonic::Mnemonic;
+#[cfg(feature = "mock")]
+pub use mock::{
+    MockReply,
+    MockServer,
+};
 pub use network_version_info::NetworkVersionInfo;
 pub use network_version_info_query::NetworkVersionInfoQuery;
 pub(crate) use network_version_info_query::NetworkVersionInfoQueryData;
@@ -278,6 +343,14 @@ pub use query::{
     AnyQueryResponse,
     Query,
 };
+pub use request_hook::{
+    RequestAttempt,
+    RequestHook,
+};
+pub use required_signers::{
+    RequiredSigner,
+    RequiredSignerReport,
+};
 pub(crate) use retry::retry;
 pub use schedule::{
     ScheduleCreateTransaction,
@@ -288,6 +361,7 @@ pub use schedule::{
     ScheduleSignTransaction,
 };
 pub use semantic_version::SemanticVersion;
+pub use signer::Signer;
 pub use staking_info::StakingInfo;
 pub use system::{
     FreezeTransaction,
@@ -306,11 +380,16 @@ pub use token::{
     FractionalFee,
     FractionalFeeData,
     NftId,
+    PendingAirdropId,
+    PendingAirdropRecord,
     RoyaltyFee,
     RoyaltyFeeData,
+    TokenAirdropTransaction,
     TokenAssociateTransaction,
     TokenAssociation,
     TokenBurnTransaction,
+    TokenCancelAirdropTransaction,
+    TokenClaimAirdropTransaction,
     TokenCreateTransaction,
     TokenDeleteTransaction,
     TokenDissociateTransaction,
@@ -325,6 +404,8 @@ pub use token::{
     TokenNftInfoQuery,
     TokenNftTransfer,
     TokenPauseTransaction,
+    TokenRejectFlow,
+    TokenRejectTransaction,
     TokenRevokeKycTransaction,
     TokenSupplyType,
     TokenType,
@@ -334,19 +415,27 @@ pub use token::{
     TokenUpdateTransaction,
     TokenWipeTransaction,
 };
+#[cfg(feature = "serde")]
+pub use topic::FileCheckpointStore;
 pub use topic::{
+    MemoryCheckpointStore,
+    PendingTopicMessageChunk,
     TopicCreateTransaction,
     TopicDeleteTransaction,
     TopicId,
     TopicInfo,
     TopicInfoQuery,
     TopicMessage,
+    TopicMessageCheckpoint,
+    TopicMessageCheckpointStore,
+    TopicMessageEnvelope,
     TopicMessageQuery,
     TopicMessageSubmitTransaction,
     TopicUpdateTransaction,
 };
 pub use transaction::{
     AnyTransaction,
+    SignatureCollection,
     Transaction,
 };
 pub use transaction_hash::TransactionHash;
@@ -357,6 +446,14 @@ pub use transaction_record::TransactionRecord;
 pub use transaction_record_query::TransactionRecordQuery;
 pub(crate) use transaction_record_query::TransactionRecordQueryData;
 pub use transaction_response::TransactionResponse;
+pub use transaction_summary::{
+    SummaryDifference,
+    SummaryField,
+    SummaryKey,
+    SummaryTransfer,
+    TransactionDiff,
+    TransactionSummary,
+};
 pub use transfer::Transfer;
 pub use transfer_transaction::TransferTransaction;
 
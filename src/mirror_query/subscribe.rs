@@ -124,7 +124,10 @@ where
 
     type Response = <Self as MirrorRequest>::Response;
 
-    type ItemStream<'a> = <Self as MirrorRequest>::ItemStream<'a> where Self: 'a;
+    type ItemStream<'a>
+        = <Self as MirrorRequest>::ItemStream<'a>
+    where
+        Self: 'a;
 
     fn subscribe_with_optional_timeout<'a>(
         &self,
@@ -142,7 +145,7 @@ where
         // note: we don't care about keeping the mirrornet around, so, we just take the channel (which is arc-like)
         let channel = client.mirrornet().load().channel();
 
-        Self::make_item_stream(crate::mirror_query::subscribe(channel, timeout, self.clone()))
+        self.make_item_stream(crate::mirror_query::subscribe(channel, timeout, self.clone()))
     }
 
     fn execute_with_optional_timeout<'a>(
@@ -158,7 +161,7 @@ where
         // note: we don't care about keeping the mirrornet around, so, we just take the channel (which is arc-like)
         let channel = client.mirrornet().load().channel();
 
-        Self::try_collect(crate::mirror_query::subscribe(channel, timeout, self.clone()))
+        self.try_collect(crate::mirror_query::subscribe(channel, timeout, self.clone()))
     }
 }
 
@@ -178,19 +181,24 @@ pub trait MirrorRequest: Send {
         channel: Channel,
     ) -> BoxFuture<'_, tonic::Result<Self::ConnectStream>>;
 
+    /// Returns the context to start the subscription with.
+    fn initial_context(&self) -> BoxFuture<'_, crate::Result<Self::Context>> {
+        Box::pin(std::future::ready(Ok(Self::Context::default())))
+    }
+
     /// Return `true` to retry establishing the stream, up to a configurable maximum timeout.
     #[allow(unused_variables)]
     fn should_retry(&self, status_code: tonic::Code) -> bool {
         false
     }
 
-    fn make_item_stream<'a, S>(stream: S) -> Self::ItemStream<'a>
+    fn make_item_stream<'a, S>(&self, stream: S) -> Self::ItemStream<'a>
     where
         S: Stream<Item = crate::Result<Self::GrpcItem>> + Send + 'a;
 
     fn update_context(context: &mut Self::Context, item: &Self::GrpcItem);
 
-    fn try_collect<'a, S>(stream: S) -> BoxFuture<'a, crate::Result<Self::Response>>
+    fn try_collect<'a, S>(&self, stream: S) -> BoxFuture<'a, crate::Result<Self::Response>>
     where
         S: Stream<Item = crate::Result<Self::GrpcItem>> + Send + 'a;
 }
@@ -214,12 +222,29 @@ pub(crate) fn subscribe<I: Send, R: MirrorRequest<GrpcItem = I> + Send + Sync>(
             .. ExponentialBackoff::default()
         };
 
-        let mut context = R::Context::default();
+        let mut context = match request.initial_context().await {
+            Ok(context) => context,
+            Err(e) => {
+                yield Err(e);
+                return;
+            }
+        };
+
+        #[cfg(feature = "tracing")]
+        let span = tracing::debug_span!(
+            "subscribe",
+            request_type = crate::request_hook::request_type_name(std::any::type_name::<R>()),
+        );
 
         loop {
             let status: Status = 'request: loop {
                 // attempt to establish the stream
-                let response = request.connect(&context, channel.clone()).await;
+                let response = request.connect(&context, channel.clone());
+
+                #[cfg(feature = "tracing")]
+                let response = tracing::Instrument::instrument(response, span.clone());
+
+                let response = response.await;
 
                 let stream = match response {
                     // success, we now have a stream and may begin waiting for messages
@@ -237,7 +262,12 @@ pub(crate) fn subscribe<I: Send, R: MirrorRequest<GrpcItem = I> + Send + Sync>(
 
                 #[allow(unused_labels)]
                 'message: loop {
-                    let message = stream.next().await.transpose();
+                    let message = stream.next();
+
+                    #[cfg(feature = "tracing")]
+                    let message = tracing::Instrument::instrument(message, span.clone());
+
+                    let message = message.await.transpose();
 
                     let message = match message {
                         Ok(Some(message)) => message,
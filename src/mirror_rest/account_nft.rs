@@ -0,0 +1,152 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use time::OffsetDateTime;
+
+use super::{
+    decode_base64,
+    parse_timestamp,
+    Links,
+    MirrorRestPage,
+};
+use crate::{
+    AccountId,
+    NftId,
+    TokenId,
+};
+
+/// An NFT, as reported by the mirror node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MirrorNft {
+    /// The ID of the NFT.
+    pub nft_id: NftId,
+
+    /// The account that owns the NFT, if any.
+    pub account_id: Option<AccountId>,
+
+    /// The account approved to spend the NFT, if any.
+    pub spender_id: Option<AccountId>,
+
+    /// The metadata of the NFT.
+    pub metadata: Vec<u8>,
+
+    /// Whether the NFT has been burned or wiped.
+    pub deleted: bool,
+
+    /// The consensus time the NFT was minted at.
+    pub created_timestamp: OffsetDateTime,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct NftJson {
+    token_id: String,
+    serial_number: u64,
+    account_id: Option<String>,
+    spender: Option<String>,
+    #[serde(default)]
+    metadata: String,
+    #[serde(default)]
+    deleted: bool,
+    created_timestamp: String,
+}
+
+#[derive(serde_derive::Deserialize)]
+pub(super) struct NftsPage {
+    nfts: Vec<NftJson>,
+    links: Links,
+}
+
+impl MirrorRestPage for NftsPage {
+    type Item = MirrorNft;
+
+    fn next_link(&self) -> Option<&str> {
+        self.links.next()
+    }
+
+    fn into_items(self) -> crate::Result<Vec<Self::Item>> {
+        self.nfts
+            .into_iter()
+            .map(|it| {
+                Ok(MirrorNft {
+                    nft_id: it.token_id.parse::<TokenId>()?.nft(it.serial_number),
+                    account_id: it.account_id.as_deref().map(str::parse).transpose()?,
+                    spender_id: it.spender.as_deref().map(str::parse).transpose()?,
+                    metadata: decode_base64(&it.metadata)?,
+                    deleted: it.deleted,
+                    created_timestamp: parse_timestamp(&it.created_timestamp)?,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::TryStreamExt;
+    use time::OffsetDateTime;
+
+    use crate::mirror_rest::test_helpers::serve;
+    use crate::{
+        AccountId,
+        MirrorRestClient,
+        TokenId,
+    };
+
+    #[tokio::test]
+    async fn account_nfts() {
+        let base_url = serve(&[(
+            "/api/v1/accounts/0.0.5006/nfts",
+            r#"{
+                "nfts": [{
+                    "account_id": "0.0.5006",
+                    "created_timestamp": "1554158542.000000001",
+                    "delegating_spender": null,
+                    "deleted": false,
+                    "metadata": "AQID",
+                    "modified_timestamp": "1554158542.000000001",
+                    "serial_number": 3,
+                    "spender": null,
+                    "token_id": "0.0.1001"
+                }],
+                "links": { "next": null }
+            }"#,
+        )])
+        .await;
+
+        let nfts: Vec<_> = MirrorRestClient::new(base_url)
+            .account_nfts(AccountId::new(0, 0, 5006))
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(nfts.len(), 1);
+
+        let nft = &nfts[0];
+        assert_eq!(nft.nft_id, TokenId::new(0, 0, 1001).nft(3));
+        assert_eq!(nft.account_id, Some(AccountId::new(0, 0, 5006)));
+        assert_eq!(nft.spender_id, None);
+        assert_eq!(nft.metadata, [1, 2, 3]);
+        assert!(!nft.deleted);
+        assert_eq!(
+            nft.created_timestamp,
+            OffsetDateTime::from_unix_timestamp_nanos(1_554_158_542_000_000_001).unwrap()
+        );
+    }
+}
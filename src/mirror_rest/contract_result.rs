@@ -0,0 +1,185 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use time::OffsetDateTime;
+
+use super::{
+    decode_hex,
+    parse_timestamp,
+    Links,
+    MirrorRestPage,
+};
+use crate::{
+    ContractId,
+    Hbar,
+};
+
+/// The result of a contract call, as reported by the mirror node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MirrorContractResult {
+    /// The contract that was called.
+    pub contract_id: ContractId,
+
+    /// The consensus time of the call.
+    pub timestamp: OffsetDateTime,
+
+    /// The EVM address of the caller.
+    pub from: Vec<u8>,
+
+    /// The amount sent to the contract with the call.
+    pub amount: Hbar,
+
+    /// The maximum gas allowed for the call.
+    pub gas_limit: u64,
+
+    /// The gas actually used by the call.
+    pub gas_used: u64,
+
+    /// The ABI encoded parameters the contract was called with.
+    pub function_parameters: Vec<u8>,
+
+    /// The ABI encoded value returned by the call.
+    pub call_result: Vec<u8>,
+
+    /// The error message the call reverted with, if any.
+    pub error_message: Option<String>,
+
+    /// The result of the transaction, for example `SUCCESS`.
+    pub result: String,
+
+    /// The hash of the transaction that made the call.
+    pub hash: Vec<u8>,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct ContractResultJson {
+    contract_id: String,
+    timestamp: String,
+    from: Option<String>,
+    #[serde(default)]
+    amount: i64,
+    #[serde(default)]
+    gas_limit: u64,
+    #[serde(default)]
+    gas_used: u64,
+    function_parameters: Option<String>,
+    call_result: Option<String>,
+    error_message: Option<String>,
+    result: String,
+    hash: Option<String>,
+}
+
+#[derive(serde_derive::Deserialize)]
+pub(super) struct ContractResultsPage {
+    results: Vec<ContractResultJson>,
+    links: Links,
+}
+
+impl MirrorRestPage for ContractResultsPage {
+    type Item = MirrorContractResult;
+
+    fn next_link(&self) -> Option<&str> {
+        self.links.next()
+    }
+
+    fn into_items(self) -> crate::Result<Vec<Self::Item>> {
+        fn decode(value: Option<String>) -> crate::Result<Vec<u8>> {
+            value.as_deref().map(decode_hex).transpose().map(Option::unwrap_or_default)
+        }
+
+        self.results
+            .into_iter()
+            .map(|it| {
+                Ok(MirrorContractResult {
+                    contract_id: it.contract_id.parse()?,
+                    timestamp: parse_timestamp(&it.timestamp)?,
+                    from: decode(it.from)?,
+                    amount: Hbar::from_tinybars(it.amount),
+                    gas_limit: it.gas_limit,
+                    gas_used: it.gas_used,
+                    function_parameters: decode(it.function_parameters)?,
+                    call_result: decode(it.call_result)?,
+                    error_message: it.error_message.filter(|it| !it.is_empty()),
+                    result: it.result,
+                    hash: decode(it.hash)?,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::TryStreamExt;
+
+    use crate::mirror_rest::test_helpers::serve;
+    use crate::{
+        ContractId,
+        Hbar,
+        MirrorRestClient,
+    };
+
+    #[tokio::test]
+    async fn contract_results() {
+        let base_url = serve(&[(
+            "/api/v1/contracts/0.0.1002/results",
+            r#"{
+                "results": [{
+                    "address": "0x00000000000000000000000000000000000003ea",
+                    "amount": 100,
+                    "call_result": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                    "contract_id": "0.0.1002",
+                    "error_message": null,
+                    "from": "0x000000000000000000000000000000000000138e",
+                    "function_parameters": "0xbb9f02dc",
+                    "gas_limit": 250000,
+                    "gas_used": 200000,
+                    "hash": "0x0102",
+                    "result": "SUCCESS",
+                    "status": "0x1",
+                    "timestamp": "1554158542.000000001",
+                    "to": "0x00000000000000000000000000000000000003ea"
+                }],
+                "links": { "next": null }
+            }"#,
+        )])
+        .await;
+
+        let results: Vec<_> = MirrorRestClient::new(base_url)
+            .contract_results(ContractId::new(0, 0, 1002))
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 1);
+
+        let result = &results[0];
+        assert_eq!(result.contract_id, ContractId::new(0, 0, 1002));
+        assert_eq!(result.amount, Hbar::from_tinybars(100));
+        assert_eq!(result.gas_limit, 250_000);
+        assert_eq!(result.gas_used, 200_000);
+        assert_eq!(result.from[18..], [0x13, 0x8e]);
+        assert_eq!(result.function_parameters, [0xbb, 0x9f, 0x02, 0xdc]);
+        assert_eq!(result.call_result.last(), Some(&1));
+        assert_eq!(result.error_message, None);
+        assert_eq!(result.result, "SUCCESS");
+        assert_eq!(result.hash, [1, 2]);
+    }
+}
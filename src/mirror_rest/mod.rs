@@ -0,0 +1,521 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+mod account_nft;
+mod contract_result;
+mod token_balance;
+mod transaction;
+
+use async_stream::try_stream;
+use futures_core::stream::BoxStream;
+use serde::de::DeserializeOwned;
+use time::{
+    Duration,
+    OffsetDateTime,
+};
+
+pub use self::account_nft::MirrorNft;
+pub use self::contract_result::MirrorContractResult;
+pub use self::token_balance::MirrorTokenBalance;
+pub use self::transaction::{
+    MirrorTransaction,
+    MirrorTransfer,
+};
+use crate::{
+    AccountId,
+    Client,
+    ContractId,
+    Error,
+    TokenId,
+    TransactionId,
+};
+
+/// Port the mirror node REST API listens on in a local network (the gRPC API listens on `5600`).
+const LOCAL_REST_PORT: u16 = 5551;
+
+/// A client for the mirror node REST API.
+///
+/// Lookups that can return more than one page of results are returned as a [`Stream`](futures_core::Stream)
+/// which fetches the next page when the current one is exhausted.
+#[derive(Debug, Clone)]
+pub struct MirrorRestClient {
+    http: reqwest::Client,
+    base_url: String,
+}
+
+impl MirrorRestClient {
+    /// Create a new `MirrorRestClient` for the mirror node at `base_url`, for example `https://testnet.mirrornode.hedera.com`.
+    #[must_use]
+    pub fn new(base_url: impl Into<String>) -> Self {
+        let base_url = base_url.into();
+
+        Self { http: reqwest::Client::new(), base_url: base_url.trim_end_matches('/').to_owned() }
+    }
+
+    /// Create a new `MirrorRestClient` for the first mirror node in `client`'s mirror network.
+    ///
+    /// Mirror nodes served over port `443` are assumed to serve the REST API over HTTPS on the same host,
+    /// local mirror nodes (`localhost:5600` or `127.0.0.1:5600`) are assumed to serve it on port `5551`.
+    ///
+    /// # Errors
+    /// - [`Error::MirrorRest`] if `client` has no mirror network configured.
+    pub fn for_client(client: &Client) -> crate::Result<Self> {
+        let address = client
+            .mirror_network()
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::mirror_rest("client has no mirror network configured"))?;
+
+        Ok(Self::new(base_url_for_address(&address)))
+    }
+
+    /// Returns the base URL of the mirror node.
+    #[must_use]
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Returns every account's balance of `token_id`.
+    pub fn token_balances(
+        &self,
+        token_id: TokenId,
+    ) -> BoxStream<'static, crate::Result<MirrorTokenBalance>> {
+        self.paginate::<token_balance::TokenBalancesPage>(format!(
+            "/api/v1/tokens/{token_id}/balances"
+        ))
+    }
+
+    /// Returns the NFTs owned by `account_id`.
+    pub fn account_nfts(
+        &self,
+        account_id: AccountId,
+    ) -> BoxStream<'static, crate::Result<MirrorNft>> {
+        self.paginate::<account_nft::NftsPage>(format!("/api/v1/accounts/{account_id}/nfts"))
+    }
+
+    /// Returns the results of calls to `contract_id`.
+    pub fn contract_results(
+        &self,
+        contract_id: ContractId,
+    ) -> BoxStream<'static, crate::Result<MirrorContractResult>> {
+        self.paginate::<contract_result::ContractResultsPage>(format!(
+            "/api/v1/contracts/{contract_id}/results"
+        ))
+    }
+
+    /// Returns the transactions that `account_id` was involved in, most recent first.
+    pub fn account_transactions(
+        &self,
+        account_id: AccountId,
+    ) -> BoxStream<'static, crate::Result<MirrorTransaction>> {
+        self.paginate::<transaction::TransactionsPage>(format!(
+            "/api/v1/transactions?account.id={account_id}"
+        ))
+    }
+
+    /// Returns the transactions with the ID `transaction_id`.
+    ///
+    /// This includes the transaction itself and any child transactions it spawned.
+    ///
+    /// # Errors
+    /// - [`Error::MirrorRest`] if the request fails or the response can't be decoded.
+    /// - [`Error::MirrorRestStatus`] if the mirror node doesn't know about the transaction.
+    pub async fn transaction(
+        &self,
+        transaction_id: TransactionId,
+    ) -> crate::Result<Vec<MirrorTransaction>> {
+        let page: transaction::TransactionsPage = self
+            .get(&format!("/api/v1/transactions/{}", format_transaction_id(&transaction_id)))
+            .await?;
+
+        page.into_items()
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> crate::Result<T> {
+        let response = self
+            .http
+            .get(format!("{}{path}", self.base_url))
+            .send()
+            .await
+            .map_err(Error::mirror_rest)?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            return Err(Error::MirrorRestStatus { status: status.as_u16(), path: path.to_owned() });
+        }
+
+        let body = response.bytes().await.map_err(Error::mirror_rest)?;
+
+        serde_json::from_slice(&body).map_err(Error::mirror_rest)
+    }
+
+    fn paginate<P>(&self, path: String) -> BoxStream<'static, crate::Result<P::Item>>
+    where
+        P: MirrorRestPage + Send + 'static,
+        P::Item: Send + 'static,
+    {
+        let client = self.clone();
+
+        Box::pin(try_stream! {
+            let mut next = Some(path);
+
+            while let Some(path) = next.take() {
+                let page: P = client.get(&path).await?;
+
+                next = page.next_link().map(ToOwned::to_owned);
+
+                for item in page.into_items()? {
+                    yield item;
+                }
+            }
+        })
+    }
+}
+
+/// A single page of a paginated mirror node REST response.
+pub(crate) trait MirrorRestPage: DeserializeOwned {
+    type Item;
+
+    /// Returns the path of the next page, if there is one.
+    fn next_link(&self) -> Option<&str>;
+
+    fn into_items(self) -> crate::Result<Vec<Self::Item>>;
+}
+
+#[derive(serde_derive::Deserialize)]
+pub(crate) struct Links {
+    next: Option<String>,
+}
+
+impl Links {
+    pub(crate) fn next(&self) -> Option<&str> {
+        self.next.as_deref()
+    }
+}
+
+fn base_url_for_address(address: &str) -> String {
+    let (host, port) = match address.rsplit_once(':') {
+        Some((host, port)) => (host, port.parse::<u16>().ok()),
+        None => (address, None),
+    };
+
+    match port {
+        Some(443) => format!("https://{host}"),
+        Some(5600) if host == "localhost" || host == "127.0.0.1" => {
+            format!("http://{host}:{LOCAL_REST_PORT}")
+        }
+        Some(port) => format!("http://{host}:{port}"),
+        None => format!("https://{host}"),
+    }
+}
+
+/// Parses a mirror node timestamp (`<seconds>.<fraction>`), the fraction has at most 9 digits.
+pub(crate) fn parse_timestamp(timestamp: &str) -> crate::Result<OffsetDateTime> {
+    let (seconds, fraction) = timestamp.split_once('.').unwrap_or((timestamp, ""));
+
+    if fraction.len() > 9 || !fraction.bytes().all(|it| it.is_ascii_digit()) {
+        return Err(Error::basic_parse(format!("invalid timestamp `{timestamp}`")));
+    }
+
+    // the fraction is of a second, so `.5` is 500000000 nanoseconds.
+    let nanos = format!("{fraction:0<9}").parse::<i64>().map_err(Error::basic_parse)?;
+
+    timestamp_from_parts(seconds, nanos)
+}
+
+fn timestamp_from_parts(seconds: &str, nanos: i64) -> crate::Result<OffsetDateTime> {
+    let seconds = seconds.parse::<i64>().map_err(Error::basic_parse)?;
+
+    Ok(OffsetDateTime::from_unix_timestamp(seconds).map_err(Error::basic_parse)?
+        + Duration::nanoseconds(nanos))
+}
+
+/// Parses a mirror node transaction ID (`<accountId>-<seconds>-<nanoseconds>`).
+pub(crate) fn parse_transaction_id(transaction_id: &str) -> crate::Result<TransactionId> {
+    const EXPECTED: &str = "expecting <accountId>-<validStartSeconds>-<validStartNanos>";
+
+    let (rest, nanos) =
+        transaction_id.rsplit_once('-').ok_or_else(|| Error::basic_parse(EXPECTED))?;
+    let (account_id, seconds) =
+        rest.rsplit_once('-').ok_or_else(|| Error::basic_parse(EXPECTED))?;
+
+    // unlike in timestamps, the nanoseconds here are a count rather than a fraction.
+    let nanos = nanos
+        .parse::<i64>()
+        .ok()
+        .filter(|it| (0..1_000_000_000).contains(it))
+        .ok_or_else(|| Error::basic_parse(EXPECTED))?;
+
+    Ok(TransactionId {
+        account_id: account_id.parse()?,
+        valid_start: timestamp_from_parts(seconds, nanos)?,
+        nonce: None,
+        scheduled: false,
+    })
+}
+
+fn format_transaction_id(transaction_id: &TransactionId) -> String {
+    format!(
+        "{}-{}-{:09}",
+        transaction_id.account_id,
+        transaction_id.valid_start.unix_timestamp(),
+        transaction_id.valid_start.nanosecond()
+    )
+}
+
+/// Decodes a `0x` prefixed hex string.
+pub(crate) fn decode_hex(value: &str) -> crate::Result<Vec<u8>> {
+    hex::decode(value.strip_prefix("0x").unwrap_or(value)).map_err(Error::basic_parse)
+}
+
+/// Decodes a base64 string.
+pub(crate) fn decode_base64(value: &str) -> crate::Result<Vec<u8>> {
+    use base64::Engine;
+
+    base64::engine::general_purpose::STANDARD.decode(value).map_err(Error::basic_parse)
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use tokio::io::{
+        AsyncReadExt,
+        AsyncWriteExt,
+    };
+    use tokio::net::TcpListener;
+
+    /// Serve `routes` (pairs of request path and JSON body) over HTTP on a local port.
+    ///
+    /// Returns the base URL of the server, unknown paths are answered with `404 Not Found`.
+    pub(crate) async fn serve(routes: &[(&str, &str)]) -> String {
+        let routes: Vec<(String, String)> =
+            routes.iter().map(|(path, body)| ((*path).to_owned(), (*body).to_owned())).collect();
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+
+                let mut buf = vec![0; 8192];
+                let len = stream.read(&mut buf).await.unwrap();
+                let request = String::from_utf8_lossy(&buf[..len]);
+                let path = request.split(' ').nth(1).unwrap_or_default();
+
+                let response = match routes.iter().find(|(route, _)| *route == path) {
+                    Some((_, body)) => format!(
+                        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                        body.len()
+                    ),
+                    None => String::from(
+                        "HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                    ),
+                };
+
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        format!("http://{address}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use assert_matches::assert_matches;
+    use futures_util::TryStreamExt;
+    use time::OffsetDateTime;
+
+    use super::test_helpers::serve;
+    use super::{
+        base_url_for_address,
+        format_transaction_id,
+        parse_timestamp,
+        parse_transaction_id,
+    };
+    use crate::{
+        AccountId,
+        Client,
+        Error,
+        MirrorRestClient,
+        TokenId,
+        TransactionId,
+    };
+
+    #[tokio::test]
+    async fn for_client_without_mirror_network() {
+        let client = Client::for_network(HashMap::new()).unwrap();
+
+        assert_matches!(MirrorRestClient::for_client(&client), Err(Error::MirrorRest(_)));
+    }
+
+    #[tokio::test]
+    async fn for_client() {
+        let client = Client::for_testnet();
+
+        assert_eq!(
+            MirrorRestClient::for_client(&client).unwrap().base_url(),
+            "https://testnet.mirrornode.hedera.com"
+        );
+    }
+
+    #[test]
+    fn base_url_for_hosted_mirror_node() {
+        assert_eq!(
+            base_url_for_address("testnet.mirrornode.hedera.com:443"),
+            "https://testnet.mirrornode.hedera.com"
+        );
+    }
+
+    #[test]
+    fn base_url_for_local_mirror_node() {
+        assert_eq!(base_url_for_address("127.0.0.1:5600"), "http://127.0.0.1:5551");
+        assert_eq!(base_url_for_address("localhost:5600"), "http://localhost:5551");
+    }
+
+    #[test]
+    fn base_url_for_custom_port() {
+        assert_eq!(
+            base_url_for_address("mirror.example.com:8080"),
+            "http://mirror.example.com:8080"
+        );
+    }
+
+    #[test]
+    fn new_trims_trailing_slash() {
+        assert_eq!(
+            MirrorRestClient::new("http://127.0.0.1:5551/").base_url(),
+            "http://127.0.0.1:5551"
+        );
+    }
+
+    #[test]
+    fn timestamp() {
+        assert_eq!(
+            parse_timestamp("1554158542.000000001").unwrap(),
+            OffsetDateTime::from_unix_timestamp_nanos(1_554_158_542_000_000_001).unwrap()
+        );
+    }
+
+    #[test]
+    fn timestamp_short_fraction() {
+        assert_eq!(
+            parse_timestamp("1700000000.5").unwrap(),
+            OffsetDateTime::from_unix_timestamp_nanos(1_700_000_000_500_000_000).unwrap()
+        );
+        assert_eq!(
+            parse_timestamp("1700000000").unwrap(),
+            OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap()
+        );
+    }
+
+    #[test]
+    fn timestamp_invalid() {
+        assert_matches!(parse_timestamp("1700000000.0000000001"), Err(Error::BasicParse(_)));
+        assert_matches!(parse_timestamp("1700000000.-5"), Err(Error::BasicParse(_)));
+    }
+
+    #[test]
+    fn transaction_id_round_trip() {
+        let transaction_id = parse_transaction_id("0.0.5006-1554158542-000000001").unwrap();
+
+        assert_eq!(
+            transaction_id,
+            TransactionId {
+                account_id: AccountId::new(0, 0, 5006),
+                valid_start: OffsetDateTime::from_unix_timestamp_nanos(1_554_158_542_000_000_001)
+                    .unwrap(),
+                nonce: None,
+                scheduled: false,
+            }
+        );
+
+        assert_eq!(format_transaction_id(&transaction_id), "0.0.5006-1554158542-000000001");
+    }
+
+    #[test]
+    fn transaction_id_unpadded_nanos() {
+        assert_eq!(
+            parse_transaction_id("0.0.5006-1554158542-1").unwrap().valid_start,
+            OffsetDateTime::from_unix_timestamp_nanos(1_554_158_542_000_000_001).unwrap()
+        );
+    }
+
+    #[test]
+    fn transaction_id_invalid() {
+        assert_matches!(parse_transaction_id("0.0.5006@1554158542.1"), Err(Error::BasicParse(_)));
+        assert_matches!(
+            parse_transaction_id("0.0.5006-1554158542-1000000000"),
+            Err(Error::BasicParse(_))
+        );
+    }
+
+    #[tokio::test]
+    async fn paginates() {
+        let base_url = serve(&[
+            (
+                "/api/v1/tokens/0.0.1001/balances",
+                r#"{
+                    "timestamp": "1554158542.000000001",
+                    "balances": [{ "account": "0.0.5006", "balance": 10, "decimals": 2 }],
+                    "links": { "next": "/api/v1/tokens/0.0.1001/balances?account.id=gt:0.0.5006" }
+                }"#,
+            ),
+            (
+                "/api/v1/tokens/0.0.1001/balances?account.id=gt:0.0.5006",
+                r#"{
+                    "timestamp": "1554158542.000000001",
+                    "balances": [{ "account": "0.0.5007", "balance": 20, "decimals": 2 }],
+                    "links": { "next": null }
+                }"#,
+            ),
+        ])
+        .await;
+
+        let balances: Vec<_> = MirrorRestClient::new(base_url)
+            .token_balances(TokenId::new(0, 0, 1001))
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            balances.iter().map(|it| (it.account_id, it.balance)).collect::<Vec<_>>(),
+            [(AccountId::new(0, 0, 5006), 10), (AccountId::new(0, 0, 5007), 20)]
+        );
+    }
+
+    #[tokio::test]
+    async fn status_error() {
+        let base_url = serve(&[]).await;
+
+        let result: crate::Result<Vec<_>> = MirrorRestClient::new(base_url)
+            .account_nfts(AccountId::new(0, 0, 5006))
+            .try_collect()
+            .await;
+
+        assert_matches!(
+            result,
+            Err(Error::MirrorRestStatus { status: 404, path }) if path == "/api/v1/accounts/0.0.5006/nfts"
+        );
+    }
+}
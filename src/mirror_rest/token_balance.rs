@@ -0,0 +1,73 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use super::{
+    Links,
+    MirrorRestPage,
+};
+use crate::AccountId;
+
+/// An account's balance of a token, as reported by the mirror node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MirrorTokenBalance {
+    /// The account holding the token.
+    pub account_id: AccountId,
+
+    /// The balance, in the token's smallest denomination.
+    pub balance: u64,
+
+    /// The number of decimal places the token is divisible by.
+    pub decimals: u32,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct TokenBalanceJson {
+    account: String,
+    balance: u64,
+    #[serde(default)]
+    decimals: u32,
+}
+
+#[derive(serde_derive::Deserialize)]
+pub(super) struct TokenBalancesPage {
+    balances: Vec<TokenBalanceJson>,
+    links: Links,
+}
+
+impl MirrorRestPage for TokenBalancesPage {
+    type Item = MirrorTokenBalance;
+
+    fn next_link(&self) -> Option<&str> {
+        self.links.next()
+    }
+
+    fn into_items(self) -> crate::Result<Vec<Self::Item>> {
+        self.balances
+            .into_iter()
+            .map(|it| {
+                Ok(MirrorTokenBalance {
+                    account_id: it.account.parse()?,
+                    balance: it.balance,
+                    decimals: it.decimals,
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,274 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use time::OffsetDateTime;
+
+use super::{
+    decode_base64,
+    parse_timestamp,
+    parse_transaction_id,
+    Links,
+    MirrorRestPage,
+};
+use crate::{
+    AccountId,
+    Error,
+    Hbar,
+    TransactionId,
+};
+
+/// A transaction, as reported by the mirror node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MirrorTransaction {
+    /// The ID of the transaction.
+    pub transaction_id: TransactionId,
+
+    /// The consensus time of the transaction.
+    pub consensus_timestamp: OffsetDateTime,
+
+    /// The type of the transaction, for example `CRYPTOTRANSFER`.
+    pub name: String,
+
+    /// The result of the transaction, for example `SUCCESS`.
+    pub result: String,
+
+    /// The node the transaction was submitted to.
+    pub node_account_id: Option<AccountId>,
+
+    /// The fee charged for the transaction.
+    pub charged_tx_fee: Hbar,
+
+    /// The maximum fee the payer was willing to pay for the transaction.
+    pub max_fee: Hbar,
+
+    /// The memo of the transaction.
+    pub memo: Vec<u8>,
+
+    /// The SHA-384 hash of the transaction.
+    pub transaction_hash: Vec<u8>,
+
+    /// The hbar transfers made by the transaction, including fees.
+    pub transfers: Vec<MirrorTransfer>,
+}
+
+/// An hbar transfer made by a [`MirrorTransaction`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MirrorTransfer {
+    /// The account that sent or received the hbar.
+    pub account_id: AccountId,
+
+    /// The amount sent (negative) or received (positive).
+    pub amount: Hbar,
+
+    /// Whether the transfer was made using an allowance.
+    pub is_approval: bool,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct TransferJson {
+    account: String,
+    amount: i64,
+    #[serde(default)]
+    is_approval: bool,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct TransactionJson {
+    transaction_id: String,
+    #[serde(default)]
+    nonce: i32,
+    #[serde(default)]
+    scheduled: bool,
+    consensus_timestamp: String,
+    name: String,
+    result: String,
+    node: Option<String>,
+    #[serde(default)]
+    charged_tx_fee: i64,
+    #[serde(default)]
+    max_fee: Option<String>,
+    #[serde(default)]
+    memo_base64: Option<String>,
+    #[serde(default)]
+    transaction_hash: Option<String>,
+    #[serde(default)]
+    transfers: Vec<TransferJson>,
+}
+
+#[derive(serde_derive::Deserialize)]
+pub(super) struct TransactionsPage {
+    transactions: Vec<TransactionJson>,
+    #[serde(default)]
+    links: Option<Links>,
+}
+
+impl MirrorRestPage for TransactionsPage {
+    type Item = MirrorTransaction;
+
+    fn next_link(&self) -> Option<&str> {
+        self.links.as_ref().and_then(Links::next)
+    }
+
+    fn into_items(self) -> crate::Result<Vec<Self::Item>> {
+        self.transactions.into_iter().map(MirrorTransaction::from_json).collect()
+    }
+}
+
+impl MirrorTransaction {
+    fn from_json(json: TransactionJson) -> crate::Result<Self> {
+        let transaction_id = TransactionId {
+            nonce: (json.nonce != 0).then_some(json.nonce),
+            scheduled: json.scheduled,
+            ..parse_transaction_id(&json.transaction_id)?
+        };
+
+        let max_fee = json
+            .max_fee
+            .as_deref()
+            .map(str::parse::<i64>)
+            .transpose()
+            .map_err(Error::basic_parse)?
+            .unwrap_or_default();
+
+        let transfers = json
+            .transfers
+            .into_iter()
+            .map(|it| {
+                Ok(MirrorTransfer {
+                    account_id: it.account.parse()?,
+                    amount: Hbar::from_tinybars(it.amount),
+                    is_approval: it.is_approval,
+                })
+            })
+            .collect::<crate::Result<_>>()?;
+
+        Ok(Self {
+            transaction_id,
+            consensus_timestamp: parse_timestamp(&json.consensus_timestamp)?,
+            name: json.name,
+            result: json.result,
+            node_account_id: json.node.as_deref().map(str::parse).transpose()?,
+            charged_tx_fee: Hbar::from_tinybars(json.charged_tx_fee),
+            max_fee: Hbar::from_tinybars(max_fee),
+            memo: json.memo_base64.as_deref().map(decode_base64).transpose()?.unwrap_or_default(),
+            transaction_hash: json
+                .transaction_hash
+                .as_deref()
+                .map(decode_base64)
+                .transpose()?
+                .unwrap_or_default(),
+            transfers,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::TryStreamExt;
+    use time::OffsetDateTime;
+
+    use crate::mirror_rest::test_helpers::serve;
+    use crate::{
+        AccountId,
+        Hbar,
+        MirrorRestClient,
+        MirrorTransfer,
+        TransactionId,
+    };
+
+    const TRANSACTION: &str = r#"{
+        "charged_tx_fee": 7,
+        "consensus_timestamp": "1554158542.000000002",
+        "entity_id": null,
+        "max_fee": "100000000",
+        "memo_base64": "aGVsbG8=",
+        "name": "CRYPTOTRANSFER",
+        "node": "0.0.3",
+        "nonce": 0,
+        "result": "SUCCESS",
+        "scheduled": false,
+        "transaction_hash": "AQI=",
+        "transaction_id": "0.0.5006-1554158542-000000001",
+        "transfers": [
+            { "account": "0.0.5006", "amount": -107, "is_approval": false },
+            { "account": "0.0.5007", "amount": 100, "is_approval": false },
+            { "account": "0.0.3", "amount": 7, "is_approval": false }
+        ],
+        "valid_duration_seconds": "120",
+        "valid_start_timestamp": "1554158542.000000001"
+    }"#;
+
+    fn test_transaction_id() -> TransactionId {
+        TransactionId {
+            account_id: AccountId::new(0, 0, 5006),
+            valid_start: OffsetDateTime::from_unix_timestamp_nanos(1_554_158_542_000_000_001)
+                .unwrap(),
+            nonce: None,
+            scheduled: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn account_transactions() {
+        let body = format!(r#"{{ "transactions": [{TRANSACTION}], "links": {{ "next": null }} }}"#);
+
+        let base_url = serve(&[("/api/v1/transactions?account.id=0.0.5006", &body)]).await;
+
+        let transactions: Vec<_> = MirrorRestClient::new(base_url)
+            .account_transactions(AccountId::new(0, 0, 5006))
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(transactions.len(), 1);
+
+        let transaction = &transactions[0];
+        assert_eq!(transaction.transaction_id, test_transaction_id());
+        assert_eq!(transaction.name, "CRYPTOTRANSFER");
+        assert_eq!(transaction.result, "SUCCESS");
+        assert_eq!(transaction.node_account_id, Some(AccountId::new(0, 0, 3)));
+        assert_eq!(transaction.charged_tx_fee, Hbar::from_tinybars(7));
+        assert_eq!(transaction.max_fee, Hbar::new(1));
+        assert_eq!(transaction.memo, b"hello");
+        assert_eq!(transaction.transaction_hash, [1, 2]);
+        assert_eq!(
+            transaction.transfers[1],
+            MirrorTransfer {
+                account_id: AccountId::new(0, 0, 5007),
+                amount: Hbar::from_tinybars(100),
+                is_approval: false,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn transaction() {
+        let body = format!(r#"{{ "transactions": [{TRANSACTION}] }}"#);
+
+        let base_url =
+            serve(&[("/api/v1/transactions/0.0.5006-1554158542-000000001", &body)]).await;
+
+        let transactions =
+            MirrorRestClient::new(base_url).transaction(test_transaction_id()).await.unwrap();
+
+        assert_eq!(transactions.len(), 1);
+        assert_eq!(transactions[0].transaction_id, test_transaction_id());
+    }
+}
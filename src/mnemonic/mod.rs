@@ -205,6 +205,38 @@ impl Mnemonic {
         }
     }
 
+    /// Recover an ECDSA(secp256k1) [`PrivateKey`] from this `Mnemonic`,
+    /// using the standard `BIP-44` path for Ethereum (`m/44'/60'/0'/0/index`).
+    ///
+    /// This matches the accounts of wallets such as MetaMask.
+    ///
+    /// # Errors
+    /// - [`Error::KeyDerive`] if this is a legacy mnemonic (which has no `BIP-39` seed).
+    /// - [`Error::KeyDerive`] if `index` is negative (hardened), or the derived key is invalid (which is astronomically unlikely).
+    pub fn to_standard_ecdsa_private_key(
+        &self,
+        passphrase: &str,
+        index: i32,
+    ) -> crate::Result<PrivateKey> {
+        const HARDENED: i32 = i32::MIN;
+
+        if self.is_legacy() {
+            return Err(Error::key_derive("legacy mnemonics don't support standard derivation"));
+        }
+
+        if index < 0 {
+            return Err(Error::key_derive("the address index must not be hardened"));
+        }
+
+        let mut key = PrivateKey::from_seed_ecdsa(&self.to_seed(passphrase))?;
+
+        for index in [44 | HARDENED, 60 | HARDENED, HARDENED, 0, index] {
+            key = key.derive(index)?;
+        }
+
+        Ok(key)
+    }
+
     pub(crate) fn to_seed(&self, phrase: &str) -> [u8; 64] {
         let mut salt = String::from("mnemonic");
         salt.push_str(phrase);
@@ -680,4 +712,44 @@ mod tests {
         "#]]
         .assert_debug_eq(key.debug_pretty());
     }
+
+    #[test]
+    fn to_standard_ecdsa_private_key() {
+        // the keys of these mnemonics are well known, such as from MetaMask and Hardhat.
+        let mnemonic = Mnemonic::from_str(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        )
+        .unwrap();
+
+        assert_eq!(
+            mnemonic.to_standard_ecdsa_private_key("", 0).unwrap().to_string_raw(),
+            "1ab42cc412b618bdea3a599e3c9bae199ebf030895b039e9db1e30dafb12b727"
+        );
+
+        assert_eq!(
+            mnemonic.to_standard_ecdsa_private_key("", 1).unwrap().to_string_raw(),
+            "9a983cb3d832fbde5ab49d692b7a8bf5b5d232479c99333d0fc8e1d21f1b55b6"
+        );
+
+        let mnemonic =
+            Mnemonic::from_str("test test test test test test test test test test test junk")
+                .unwrap();
+
+        assert_eq!(
+            mnemonic.to_standard_ecdsa_private_key("", 0).unwrap().to_string_raw(),
+            "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80"
+        );
+    }
+
+    #[test]
+    fn to_standard_ecdsa_private_key_legacy() {
+        const MNEMONIC: &str = concat!(
+            "jolly kidnap tom lawn drunk chick optic lust mutter mole bride ",
+            "galley dense member sage neural widow decide curb aboard margin manure"
+        );
+
+        let mnemonic = Mnemonic::from_str(MNEMONIC).unwrap();
+
+        assert_matches!(mnemonic.to_standard_ecdsa_private_key("", 0), Err(Error::KeyDerive(_)));
+    }
 }
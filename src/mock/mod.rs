@@ -0,0 +1,568 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+mod service;
+
+use std::collections::{
+    HashMap,
+    VecDeque,
+};
+use std::net::{
+    Ipv4Addr,
+    SocketAddr,
+};
+
+use parking_lot::Mutex;
+use time::{
+    Duration,
+    OffsetDateTime,
+};
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+use triomphe::Arc;
+
+use crate::{
+    AccountId,
+    Client,
+    Status,
+    TopicId,
+    TransactionReceipt,
+};
+
+/// A scripted reply for the next request a [`MockServer`] receives.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum MockReply {
+    /// Answer with the given pre-check status, for example [`Status::Busy`] or [`Status::TransactionExpired`].
+    PreCheck(Status),
+
+    /// Fail the request with the given gRPC status, for example [`tonic::Code::Unavailable`].
+    Grpc(tonic::Status),
+
+    /// Answer a [`TransactionReceiptQuery`](crate::TransactionReceiptQuery) with the given receipt.
+    ///
+    /// Transactions ignore this reply and pass pre-check.
+    Receipt(TransactionReceipt),
+
+    /// Answer a query with a protobuf-encoded `Response`.
+    ///
+    /// Transactions ignore this reply and pass pre-check.
+    Response(Vec<u8>),
+}
+
+/// A message a [`MockServer`] streams to topic subscribers.
+#[derive(Debug, Clone)]
+struct MockTopicMessage {
+    consensus_timestamp: OffsetDateTime,
+    sequence_number: u64,
+    contents: Vec<u8>,
+}
+
+#[derive(Default)]
+struct MockState {
+    transaction_replies: Mutex<VecDeque<MockReply>>,
+    query_replies: Mutex<VecDeque<MockReply>>,
+    topic_messages: Mutex<HashMap<TopicId, Vec<MockTopicMessage>>>,
+    requests: Mutex<Vec<String>>,
+}
+
+/// An in-process consensus node and mirror node, for testing without a Hedera network.
+///
+/// The server answers the gRPC services the SDK calls from replies scripted with
+/// [`push_transaction_reply`](Self::push_transaction_reply) and [`push_query_reply`](Self::push_query_reply),
+/// and streams topic messages scripted with [`push_topic_message`](Self::push_topic_message).
+///
+/// Without a scripted reply:
+/// - transactions pass pre-check.
+/// - receipt queries answer with a [`Status::Success`] receipt.
+/// - cost queries always answer with a cost of zero, and don't use a scripted reply.
+/// - any other query fails pre-check with [`Status::NotSupported`].
+///
+/// The server shuts down when dropped.
+pub struct MockServer {
+    address: SocketAddr,
+    state: Arc<MockState>,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl MockServer {
+    /// The account ID of the mock consensus node.
+    pub const NODE_ACCOUNT_ID: AccountId = AccountId::new(0, 0, 3);
+
+    /// Start a new mock server listening on a random port of `127.0.0.1`.
+    ///
+    /// # Errors
+    /// - If binding the listener fails.
+    pub async fn start() -> std::io::Result<Self> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+        let address = listener.local_addr()?;
+
+        let state = Arc::new(MockState::default());
+        let (shutdown, shutdown_rx) = oneshot::channel::<()>();
+
+        let router = service::router(&state);
+
+        let incoming = async_stream::stream! {
+            loop {
+                yield listener.accept().await.map(|(stream, _)| stream);
+            }
+        };
+
+        tokio::spawn(async move {
+            let shutdown = async {
+                // either a shutdown was requested or the server was dropped, both mean we should stop.
+                let _ = shutdown_rx.await;
+            };
+
+            if let Err(e) = router.serve_with_incoming_shutdown(incoming, shutdown).await {
+                log::error!("mock server failed: {e}");
+            }
+        });
+
+        Ok(Self { address, state, shutdown: Some(shutdown) })
+    }
+
+    /// Returns the address the server is listening on.
+    #[must_use]
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Returns a new client which uses this server as both its only node and its mirror node.
+    ///
+    /// The client has no operator.
+    ///
+    /// # Panics
+    /// - If the server's address can't be parsed as a node address (it always can).
+    #[must_use]
+    pub fn client(&self) -> Client {
+        let network = HashMap::from([(self.address.to_string(), Self::NODE_ACCOUNT_ID)]);
+
+        let client = Client::for_network(network)
+            .expect("a socket address should always be a valid node address");
+
+        client.set_mirror_network([self.address.to_string()]);
+
+        client
+    }
+
+    /// Queue `reply` for the next transaction the server receives.
+    pub fn push_transaction_reply(&self, reply: MockReply) {
+        self.state.transaction_replies.lock().push_back(reply);
+    }
+
+    /// Queue `reply` for the next query the server receives (other than cost queries).
+    pub fn push_query_reply(&self, reply: MockReply) {
+        self.state.query_replies.lock().push_back(reply);
+    }
+
+    /// Add a message with `contents` to `topic_id`, which is streamed to every subscriber of the topic.
+    ///
+    /// Messages are given increasing sequence numbers and consensus timestamps,
+    /// subscriptions end after every matching message has been streamed.
+    pub fn push_topic_message(&self, topic_id: TopicId, contents: impl Into<Vec<u8>>) {
+        let mut topic_messages = self.state.topic_messages.lock();
+        let messages = topic_messages.entry(topic_id).or_default();
+
+        let now = OffsetDateTime::now_utc();
+
+        let consensus_timestamp = match messages.last() {
+            Some(last) if last.consensus_timestamp >= now => {
+                last.consensus_timestamp + Duration::nanoseconds(1)
+            }
+            _ => now,
+        };
+
+        let sequence_number = messages.len() as u64 + 1;
+
+        messages.push(MockTopicMessage {
+            consensus_timestamp,
+            sequence_number,
+            contents: contents.into(),
+        });
+    }
+
+    /// Returns the path of every gRPC request the server has received, in order.
+    ///
+    /// For example `/proto.CryptoService/cryptoTransfer`.
+    #[must_use]
+    pub fn requests(&self) -> Vec<String> {
+        self.state.requests.lock().clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::MockServer;
+    use crate::{
+        AccountId,
+        Client,
+        Hbar,
+        PrivateKey,
+        TransferTransaction,
+    };
+
+    pub(crate) const TRANSFER: &str = "/proto.CryptoService/cryptoTransfer";
+
+    /// Returns a client for `server`, with an operator (`0.0.1001`) that can pay for transactions.
+    pub(crate) fn client(server: &MockServer) -> Client {
+        let client = server.client();
+        client.set_operator(AccountId::new(0, 0, 1001), PrivateKey::generate_ed25519());
+
+        client
+    }
+
+    /// Returns a transfer of 1 hbar from the operator of [`client`] to `0.0.1002`.
+    pub(crate) fn make_transfer() -> TransferTransaction {
+        let mut tx = TransferTransaction::new();
+
+        tx.hbar_transfer(AccountId::new(0, 0, 1001), Hbar::new(-1))
+            .hbar_transfer(AccountId::new(0, 0, 1002), Hbar::new(1));
+
+        tx
+    }
+
+    /// Returns the number of transfers `server` has received, including retries.
+    pub(crate) fn transfer_count(server: &MockServer) -> usize {
+        server.requests().iter().filter(|it| *it == TRANSFER).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use futures_util::StreamExt;
+    use hedera_proto::services;
+    use prost::Message;
+
+    use super::test_helpers::{
+        client,
+        make_transfer,
+        transfer_count,
+    };
+    use super::{
+        MockReply,
+        MockServer,
+    };
+    use crate::protobuf::ToProtobuf;
+    use crate::{
+        AccountBalanceQuery,
+        AccountId,
+        Error,
+        MemoryCheckpointStore,
+        PrivateKey,
+        Status,
+        TopicId,
+        TopicMessageCheckpointStore,
+        TopicMessageEnvelope,
+        TopicMessageQuery,
+        TransactionReceipt,
+    };
+
+    fn make_receipt(status: Status, account_id: Option<AccountId>) -> TransactionReceipt {
+        let receipt = services::TransactionReceipt {
+            status: status as i32,
+            account_id: account_id.to_protobuf(),
+            ..Default::default()
+        };
+
+        TransactionReceipt::from_bytes(&receipt.encode_to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn transaction_retries_busy() {
+        let server = MockServer::start().await.unwrap();
+        let client = client(&server);
+
+        server.push_transaction_reply(MockReply::PreCheck(Status::Busy));
+
+        let response = make_transfer().execute(&client).await.unwrap();
+
+        assert_eq!(response.node_account_id, MockServer::NODE_ACCOUNT_ID);
+        assert_eq!(transfer_count(&server), 2);
+    }
+
+    #[tokio::test]
+    async fn transaction_pre_check_failure() {
+        let server = MockServer::start().await.unwrap();
+        let client = client(&server);
+
+        server.push_transaction_reply(MockReply::PreCheck(Status::InsufficientPayerBalance));
+
+        let res = make_transfer().execute(&client).await;
+
+        assert_matches!(
+            res,
+            Err(Error::TransactionPreCheckStatus { status: Status::InsufficientPayerBalance, .. })
+        );
+        assert_eq!(transfer_count(&server), 1);
+    }
+
+    #[tokio::test]
+    async fn scripted_receipt() {
+        let server = MockServer::start().await.unwrap();
+        let client = client(&server);
+
+        let receipt = make_receipt(Status::Success, Some(AccountId::new(0, 0, 5005)));
+
+        server.push_query_reply(MockReply::Receipt(receipt));
+
+        let receipt = make_transfer().execute(&client).await.unwrap().get_receipt(&client).await;
+
+        assert_eq!(receipt.unwrap().account_id, Some(AccountId::new(0, 0, 5005)));
+    }
+
+    #[tokio::test]
+    async fn failed_receipt() {
+        let server = MockServer::start().await.unwrap();
+        let client = client(&server);
+
+        server.push_query_reply(MockReply::Receipt(make_receipt(Status::InvalidAccountId, None)));
+
+        let res = make_transfer().execute(&client).await.unwrap().get_receipt(&client).await;
+
+        assert_matches!(res, Err(Error::ReceiptStatus { status: Status::InvalidAccountId, .. }));
+    }
+
+    #[tokio::test]
+    async fn unscripted_query_is_not_supported() {
+        let server = MockServer::start().await.unwrap();
+        let client = client(&server);
+
+        let res = AccountBalanceQuery::new()
+            .account_id(AccountId::new(0, 0, 1001))
+            .execute(&client)
+            .await;
+
+        assert_matches!(
+            res,
+            Err(Error::QueryNoPaymentPreCheckStatus { status: Status::NotSupported })
+        );
+    }
+
+    #[tokio::test]
+    async fn topic_messages() {
+        let server = MockServer::start().await.unwrap();
+        let client = server.client();
+
+        let topic_id = TopicId::new(0, 0, 6006);
+
+        server.push_topic_message(topic_id, "hello");
+        server.push_topic_message(topic_id, "world");
+        server.push_topic_message(TopicId::new(0, 0, 6007), "other");
+
+        let messages = TopicMessageQuery::new().topic_id(topic_id).execute(&client).await.unwrap();
+
+        let contents: Vec<_> = messages.iter().map(|it| it.contents.as_slice()).collect();
+        let sequence_numbers: Vec<_> = messages.iter().map(|it| it.sequence_number).collect();
+
+        assert_eq!(contents, [b"hello".as_slice(), b"world"]);
+        assert_eq!(sequence_numbers, [1, 2]);
+    }
+
+    #[tokio::test]
+    async fn topic_messages_resume_from_checkpoint() {
+        let server = MockServer::start().await.unwrap();
+        let client = server.client();
+
+        let topic_id = TopicId::new(0, 0, 6006);
+
+        server.push_topic_message(topic_id, "hello");
+        server.push_topic_message(topic_id, "there");
+        server.push_topic_message(topic_id, "world");
+
+        let store = MemoryCheckpointStore::new();
+
+        let mut query = TopicMessageQuery::new();
+        query.topic_id(topic_id).checkpoint_store(store.clone());
+
+        {
+            let mut stream = query.subscribe(&client);
+
+            let first = stream.next().await.unwrap().unwrap();
+            let second = stream.next().await.unwrap().unwrap();
+
+            assert_eq!(first.contents, b"hello");
+            assert_eq!(second.contents, b"there");
+        }
+
+        // the second message was never handled, since the next message wasn't requested.
+        assert_eq!(store.checkpoint().unwrap().sequence_number, 1);
+
+        let messages = query.execute(&client).await.unwrap();
+
+        let contents: Vec<_> = messages.iter().map(|it| it.contents.as_slice()).collect();
+
+        assert_eq!(contents, [b"there".as_slice(), b"world"]);
+        assert_eq!(store.checkpoint().unwrap().sequence_number, 3);
+    }
+
+    #[tokio::test]
+    async fn topic_messages_manual_acknowledge() {
+        let server = MockServer::start().await.unwrap();
+        let client = server.client();
+
+        let topic_id = TopicId::new(0, 0, 6006);
+
+        server.push_topic_message(topic_id, "hello");
+        server.push_topic_message(topic_id, "there");
+        server.push_topic_message(topic_id, "world");
+
+        let store = MemoryCheckpointStore::new();
+
+        let mut query = TopicMessageQuery::new();
+        query.topic_id(topic_id).checkpoint_store(store.clone()).manual_acknowledge(true);
+
+        {
+            let mut stream = query.subscribe(&client);
+
+            for expected in [b"hello", b"there"] {
+                let message = stream.next().await.unwrap().unwrap();
+
+                assert_eq!(message.contents, expected);
+
+                store.save(message.checkpoint.as_ref().unwrap()).await.unwrap();
+            }
+        }
+
+        // both messages were acknowledged, even though the next one was never requested.
+        assert_eq!(store.checkpoint().unwrap().sequence_number, 2);
+
+        let messages = query.execute(&client).await.unwrap();
+
+        let contents: Vec<_> = messages.iter().map(|it| it.contents.as_slice()).collect();
+
+        assert_eq!(contents, [b"world".as_slice()]);
+
+        // nothing is saved unless it's acknowledged.
+        assert_eq!(store.checkpoint().unwrap().sequence_number, 2);
+    }
+
+    #[tokio::test]
+    async fn topic_messages_decrypted() {
+        let server = MockServer::start().await.unwrap();
+        let client = server.client();
+
+        let topic_id = TopicId::new(0, 0, 6006);
+        let key = PrivateKey::generate_ecdsa();
+
+        let envelope = TopicMessageEnvelope::seal(b"secret", &[key.public_key()]).unwrap();
+
+        server.push_topic_message(topic_id, "hello");
+        server.push_topic_message(topic_id, envelope.to_bytes());
+
+        let messages = TopicMessageQuery::new()
+            .topic_id(topic_id)
+            .decryption_key(key)
+            .execute(&client)
+            .await
+            .unwrap();
+
+        let contents: Vec<_> = messages.iter().map(|it| it.contents.as_slice()).collect();
+
+        assert_eq!(contents, [b"hello".as_slice(), b"secret"]);
+    }
+
+    #[tokio::test]
+    async fn topic_messages_for_other_recipients() {
+        let server = MockServer::start().await.unwrap();
+        let client = server.client();
+
+        let topic_id = TopicId::new(0, 0, 6006);
+        let key = PrivateKey::generate_ecdsa();
+        let other_key = PrivateKey::generate_ecdsa();
+
+        let envelope = TopicMessageEnvelope::seal(b"secret", &[key.public_key()]).unwrap();
+        let other_envelope =
+            TopicMessageEnvelope::seal(b"other secret", &[other_key.public_key()]).unwrap();
+
+        server.push_topic_message(topic_id, other_envelope.to_bytes());
+        server.push_topic_message(topic_id, envelope.to_bytes());
+
+        let messages = TopicMessageQuery::new()
+            .topic_id(topic_id)
+            .decryption_key(key)
+            .execute(&client)
+            .await
+            .unwrap();
+
+        let contents: Vec<_> = messages.iter().map(|it| it.contents.as_slice()).collect();
+
+        // the message for the other key is still encrypted, and doesn't end the subscription.
+        assert_eq!(contents, [other_envelope.to_bytes().as_slice(), b"secret"]);
+    }
+
+    #[tokio::test]
+    async fn topic_messages_that_look_encrypted() {
+        let server = MockServer::start().await.unwrap();
+        let client = server.client();
+
+        let topic_id = TopicId::new(0, 0, 6006);
+
+        server.push_topic_message(topic_id, b"HCSE\x01 isn't an envelope".as_slice());
+
+        let messages = TopicMessageQuery::new()
+            .topic_id(topic_id)
+            .decryption_key(PrivateKey::generate_ecdsa())
+            .execute(&client)
+            .await
+            .unwrap();
+
+        assert_eq!(messages[0].contents, b"HCSE\x01 isn't an envelope");
+    }
+
+    #[tokio::test]
+    async fn topic_messages_tampered_keeps_checkpoint() {
+        let server = MockServer::start().await.unwrap();
+        let client = server.client();
+
+        let topic_id = TopicId::new(0, 0, 6006);
+        let key = PrivateKey::generate_ecdsa();
+
+        let mut tampered =
+            TopicMessageEnvelope::seal(b"secret", &[key.public_key()]).unwrap().to_bytes();
+        *tampered.last_mut().unwrap() ^= 1;
+
+        server.push_topic_message(topic_id, "hello");
+        server.push_topic_message(topic_id, tampered);
+
+        let store = MemoryCheckpointStore::new();
+
+        let mut query = TopicMessageQuery::new();
+        query.topic_id(topic_id).checkpoint_store(store.clone()).decryption_key(key);
+
+        {
+            let mut stream = query.subscribe(&client);
+
+            assert_eq!(stream.next().await.unwrap().unwrap().contents, b"hello");
+            assert_matches!(stream.next().await.unwrap(), Err(Error::TopicMessageEnvelope(_)));
+        }
+
+        // the tampered message is delivered again on resume.
+        assert_eq!(store.checkpoint().unwrap().sequence_number, 1);
+    }
+}
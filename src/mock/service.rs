@@ -0,0 +1,370 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+use std::convert::Infallible;
+use std::marker::PhantomData;
+use std::task::{
+    Context,
+    Poll,
+};
+
+use futures_core::Stream;
+use hedera_proto::{
+    mirror,
+    services,
+};
+use prost::Message;
+use time::OffsetDateTime;
+use tonic::body::BoxBody;
+use tonic::codec::ProstCodec;
+use tonic::codegen::{
+    http,
+    Body,
+    BoxFuture,
+    StdError,
+};
+use tonic::server::{
+    Grpc,
+    NamedService,
+};
+use tonic::transport::server::Router;
+use tonic::transport::Server;
+use tower::{
+    service_fn,
+    Service,
+};
+use triomphe::Arc;
+
+use super::{
+    MockReply,
+    MockState,
+};
+use crate::protobuf::{
+    FromProtobuf,
+    ToProtobuf,
+};
+use crate::{
+    Status,
+    TopicId,
+};
+
+/// Running hashes of mock topic messages are all zeroes, this is the length of a real one.
+const RUNNING_HASH_LEN: usize = 48;
+
+const RUNNING_HASH_VERSION: u64 = 3;
+
+/// A gRPC service which answers every method of the service named `N` from the shared [`MockState`].
+///
+/// Rather than implementing every generated server trait, methods are routed by name,
+/// so methods added to the protobufs are answered without changes here.
+pub(super) struct MockService<N> {
+    state: Arc<MockState>,
+    _name: PhantomData<fn() -> N>,
+}
+
+impl<N> MockService<N> {
+    fn new(state: &Arc<MockState>) -> Self {
+        Self { state: Arc::clone(state), _name: PhantomData }
+    }
+}
+
+// a derive would require `N: Clone`.
+impl<N> Clone for MockService<N> {
+    fn clone(&self) -> Self {
+        Self { state: Arc::clone(&self.state), _name: PhantomData }
+    }
+}
+
+macro_rules! named_services {
+    ($($name:ident => $service:literal),* $(,)?) => {
+        $(
+            pub(super) enum $name {}
+
+            impl NamedService for MockService<$name> {
+                const NAME: &'static str = $service;
+            }
+        )*
+
+        pub(super) fn router(state: &Arc<MockState>) -> Router {
+            Server::builder()
+                $(.add_service(MockService::<$name>::new(state)))*
+        }
+    };
+}
+
+named_services! {
+    Crypto => "proto.CryptoService",
+    SmartContract => "proto.SmartContractService",
+    File => "proto.FileService",
+    Consensus => "proto.ConsensusService",
+    Token => "proto.TokenService",
+    Schedule => "proto.ScheduleService",
+    Freeze => "proto.FreezeService",
+    Network => "proto.NetworkService",
+    Util => "proto.UtilService",
+    AddressBook => "proto.AddressBookService",
+    MirrorConsensus => "com.hedera.mirror.api.proto.ConsensusService",
+}
+
+impl<N, B> Service<http::Request<B>> for MockService<N>
+where
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let state = Arc::clone(&self.state);
+        let path = request.uri().path().to_owned();
+
+        state.requests.lock().push(path.clone());
+
+        Box::pin(async move {
+            let (service, method) =
+                path.trim_start_matches('/').split_once('/').unwrap_or_default();
+
+            let response = if service == <MockService<MirrorConsensus> as NamedService>::NAME {
+                match method {
+                    "subscribeTopic" => {
+                        let mut grpc = Grpc::new(ProstCodec::<
+                            mirror::ConsensusTopicResponse,
+                            mirror::ConsensusTopicQuery,
+                        >::default());
+                        let handler = service_fn(move |request| {
+                            std::future::ready(subscribe_topic(&state, request))
+                        });
+
+                        grpc.server_streaming(handler, request).await
+                    }
+
+                    _ => {
+                        tonic::Status::unimplemented(format!("unknown method `{method}`")).to_http()
+                    }
+                }
+            } else if is_query(method) {
+                let mut grpc =
+                    Grpc::new(ProstCodec::<services::Response, services::Query>::default());
+                let handler =
+                    service_fn(move |request| std::future::ready(answer_query(&state, request)));
+
+                grpc.unary(handler, request).await
+            } else {
+                let mut grpc = Grpc::new(ProstCodec::<
+                    services::TransactionResponse,
+                    services::Transaction,
+                >::default());
+                let handler = service_fn(move |request| {
+                    std::future::ready(answer_transaction(&state, request))
+                });
+
+                grpc.unary(handler, request).await
+            };
+
+            Ok(response)
+        })
+    }
+}
+
+/// Returns `true` if `method` answers a `Query` rather than submitting a `Transaction`.
+fn is_query(method: &str) -> bool {
+    // every query method starts with `get`, other than a few oddly named exceptions.
+    method.starts_with("get")
+        || matches!(method, "cryptoGetBalance" | "contractCallLocalMethod" | "ContractGetBytecode")
+}
+
+fn answer_transaction(
+    state: &MockState,
+    _request: tonic::Request<services::Transaction>,
+) -> Result<tonic::Response<services::TransactionResponse>, tonic::Status> {
+    let status = match state.transaction_replies.lock().pop_front() {
+        Some(MockReply::PreCheck(status)) => status,
+        Some(MockReply::Grpc(status)) => return Err(status),
+        Some(MockReply::Receipt(_) | MockReply::Response(_)) | None => Status::Ok,
+    };
+
+    Ok(tonic::Response::new(services::TransactionResponse {
+        node_transaction_precheck_code: status as i32,
+        cost: 0,
+    }))
+}
+
+fn answer_query(
+    state: &MockState,
+    request: tonic::Request<services::Query>,
+) -> Result<tonic::Response<services::Response>, tonic::Status> {
+    let query = request
+        .into_inner()
+        .query
+        .ok_or_else(|| tonic::Status::invalid_argument("unexpected missing `query`"))?;
+
+    let Some(header) = query_header(&query) else {
+        return Err(tonic::Status::unimplemented("the mock server can't answer this query"));
+    };
+
+    // cost queries are always free, so that paid queries don't need a reply scripted for their cost.
+    if header.response_type == services::ResponseType::CostAnswer as i32 {
+        return Ok(tonic::Response::new(response_with_header(&query, Status::Ok)));
+    }
+
+    let is_receipt = matches!(query, services::query::Query::TransactionGetReceipt(_));
+
+    let response = match state.query_replies.lock().pop_front() {
+        Some(MockReply::PreCheck(status)) => response_with_header(&query, status),
+        Some(MockReply::Grpc(status)) => return Err(status),
+        Some(MockReply::Receipt(receipt)) => receipt_response(receipt.to_protobuf()),
+        Some(MockReply::Response(bytes)) => services::Response::decode(bytes.as_slice())
+            .map_err(|it| tonic::Status::internal(it.to_string()))?,
+        None if is_receipt => receipt_response(services::TransactionReceipt {
+            status: Status::Success as i32,
+            ..Default::default()
+        }),
+        None => response_with_header(&query, Status::NotSupported),
+    };
+
+    Ok(tonic::Response::new(response))
+}
+
+fn ok_header() -> services::ResponseHeader {
+    services::ResponseHeader {
+        node_transaction_precheck_code: Status::Ok as i32,
+        ..Default::default()
+    }
+}
+
+fn receipt_response(receipt: services::TransactionReceipt) -> services::Response {
+    services::Response {
+        response: Some(services::response::Response::TransactionGetReceipt(
+            services::TransactionGetReceiptResponse {
+                header: Some(ok_header()),
+                receipt: Some(receipt),
+                ..Default::default()
+            },
+        )),
+    }
+}
+
+macro_rules! answerable_queries {
+    ($($query:ident => $response:ident($ty:ident)),* $(,)?) => {
+        /// Returns the header of `query`, or `None` if the mock server can't answer it.
+        fn query_header(query: &services::query::Query) -> Option<&services::QueryHeader> {
+            use services::query::Query;
+
+            match query {
+                $(Query::$query(it) => it.header.as_ref(),)*
+                _ => None,
+            }
+        }
+
+        /// Returns an otherwise empty response to `query` with the given pre-check `status`.
+        fn response_with_header(
+            query: &services::query::Query,
+            status: Status,
+        ) -> services::Response {
+            use services::query::Query;
+            use services::response::Response;
+
+            let header = Some(services::ResponseHeader {
+                node_transaction_precheck_code: status as i32,
+                ..Default::default()
+            });
+
+            let response = match query {
+                $(Query::$query(_) => Response::$response(services::$ty { header, ..Default::default() }),)*
+                // `query_header` already filtered out any query that isn't listed.
+                _ => unreachable!(),
+            };
+
+            services::Response { response: Some(response) }
+        }
+    };
+}
+
+answerable_queries! {
+    CryptogetAccountBalance => CryptogetAccountBalance(CryptoGetAccountBalanceResponse),
+    CryptoGetAccountRecords => CryptoGetAccountRecords(CryptoGetAccountRecordsResponse),
+    CryptoGetInfo => CryptoGetInfo(CryptoGetInfoResponse),
+    CryptoGetProxyStakers => CryptoGetProxyStakers(CryptoGetStakersResponse),
+    ContractCallLocal => ContractCallLocal(ContractCallLocalResponse),
+    ContractGetInfo => ContractGetInfo(ContractGetInfoResponse),
+    ContractGetBytecode => ContractGetBytecodeResponse(ContractGetBytecodeResponse),
+    FileGetContents => FileGetContents(FileGetContentsResponse),
+    FileGetInfo => FileGetInfo(FileGetInfoResponse),
+    TransactionGetReceipt => TransactionGetReceipt(TransactionGetReceiptResponse),
+    TransactionGetRecord => TransactionGetRecord(TransactionGetRecordResponse),
+    ConsensusGetTopicInfo => ConsensusGetTopicInfo(ConsensusGetTopicInfoResponse),
+    NetworkGetVersionInfo => NetworkGetVersionInfo(NetworkGetVersionInfoResponse),
+    TokenGetInfo => TokenGetInfo(TokenGetInfoResponse),
+    TokenGetNftInfo => TokenGetNftInfo(TokenGetNftInfoResponse),
+    ScheduleGetInfo => ScheduleGetInfo(ScheduleGetInfoResponse),
+}
+
+fn subscribe_topic(
+    state: &MockState,
+    request: tonic::Request<mirror::ConsensusTopicQuery>,
+) -> Result<
+    tonic::Response<impl Stream<Item = Result<mirror::ConsensusTopicResponse, tonic::Status>>>,
+    tonic::Status,
+> {
+    let request = request.into_inner();
+
+    let topic_id = request
+        .topic_id
+        .ok_or_else(|| tonic::Status::invalid_argument("unexpected missing `topic_id`"))?;
+
+    let topic_id = TopicId::from_protobuf(topic_id)
+        .map_err(|it| tonic::Status::invalid_argument(it.to_string()))?;
+
+    let start_time = request.consensus_start_time.map(OffsetDateTime::from);
+    let end_time = request.consensus_end_time.map(OffsetDateTime::from);
+
+    let limit = match request.limit {
+        0 => usize::MAX,
+        limit => usize::try_from(limit).unwrap_or(usize::MAX),
+    };
+
+    let messages: Vec<_> = state
+        .topic_messages
+        .lock()
+        .get(&topic_id)
+        .into_iter()
+        .flatten()
+        .filter(|it| start_time.map_or(true, |start| it.consensus_timestamp >= start))
+        .filter(|it| end_time.map_or(true, |end| it.consensus_timestamp < end))
+        .take(limit)
+        .map(|it| {
+            Ok(mirror::ConsensusTopicResponse {
+                consensus_timestamp: Some(it.consensus_timestamp.into()),
+                message: it.contents.clone(),
+                running_hash: vec![0; RUNNING_HASH_LEN],
+                sequence_number: it.sequence_number,
+                running_hash_version: RUNNING_HASH_VERSION,
+                chunk_info: None,
+            })
+        })
+        .collect();
+
+    // the stream ends after the scripted messages, which completes the subscription.
+    Ok(tonic::Response::new(futures_util::stream::iter(messages)))
+}
@@ -27,6 +27,7 @@ use crate::{
     AccountId,
     Error,
     FromProtobuf,
+    Key,
 };
 
 fn parse_socket_addr_v4(ip: Vec<u8>, port: i32) -> crate::Result<SocketAddrV4> {
@@ -44,6 +45,20 @@ fn parse_socket_addr_v4(ip: Vec<u8>, port: i32) -> crate::Result<SocketAddrV4> {
     Ok(SocketAddrV4::new(octets.into(), port))
 }
 
+pub(crate) fn service_endpoint_from_protobuf(
+    pb: services::ServiceEndpoint,
+) -> crate::Result<SocketAddrV4> {
+    parse_socket_addr_v4(pb.ip_address_v4, pb.port)
+}
+
+pub(crate) fn service_endpoint_to_protobuf(address: &SocketAddrV4) -> services::ServiceEndpoint {
+    services::ServiceEndpoint {
+        ip_address_v4: address.ip().octets().to_vec(),
+        port: i32::from(address.port()),
+        domain_name: String::new(),
+    }
+}
+
 /// The data about a node, including its service endpoints and the Hedera account to be paid for
 /// services provided by the node (that is, queries answered and transactions submitted.).
 #[derive(Debug, Clone)]
@@ -71,6 +86,24 @@ pub struct NodeAddress {
 
     /// A description of the node, up to 100 bytes.
     pub description: String,
+
+    /// A node's gossip IP addresses and ports, used for communication between consensus nodes.
+    ///
+    /// This isn't part of the address book published by the network, so it will be empty
+    /// for addresses read from it.
+    pub gossip_endpoints: Vec<SocketAddrV4>,
+
+    /// The SHA-384 hash of the node's gRPC TLS certificate.
+    ///
+    /// This isn't part of the address book published by the network, so it will be empty
+    /// for addresses read from it.
+    pub grpc_certificate_hash: Vec<u8>,
+
+    /// The key that must sign updates to the node (and its deletion).
+    ///
+    /// This isn't part of the address book published by the network, so it will be `None`
+    /// for addresses read from it.
+    pub admin_key: Option<Key>,
 }
 
 impl FromProtobuf<services::NodeAddress> for NodeAddress {
@@ -88,7 +121,7 @@ impl FromProtobuf<services::NodeAddress> for NodeAddress {
         }
 
         for address in pb.service_endpoint {
-            addresses.push(parse_socket_addr_v4(address.ip_address_v4, address.port)?);
+            addresses.push(service_endpoint_from_protobuf(address)?);
         }
 
         let node_account_id = AccountId::from_protobuf(pb_getf!(pb, node_account_id)?)?;
@@ -100,6 +133,9 @@ impl FromProtobuf<services::NodeAddress> for NodeAddress {
             service_endpoints: addresses,
             tls_certificate_hash: pb.node_cert_hash,
             node_account_id,
+            gossip_endpoints: Vec::new(),
+            grpc_certificate_hash: Vec::new(),
+            admin_key: None,
         })
     }
 }
@@ -108,14 +144,8 @@ impl ToProtobuf for NodeAddress {
     type Protobuf = services::NodeAddress;
 
     fn to_protobuf(&self) -> Self::Protobuf {
-        let service_endpoint = self
-            .service_endpoints
-            .iter()
-            .map(|it| services::ServiceEndpoint {
-                ip_address_v4: it.ip().octets().to_vec(),
-                port: i32::from(it.port()),
-            })
-            .collect();
+        let service_endpoint =
+            self.service_endpoints.iter().map(service_endpoint_to_protobuf).collect();
 
         services::NodeAddress {
             rsa_pub_key: hex::encode(&self.rsa_public_key),
@@ -74,11 +74,12 @@ impl Execute for PingQuery {
         false
     }
 
-    fn make_request(
-        &self,
-        _transaction_id: Option<&crate::TransactionId>,
+    fn make_request<'a>(
+        &'a self,
+        _transaction_id: Option<&'a crate::TransactionId>,
         node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
+    ) -> futures_core::future::BoxFuture<'a, crate::Result<(Self::GrpcRequest, Self::Context)>>
+    {
         const HEADER: services::QueryHeader = services::QueryHeader {
             payment: None,
             response_type: services::ResponseType::AnswerOnly as i32,
@@ -99,7 +100,7 @@ impl Execute for PingQuery {
             )),
         };
 
-        Ok((query, ()))
+        Box::pin(std::future::ready(Ok((query, ()))))
     }
 
     fn execute(
@@ -18,6 +18,7 @@
  * ‍
  */
 
+use futures_core::future::BoxFuture;
 use hedera_proto::services;
 use tonic::transport::Channel;
 
@@ -26,6 +27,7 @@ use crate::execute::{
     execute,
     Execute,
 };
+use crate::execute_policy::ExecutePolicy;
 use crate::query::execute::response_header;
 use crate::query::QueryExecute;
 use crate::{
@@ -77,21 +79,25 @@ where
         false
     }
 
+    fn execute_policy(&self) -> Option<&ExecutePolicy> {
+        Execute::execute_policy(self.0)
+    }
+
     fn operator_account_id(&self) -> Option<&AccountId> {
         None
     }
 
-    fn make_request(
-        &self,
-        _transaction_id: Option<&TransactionId>,
+    fn make_request<'b>(
+        &'b self,
+        _transaction_id: Option<&'b TransactionId>,
         _node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
+    ) -> BoxFuture<'b, crate::Result<(Self::GrpcRequest, Self::Context)>> {
         let header = services::QueryHeader {
             response_type: services::ResponseType::CostAnswer as i32,
             payment: None,
         };
 
-        Ok((self.0.data.to_query_protobuf(header), ()))
+        Box::pin(std::future::ready(Ok((self.0.data.to_query_protobuf(header), ()))))
     }
 
     fn execute(
@@ -20,11 +20,13 @@
 
 use std::fmt::Debug;
 
+use futures_core::future::BoxFuture;
 use hedera_proto::services;
 use tonic::transport::Channel;
 
 use crate::entity_id::ValidateChecksums;
 use crate::execute::Execute;
+use crate::execute_policy::ExecutePolicy;
 use crate::query::{
     AnyQueryData,
     ToQueryProtobuf,
@@ -116,6 +118,10 @@ where
         self.payment.operator_account_id()
     }
 
+    fn execute_policy(&self) -> Option<&ExecutePolicy> {
+        Some(&self.policy)
+    }
+
     fn should_retry_pre_check(&self, status: Status) -> bool {
         self.data.should_retry_pre_check(status)
     }
@@ -124,20 +130,22 @@ where
         self.data.should_retry(response)
     }
 
-    fn make_request(
-        &self,
-        transaction_id: Option<&TransactionId>,
+    fn make_request<'a>(
+        &'a self,
+        transaction_id: Option<&'a TransactionId>,
         node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
-        let payment = if self.data.is_payment_required() {
-            Some(self.payment.make_request(transaction_id, node_account_id)?.0)
-        } else {
-            None
-        };
-
-        let header = services::QueryHeader { response_type: 0, payment };
-
-        Ok((self.data.to_query_protobuf(header), ()))
+    ) -> BoxFuture<'a, crate::Result<(Self::GrpcRequest, Self::Context)>> {
+        Box::pin(async move {
+            let payment = if self.data.is_payment_required() {
+                Some(self.payment.make_request(transaction_id, node_account_id).await?.0)
+            } else {
+                None
+            };
+
+            let header = services::QueryHeader { response_type: 0, payment };
+
+            Ok((self.data.to_query_protobuf(header), ()))
+        })
     }
 
     fn execute(
@@ -22,6 +22,7 @@ use futures_core::future::BoxFuture;
 use time::Duration;
 
 use crate::execute::execute;
+use crate::execute_policy::ExecutePolicy;
 use crate::query::cost::QueryCost;
 use crate::query::payment_transaction::PaymentTransaction;
 use crate::{
@@ -29,6 +30,8 @@ use crate::{
     Client,
     Error,
     Hbar,
+    NodeSelector,
+    RetryPolicy,
     TransactionId,
     TransactionReceiptQuery,
 };
@@ -58,6 +61,7 @@ where
 {
     pub(crate) data: D,
     pub(crate) payment: PaymentTransaction,
+    policy: ExecutePolicy,
 }
 
 impl<D> Query<D>
@@ -195,6 +199,34 @@ where
         self
     }
 
+    /// Returns the retry policy for this query, if one was set.
+    ///
+    /// When `None`, the client's [retry policy](Client::set_retry_policy) is used.
+    #[must_use]
+    pub fn get_retry_policy(&self) -> Option<&dyn RetryPolicy> {
+        self.policy.retry_policy.as_deref()
+    }
+
+    /// Sets the retry policy for this query, overriding the client's.
+    pub fn retry_policy(&mut self, policy: impl RetryPolicy + 'static) -> &mut Self {
+        self.policy.retry_policy = Some(ExecutePolicy::arc_retry_policy(policy));
+        self
+    }
+
+    /// Returns the node selector for this query, if one was set.
+    ///
+    /// When `None`, the client's [node selector](Client::set_node_selector) is used.
+    #[must_use]
+    pub fn get_node_selector(&self) -> Option<&dyn NodeSelector> {
+        self.policy.node_selector.as_deref()
+    }
+
+    /// Sets the node selector for this query, overriding the client's.
+    pub fn node_selector(&mut self, selector: impl NodeSelector + 'static) -> &mut Self {
+        self.policy.node_selector = Some(ExecutePolicy::arc_node_selector(selector));
+        self
+    }
+
     /// Fetch the cost of this query.
     pub async fn get_cost(&self, client: &Client) -> crate::Result<Hbar> {
         self.get_cost_with_optional_timeout(client, None).await
@@ -0,0 +1,155 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::time::Duration;
+
+use crate::{
+    AccountId,
+    Status,
+};
+
+/// Details about a single attempt to send a request to a node.
+///
+/// See [`RequestHook`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct RequestAttempt {
+    /// The type of the request, for example `TransferTransaction` or `AccountBalanceQuery`.
+    pub request_type: &'static str,
+
+    /// The node the request was sent to.
+    pub node_account_id: AccountId,
+
+    /// The number of this attempt, starting at `1`.
+    ///
+    /// Every node tried counts as an attempt, across all retries of the request.
+    pub attempt: usize,
+
+    /// How long the node took to answer, or to fail.
+    pub latency: Duration,
+
+    /// The gRPC status of the call, [`tonic::Code::Ok`] if the node answered.
+    pub grpc_status: tonic::Code,
+
+    /// The pre-check status the node answered with, if it answered.
+    pub status: Option<Status>,
+}
+
+/// Observes every attempt to send a request to a node, for instance to record metrics.
+///
+/// Set with [`Client::set_request_hook`](crate::Client::set_request_hook).
+///
+/// Any `Fn(&RequestAttempt)` closure is a `RequestHook`.
+pub trait RequestHook: Send + Sync {
+    /// Called after every attempt to send a request to a node.
+    ///
+    /// This is called while the request is executing, so it should return quickly.
+    fn on_attempt(&self, attempt: &RequestAttempt);
+}
+
+impl<F> RequestHook for F
+where
+    F: Fn(&RequestAttempt) + Send + Sync,
+{
+    fn on_attempt(&self, attempt: &RequestAttempt) {
+        self(attempt);
+    }
+}
+
+/// Returns a short name for a request from its full type name.
+///
+/// Requests are generic over their data (`Transaction<TransferTransactionData>`),
+/// so the innermost type argument (without `Data`) is the interesting part.
+pub(crate) fn request_type_name(type_name: &'static str) -> &'static str {
+    let name = &type_name[type_name.rfind('<').map_or(0, |it| it + 1)..];
+    let name = &name[..name.find(['>', ',']).unwrap_or(name.len())];
+    let name = &name[name.rfind("::").map_or(0, |it| it + 2)..];
+
+    name.strip_suffix("Data").unwrap_or(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::type_name;
+
+    use super::request_type_name;
+    use crate::ping_query::PingQuery;
+    use crate::{
+        AccountBalanceQuery,
+        AnyTransaction,
+        TransferTransaction,
+    };
+
+    #[test]
+    fn request_type_names() {
+        assert_eq!(request_type_name(type_name::<TransferTransaction>()), "TransferTransaction");
+        assert_eq!(request_type_name(type_name::<AccountBalanceQuery>()), "AccountBalanceQuery");
+        assert_eq!(request_type_name(type_name::<AnyTransaction>()), "AnyTransaction");
+        assert_eq!(request_type_name(type_name::<PingQuery>()), "PingQuery");
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod mock_tests {
+    use parking_lot::Mutex;
+    use triomphe::Arc;
+
+    use super::RequestAttempt;
+    use crate::mock::test_helpers::{
+        client,
+        make_transfer,
+    };
+    use crate::{
+        MockReply,
+        MockServer,
+        Status,
+    };
+
+    #[tokio::test]
+    async fn request_hook() {
+        let server = MockServer::start().await.unwrap();
+        let client = client(&server);
+
+        let attempts = Arc::new(Mutex::new(Vec::new()));
+
+        client.set_request_hook({
+            let attempts = Arc::clone(&attempts);
+            move |attempt: &RequestAttempt| attempts.lock().push(attempt.clone())
+        });
+
+        server.push_transaction_reply(MockReply::PreCheck(Status::Busy));
+
+        make_transfer().execute(&client).await.unwrap();
+
+        let attempts = attempts.lock();
+
+        assert_eq!(attempts.len(), 2);
+
+        for (index, attempt) in attempts.iter().enumerate() {
+            assert_eq!(attempt.request_type, "TransferTransaction");
+            assert_eq!(attempt.node_account_id, MockServer::NODE_ACCOUNT_ID);
+            assert_eq!(attempt.attempt, index + 1);
+            assert_eq!(attempt.grpc_status, tonic::Code::Ok);
+        }
+
+        assert_eq!(attempts[0].status, Some(Status::Busy));
+        assert_eq!(attempts[1].status, Some(Status::Ok));
+    }
+}
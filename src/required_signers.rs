@@ -0,0 +1,622 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::future::Future;
+
+use hedera_proto::services;
+use prost::Message;
+
+use crate::transaction::TransactionExecute;
+use crate::{
+    AccountId,
+    AccountInfoQuery,
+    Client,
+    ContractId,
+    ContractInfoQuery,
+    Error,
+    FileId,
+    FileInfoQuery,
+    FromProtobuf,
+    Key,
+    KeyList,
+    KeySignatureReport,
+    ScheduleId,
+    ScheduleInfoQuery,
+    TokenId,
+    TokenInfoQuery,
+    TopicId,
+    TopicInfoQuery,
+    Transaction,
+};
+
+/// An entity whose key must sign a transaction.
+///
+/// See [`Transaction::required_signers`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RequiredSigner {
+    /// The key of an account, such as the payer or the sender of a transfer.
+    Account(AccountId),
+
+    /// A key set by the transaction itself, such as the new key in an `AccountUpdateTransaction`.
+    Key(Key),
+
+    /// The admin key of a token.
+    TokenAdmin(TokenId),
+
+    /// The KYC key of a token.
+    TokenKyc(TokenId),
+
+    /// The freeze key of a token.
+    TokenFreeze(TokenId),
+
+    /// The wipe key of a token.
+    TokenWipe(TokenId),
+
+    /// The supply key of a token.
+    TokenSupply(TokenId),
+
+    /// The fee schedule key of a token.
+    TokenFeeSchedule(TokenId),
+
+    /// The pause key of a token.
+    TokenPause(TokenId),
+
+    /// The metadata key of a token.
+    TokenMetadata(TokenId),
+
+    /// The admin key of a topic.
+    TopicAdmin(TopicId),
+
+    /// The submit key of a topic.
+    TopicSubmit(TopicId),
+
+    /// The keys of a file.
+    File(FileId),
+
+    /// The admin key of a contract.
+    ContractAdmin(ContractId),
+
+    /// The admin key of a schedule.
+    ScheduleAdmin(ScheduleId),
+
+    /// The admin key of a consensus node.
+    ///
+    /// A `NodeDeleteTransaction` can also be signed by the council instead.
+    NodeAdmin(u64),
+}
+
+impl RequiredSigner {
+    /// Fetch the key for this signer from the network.
+    ///
+    /// Returns `None` if the entity doesn't have that key (for instance, a topic without a submit key),
+    /// in which case no signature is needed for it.
+    ///
+    /// # Errors
+    /// - See [`AccountInfoQuery::execute`] (and the other info queries).
+    /// - [`Error::RequiredSignerKeyUnavailable`] for a [`NodeAdmin`](Self::NodeAdmin),
+    ///   since node admin keys aren't part of the published address book.
+    pub async fn fetch_key(self, client: &Client) -> crate::Result<Option<Key>> {
+        async fn token(id: TokenId, client: &Client) -> crate::Result<crate::TokenInfo> {
+            TokenInfoQuery::new().token_id(id).execute(client).await
+        }
+
+        async fn topic(id: TopicId, client: &Client) -> crate::Result<crate::TopicInfo> {
+            TopicInfoQuery::new().topic_id(id).execute(client).await
+        }
+
+        let key = match self {
+            Self::Account(id) => {
+                Some(AccountInfoQuery::new().account_id(id).execute(client).await?.key)
+            }
+            Self::Key(key) => Some(key),
+            Self::TokenAdmin(id) => token(id, client).await?.admin_key,
+            Self::TokenKyc(id) => token(id, client).await?.kyc_key,
+            Self::TokenFreeze(id) => token(id, client).await?.freeze_key,
+            Self::TokenWipe(id) => token(id, client).await?.wipe_key,
+            Self::TokenSupply(id) => token(id, client).await?.supply_key,
+            Self::TokenFeeSchedule(id) => token(id, client).await?.fee_schedule_key,
+            Self::TokenPause(id) => token(id, client).await?.pause_key,
+            Self::TokenMetadata(id) => token(id, client).await?.metadata_key,
+            Self::TopicAdmin(id) => topic(id, client).await?.admin_key,
+            Self::TopicSubmit(id) => topic(id, client).await?.submit_key,
+            Self::File(id) => {
+                Some(Key::KeyList(FileInfoQuery::new().file_id(id).execute(client).await?.keys))
+            }
+            Self::ContractAdmin(id) => {
+                ContractInfoQuery::new().contract_id(id).execute(client).await?.admin_key
+            }
+            Self::ScheduleAdmin(id) => {
+                ScheduleInfoQuery::new().schedule_id(id).execute(client).await?.admin_key
+            }
+            Self::NodeAdmin(_) => return Err(Error::RequiredSignerKeyUnavailable(Box::new(self))),
+        };
+
+        Ok(key)
+    }
+}
+
+/// How well the signatures on a transaction satisfy one of its [`RequiredSigner`]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequiredSignerReport {
+    /// The entity that must sign.
+    pub signer: RequiredSigner,
+
+    /// The signatures found for the entity's key.
+    pub report: KeySignatureReport,
+}
+
+impl<D: TransactionExecute> Transaction<D> {
+    /// Returns the entities whose keys must sign this transaction, starting with the payer.
+    ///
+    /// This is worked out from the transaction alone, so requirements that depend on network state,
+    /// such as an account that requires receiver signatures, aren't included.
+    ///
+    /// For the same reason, the signatories of a scheduled transaction aren't included:
+    /// a `ScheduleSignTransaction` only names the schedule, and the keys it should be signed with
+    /// depend on the scheduled transaction, which can be fetched with a [`ScheduleInfoQuery`].
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`] if the transaction body can't be decoded.
+    /// - If the transaction has no transaction ID, see [`Transaction::to_bytes`].
+    ///
+    /// # Panics
+    /// - If `!self.is_frozen()`.
+    pub fn required_signers(&self) -> crate::Result<Vec<RequiredSigner>> {
+        assert!(self.is_frozen(), "Transaction must be frozen to get its required signers");
+
+        let sources = self.make_sources()?;
+
+        let mut signers = Signers::default();
+
+        // every chunk has the same signing requirements, so the first is representative.
+        let Some(signed) = sources.signed_transactions().first() else {
+            return Ok(signers.0);
+        };
+
+        let body =
+            services::TransactionBody::decode(&*signed.body_bytes).map_err(Error::from_protobuf)?;
+
+        let payer = body.transaction_id.and_then(|it| it.account_id);
+
+        signers.account(payer.clone())?;
+
+        if let Some(data) = body.data {
+            signers.data(data, payer.as_ref())?;
+        }
+
+        Ok(signers.0)
+    }
+
+    /// Check the signatures on this transaction against the keys of each of its [required signers](Self::required_signers).
+    ///
+    /// `resolve` is called for every signer to look up its key, [`RequiredSigner::fetch_key`] can be used to fetch them from the network.
+    /// Signers that resolve to `None` don't need to sign, and are left out of the result.
+    ///
+    /// # Errors
+    /// - See [`Transaction::required_signers`].
+    /// - Any error returned by `resolve`.
+    ///
+    /// # Panics
+    /// - If `!self.is_frozen()`.
+    pub async fn required_signer_reports<F, Fut>(
+        &self,
+        mut resolve: F,
+    ) -> crate::Result<Vec<RequiredSignerReport>>
+    where
+        F: FnMut(RequiredSigner) -> Fut + Send,
+        Fut: Future<Output = crate::Result<Option<Key>>> + Send,
+    {
+        let mut reports = Vec::new();
+
+        for signer in self.required_signers()? {
+            let Some(key) = resolve(signer.clone()).await? else {
+                continue;
+            };
+
+            reports
+                .push(RequiredSignerReport { report: KeySignatureReport::new(&key, self), signer });
+        }
+
+        Ok(reports)
+    }
+}
+
+#[derive(Default)]
+struct Signers(Vec<RequiredSigner>);
+
+impl Signers {
+    fn push(&mut self, signer: RequiredSigner) {
+        if !self.0.contains(&signer) {
+            self.0.push(signer);
+        }
+    }
+
+    fn account(&mut self, id: Option<services::AccountId>) -> crate::Result<()> {
+        if let Some(id) = Option::from_protobuf(id)? {
+            self.push(RequiredSigner::Account(id));
+        }
+
+        Ok(())
+    }
+
+    /// Adds `id`, or `default` (the payer) if `id` is missing.
+    fn account_or(
+        &mut self,
+        id: Option<services::AccountId>,
+        default: Option<&services::AccountId>,
+    ) -> crate::Result<()> {
+        self.account(id.or_else(|| default.cloned()))
+    }
+
+    fn key(&mut self, key: Option<services::Key>) -> crate::Result<()> {
+        if let Some(key) = Option::from_protobuf(key)? {
+            self.push(RequiredSigner::Key(key));
+        }
+
+        Ok(())
+    }
+
+    fn key_list(&mut self, keys: Option<services::KeyList>) -> crate::Result<()> {
+        if let Some(keys) = Option::<KeyList>::from_protobuf(keys)? {
+            self.push(RequiredSigner::Key(Key::KeyList(keys)));
+        }
+
+        Ok(())
+    }
+
+    fn token(
+        &mut self,
+        id: Option<services::TokenId>,
+        signer: fn(TokenId) -> RequiredSigner,
+    ) -> crate::Result<()> {
+        if let Some(id) = Option::from_protobuf(id)? {
+            self.push(signer(id));
+        }
+
+        Ok(())
+    }
+
+    fn topic(
+        &mut self,
+        id: Option<services::TopicId>,
+        signer: fn(TopicId) -> RequiredSigner,
+    ) -> crate::Result<()> {
+        if let Some(id) = Option::from_protobuf(id)? {
+            self.push(signer(id));
+        }
+
+        Ok(())
+    }
+
+    fn file(&mut self, id: Option<services::FileId>) -> crate::Result<()> {
+        if let Some(id) = Option::from_protobuf(id)? {
+            self.push(RequiredSigner::File(id));
+        }
+
+        Ok(())
+    }
+
+    fn contract(&mut self, id: Option<services::ContractId>) -> crate::Result<()> {
+        if let Some(id) = Option::from_protobuf(id)? {
+            self.push(RequiredSigner::ContractAdmin(id));
+        }
+
+        Ok(())
+    }
+
+    /// Senders of non-approved transfers need to sign, approved transfers are signed for by the spender (the payer).
+    fn transfers(&mut self, transfers: Vec<services::AccountAmount>) -> crate::Result<()> {
+        for transfer in transfers {
+            if transfer.amount < 0 && !transfer.is_approval {
+                self.account(transfer.account_id)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn token_transfers(
+        &mut self,
+        transfers: Vec<services::TokenTransferList>,
+    ) -> crate::Result<()> {
+        for transfer in transfers {
+            self.transfers(transfer.transfers)?;
+
+            for nft in transfer.nft_transfers {
+                if !nft.is_approval {
+                    self.account(nft.sender_account_id)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// `payer` stands in for the owner of allowances and rejections that don't name one.
+    fn data(
+        &mut self,
+        data: services::transaction_body::Data,
+        payer: Option<&services::AccountId>,
+    ) -> crate::Result<()> {
+        use services::transaction_body::Data;
+
+        match data {
+            Data::CryptoTransfer(it) => {
+                self.transfers(it.transfers.map(|it| it.account_amounts).unwrap_or_default())?;
+                self.token_transfers(it.token_transfers)?;
+            }
+            Data::TokenAirdrop(it) => self.token_transfers(it.token_transfers)?,
+            Data::CryptoUpdateAccount(it) => {
+                self.account(it.account_id_to_update)?;
+                self.key(it.key)?;
+            }
+            Data::CryptoDelete(it) => self.account(it.delete_account_id)?,
+            Data::CryptoApproveAllowance(it) => {
+                for allowance in it.crypto_allowances {
+                    self.account_or(allowance.owner, payer)?;
+                }
+
+                for allowance in it.token_allowances {
+                    self.account_or(allowance.owner, payer)?;
+                }
+
+                for allowance in it.nft_allowances {
+                    self.account_or(allowance.owner, payer)?;
+                }
+            }
+            Data::CryptoDeleteAllowance(it) => {
+                for allowance in it.nft_allowances {
+                    self.account_or(allowance.owner, payer)?;
+                }
+            }
+            Data::FileCreate(it) => self.key_list(it.keys)?,
+            Data::FileUpdate(it) => {
+                self.file(it.file_id)?;
+                self.key_list(it.keys)?;
+            }
+            Data::FileAppend(it) => self.file(it.file_id)?,
+            Data::FileDelete(it) => self.file(it.file_id)?,
+            Data::ContractCreateInstance(it) => {
+                self.key(it.admin_key)?;
+                self.account(it.auto_renew_account_id)?;
+            }
+            Data::ContractUpdateInstance(it) => {
+                self.contract(it.contract_id)?;
+                self.key(it.admin_key)?;
+                self.account(it.auto_renew_account_id)?;
+            }
+            Data::ContractDeleteInstance(it) => self.contract(it.contract_id)?,
+            Data::ConsensusCreateTopic(it) => {
+                self.key(it.admin_key)?;
+                self.account(it.auto_renew_account)?;
+            }
+            Data::ConsensusUpdateTopic(it) => {
+                self.topic(it.topic_id, RequiredSigner::TopicAdmin)?;
+                self.key(it.admin_key)?;
+                self.account(it.auto_renew_account)?;
+            }
+            Data::ConsensusDeleteTopic(it) => {
+                self.topic(it.topic_id, RequiredSigner::TopicAdmin)?
+            }
+            Data::ConsensusSubmitMessage(it) => {
+                self.topic(it.topic_id, RequiredSigner::TopicSubmit)?;
+            }
+            Data::TokenCreation(it) => {
+                self.account(it.treasury)?;
+                self.key(it.admin_key)?;
+                self.account(it.auto_renew_account)?;
+            }
+            Data::TokenUpdate(it) => {
+                self.token(it.token, RequiredSigner::TokenAdmin)?;
+                self.account(it.treasury)?;
+                self.key(it.admin_key)?;
+                self.account(it.auto_renew_account)?;
+            }
+            Data::TokenDeletion(it) => self.token(it.token, RequiredSigner::TokenAdmin)?,
+            Data::TokenMint(it) => self.token(it.token, RequiredSigner::TokenSupply)?,
+            Data::TokenBurn(it) => self.token(it.token, RequiredSigner::TokenSupply)?,
+            Data::TokenWipe(it) => self.token(it.token, RequiredSigner::TokenWipe)?,
+            Data::TokenFreeze(it) => self.token(it.token, RequiredSigner::TokenFreeze)?,
+            Data::TokenUnfreeze(it) => self.token(it.token, RequiredSigner::TokenFreeze)?,
+            Data::TokenGrantKyc(it) => self.token(it.token, RequiredSigner::TokenKyc)?,
+            Data::TokenRevokeKyc(it) => self.token(it.token, RequiredSigner::TokenKyc)?,
+            Data::TokenPause(it) => self.token(it.token, RequiredSigner::TokenPause)?,
+            Data::TokenUnpause(it) => self.token(it.token, RequiredSigner::TokenPause)?,
+            Data::TokenFeeScheduleUpdate(it) => {
+                self.token(it.token_id, RequiredSigner::TokenFeeSchedule)?;
+            }
+            Data::TokenUpdateNfts(it) => self.token(it.token, RequiredSigner::TokenMetadata)?,
+            Data::TokenAssociate(it) => self.account(it.account)?,
+            Data::TokenDissociate(it) => self.account(it.account)?,
+            Data::TokenClaimAirdrop(it) => {
+                for id in it.pending_airdrops {
+                    self.account(id.receiver_id)?;
+                }
+            }
+            Data::TokenCancelAirdrop(it) => {
+                for id in it.pending_airdrops {
+                    self.account(id.sender_id)?;
+                }
+            }
+            Data::TokenReject(it) => self.account_or(it.owner, payer)?,
+            Data::ScheduleCreate(it) => self.key(it.admin_key)?,
+            Data::ScheduleDelete(it) => {
+                if let Some(id) = Option::from_protobuf(it.schedule_id)? {
+                    self.push(RequiredSigner::ScheduleAdmin(id));
+                }
+            }
+            // the signatories are whoever the scheduled transaction needs, which only the network knows.
+            Data::ScheduleSign(_) => {}
+            Data::NodeCreate(it) => self.key(it.admin_key)?,
+            Data::NodeUpdate(it) => {
+                self.push(RequiredSigner::NodeAdmin(it.node_id));
+                self.key(it.admin_key)?;
+            }
+            Data::NodeDelete(it) => self.push(RequiredSigner::NodeAdmin(it.node_id)),
+            // everything else only needs the payer's signature (or requires privileged accounts).
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::transaction::test_helpers::{
+        unused_private_key,
+        TEST_ACCOUNT_ID,
+        TEST_TOKEN_ID,
+    };
+    use crate::{
+        AccountId,
+        AccountUpdateTransaction,
+        Hbar,
+        Key,
+        NodeDeleteTransaction,
+        NodeUpdateTransaction,
+        PrivateKey,
+        RequiredSigner,
+        TokenMintTransaction,
+        TokenUpdateTransaction,
+        TransferTransaction,
+    };
+
+    const SENDER: AccountId = AccountId::new(0, 0, 1001);
+    const OWNER: AccountId = AccountId::new(0, 0, 1002);
+    const RECEIVER: AccountId = AccountId::new(0, 0, 1003);
+
+    fn make_transfer() -> TransferTransaction {
+        let mut tx = TransferTransaction::new_for_tests();
+
+        tx.hbar_transfer(SENDER, Hbar::new(-2))
+            .approved_hbar_transfer(OWNER, Hbar::new(-1))
+            .hbar_transfer(RECEIVER, Hbar::new(3))
+            .freeze()
+            .unwrap();
+
+        tx
+    }
+
+    #[test]
+    fn transfer() {
+        let signers = make_transfer().required_signers().unwrap();
+
+        assert_eq!(
+            signers,
+            [RequiredSigner::Account(TEST_ACCOUNT_ID), RequiredSigner::Account(SENDER)]
+        );
+    }
+
+    #[test]
+    fn account_update() {
+        let key = PrivateKey::generate_ed25519().public_key();
+
+        let mut tx = AccountUpdateTransaction::new_for_tests();
+        tx.account_id(SENDER).key(key).freeze().unwrap();
+
+        assert_eq!(
+            tx.required_signers().unwrap(),
+            [
+                RequiredSigner::Account(TEST_ACCOUNT_ID),
+                RequiredSigner::Account(SENDER),
+                RequiredSigner::Key(Key::Single(key)),
+            ]
+        );
+    }
+
+    #[test]
+    fn token_keys() {
+        let mut tx = TokenUpdateTransaction::new_for_tests();
+        tx.token_id(TEST_TOKEN_ID).freeze().unwrap();
+
+        assert_eq!(
+            tx.required_signers().unwrap(),
+            [RequiredSigner::Account(TEST_ACCOUNT_ID), RequiredSigner::TokenAdmin(TEST_TOKEN_ID)]
+        );
+
+        let mut tx = TokenMintTransaction::new_for_tests();
+        tx.token_id(TEST_TOKEN_ID).amount(10).freeze().unwrap();
+
+        assert_eq!(
+            tx.required_signers().unwrap(),
+            [RequiredSigner::Account(TEST_ACCOUNT_ID), RequiredSigner::TokenSupply(TEST_TOKEN_ID)]
+        );
+    }
+
+    #[test]
+    fn node_admin() {
+        let key = PrivateKey::generate_ed25519().public_key();
+
+        let mut tx = NodeUpdateTransaction::new_for_tests();
+        tx.node_id(3).admin_key(key).freeze().unwrap();
+
+        assert_eq!(
+            tx.required_signers().unwrap(),
+            [
+                RequiredSigner::Account(TEST_ACCOUNT_ID),
+                RequiredSigner::NodeAdmin(3),
+                RequiredSigner::Key(Key::Single(key)),
+            ]
+        );
+
+        let mut tx = NodeDeleteTransaction::new_for_tests();
+        tx.node_id(3).freeze().unwrap();
+
+        assert_eq!(
+            tx.required_signers().unwrap(),
+            [RequiredSigner::Account(TEST_ACCOUNT_ID), RequiredSigner::NodeAdmin(3)]
+        );
+    }
+
+    #[tokio::test]
+    async fn reports() {
+        let sender_key = PrivateKey::generate_ed25519();
+
+        let keys = HashMap::from([
+            (
+                RequiredSigner::Account(TEST_ACCOUNT_ID),
+                Key::Single(unused_private_key().public_key()),
+            ),
+            (RequiredSigner::Account(SENDER), Key::Single(sender_key.public_key())),
+        ]);
+
+        let resolve = |signer: RequiredSigner| {
+            let key = keys.get(&signer).cloned();
+            async move { crate::Result::Ok(key) }
+        };
+
+        let mut tx = make_transfer();
+
+        let reports = tx.required_signer_reports(resolve).await.unwrap();
+        assert_eq!(reports.len(), 2);
+        assert!(reports[0].report.is_satisfied);
+        assert_eq!(reports[1].signer, RequiredSigner::Account(SENDER));
+        assert!(!reports[1].report.is_satisfied);
+
+        tx.sign(sender_key);
+
+        let reports = tx.required_signer_reports(resolve).await.unwrap();
+        assert!(reports.iter().all(|it| it.report.is_satisfied));
+    }
+}
@@ -0,0 +1,1001 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! `Serialize` and `Deserialize` impls for types that don't derive them.
+//!
+//! IDs and keys use their canonical string forms, amounts of hbar are in tinybars,
+//! timestamps are `seconds.nanoseconds` strings (like in transaction IDs), durations are in seconds
+//! (or milliseconds for client settings),
+//! and bytes are hex encoded.
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::de::Error as _;
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+
+use crate::{
+    AccountId,
+    ContractId,
+    DelegateContractId,
+    EvmAddress,
+    FileId,
+    Hbar,
+    Key,
+    KeyList,
+    LedgerId,
+    NftId,
+    PublicKey,
+    ScheduleId,
+    SemanticVersion,
+    TokenId,
+    TopicId,
+    TransactionId,
+};
+
+fn serialize_display<T: Display, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+fn deserialize_from_str<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FromStr,
+    T::Err: Display,
+    D: Deserializer<'de>,
+{
+    String::deserialize(deserializer)?.parse().map_err(D::Error::custom)
+}
+
+/// Implements `Serialize` and `Deserialize` using `Display` and `FromStr`.
+macro_rules! impl_serde_str {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serialize_display(self, serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserialize_from_str(deserializer)
+                }
+            }
+        )+
+    };
+}
+
+impl_serde_str! {
+    AccountId,
+    ContractId,
+    DelegateContractId,
+    EvmAddress,
+    FileId,
+    LedgerId,
+    NftId,
+    PublicKey,
+    ScheduleId,
+    SemanticVersion,
+    TokenId,
+    TopicId,
+    TransactionId,
+}
+
+impl Serialize for Hbar {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.to_tinybars())
+    }
+}
+
+impl<'de> Deserialize<'de> for Hbar {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        i64::deserialize(deserializer).map(Hbar::from_tinybars)
+    }
+}
+
+/// A single key is its public key string, everything else is an object.
+#[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+#[serde(untagged)]
+enum KeyRepr {
+    Single(PublicKey),
+    #[serde(rename_all = "camelCase")]
+    ContractId {
+        contract_id: ContractId,
+    },
+    #[serde(rename_all = "camelCase")]
+    DelegateContractId {
+        delegate_contract_id: DelegateContractId,
+    },
+    KeyList(KeyList),
+}
+
+impl Serialize for Key {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let repr = match self.clone() {
+            Key::Single(it) => KeyRepr::Single(it),
+            Key::ContractId(contract_id) => KeyRepr::ContractId { contract_id },
+            Key::DelegateContractId(delegate_contract_id) => {
+                KeyRepr::DelegateContractId { delegate_contract_id }
+            }
+            Key::KeyList(it) => KeyRepr::KeyList(it),
+        };
+
+        repr.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Key {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match KeyRepr::deserialize(deserializer)? {
+            KeyRepr::Single(it) => Key::Single(it),
+            KeyRepr::ContractId { contract_id } => Key::ContractId(contract_id),
+            KeyRepr::DelegateContractId { delegate_contract_id } => {
+                Key::DelegateContractId(delegate_contract_id)
+            }
+            KeyRepr::KeyList(it) => Key::KeyList(it),
+        })
+    }
+}
+
+/// [`Status`](crate::Status) as its protobuf name, for example `SUCCESS`.
+pub(crate) mod status {
+    use serde::de::Error as _;
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serializer,
+    };
+
+    use crate::Status;
+
+    pub(crate) fn serialize<S: Serializer>(
+        status: &Status,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(status.as_str_name())
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Status, D::Error> {
+        let name = String::deserialize(deserializer)?;
+
+        Status::from_str_name(&name)
+            .ok_or_else(|| D::Error::custom(format!("unknown status `{name}`")))
+    }
+}
+
+/// An `OffsetDateTime` as a `seconds.nanoseconds` string.
+pub(crate) mod timestamp {
+    use serde::de::Error as _;
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serializer,
+    };
+    use time::OffsetDateTime;
+
+    pub(crate) fn serialize<S: Serializer>(
+        timestamp: &OffsetDateTime,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!(
+            "{}.{:09}",
+            timestamp.unix_timestamp(),
+            timestamp.nanosecond()
+        ))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<OffsetDateTime, D::Error> {
+        let s = String::deserialize(deserializer)?;
+
+        let (seconds, nanos) = s.split_once('.').unwrap_or((&s, "0"));
+
+        let seconds: i64 = seconds.parse().map_err(D::Error::custom)?;
+        let nanos: u32 = match nanos.len() {
+            1..=9 => format!("{nanos:0<9}").parse().map_err(D::Error::custom)?,
+            _ => return Err(D::Error::custom(format!("invalid timestamp `{s}`"))),
+        };
+
+        OffsetDateTime::from_unix_timestamp(seconds)
+            .and_then(|it| it.replace_nanosecond(nanos))
+            .map_err(D::Error::custom)
+    }
+}
+
+/// An `Option<OffsetDateTime>`, see [`timestamp`].
+pub(crate) mod option_timestamp {
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serializer,
+    };
+    use time::OffsetDateTime;
+
+    #[derive(serde_derive::Deserialize)]
+    struct Timestamp(#[serde(with = "super::timestamp")] OffsetDateTime);
+
+    pub(crate) fn serialize<S: Serializer>(
+        timestamp: &Option<OffsetDateTime>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match timestamp {
+            Some(it) => super::timestamp::serialize(it, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<OffsetDateTime>, D::Error> {
+        Ok(Option::<Timestamp>::deserialize(deserializer)?.map(|it| it.0))
+    }
+}
+
+/// An `Option<Duration>` in whole seconds.
+pub(crate) mod option_duration {
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serializer,
+    };
+    use time::Duration;
+
+    pub(crate) fn serialize<S: Serializer>(
+        duration: &Option<Duration>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match duration {
+            Some(it) => serializer.serialize_i64(it.whole_seconds()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Duration>, D::Error> {
+        Ok(Option::<i64>::deserialize(deserializer)?.map(Duration::seconds))
+    }
+}
+
+/// An `Option<std::time::Duration>` in whole milliseconds.
+pub(crate) mod option_millis {
+    use std::time::Duration;
+
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serializer,
+    };
+
+    pub(crate) fn serialize<S: Serializer>(
+        duration: &Option<Duration>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match duration {
+            // durations that don't fit in a `u64` of milliseconds aren't useful as a setting.
+            Some(it) => serializer.serialize_u64(u64::try_from(it.as_millis()).unwrap_or(u64::MAX)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Duration>, D::Error> {
+        Ok(Option::<u64>::deserialize(deserializer)?.map(Duration::from_millis))
+    }
+}
+
+/// Bytes as a hex string.
+pub(crate) mod hex {
+    use serde::de::Error as _;
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serializer,
+    };
+
+    pub(crate) fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&::hex::encode(bytes))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<u8>, D::Error> {
+        ::hex::decode(String::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+}
+
+/// `Option<Vec<u8>>` as a hex string, see [`hex`].
+pub(crate) mod option_hex {
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serializer,
+    };
+
+    #[derive(serde_derive::Deserialize)]
+    struct Hex(#[serde(with = "super::hex")] Vec<u8>);
+
+    pub(crate) fn serialize<S: Serializer>(
+        bytes: &Option<Vec<u8>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match bytes {
+            Some(it) => super::hex::serialize(it, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Vec<u8>>, D::Error> {
+        Ok(Option::<Hex>::deserialize(deserializer)?.map(|it| it.0))
+    }
+}
+
+/// A list of byte strings as hex strings, see [`hex`].
+pub(crate) mod hex_list {
+    use serde::ser::SerializeSeq;
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serializer,
+    };
+
+    #[derive(serde_derive::Deserialize)]
+    struct Hex(#[serde(with = "super::hex")] Vec<u8>);
+
+    pub(crate) fn serialize<S: Serializer>(
+        list: &[Vec<u8>],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(list.len()))?;
+
+        for bytes in list {
+            seq.serialize_element(&::hex::encode(bytes))?;
+        }
+
+        seq.end()
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Vec<u8>>, D::Error> {
+        Ok(Vec::<Hex>::deserialize(deserializer)?.into_iter().map(|it| it.0).collect())
+    }
+}
+
+/// A `HashMap` with its entries sorted by key, so that the output doesn't change from run to run.
+///
+/// Keys are sorted by their string form, which is also how they end up in JSON.
+pub(crate) mod sorted_map {
+    use std::collections::{
+        BTreeMap,
+        HashMap,
+    };
+    use std::fmt::Display;
+    use std::hash::Hash;
+
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+    };
+
+    pub(crate) fn serialize<K: Display, V: Serialize, S: Serializer>(
+        map: &HashMap<K, V>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        map.iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect::<BTreeMap<_, _>>()
+            .serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, K, V, D>(deserializer: D) -> Result<HashMap<K, V>, D::Error>
+    where
+        K: Eq + Hash + Deserialize<'de>,
+        V: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        HashMap::deserialize(deserializer)
+    }
+}
+
+/// A `HashMap` of `HashMap`s with both levels sorted, see [`sorted_map`].
+pub(crate) mod sorted_nested_map {
+    use std::collections::{
+        BTreeMap,
+        HashMap,
+    };
+    use std::fmt::Display;
+    use std::hash::Hash;
+
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+    };
+
+    pub(crate) fn serialize<K1, K2, V, S>(
+        map: &HashMap<K1, HashMap<K2, V>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        K1: Display,
+        K2: Display,
+        V: Serialize,
+        S: Serializer,
+    {
+        map.iter()
+            .map(|(key, inner)| {
+                let inner: BTreeMap<_, _> =
+                    inner.iter().map(|(key, value)| (key.to_string(), value)).collect();
+
+                (key.to_string(), inner)
+            })
+            .collect::<BTreeMap<_, _>>()
+            .serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, K1, K2, V, D>(
+        deserializer: D,
+    ) -> Result<HashMap<K1, HashMap<K2, V>>, D::Error>
+    where
+        K1: Eq + Hash + Deserialize<'de>,
+        K2: Eq + Hash + Deserialize<'de>,
+        V: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        HashMap::deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    use expect_test::expect;
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+    use time::{
+        Duration,
+        OffsetDateTime,
+    };
+
+    use crate::{
+        AccountId,
+        AccountInfo,
+        ContractFunctionResult,
+        ContractId,
+        ContractLogInfo,
+        ContractNonceInfo,
+        ExchangeRate,
+        Hbar,
+        Key,
+        KeyList,
+        LedgerId,
+        NftId,
+        PublicKey,
+        Status,
+        TokenId,
+        TokenInfo,
+        TokenNftTransfer,
+        TokenSupplyType,
+        TokenType,
+        TopicMessage,
+        TransactionId,
+        TransactionReceipt,
+        TransactionRecord,
+        Transfer,
+    };
+
+    const PK: &str =
+        "302a300506032b6570032100e0c8ec2758a5879ffac226a13c0c516b799e72e35141a0dd828f94d37988a4b7";
+
+    const TX_ID: &str = "0.0.5006@1554158542.0";
+
+    fn timestamp() -> OffsetDateTime {
+        OffsetDateTime::from_unix_timestamp(1554158542).unwrap()
+    }
+
+    /// Serializes `value`, checking that it deserializes back to the same JSON.
+    fn to_json<T: Serialize + DeserializeOwned>(value: &T) -> String {
+        let json = serde_json::to_string_pretty(value).unwrap();
+
+        let value: T = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string_pretty(&value).unwrap(), json);
+
+        json
+    }
+
+    fn make_receipt() -> TransactionReceipt {
+        TransactionReceipt {
+            transaction_id: Some(TransactionId::from_str(TX_ID).unwrap()),
+            status: Status::Success,
+            account_id: Some(AccountId::new(0, 0, 5006)),
+            file_id: None,
+            contract_id: None,
+            topic_id: None,
+            topic_sequence_number: 0,
+            topic_running_hash: None,
+            topic_running_hash_version: 0,
+            token_id: None,
+            total_supply: 0,
+            schedule_id: None,
+            scheduled_transaction_id: None,
+            serials: Vec::from([1, 2]),
+            node_id: 0,
+            duplicates: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn ids_use_string_form() {
+        let account_id = AccountId::new(0, 0, 5006);
+        let nft_id = NftId { token_id: TokenId::new(1, 2, 3), serial: 4 };
+        let tx_id = TransactionId::from_str("0.0.5006@1554158542.0").unwrap();
+
+        expect![[r#""0.0.5006""#]].assert_eq(&serde_json::to_string(&account_id).unwrap());
+        expect![[r#""1.2.3/4""#]].assert_eq(&serde_json::to_string(&nft_id).unwrap());
+        expect![[r#""0.0.5006@1554158542.0""#]].assert_eq(&serde_json::to_string(&tx_id).unwrap());
+
+        assert_eq!(serde_json::from_str::<AccountId>(r#""0.0.5006""#).unwrap(), account_id);
+        assert_eq!(serde_json::from_str::<NftId>(r#""1.2.3/4""#).unwrap(), nft_id);
+        assert_eq!(
+            serde_json::from_str::<TransactionId>(r#""0.0.5006@1554158542.0""#).unwrap(),
+            tx_id
+        );
+    }
+
+    #[test]
+    fn invalid_id_is_an_error() {
+        assert!(serde_json::from_str::<AccountId>(r#""not an id""#).is_err());
+        assert!(serde_json::from_str::<AccountId>("5006").is_err());
+    }
+
+    #[test]
+    fn hbar_is_tinybars() {
+        expect!["200000000"].assert_eq(&serde_json::to_string(&Hbar::new(2)).unwrap());
+
+        assert_eq!(serde_json::from_str::<Hbar>("-5").unwrap(), Hbar::from_tinybars(-5));
+    }
+
+    #[test]
+    fn keys() {
+        let public_key = PublicKey::from_str(PK).unwrap();
+
+        let key = Key::KeyList(KeyList {
+            keys: vec![
+                Key::Single(public_key),
+                Key::ContractId(ContractId::new(0, 0, 5007)),
+                Key::KeyList(KeyList { keys: vec![Key::Single(public_key)], threshold: None }),
+            ],
+            threshold: Some(2),
+        });
+
+        let json = serde_json::to_string_pretty(&key).unwrap();
+
+        expect![[r#"
+            {
+              "keys": [
+                "302a300506032b6570032100e0c8ec2758a5879ffac226a13c0c516b799e72e35141a0dd828f94d37988a4b7",
+                {
+                  "contractId": "0.0.5007"
+                },
+                {
+                  "keys": [
+                    "302a300506032b6570032100e0c8ec2758a5879ffac226a13c0c516b799e72e35141a0dd828f94d37988a4b7"
+                  ]
+                }
+              ],
+              "threshold": 2
+            }"#]]
+        .assert_eq(&json);
+
+        assert_eq!(serde_json::from_str::<Key>(&json).unwrap(), key);
+    }
+
+    #[test]
+    fn structs_are_camel_case() {
+        let transfer = Transfer { account_id: AccountId::new(0, 0, 5006), amount: Hbar::new(-1) };
+
+        expect![[r#"{"accountId":"0.0.5006","amount":-100000000}"#]]
+            .assert_eq(&serde_json::to_string(&transfer).unwrap());
+
+        let rate = ExchangeRate {
+            hbars: 30_000,
+            cents: 12,
+            expiration_time: OffsetDateTime::from_unix_timestamp(1554158542).unwrap(),
+        };
+
+        let json = serde_json::to_string(&rate).unwrap();
+
+        expect![[r#"{"hbars":30000,"cents":12,"expirationTime":"1554158542.000000000"}"#]]
+            .assert_eq(&json);
+
+        let rate2: ExchangeRate = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(rate2.expiration_time, rate.expiration_time);
+    }
+
+    #[test]
+    fn transaction_receipt() {
+        expect![[r#"
+            {
+              "transactionId": "0.0.5006@1554158542.0",
+              "status": "SUCCESS",
+              "accountId": "0.0.5006",
+              "fileId": null,
+              "contractId": null,
+              "topicId": null,
+              "topicSequenceNumber": 0,
+              "topicRunningHash": null,
+              "topicRunningHashVersion": 0,
+              "tokenId": null,
+              "totalSupply": 0,
+              "scheduleId": null,
+              "scheduledTransactionId": null,
+              "serials": [
+                1,
+                2
+              ],
+              "nodeId": 0,
+              "duplicates": [],
+              "children": []
+            }"#]]
+        .assert_eq(&to_json(&make_receipt()));
+    }
+
+    #[test]
+    fn transaction_record() {
+        let sender = AccountId::new(0, 0, 5006);
+        let receiver = AccountId::new(0, 0, 5007);
+        let nft_token = TokenId::new(0, 0, 1002);
+
+        let record = TransactionRecord {
+            receipt: make_receipt(),
+            transaction_hash: Vec::from([1, 2, 3]),
+            consensus_timestamp: timestamp(),
+            contract_function_result: None,
+            transfers: Vec::from([Transfer {
+                account_id: sender,
+                amount: Hbar::from_tinybars(-100),
+            }]),
+            // enough entries that an unsorted map would show up as a flaky snapshot.
+            token_transfers: HashMap::from([
+                (TokenId::new(0, 0, 1001), HashMap::from([(receiver, 10), (sender, -10)])),
+                (TokenId::new(0, 0, 1000), HashMap::from([(sender, 5)])),
+            ]),
+            token_nft_transfers: HashMap::from([(
+                nft_token,
+                Vec::from([TokenNftTransfer {
+                    token_id: nft_token,
+                    sender,
+                    receiver,
+                    serial: 1,
+                    is_approved: false,
+                }]),
+            )]),
+            transaction_id: TransactionId::from_str(TX_ID).unwrap(),
+            transaction_memo: "memo".to_owned(),
+            transaction_fee: Hbar::from_tinybars(100_000),
+            schedule_ref: None,
+            assessed_custom_fees: Vec::new(),
+            automatic_token_associations: Vec::new(),
+            parent_consensus_timestamp: None,
+            alias_key: None,
+            children: Vec::new(),
+            duplicates: Vec::new(),
+            ethereum_hash: Vec::new(),
+            prng_bytes: None,
+            prng_number: None,
+            evm_address: None,
+            pending_airdrop_records: Vec::new(),
+        };
+
+        expect![[r#"
+            {
+              "receipt": {
+                "transactionId": "0.0.5006@1554158542.0",
+                "status": "SUCCESS",
+                "accountId": "0.0.5006",
+                "fileId": null,
+                "contractId": null,
+                "topicId": null,
+                "topicSequenceNumber": 0,
+                "topicRunningHash": null,
+                "topicRunningHashVersion": 0,
+                "tokenId": null,
+                "totalSupply": 0,
+                "scheduleId": null,
+                "scheduledTransactionId": null,
+                "serials": [
+                  1,
+                  2
+                ],
+                "nodeId": 0,
+                "duplicates": [],
+                "children": []
+              },
+              "transactionHash": "010203",
+              "consensusTimestamp": "1554158542.000000000",
+              "contractFunctionResult": null,
+              "transfers": [
+                {
+                  "accountId": "0.0.5006",
+                  "amount": -100
+                }
+              ],
+              "tokenTransfers": {
+                "0.0.1000": {
+                  "0.0.5006": 5
+                },
+                "0.0.1001": {
+                  "0.0.5006": -10,
+                  "0.0.5007": 10
+                }
+              },
+              "tokenNftTransfers": {
+                "0.0.1002": [
+                  {
+                    "tokenId": "0.0.1002",
+                    "sender": "0.0.5006",
+                    "receiver": "0.0.5007",
+                    "serial": 1,
+                    "isApproved": false
+                  }
+                ]
+              },
+              "transactionId": "0.0.5006@1554158542.0",
+              "transactionMemo": "memo",
+              "transactionFee": 100000,
+              "scheduleRef": null,
+              "assessedCustomFees": [],
+              "automaticTokenAssociations": [],
+              "parentConsensusTimestamp": null,
+              "aliasKey": null,
+              "children": [],
+              "duplicates": [],
+              "ethereumHash": "",
+              "prngBytes": null,
+              "prngNumber": null,
+              "evmAddress": null,
+              "pendingAirdropRecords": []
+            }"#]]
+        .assert_eq(&to_json(&record));
+    }
+
+    #[test]
+    fn token_info() {
+        let info = TokenInfo {
+            token_id: TokenId::new(0, 0, 1000),
+            name: "Test".to_owned(),
+            symbol: "T".to_owned(),
+            decimals: 2,
+            total_supply: 1000,
+            treasury_account_id: AccountId::new(0, 0, 5006),
+            admin_key: Some(Key::Single(PublicKey::from_str(PK).unwrap())),
+            kyc_key: None,
+            freeze_key: None,
+            wipe_key: None,
+            supply_key: None,
+            fee_schedule_key: None,
+            default_freeze_status: Some(false),
+            default_kyc_status: None,
+            is_deleted: false,
+            auto_renew_account: None,
+            auto_renew_period: Some(Duration::days(90)),
+            expiration_time: Some(timestamp()),
+            token_memo: String::new(),
+            token_type: TokenType::FungibleCommon,
+            supply_type: TokenSupplyType::Infinite,
+            max_supply: 0,
+            custom_fees: Vec::new(),
+            pause_key: None,
+            pause_status: None,
+            ledger_id: LedgerId::testnet(),
+            metadata: Vec::new(),
+            metadata_key: None,
+        };
+
+        expect![[r#"
+            {
+              "tokenId": "0.0.1000",
+              "name": "Test",
+              "symbol": "T",
+              "decimals": 2,
+              "totalSupply": 1000,
+              "treasuryAccountId": "0.0.5006",
+              "adminKey": "302a300506032b6570032100e0c8ec2758a5879ffac226a13c0c516b799e72e35141a0dd828f94d37988a4b7",
+              "kycKey": null,
+              "freezeKey": null,
+              "wipeKey": null,
+              "supplyKey": null,
+              "feeScheduleKey": null,
+              "defaultFreezeStatus": false,
+              "defaultKycStatus": null,
+              "isDeleted": false,
+              "autoRenewAccount": null,
+              "autoRenewPeriod": 7776000,
+              "expirationTime": "1554158542.000000000",
+              "tokenMemo": "",
+              "tokenType": "fungibleCommon",
+              "supplyType": "infinite",
+              "maxSupply": 0,
+              "customFees": [],
+              "pauseKey": null,
+              "pauseStatus": null,
+              "ledgerId": "testnet",
+              "metadata": "",
+              "metadataKey": null
+            }"#]]
+        .assert_eq(&to_json(&info));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn account_info() {
+        let info = AccountInfo {
+            account_id: AccountId::new(0, 0, 5006),
+            contract_account_id: "000000000000000000000000000000000000138e".to_owned(),
+            is_deleted: false,
+            proxy_account_id: None,
+            proxy_received: Hbar::ZERO,
+            key: Key::Single(PublicKey::from_str(PK).unwrap()),
+            balance: Hbar::new(10),
+            send_record_threshold: Hbar::ZERO,
+            receive_record_threshold: Hbar::ZERO,
+            is_receiver_signature_required: false,
+            expiration_time: Some(timestamp()),
+            auto_renew_period: Some(Duration::days(90)),
+            account_memo: String::new(),
+            owned_nfts: 0,
+            max_automatic_token_associations: 0,
+            alias_key: None,
+            ethereum_nonce: 0,
+            ledger_id: LedgerId::testnet(),
+            staking: None,
+        };
+
+        expect![[r#"
+            {
+              "accountId": "0.0.5006",
+              "contractAccountId": "000000000000000000000000000000000000138e",
+              "isDeleted": false,
+              "proxyAccountId": null,
+              "proxyReceived": 0,
+              "key": "302a300506032b6570032100e0c8ec2758a5879ffac226a13c0c516b799e72e35141a0dd828f94d37988a4b7",
+              "balance": 1000000000,
+              "sendRecordThreshold": 0,
+              "receiveRecordThreshold": 0,
+              "isReceiverSignatureRequired": false,
+              "expirationTime": "1554158542.000000000",
+              "autoRenewPeriod": 7776000,
+              "accountMemo": "",
+              "ownedNfts": 0,
+              "maxAutomaticTokenAssociations": 0,
+              "aliasKey": null,
+              "ethereumNonce": 0,
+              "ledgerId": "testnet",
+              "staking": null
+            }"#]]
+        .assert_eq(&to_json(&info));
+    }
+
+    #[test]
+    fn contract_function_result() {
+        let contract_id = ContractId::new(0, 0, 5007);
+
+        let result = ContractFunctionResult {
+            contract_id,
+            evm_address: None,
+            bytes: Vec::from([1, 2, 3]),
+            error_message: None,
+            bloom: Vec::new(),
+            gas_used: 100,
+            gas: 200,
+            hbar_amount: 0,
+            contract_function_parameters_bytes: Vec::new(),
+            sender_account_id: Some(AccountId::new(0, 0, 5006)),
+            logs: Vec::from([ContractLogInfo {
+                contract_id,
+                bloom: Vec::new(),
+                topics: Vec::from([Vec::from([0xaa, 0xaa])]),
+                data: Vec::new(),
+            }]),
+            contract_nonces: Vec::from([ContractNonceInfo { contract_id, nonce: 1 }]),
+            signer_nonce: Some(1),
+        };
+
+        expect![[r#"
+            {
+              "contractId": "0.0.5007",
+              "evmAddress": null,
+              "bytes": "010203",
+              "errorMessage": null,
+              "bloom": "",
+              "gasUsed": 100,
+              "gas": 200,
+              "hbarAmount": 0,
+              "contractFunctionParametersBytes": "",
+              "senderAccountId": "0.0.5006",
+              "logs": [
+                {
+                  "contractId": "0.0.5007",
+                  "bloom": "",
+                  "topics": [
+                    "aaaa"
+                  ],
+                  "data": ""
+                }
+              ],
+              "contractNonces": [
+                {
+                  "contractId": "0.0.5007",
+                  "nonce": 1
+                }
+              ],
+              "signerNonce": 1
+            }"#]]
+        .assert_eq(&to_json(&result));
+    }
+
+    #[test]
+    fn topic_message() {
+        let message = TopicMessage {
+            consensus_timestamp: timestamp(),
+            contents: b"hello".to_vec(),
+            running_hash: Vec::from([1, 2, 3]),
+            running_hash_version: 3,
+            sequence_number: 7,
+            chunks: None,
+            transaction: Some(TransactionId::from_str(TX_ID).unwrap()),
+            checkpoint: None,
+        };
+
+        expect![[r#"
+            {
+              "consensusTimestamp": "1554158542.000000000",
+              "contents": "68656c6c6f",
+              "runningHash": "010203",
+              "runningHashVersion": 3,
+              "sequenceNumber": 7,
+              "chunks": null,
+              "transaction": "0.0.5006@1554158542.0"
+            }"#]]
+        .assert_eq(&to_json(&message));
+    }
+}
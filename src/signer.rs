@@ -19,7 +19,10 @@
  */
 
 use std::fmt;
+use std::future::Future;
 
+use futures_core::future::BoxFuture;
+use futures_util::FutureExt;
 use triomphe::Arc;
 use unsize::{
     CoerceUnsize,
@@ -27,25 +30,78 @@ use unsize::{
 };
 
 use crate::{
+    Error,
     PrivateKey,
     PublicKey,
 };
 
+/// Signs transactions on behalf of a [`PublicKey`].
+///
+/// This is the extension point for keys that aren't held in memory,
+/// such as keys in an HSM, a KMS service, a remote signer, or a flow that waits for a user to approve.
+///
+/// Synchronous APIs that need signatures, such as [`Transaction::to_bytes`](crate::Transaction::to_bytes),
+/// only work with signers that finish without waiting, otherwise they fail with [`Error::Signature`].
+pub trait Signer: Send + Sync {
+    /// Returns the public key of the signatures returned by [`sign`](Self::sign).
+    ///
+    /// This is called when the signer is added, rather than every time a signature is needed.
+    fn public_key(&self) -> PublicKey;
+
+    /// Signs each of the `messages`, returning the signatures in the same order.
+    ///
+    /// Each message is the body bytes of one transaction, so a transaction sent to several nodes (or in several chunks)
+    /// is signed in one batch.
+    ///
+    /// # Errors
+    /// If signing fails for any reason; the error is returned from the operation that needed the signatures,
+    /// conventionally as [`Error::Signature`].
+    fn sign<'a>(&'a self, messages: &'a [&'a [u8]]) -> BoxFuture<'a, crate::Result<Vec<Vec<u8>>>>;
+}
+
+impl Signer for PrivateKey {
+    fn public_key(&self) -> PublicKey {
+        PrivateKey::public_key(self)
+    }
+
+    fn sign<'a>(&'a self, messages: &'a [&'a [u8]]) -> BoxFuture<'a, crate::Result<Vec<Vec<u8>>>> {
+        Box::pin(std::future::ready(Ok(messages
+            .iter()
+            .map(|it| PrivateKey::sign(self, it))
+            .collect())))
+    }
+}
+
+/// Adapts the closures given to functions like [`Transaction::sign_with`](crate::Transaction::sign_with) to a [`Signer`].
+struct FnSigner<F> {
+    public_key: PublicKey,
+    f: F,
+}
+
+impl<F: Fn(&[u8]) -> Vec<u8> + Send + Sync> Signer for FnSigner<F> {
+    fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    fn sign<'a>(&'a self, messages: &'a [&'a [u8]]) -> BoxFuture<'a, crate::Result<Vec<Vec<u8>>>> {
+        Box::pin(std::future::ready(Ok(messages.iter().map(|it| (self.f)(it)).collect())))
+    }
+}
+
 #[derive(Clone)]
 pub(crate) enum AnySigner {
     PrivateKey(PrivateKey),
     // public key is 216 bytes.
     // Here be a story of dragons.
     // Once an engineer attempted to downgrade this `Arc` to a mere `Box`, alas it was not meant to be.
-    // For the Fn must be cloned, and `dyn Fn` must not.
+    // For the signer must be cloned, and `dyn Signer` must not.
     // The plan to not pay the price of Arc was doomed from the very beginning.
-    // Attempts to avoid the arc, the cloning of the `Fn`, all end in misery,
+    // Attempts to avoid the arc, the cloning of the signer, all end in misery,
     // for the `Client` must have `AnySigner`, not a `PrivateKey`, and the `ContractCreateFlow`...
     // Well, it must be executable multiple times, for ownership reasons.
-    // lint note: can't reasonably resolve this because putting the `type` on anything but the `Fn(..)` part is useless
-    // but we can't do that because trait aliases don't exist.
-    #[allow(clippy::type_complexity)]
-    Arbitrary(Box<PublicKey>, Arc<dyn Fn(&[u8]) -> Vec<u8> + Send + Sync>),
+    //
+    // The public key is kept next to the signer so that getting it stays cheap.
+    Arbitrary(Box<PublicKey>, Arc<dyn Signer>),
 }
 
 impl AnySigner {
@@ -53,10 +109,13 @@ impl AnySigner {
         public_key: Box<PublicKey>,
         signer: F,
     ) -> Self {
-        Self::Arbitrary(
-            public_key,
-            Arc::new(signer).unsize(Coercion!(to dyn Fn(&[u8]) -> Vec<u8> + Send + Sync)),
-        )
+        Self::signer(FnSigner { public_key: *public_key, f: signer })
+    }
+
+    pub(crate) fn signer<S: Signer + 'static>(signer: S) -> Self {
+        let public_key = Box::new(signer.public_key());
+
+        Self::Arbitrary(public_key, Arc::new(signer).unsize(Coercion!(to dyn Signer)))
     }
 }
 
@@ -65,7 +124,7 @@ impl fmt::Debug for AnySigner {
         match self {
             Self::PrivateKey(_) => f.debug_tuple("PrivateKey").field(&"..").finish(),
             Self::Arbitrary(arg0, _) => {
-                f.debug_tuple("Arbitrary").field(arg0).field(&"Fn").finish()
+                f.debug_tuple("Arbitrary").field(arg0).field(&"Signer").finish()
             }
         }
     }
@@ -80,14 +139,45 @@ impl AnySigner {
         }
     }
 
-    pub(crate) fn sign(&self, message: &[u8]) -> (PublicKey, Vec<u8>) {
+    /// Signs every message in `messages`, returning one signature per message.
+    pub(crate) async fn sign_all(&self, messages: &[&[u8]]) -> crate::Result<Vec<Vec<u8>>> {
         match self {
-            AnySigner::PrivateKey(it) => (it.public_key(), it.sign(message)),
-            AnySigner::Arbitrary(public, signer) => {
-                let bytes = signer(message);
+            AnySigner::PrivateKey(it) => {
+                Ok(messages.iter().map(|message| it.sign(message)).collect())
+            }
+            AnySigner::Arbitrary(_, signer) => {
+                let signatures = signer.sign(messages).await?;
 
-                (**public, bytes)
+                if signatures.len() != messages.len() {
+                    return Err(Error::Signature(
+                        format!(
+                            "signer returned {} signatures for {} messages",
+                            signatures.len(),
+                            messages.len()
+                        )
+                        .into(),
+                    ));
+                }
+
+                Ok(signatures)
             }
         }
     }
+
+    pub(crate) async fn sign(&self, message: &[u8]) -> crate::Result<(PublicKey, Vec<u8>)> {
+        let signature = self.sign_all(&[message]).await?.swap_remove(0);
+
+        Ok((self.public_key(), signature))
+    }
+}
+
+/// Runs a signing future to completion from synchronous code.
+///
+/// Synchronous code can't wait for a signer, so this fails if the future isn't immediately ready.
+pub(crate) fn sign_now<T>(fut: impl Future<Output = crate::Result<T>>) -> crate::Result<T> {
+    fut.now_or_never().unwrap_or_else(|| {
+        Err(Error::Signature(
+            "signer didn't finish immediately, which is required by synchronous APIs".into(),
+        ))
+    })
 }
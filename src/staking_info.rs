@@ -11,6 +11,8 @@ use crate::{
 // todo(sr): is this right?
 /// Info related to account/contract staking settings.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct StakingInfo {
     /// If `true`, the contract declines receiving a staking reward. The default value is `false`.
     pub decline_staking_reward: bool,
@@ -18,6 +20,7 @@ pub struct StakingInfo {
     /// The staking period during which either the staking settings for this account or contract changed (such as starting
     /// staking or changing staked_node_id) or the most recent reward was earned, whichever is later. If this account or contract
     /// is not currently staked to a node, then this field is not set.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::option_timestamp"))]
     pub stake_period_start: Option<OffsetDateTime>,
 
     /// The amount in `Hbar` that will be received in the next reward situation.
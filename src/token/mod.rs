@@ -21,9 +21,14 @@
 mod assessed_custom_fee;
 mod custom_fees;
 mod nft_id;
+mod pending_airdrop_id;
+mod pending_airdrop_record;
+mod token_airdrop_transaction;
 mod token_associate_transaction;
 mod token_association;
 mod token_burn_transaction;
+mod token_cancel_airdrop_transaction;
+mod token_claim_airdrop_transaction;
 mod token_create_transaction;
 mod token_delete_transaction;
 mod token_dissociate_transaction;
@@ -39,6 +44,8 @@ mod token_nft_info;
 mod token_nft_info_query;
 mod token_nft_transfer;
 mod token_pause_transaction;
+mod token_reject_flow;
+mod token_reject_transaction;
 mod token_revoke_kyc_transaction;
 mod token_supply_type;
 mod token_type;
@@ -62,6 +69,12 @@ pub use custom_fees::{
     RoyaltyFeeData,
 };
 pub use nft_id::NftId;
+pub use pending_airdrop_id::PendingAirdropId;
+pub use pending_airdrop_record::PendingAirdropRecord;
+pub use token_airdrop_transaction::{
+    TokenAirdropTransaction,
+    TokenAirdropTransactionData,
+};
 pub use token_associate_transaction::{
     TokenAssociateTransaction,
     TokenAssociateTransactionData,
@@ -71,6 +84,14 @@ pub use token_burn_transaction::{
     TokenBurnTransaction,
     TokenBurnTransactionData,
 };
+pub use token_cancel_airdrop_transaction::{
+    TokenCancelAirdropTransaction,
+    TokenCancelAirdropTransactionData,
+};
+pub use token_claim_airdrop_transaction::{
+    TokenClaimAirdropTransaction,
+    TokenClaimAirdropTransactionData,
+};
 pub use token_create_transaction::{
     TokenCreateTransaction,
     TokenCreateTransactionData,
@@ -115,6 +136,11 @@ pub use token_pause_transaction::{
     TokenPauseTransaction,
     TokenPauseTransactionData,
 };
+pub use token_reject_flow::TokenRejectFlow;
+pub use token_reject_transaction::{
+    TokenRejectTransaction,
+    TokenRejectTransactionData,
+};
 pub use token_revoke_kyc_transaction::{
     TokenRevokeKycTransaction,
     TokenRevokeKycTransactionData,
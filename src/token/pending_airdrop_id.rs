@@ -0,0 +1,175 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::services;
+
+use crate::entity_id::ValidateChecksums;
+use crate::ledger_id::RefLedgerId;
+use crate::{
+    AccountId,
+    Error,
+    FromProtobuf,
+    NftId,
+    ToProtobuf,
+    TokenId,
+};
+
+/// A unique identifier for a pending airdrop.
+///
+/// A pending airdrop is created when the receiver of an airdrop is not associated with the token
+/// (and has no free auto-association slots), and exists until it is claimed by the receiver or
+/// cancelled by the sender.
+///
+/// Exactly one of `token_id` (for fungible tokens) or `nft_id` (for non-fungible tokens) is set.
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct PendingAirdropId {
+    /// The account that initiated the airdrop.
+    pub sender_id: AccountId,
+
+    /// The account that will receive the airdropped tokens.
+    pub receiver_id: AccountId,
+
+    /// The fungible token being airdropped.
+    pub token_id: Option<TokenId>,
+
+    /// The non-fungible token being airdropped.
+    pub nft_id: Option<NftId>,
+}
+
+impl PendingAirdropId {
+    /// Create a new `PendingAirdropId` for a pending airdrop of a fungible token.
+    #[must_use]
+    pub fn new_token_id(sender_id: AccountId, receiver_id: AccountId, token_id: TokenId) -> Self {
+        Self { sender_id, receiver_id, token_id: Some(token_id), nft_id: None }
+    }
+
+    /// Create a new `PendingAirdropId` for a pending airdrop of a non-fungible token.
+    #[must_use]
+    pub fn new_nft_id(sender_id: AccountId, receiver_id: AccountId, nft_id: NftId) -> Self {
+        Self { sender_id, receiver_id, token_id: None, nft_id: Some(nft_id) }
+    }
+
+    /// Create a new `PendingAirdropId` from protobuf-encoded `bytes`.
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`] if decoding the bytes fails to produce a valid protobuf.
+    /// - [`Error::FromProtobuf`] if decoding the protobuf fails.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        FromProtobuf::from_bytes(bytes)
+    }
+
+    /// Convert `self` to a protobuf-encoded [`Vec<u8>`].
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        ToProtobuf::to_bytes(self)
+    }
+}
+
+impl FromProtobuf<services::PendingAirdropId> for PendingAirdropId {
+    fn from_protobuf(pb: services::PendingAirdropId) -> crate::Result<Self> {
+        use services::pending_airdrop_id::TokenReference;
+
+        let (token_id, nft_id) = match pb_getf!(pb, token_reference)? {
+            TokenReference::FungibleTokenType(it) => (Some(TokenId::from_protobuf(it)?), None),
+            TokenReference::NonFungibleToken(it) => (None, Some(NftId::from_protobuf(it)?)),
+        };
+
+        Ok(Self {
+            sender_id: AccountId::from_protobuf(pb_getf!(pb, sender_id)?)?,
+            receiver_id: AccountId::from_protobuf(pb_getf!(pb, receiver_id)?)?,
+            token_id,
+            nft_id,
+        })
+    }
+}
+
+impl ToProtobuf for PendingAirdropId {
+    type Protobuf = services::PendingAirdropId;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        use services::pending_airdrop_id::TokenReference;
+
+        let token_reference = match (&self.token_id, &self.nft_id) {
+            (Some(token_id), _) => Some(TokenReference::FungibleTokenType(token_id.to_protobuf())),
+            (None, Some(nft_id)) => Some(TokenReference::NonFungibleToken(nft_id.to_protobuf())),
+            (None, None) => None,
+        };
+
+        services::PendingAirdropId {
+            sender_id: Some(self.sender_id.to_protobuf()),
+            receiver_id: Some(self.receiver_id.to_protobuf()),
+            token_reference,
+        }
+    }
+}
+
+impl ValidateChecksums for PendingAirdropId {
+    fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
+        self.sender_id.validate_checksums(ledger_id)?;
+        self.receiver_id.validate_checksums(ledger_id)?;
+        self.token_id.validate_checksums(ledger_id)?;
+        self.nft_id.validate_checksums(ledger_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AccountId,
+        PendingAirdropId,
+        TokenId,
+    };
+
+    #[test]
+    fn to_from_bytes_token_id() {
+        let id = PendingAirdropId::new_token_id(
+            AccountId::new(0, 0, 5005),
+            AccountId::new(0, 0, 5006),
+            TokenId::new(1, 2, 3),
+        );
+
+        assert_eq!(PendingAirdropId::from_bytes(&id.to_bytes()).unwrap(), id);
+    }
+
+    #[test]
+    fn to_from_bytes_nft_id() {
+        let id = PendingAirdropId::new_nft_id(
+            AccountId::new(0, 0, 5005),
+            AccountId::new(0, 0, 5006),
+            TokenId::new(1, 2, 3).nft(4),
+        );
+
+        assert_eq!(PendingAirdropId::from_bytes(&id.to_bytes()).unwrap(), id);
+    }
+
+    #[test]
+    fn from_bytes_missing_token_reference() {
+        let id = PendingAirdropId {
+            sender_id: AccountId::new(0, 0, 5005),
+            receiver_id: AccountId::new(0, 0, 5006),
+            token_id: None,
+            nft_id: None,
+        };
+
+        assert!(PendingAirdropId::from_bytes(&id.to_bytes()).is_err());
+    }
+}
@@ -0,0 +1,63 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::services;
+
+use crate::{
+    FromProtobuf,
+    PendingAirdropId,
+    ToProtobuf,
+};
+
+/// A record of a new pending airdrop, as reported in a [`TransactionRecord`](crate::TransactionRecord).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct PendingAirdropRecord {
+    /// The unique identifier for the pending airdrop.
+    pub pending_airdrop_id: PendingAirdropId,
+
+    /// The amount of fungible tokens pending, in the lowest denomination of the token.
+    ///
+    /// This is `None` for non-fungible tokens.
+    pub pending_airdrop_value: Option<u64>,
+}
+
+impl FromProtobuf<services::PendingAirdropRecord> for PendingAirdropRecord {
+    fn from_protobuf(pb: services::PendingAirdropRecord) -> crate::Result<Self> {
+        Ok(Self {
+            pending_airdrop_id: PendingAirdropId::from_protobuf(pb_getf!(pb, pending_airdrop_id)?)?,
+            pending_airdrop_value: pb.pending_airdrop_value.map(|it| it.amount),
+        })
+    }
+}
+
+impl ToProtobuf for PendingAirdropRecord {
+    type Protobuf = services::PendingAirdropRecord;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::PendingAirdropRecord {
+            pending_airdrop_id: Some(self.pending_airdrop_id.to_protobuf()),
+            pending_airdrop_value: self
+                .pending_airdrop_value
+                .map(|amount| services::PendingAirdropValue { amount }),
+        }
+    }
+}
@@ -0,0 +1,461 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::HashMap;
+
+use hedera_proto::services;
+use hedera_proto::services::token_service_client::TokenServiceClient;
+use tonic::transport::Channel;
+
+use crate::ledger_id::RefLedgerId;
+use crate::protobuf::FromProtobuf;
+use crate::transaction::{
+    AnyTransactionData,
+    ChunkInfo,
+    ToSchedulableTransactionDataProtobuf,
+    ToTransactionDataProtobuf,
+    TransactionData,
+    TransactionExecute,
+};
+use crate::transfer_transaction::{
+    TokenTransfer,
+    Transfer,
+};
+use crate::{
+    AccountId,
+    BoxGrpcFuture,
+    Error,
+    NftId,
+    ToProtobuf,
+    TokenId,
+    TokenNftTransfer,
+    Transaction,
+    ValidateChecksums,
+};
+
+/// Airdrop one or more tokens to one or more accounts.
+///
+/// This distributes tokens from the balance of one or more sending accounts to the balance of
+/// one or more recipient accounts. Accounts that are already associated with the token (or that
+/// have free auto-association slots) receive the tokens immediately. For all other accounts a
+/// _pending airdrop_ is created, which the recipient can claim with a
+/// [`TokenClaimAirdropTransaction`](crate::TokenClaimAirdropTransaction), or the sender can cancel
+/// with a [`TokenCancelAirdropTransaction`](crate::TokenCancelAirdropTransaction).
+///
+/// - Hbar transfers are not supported.
+/// - Each negative amount is withdrawn from the corresponding account (a sender), and each
+/// positive one is added to the corresponding account (a receiver). The amounts for each token
+/// must sum to zero.
+///
+/// All amounts are in the lowest denomination of the token.
+pub type TokenAirdropTransaction = Transaction<TokenAirdropTransactionData>;
+
+#[derive(Debug, Clone, Default)]
+pub struct TokenAirdropTransactionData {
+    token_transfers: Vec<TokenTransfer>,
+}
+
+impl TokenAirdropTransaction {
+    fn _token_transfer(
+        &mut self,
+        token_id: TokenId,
+        account_id: AccountId,
+        amount: i64,
+        approved: bool,
+        expected_decimals: Option<u32>,
+    ) -> &mut Self {
+        let transfer = Transfer { account_id, amount, is_approval: approved };
+        let data = self.data_mut();
+
+        if let Some(tt) = data.token_transfers.iter_mut().find(|tt| tt.token_id == token_id) {
+            tt.expected_decimals = expected_decimals;
+            tt.transfers.push(transfer);
+        } else {
+            data.token_transfers.push(TokenTransfer {
+                token_id,
+                expected_decimals,
+                nft_transfers: Vec::new(),
+                transfers: vec![transfer],
+            });
+        }
+
+        self
+    }
+
+    /// Add a non-approved token transfer to the airdrop.
+    ///
+    /// `amount` is in the lowest denomination for the token (if the token has `2` decimals this would be `0.01` tokens).
+    pub fn token_transfer(
+        &mut self,
+        token_id: TokenId,
+        account_id: AccountId,
+        amount: i64,
+    ) -> &mut Self {
+        self._token_transfer(token_id, account_id, amount, false, None)
+    }
+
+    /// Add an approved token transfer to the airdrop.
+    ///
+    /// `amount` is in the lowest denomination for the token (if the token has `2` decimals this would be `0.01` tokens).
+    pub fn approved_token_transfer(
+        &mut self,
+        token_id: TokenId,
+        account_id: AccountId,
+        amount: i64,
+    ) -> &mut Self {
+        self._token_transfer(token_id, account_id, amount, true, None)
+    }
+
+    /// Add a non-approved token transfer to the airdrop, ensuring that the token has `expected_decimals` decimals.
+    ///
+    /// `amount` is _still_ in the lowest denomination, however,
+    /// you will get an error if the token has a different amount of decimals than `expected_decimals`.
+    pub fn token_transfer_with_decimals(
+        &mut self,
+        token_id: TokenId,
+        account_id: AccountId,
+        amount: i64,
+        expected_decimals: u32,
+    ) -> &mut Self {
+        self._token_transfer(token_id, account_id, amount, false, Some(expected_decimals))
+    }
+
+    /// Add an approved token transfer to the airdrop, ensuring that the token has `expected_decimals` decimals.
+    ///
+    /// `amount` is _still_ in the lowest denomination, however,
+    /// you will get an error if the token has a different amount of decimals than `expected_decimals`.
+    pub fn approved_token_transfer_with_decimals(
+        &mut self,
+        token_id: TokenId,
+        account_id: AccountId,
+        amount: i64,
+        expected_decimals: u32,
+    ) -> &mut Self {
+        self._token_transfer(token_id, account_id, amount, true, Some(expected_decimals))
+    }
+
+    /// Returns all the token transfers associated with this airdrop.
+    pub fn get_token_transfers(&self) -> HashMap<TokenId, HashMap<AccountId, i64>> {
+        use std::collections::hash_map::Entry;
+
+        self.data().token_transfers.iter().fold(
+            HashMap::with_capacity(self.data().token_transfers.len()),
+            |mut map, transfer| {
+                let iter = transfer.transfers.iter().map(|it| (it.account_id, it.amount));
+                match map.entry(transfer.token_id) {
+                    Entry::Occupied(mut it) => it.get_mut().extend(iter),
+                    Entry::Vacant(it) => {
+                        it.insert(iter.collect());
+                    }
+                }
+
+                map
+            },
+        )
+    }
+
+    /// Returns the decimals associated with each token.
+    pub fn get_token_decimals(&self) -> HashMap<TokenId, u32> {
+        self.data()
+            .token_transfers
+            .iter()
+            .filter_map(|it| it.expected_decimals.map(|decimals| (it.token_id, decimals)))
+            .collect()
+    }
+
+    fn _nft_transfer(
+        &mut self,
+        nft_id: NftId,
+        sender_account_id: AccountId,
+        receiver_account_id: AccountId,
+        approved: bool,
+    ) -> &mut Self {
+        let NftId { token_id, serial } = nft_id;
+        let transfer = TokenNftTransfer {
+            token_id,
+            serial,
+            sender: sender_account_id,
+            receiver: receiver_account_id,
+            is_approved: approved,
+        };
+
+        let data = self.data_mut();
+
+        if let Some(tt) = data.token_transfers.iter_mut().find(|tt| tt.token_id == token_id) {
+            tt.nft_transfers.push(transfer);
+        } else {
+            data.token_transfers.push(TokenTransfer {
+                token_id,
+                expected_decimals: None,
+                transfers: Vec::new(),
+                nft_transfers: vec![transfer],
+            });
+        }
+
+        self
+    }
+
+    /// Add an approved nft transfer to the airdrop.
+    pub fn approved_nft_transfer(
+        &mut self,
+        nft_id: impl Into<NftId>,
+        sender_account_id: AccountId,
+        receiver_account_id: AccountId,
+    ) -> &mut Self {
+        self._nft_transfer(nft_id.into(), sender_account_id, receiver_account_id, true)
+    }
+
+    /// Add a non-approved nft transfer to the airdrop.
+    pub fn nft_transfer(
+        &mut self,
+        nft_id: impl Into<NftId>,
+        sender_account_id: AccountId,
+        receiver_account_id: AccountId,
+    ) -> &mut Self {
+        self._nft_transfer(nft_id.into(), sender_account_id, receiver_account_id, false)
+    }
+
+    /// Returns all the NFT transfers associated with this airdrop.
+    pub fn get_nft_transfers(&self) -> HashMap<TokenId, Vec<TokenNftTransfer>> {
+        self.data()
+            .token_transfers
+            .iter()
+            .map(|it| (it.token_id, it.nft_transfers.clone()))
+            .collect()
+    }
+}
+
+impl TransactionData for TokenAirdropTransactionData {}
+
+impl TransactionExecute for TokenAirdropTransactionData {
+    fn execute(
+        &self,
+        channel: Channel,
+        request: services::Transaction,
+    ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
+        Box::pin(async { TokenServiceClient::new(channel).airdrop_tokens(request).await })
+    }
+}
+
+impl ValidateChecksums for TokenAirdropTransactionData {
+    fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
+        for token_transfer in &self.token_transfers {
+            token_transfer.token_id.validate_checksums(ledger_id)?;
+            for transfer in &token_transfer.transfers {
+                transfer.account_id.validate_checksums(ledger_id)?;
+            }
+            for nft_transfer in &token_transfer.nft_transfers {
+                nft_transfer.sender.validate_checksums(ledger_id)?;
+                nft_transfer.receiver.validate_checksums(ledger_id)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ToTransactionDataProtobuf for TokenAirdropTransactionData {
+    fn to_transaction_data_protobuf(
+        &self,
+        chunk_info: &ChunkInfo,
+    ) -> services::transaction_body::Data {
+        let _ = chunk_info.assert_single_transaction();
+
+        services::transaction_body::Data::TokenAirdrop(self.to_protobuf())
+    }
+}
+
+impl ToSchedulableTransactionDataProtobuf for TokenAirdropTransactionData {
+    fn to_schedulable_transaction_data_protobuf(
+        &self,
+    ) -> services::schedulable_transaction_body::Data {
+        services::schedulable_transaction_body::Data::TokenAirdrop(self.to_protobuf())
+    }
+}
+
+impl From<TokenAirdropTransactionData> for AnyTransactionData {
+    fn from(transaction: TokenAirdropTransactionData) -> Self {
+        Self::TokenAirdrop(transaction)
+    }
+}
+
+impl FromProtobuf<services::TokenAirdropTransactionBody> for TokenAirdropTransactionData {
+    fn from_protobuf(pb: services::TokenAirdropTransactionBody) -> crate::Result<Self> {
+        Ok(Self { token_transfers: Vec::from_protobuf(pb.token_transfers)? })
+    }
+}
+
+impl ToProtobuf for TokenAirdropTransactionData {
+    type Protobuf = services::TokenAirdropTransactionBody;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::TokenAirdropTransactionBody {
+            token_transfers: self.token_transfers.to_protobuf(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use expect_test::expect_file;
+    use hedera_proto::services;
+
+    use crate::protobuf::{
+        FromProtobuf,
+        ToProtobuf,
+    };
+    use crate::token::TokenAirdropTransactionData;
+    use crate::transaction::test_helpers::{
+        check_body,
+        transaction_body,
+        TEST_ACCOUNT_ID,
+        TEST_TOKEN_ID,
+    };
+    use crate::{
+        AccountId,
+        AnyTransaction,
+        TokenAirdropTransaction,
+        TokenId,
+    };
+
+    const TEST_RECEIVER_ID: AccountId = AccountId::new(0, 0, 5008);
+    const TEST_NFT_TOKEN_ID: TokenId = TokenId::new(4, 5, 6);
+
+    fn make_transaction() -> TokenAirdropTransaction {
+        let mut tx = TokenAirdropTransaction::new_for_tests();
+
+        tx.token_transfer_with_decimals(TEST_TOKEN_ID, TEST_ACCOUNT_ID, -800, 3)
+            .token_transfer_with_decimals(TEST_TOKEN_ID, TEST_RECEIVER_ID, 800, 3)
+            .approved_nft_transfer(TEST_NFT_TOKEN_ID.nft(1), TEST_ACCOUNT_ID, TEST_RECEIVER_ID)
+            .nft_transfer(TEST_NFT_TOKEN_ID.nft(2), TEST_ACCOUNT_ID, TEST_RECEIVER_ID)
+            .freeze()
+            .unwrap();
+
+        tx
+    }
+
+    #[test]
+    fn serialize() {
+        let tx = make_transaction();
+
+        let tx = transaction_body(tx);
+
+        let tx = check_body(tx);
+
+        expect_file!["./snapshots/token_airdrop_transaction/serialize.txt"].assert_debug_eq(&tx);
+    }
+
+    #[test]
+    fn to_from_bytes() {
+        let tx = make_transaction();
+
+        let tx2 = AnyTransaction::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+
+        let tx = transaction_body(tx);
+        let tx2 = transaction_body(tx2);
+
+        assert_eq!(tx, tx2);
+    }
+
+    #[test]
+    fn from_proto_body() {
+        let tx = services::TokenAirdropTransactionBody {
+            token_transfers: Vec::from([services::TokenTransferList {
+                token: Some(TEST_TOKEN_ID.to_protobuf()),
+                transfers: Vec::from([services::AccountAmount {
+                    account_id: Some(TEST_ACCOUNT_ID.to_protobuf()),
+                    amount: -800,
+                    is_approval: true,
+                }]),
+                nft_transfers: Vec::new(),
+                expected_decimals: Some(3),
+            }]),
+        };
+
+        let data = TokenAirdropTransactionData::from_protobuf(tx).unwrap();
+
+        assert_eq!(data.token_transfers.len(), 1);
+        assert_eq!(data.token_transfers[0].token_id, TEST_TOKEN_ID);
+        assert_eq!(data.token_transfers[0].expected_decimals, Some(3));
+        assert_eq!(data.token_transfers[0].transfers[0].account_id, TEST_ACCOUNT_ID);
+        assert_eq!(data.token_transfers[0].transfers[0].amount, -800);
+        assert!(data.token_transfers[0].transfers[0].is_approval);
+    }
+
+    #[test]
+    fn get_set_token_transfers() {
+        let mut tx = TokenAirdropTransaction::new();
+        tx.token_transfer(TEST_TOKEN_ID, TEST_ACCOUNT_ID, -1).approved_token_transfer(
+            TEST_TOKEN_ID,
+            TEST_RECEIVER_ID,
+            1,
+        );
+
+        assert_eq!(
+            tx.get_token_transfers(),
+            HashMap::from([(
+                TEST_TOKEN_ID,
+                HashMap::from([(TEST_ACCOUNT_ID, -1), (TEST_RECEIVER_ID, 1)])
+            )])
+        );
+        assert!(tx.get_token_decimals().is_empty());
+    }
+
+    #[test]
+    fn get_set_token_decimals() {
+        let mut tx = TokenAirdropTransaction::new();
+        tx.approved_token_transfer_with_decimals(TEST_TOKEN_ID, TEST_ACCOUNT_ID, -1, 3)
+            .token_transfer_with_decimals(TEST_TOKEN_ID, TEST_RECEIVER_ID, 1, 3);
+
+        assert_eq!(tx.get_token_decimals(), HashMap::from([(TEST_TOKEN_ID, 3)]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_token_transfers_frozen_panic() {
+        make_transaction().token_transfer(TEST_TOKEN_ID, TEST_ACCOUNT_ID, -1);
+    }
+
+    #[test]
+    fn get_set_nft_transfers() {
+        let mut tx = TokenAirdropTransaction::new();
+        tx.nft_transfer(TEST_NFT_TOKEN_ID.nft(1), TEST_ACCOUNT_ID, TEST_RECEIVER_ID);
+
+        let nft_transfers = tx.get_nft_transfers();
+        let nft_transfers = &nft_transfers[&TEST_NFT_TOKEN_ID];
+
+        assert_eq!(nft_transfers.len(), 1);
+        assert_eq!(nft_transfers[0].serial, 1);
+        assert_eq!(nft_transfers[0].sender, TEST_ACCOUNT_ID);
+        assert_eq!(nft_transfers[0].receiver, TEST_RECEIVER_ID);
+        assert!(!nft_transfers[0].is_approved);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_nft_transfers_frozen_panic() {
+        make_transaction().nft_transfer(
+            TEST_NFT_TOKEN_ID.nft(1),
+            TEST_ACCOUNT_ID,
+            TEST_RECEIVER_ID,
+        );
+    }
+}
@@ -29,6 +29,8 @@ use crate::{
 
 /// A token <-> account association.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TokenAssociation {
     /// The token involved in the association.
     pub token_id: TokenId,
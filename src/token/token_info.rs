@@ -43,6 +43,8 @@ use crate::{
 
 /// Response from [`TokenInfoQuery`][crate::TokenInfoQuery].
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TokenInfo {
     /// The ID of the token for which information is requested.
     pub token_id: TokenId,
@@ -94,9 +96,11 @@ pub struct TokenInfo {
     pub auto_renew_account: Option<AccountId>,
 
     /// The interval at which the auto-renew account will be charged to extend the token's expiry
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::option_duration"))]
     pub auto_renew_period: Option<Duration>,
 
     /// The epoch second at which the token will expire
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::option_timestamp"))]
     pub expiration_time: Option<OffsetDateTime>,
 
     /// The memo associated with the token
@@ -124,6 +128,7 @@ pub struct TokenInfo {
     pub ledger_id: LedgerId,
 
     /// Represents the metadata of the token definition.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub metadata: Vec<u8>,
 
     /// The key which can change the metadata of a token
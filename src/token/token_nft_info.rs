@@ -33,6 +33,8 @@ use crate::{
 /// Response from [`TokenNftInfoQuery`][crate::TokenNftInfoQuery].
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TokenNftInfo {
     /// The ID of the NFT.
     pub nft_id: NftId,
@@ -41,9 +43,11 @@ pub struct TokenNftInfo {
     pub account_id: AccountId,
 
     /// Effective consensus timestamp at which the NFT was minted.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::timestamp"))]
     pub creation_time: OffsetDateTime,
 
     /// The unique metadata of the NFT.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub metadata: Vec<u8>,
 
     /// If an allowance is granted for the NFT, its corresponding spender account.
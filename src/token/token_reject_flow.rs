@@ -0,0 +1,334 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use crate::signer::AnySigner;
+use crate::{
+    AccountId,
+    Client,
+    NftId,
+    PrivateKey,
+    PublicKey,
+    Signer,
+    TokenDissociateTransaction,
+    TokenId,
+    TokenRejectTransaction,
+    TransactionResponse,
+};
+
+/// Reject undesired tokens and dissociate from them.
+///
+/// The operation of this flow is as follows:
+/// 1. Execute a [`TokenRejectTransaction`] returning all the given tokens and NFTs to their treasuries.
+/// 2. Execute a [`TokenDissociateTransaction`] dissociating the owner from every token involved in step 1.
+#[derive(Default, Debug)]
+pub struct TokenRejectFlow {
+    owner: Option<AccountId>,
+    token_ids: Vec<TokenId>,
+    nft_ids: Vec<NftId>,
+    node_account_ids: Option<Vec<AccountId>>,
+    freeze_with_client: Option<Client>,
+    signer: Option<AnySigner>,
+}
+
+impl TokenRejectFlow {
+    /// Create a new `TokenRejectFlow`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the account holding the tokens to be rejected.
+    #[must_use]
+    pub fn get_owner(&self) -> Option<AccountId> {
+        self.owner
+    }
+
+    /// Sets the account holding the tokens to be rejected.
+    ///
+    /// Defaults to the operator of the client the flow is executed with.
+    pub fn owner(&mut self, owner: AccountId) -> &mut Self {
+        self.owner = Some(owner);
+
+        self
+    }
+
+    /// Returns the fungible tokens to reject.
+    #[must_use]
+    pub fn get_token_ids(&self) -> &[TokenId] {
+        &self.token_ids
+    }
+
+    /// Sets the fungible tokens to reject.
+    pub fn token_ids(&mut self, token_ids: impl IntoIterator<Item = TokenId>) -> &mut Self {
+        self.token_ids = token_ids.into_iter().collect();
+
+        self
+    }
+
+    /// Adds a fungible token to reject.
+    pub fn add_token_id(&mut self, token_id: TokenId) -> &mut Self {
+        self.token_ids.push(token_id);
+
+        self
+    }
+
+    /// Returns the NFTs to reject.
+    #[must_use]
+    pub fn get_nft_ids(&self) -> &[NftId] {
+        &self.nft_ids
+    }
+
+    /// Sets the NFTs to reject.
+    pub fn nft_ids(&mut self, nft_ids: impl IntoIterator<Item = NftId>) -> &mut Self {
+        self.nft_ids = nft_ids.into_iter().collect();
+
+        self
+    }
+
+    /// Adds an NFT to reject.
+    pub fn add_nft_id(&mut self, nft_id: impl Into<NftId>) -> &mut Self {
+        self.nft_ids.push(nft_id.into());
+
+        self
+    }
+
+    /// Returns the account IDs of the nodes the transactions may be submitted to.
+    #[must_use]
+    pub fn get_node_account_ids(&self) -> Option<&[AccountId]> {
+        self.node_account_ids.as_deref()
+    }
+
+    /// Sets the account IDs of the nodes the transactions may be submitted to.
+    ///
+    /// Defaults to the full list of nodes configured on the client.
+    pub fn node_account_ids(
+        &mut self,
+        node_account_ids: impl IntoIterator<Item = AccountId>,
+    ) -> &mut Self {
+        self.node_account_ids = Some(node_account_ids.into_iter().collect());
+
+        self
+    }
+
+    /// Sets the client to use for freezing the generated transactions.
+    ///
+    /// By default freezing will use the client provided to ``execute``.
+    pub fn freeze_with(&mut self, client: Client) -> &mut Self {
+        self.freeze_with_client = Some(client);
+
+        self
+    }
+
+    /// Sets the signer for use in the generated transactions.
+    ///
+    /// Important: Only *one* signer is allowed.
+    pub fn sign(&mut self, key: PrivateKey) -> &mut Self {
+        self.signer = Some(AnySigner::PrivateKey(key));
+
+        self
+    }
+
+    /// Sets the signer for use in the generated transactions.
+    ///
+    /// Important: Only *one* signer is allowed.
+    pub fn sign_with<F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static>(
+        &mut self,
+        public_key: PublicKey,
+        signer: F,
+    ) -> &mut Self {
+        self.signer = Some(AnySigner::arbitrary(Box::new(public_key), signer));
+
+        self
+    }
+
+    /// Sets the signer for use in the generated transactions.
+    ///
+    /// Important: Only *one* signer is allowed.
+    pub fn sign_with_signer<S: Signer + 'static>(&mut self, signer: S) -> &mut Self {
+        self.signer = Some(AnySigner::signer(signer));
+
+        self
+    }
+
+    /// Generates the required transactions and executes them all.
+    ///
+    /// Returns the response of the [`TokenRejectTransaction`].
+    pub async fn execute(&self, client: &Client) -> crate::Result<TransactionResponse> {
+        self.execute_with_optional_timeout(client, None).await
+    }
+
+    /// Generates the required transactions and executes them all.
+    ///
+    /// Returns the response of the [`TokenRejectTransaction`].
+    pub async fn execute_with_timeout(
+        &self,
+        client: &Client,
+        timeout_per_transaction: std::time::Duration,
+    ) -> crate::Result<TransactionResponse> {
+        self.execute_with_optional_timeout(client, Some(timeout_per_transaction)).await
+    }
+
+    async fn execute_with_optional_timeout(
+        &self,
+        client: &Client,
+        timeout_per_transaction: Option<std::time::Duration>,
+    ) -> crate::Result<TransactionResponse> {
+        // todo: proper error
+        let owner = self
+            .owner
+            .or_else(|| client.get_operator_account_id())
+            .expect("Must call `Client.set_operator` or set an owner to use token reject flow");
+
+        let response = self
+            .make_token_reject_transaction()?
+            .execute_with_optional_timeout(client, timeout_per_transaction)
+            .await?;
+
+        response
+            .get_receipt_query()
+            .execute_with_optional_timeout(client, timeout_per_transaction)
+            .await?;
+
+        self.make_token_dissociate_transaction(owner)?
+            .execute_with_optional_timeout(client, timeout_per_transaction)
+            .await?
+            .get_receipt_query()
+            .execute_with_optional_timeout(client, timeout_per_transaction)
+            .await?;
+
+        Ok(response)
+    }
+
+    fn make_token_reject_transaction(&self) -> crate::Result<TokenRejectTransaction> {
+        let mut tmp = TokenRejectTransaction::new();
+
+        tmp.token_ids(self.token_ids.iter().copied()).nft_ids(self.nft_ids.iter().copied());
+
+        if let Some(owner) = self.owner {
+            tmp.owner(owner);
+        }
+
+        if let Some(node_account_ids) = &self.node_account_ids {
+            tmp.node_account_ids(node_account_ids.iter().copied());
+        }
+
+        if let Some(client) = &self.freeze_with_client {
+            tmp.freeze_with(client)?;
+        }
+
+        if let Some(signer) = &self.signer {
+            tmp.sign_signer(signer.clone());
+        }
+
+        Ok(tmp)
+    }
+
+    fn make_token_dissociate_transaction(
+        &self,
+        owner: AccountId,
+    ) -> crate::Result<TokenDissociateTransaction> {
+        // every token involved in the rejection, without duplicates (several NFTs may share a token).
+        let mut token_ids: Vec<TokenId> = Vec::new();
+        for token_id in
+            self.token_ids.iter().copied().chain(self.nft_ids.iter().map(|it| it.token_id))
+        {
+            if !token_ids.contains(&token_id) {
+                token_ids.push(token_id);
+            }
+        }
+
+        let mut tmp = TokenDissociateTransaction::new();
+
+        tmp.account_id(owner).token_ids(token_ids);
+
+        if let Some(node_account_ids) = &self.node_account_ids {
+            tmp.node_account_ids(node_account_ids.iter().copied());
+        }
+
+        if let Some(client) = &self.freeze_with_client {
+            tmp.freeze_with(client)?;
+        }
+
+        if let Some(signer) = &self.signer {
+            tmp.sign_signer(signer.clone());
+        }
+
+        Ok(tmp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AccountId,
+        TokenId,
+        TokenRejectFlow,
+    };
+
+    #[test]
+    fn get_set_owner() {
+        let mut flow = TokenRejectFlow::new();
+        flow.owner(AccountId::new(0, 0, 5006));
+
+        assert_eq!(flow.get_owner(), Some(AccountId::new(0, 0, 5006)));
+    }
+
+    #[test]
+    fn get_set_token_ids() {
+        const TOKEN_IDS: [TokenId; 2] = [TokenId::new(1, 2, 3), TokenId::new(3, 2, 1)];
+        let mut flow = TokenRejectFlow::new();
+        flow.token_ids(TOKEN_IDS);
+
+        assert_eq!(flow.get_token_ids(), TOKEN_IDS.as_slice());
+    }
+
+    #[test]
+    fn get_set_nft_ids() {
+        let nft_ids = [TokenId::new(1, 2, 3).nft(1), TokenId::new(1, 2, 3).nft(2)];
+        let mut flow = TokenRejectFlow::new();
+        flow.nft_ids(nft_ids);
+
+        assert_eq!(flow.get_nft_ids(), nft_ids.as_slice());
+    }
+
+    #[test]
+    fn get_set_node_account_ids() {
+        const ACCOUNT_IDS: [AccountId; 3] =
+            [AccountId::new(1, 2, 3), AccountId::new(1, 3, 2), AccountId::new(2, 1, 3)];
+        let mut flow = TokenRejectFlow::new();
+        flow.node_account_ids(ACCOUNT_IDS);
+
+        assert_eq!(flow.get_node_account_ids(), Some(ACCOUNT_IDS.as_slice()));
+    }
+
+    #[test]
+    fn dissociates_every_rejected_token_once() {
+        let mut flow = TokenRejectFlow::new();
+        flow.add_token_id(TokenId::new(0, 0, 3))
+            .add_nft_id(TokenId::new(0, 0, 4).nft(1))
+            .add_nft_id(TokenId::new(0, 0, 4).nft(2))
+            .add_nft_id(TokenId::new(0, 0, 3).nft(1));
+
+        let tx = flow.make_token_dissociate_transaction(AccountId::new(0, 0, 5006)).unwrap();
+
+        assert_eq!(tx.get_account_id(), Some(AccountId::new(0, 0, 5006)));
+        assert_eq!(tx.get_token_ids(), &[TokenId::new(0, 0, 3), TokenId::new(0, 0, 4)]);
+    }
+}
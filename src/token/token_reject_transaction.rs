@@ -0,0 +1,368 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::services;
+use hedera_proto::services::token_service_client::TokenServiceClient;
+use tonic::transport::Channel;
+
+use crate::ledger_id::RefLedgerId;
+use crate::protobuf::FromProtobuf;
+use crate::transaction::{
+    AnyTransactionData,
+    ChunkInfo,
+    ToSchedulableTransactionDataProtobuf,
+    ToTransactionDataProtobuf,
+    TransactionData,
+    TransactionExecute,
+};
+use crate::{
+    AccountId,
+    BoxGrpcFuture,
+    Error,
+    NftId,
+    ToProtobuf,
+    TokenId,
+    Transaction,
+    ValidateChecksums,
+};
+
+/// Reject undesired token(s), returning them to the treasury of each token.
+///
+/// Rejected fungible tokens are transferred back in full, and each rejected NFT is returned
+/// individually. The owner's association with the tokens is kept; use
+/// [`TokenRejectFlow`](crate::TokenRejectFlow) to also dissociate from them.
+///
+/// - The transaction must be signed by the owner's key.
+/// - If `owner` is not set, the transaction's payer account is the owner.
+/// - Custom fees are not charged and the token's treasury pays no fees for the transfer.
+/// - If the owner does not hold any of the given tokens or NFTs, the transaction will resolve to
+/// `INSUFFICIENT_TOKEN_BALANCE` or `INVALID_OWNER_ID` respectively.
+pub type TokenRejectTransaction = Transaction<TokenRejectTransactionData>;
+
+#[derive(Debug, Clone, Default)]
+pub struct TokenRejectTransactionData {
+    /// The account holding the tokens to be rejected.
+    owner: Option<AccountId>,
+
+    /// The fungible tokens to reject.
+    token_ids: Vec<TokenId>,
+
+    /// The NFTs to reject.
+    nft_ids: Vec<NftId>,
+}
+
+impl TokenRejectTransaction {
+    /// Returns the account holding the tokens to be rejected.
+    #[must_use]
+    pub fn get_owner(&self) -> Option<AccountId> {
+        self.data().owner
+    }
+
+    /// Sets the account holding the tokens to be rejected.
+    pub fn owner(&mut self, owner: AccountId) -> &mut Self {
+        self.data_mut().owner = Some(owner);
+        self
+    }
+
+    /// Returns the fungible tokens to reject.
+    #[must_use]
+    pub fn get_token_ids(&self) -> &[TokenId] {
+        &self.data().token_ids
+    }
+
+    /// Sets the fungible tokens to reject.
+    pub fn token_ids(&mut self, token_ids: impl IntoIterator<Item = TokenId>) -> &mut Self {
+        self.data_mut().token_ids = token_ids.into_iter().collect();
+        self
+    }
+
+    /// Adds a fungible token to reject.
+    pub fn add_token_id(&mut self, token_id: TokenId) -> &mut Self {
+        self.data_mut().token_ids.push(token_id);
+        self
+    }
+
+    /// Returns the NFTs to reject.
+    #[must_use]
+    pub fn get_nft_ids(&self) -> &[NftId] {
+        &self.data().nft_ids
+    }
+
+    /// Sets the NFTs to reject.
+    pub fn nft_ids(&mut self, nft_ids: impl IntoIterator<Item = NftId>) -> &mut Self {
+        self.data_mut().nft_ids = nft_ids.into_iter().collect();
+        self
+    }
+
+    /// Adds an NFT to reject.
+    pub fn add_nft_id(&mut self, nft_id: impl Into<NftId>) -> &mut Self {
+        self.data_mut().nft_ids.push(nft_id.into());
+        self
+    }
+}
+
+impl TransactionData for TokenRejectTransactionData {}
+
+impl TransactionExecute for TokenRejectTransactionData {
+    fn execute(
+        &self,
+        channel: Channel,
+        request: services::Transaction,
+    ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
+        Box::pin(async { TokenServiceClient::new(channel).reject_token(request).await })
+    }
+}
+
+impl ValidateChecksums for TokenRejectTransactionData {
+    fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
+        self.owner.validate_checksums(ledger_id)?;
+        for token_id in &self.token_ids {
+            token_id.validate_checksums(ledger_id)?;
+        }
+        for nft_id in &self.nft_ids {
+            nft_id.validate_checksums(ledger_id)?;
+        }
+        Ok(())
+    }
+}
+
+impl ToTransactionDataProtobuf for TokenRejectTransactionData {
+    fn to_transaction_data_protobuf(
+        &self,
+        chunk_info: &ChunkInfo,
+    ) -> services::transaction_body::Data {
+        let _ = chunk_info.assert_single_transaction();
+
+        services::transaction_body::Data::TokenReject(self.to_protobuf())
+    }
+}
+
+impl ToSchedulableTransactionDataProtobuf for TokenRejectTransactionData {
+    fn to_schedulable_transaction_data_protobuf(
+        &self,
+    ) -> services::schedulable_transaction_body::Data {
+        services::schedulable_transaction_body::Data::TokenReject(self.to_protobuf())
+    }
+}
+
+impl From<TokenRejectTransactionData> for AnyTransactionData {
+    fn from(transaction: TokenRejectTransactionData) -> Self {
+        Self::TokenReject(transaction)
+    }
+}
+
+impl FromProtobuf<services::TokenRejectTransactionBody> for TokenRejectTransactionData {
+    fn from_protobuf(pb: services::TokenRejectTransactionBody) -> crate::Result<Self> {
+        use services::token_reference::TokenIdentifier;
+
+        let mut token_ids = Vec::new();
+        let mut nft_ids = Vec::new();
+
+        for rejection in pb.rejections {
+            match pb_getf!(rejection, token_identifier)? {
+                TokenIdentifier::FungibleToken(it) => token_ids.push(TokenId::from_protobuf(it)?),
+                TokenIdentifier::Nft(it) => nft_ids.push(NftId::from_protobuf(it)?),
+            }
+        }
+
+        Ok(Self { owner: Option::from_protobuf(pb.owner)?, token_ids, nft_ids })
+    }
+}
+
+impl ToProtobuf for TokenRejectTransactionData {
+    type Protobuf = services::TokenRejectTransactionBody;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        use services::token_reference::TokenIdentifier;
+
+        let fungible =
+            self.token_ids.iter().map(|it| TokenIdentifier::FungibleToken(it.to_protobuf()));
+        let nfts = self.nft_ids.iter().map(|it| TokenIdentifier::Nft(it.to_protobuf()));
+
+        let rejections = fungible
+            .chain(nfts)
+            .map(|it| services::TokenReference { token_identifier: Some(it) })
+            .collect();
+
+        services::TokenRejectTransactionBody { owner: self.owner.to_protobuf(), rejections }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect_file;
+    use hedera_proto::services;
+
+    use crate::protobuf::{
+        FromProtobuf,
+        ToProtobuf,
+    };
+    use crate::token::TokenRejectTransactionData;
+    use crate::transaction::test_helpers::{
+        check_body,
+        transaction_body,
+        TEST_ACCOUNT_ID,
+        TEST_TOKEN_ID,
+    };
+    use crate::{
+        AnyTransaction,
+        NftId,
+        TokenId,
+        TokenRejectTransaction,
+    };
+
+    const TEST_NFT_TOKEN_ID: TokenId = TokenId::new(4, 5, 6);
+
+    fn test_nft_ids() -> [NftId; 2] {
+        [TEST_NFT_TOKEN_ID.nft(1), TEST_NFT_TOKEN_ID.nft(2)]
+    }
+
+    fn make_transaction() -> TokenRejectTransaction {
+        let mut tx = TokenRejectTransaction::new_for_tests();
+
+        tx.owner(TEST_ACCOUNT_ID)
+            .token_ids([TEST_TOKEN_ID])
+            .nft_ids(test_nft_ids())
+            .freeze()
+            .unwrap();
+
+        tx
+    }
+
+    #[test]
+    fn serialize() {
+        let tx = make_transaction();
+
+        let tx = transaction_body(tx);
+
+        let tx = check_body(tx);
+
+        expect_file!["./snapshots/token_reject_transaction/serialize.txt"].assert_debug_eq(&tx);
+    }
+
+    #[test]
+    fn to_from_bytes() {
+        let tx = make_transaction();
+
+        let tx2 = AnyTransaction::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+
+        let tx = transaction_body(tx);
+        let tx2 = transaction_body(tx2);
+
+        assert_eq!(tx, tx2);
+    }
+
+    #[test]
+    fn from_proto_body() {
+        use services::token_reference::TokenIdentifier;
+
+        let tx = services::TokenRejectTransactionBody {
+            owner: Some(TEST_ACCOUNT_ID.to_protobuf()),
+            rejections: Vec::from([
+                services::TokenReference {
+                    token_identifier: Some(TokenIdentifier::Nft(test_nft_ids()[0].to_protobuf())),
+                },
+                services::TokenReference {
+                    token_identifier: Some(TokenIdentifier::FungibleToken(
+                        TEST_TOKEN_ID.to_protobuf(),
+                    )),
+                },
+            ]),
+        };
+
+        let data = TokenRejectTransactionData::from_protobuf(tx).unwrap();
+
+        assert_eq!(data.owner, Some(TEST_ACCOUNT_ID));
+        assert_eq!(data.token_ids, [TEST_TOKEN_ID]);
+        assert_eq!(data.nft_ids, [test_nft_ids()[0]]);
+    }
+
+    #[test]
+    fn get_set_owner() {
+        let mut tx = TokenRejectTransaction::new();
+        tx.owner(TEST_ACCOUNT_ID);
+
+        assert_eq!(tx.get_owner(), Some(TEST_ACCOUNT_ID));
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_owner_frozen_panic() {
+        make_transaction().owner(TEST_ACCOUNT_ID);
+    }
+
+    #[test]
+    fn get_set_token_ids() {
+        let mut tx = TokenRejectTransaction::new();
+        tx.token_ids([TEST_TOKEN_ID]);
+
+        assert_eq!(tx.get_token_ids(), &[TEST_TOKEN_ID]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_token_ids_frozen_panic() {
+        make_transaction().token_ids([TEST_TOKEN_ID]);
+    }
+
+    #[test]
+    fn add_token_id() {
+        let mut tx = TokenRejectTransaction::new();
+        tx.add_token_id(TEST_TOKEN_ID).add_token_id(TEST_NFT_TOKEN_ID);
+
+        assert_eq!(tx.get_token_ids(), &[TEST_TOKEN_ID, TEST_NFT_TOKEN_ID]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_token_id_frozen_panic() {
+        make_transaction().add_token_id(TEST_TOKEN_ID);
+    }
+
+    #[test]
+    fn get_set_nft_ids() {
+        let mut tx = TokenRejectTransaction::new();
+        tx.nft_ids(test_nft_ids());
+
+        assert_eq!(tx.get_nft_ids(), &test_nft_ids());
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_nft_ids_frozen_panic() {
+        make_transaction().nft_ids(test_nft_ids());
+    }
+
+    #[test]
+    fn add_nft_id() {
+        let [first, second] = test_nft_ids();
+
+        let mut tx = TokenRejectTransaction::new();
+        tx.add_nft_id(first).add_nft_id((TEST_NFT_TOKEN_ID, 2));
+
+        assert_eq!(tx.get_nft_ids(), &[first, second]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_nft_id_frozen_panic() {
+        make_transaction().add_nft_id(test_nft_ids()[0]);
+    }
+}
@@ -18,12 +18,15 @@
  * ‍
  */
 
+mod running_hash;
 mod topic_create_transaction;
 mod topic_delete_transaction;
 mod topic_id;
 mod topic_info;
 mod topic_info_query;
 mod topic_message;
+mod topic_message_checkpoint;
+mod topic_message_envelope;
 mod topic_message_query;
 mod topic_message_submit_transaction;
 mod topic_update_transaction;
@@ -37,6 +40,15 @@ pub use topic_info::TopicInfo;
 pub use topic_info_query::TopicInfoQuery;
 pub(crate) use topic_info_query::TopicInfoQueryData;
 pub use topic_message::TopicMessage;
+#[cfg(feature = "serde")]
+pub use topic_message_checkpoint::FileCheckpointStore;
+pub use topic_message_checkpoint::{
+    MemoryCheckpointStore,
+    PendingTopicMessageChunk,
+    TopicMessageCheckpoint,
+    TopicMessageCheckpointStore,
+};
+pub use topic_message_envelope::TopicMessageEnvelope;
 pub use topic_message_query::TopicMessageQuery;
 pub(crate) use topic_message_query::TopicMessageQueryData;
 pub use topic_message_submit_transaction::TopicMessageSubmitTransaction;
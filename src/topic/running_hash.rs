@@ -0,0 +1,331 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use sha2::{
+    Digest,
+    Sha384,
+};
+use time::OffsetDateTime;
+
+use super::topic_message::PbTopicMessageHeader;
+use crate::{
+    AccountId,
+    TopicId,
+    TopicMessageVerifyError,
+};
+
+/// The only running hash version that can be verified.
+const RUNNING_HASH_VERSION: u64 = 3;
+
+/// The length of a (SHA-384) running hash.
+pub(crate) const RUNNING_HASH_LEN: usize = 48;
+
+/// The length of the block that's hashed: the previous running hash, seven `long`s, an `int`, and the message hash.
+const BLOCK_LEN: u8 = (RUNNING_HASH_LEN + 7 * 8 + 4 + RUNNING_HASH_LEN) as u8;
+
+/// Converts `running_hash` to an array, if it's the right length.
+pub(crate) fn running_hash_array(
+    sequence_number: u64,
+    running_hash: &[u8],
+) -> Result<[u8; RUNNING_HASH_LEN], TopicMessageVerifyError> {
+    running_hash.try_into().map_err(|_| TopicMessageVerifyError::InvalidRunningHash {
+        sequence_number,
+        length: running_hash.len(),
+    })
+}
+
+/// Computes the version 3 running hash of a topic after the given message.
+///
+/// The network computes this by writing the fields with a Java `ObjectOutputStream`,
+/// so the input starts with the stream header and a single block data header.
+pub(crate) fn running_hash(
+    previous_running_hash: &[u8; RUNNING_HASH_LEN],
+    payer: AccountId,
+    topic_id: TopicId,
+    consensus_timestamp: OffsetDateTime,
+    sequence_number: u64,
+    message: &[u8],
+) -> [u8; 48] {
+    let mut data = Vec::with_capacity(usize::from(BLOCK_LEN));
+
+    data.extend_from_slice(previous_running_hash);
+    data.extend_from_slice(&RUNNING_HASH_VERSION.to_be_bytes());
+    data.extend_from_slice(&payer.shard.to_be_bytes());
+    data.extend_from_slice(&payer.realm.to_be_bytes());
+    data.extend_from_slice(&payer.num.to_be_bytes());
+    data.extend_from_slice(&topic_id.shard.to_be_bytes());
+    data.extend_from_slice(&topic_id.realm.to_be_bytes());
+    data.extend_from_slice(&topic_id.num.to_be_bytes());
+    data.extend_from_slice(&consensus_timestamp.unix_timestamp().to_be_bytes());
+    data.extend_from_slice(&consensus_timestamp.nanosecond().to_be_bytes());
+    data.extend_from_slice(&sequence_number.to_be_bytes());
+    data.extend_from_slice(&Sha384::digest(message));
+
+    debug_assert_eq!(data.len(), usize::from(BLOCK_LEN));
+
+    let mut hasher = Sha384::new();
+
+    // `STREAM_MAGIC`, `STREAM_VERSION`, `TC_BLOCKDATA`.
+    hasher.update([0xac, 0xed, 0x00, 0x05, 0x77, BLOCK_LEN]);
+    hasher.update(&data);
+
+    hasher.finalize().into()
+}
+
+/// Checks the running hash and sequence number of each message of a topic against the message before it.
+#[derive(Debug, Clone)]
+pub(crate) struct RunningHashVerifier {
+    topic_id: TopicId,
+    // the sequence number and running hash of the last message.
+    previous: Option<(u64, [u8; RUNNING_HASH_LEN])>,
+}
+
+impl RunningHashVerifier {
+    /// Create a verifier for `topic_id`.
+    ///
+    /// If `previous` isn't given, the first message can't be checked and is trusted.
+    pub(crate) fn new(topic_id: TopicId, previous: Option<(u64, [u8; RUNNING_HASH_LEN])>) -> Self {
+        Self { topic_id, previous }
+    }
+
+    pub(crate) fn verify(
+        &mut self,
+        header: &PbTopicMessageHeader,
+        payer: Option<AccountId>,
+    ) -> Result<(), TopicMessageVerifyError> {
+        let sequence_number = header.sequence_number;
+
+        if header.running_hash_version != RUNNING_HASH_VERSION {
+            return Err(TopicMessageVerifyError::UnsupportedVersion {
+                sequence_number,
+                version: header.running_hash_version,
+            });
+        }
+
+        // checked even when the message is trusted, since the next message is checked against it.
+        let actual_running_hash = running_hash_array(sequence_number, &header.running_hash)?;
+
+        if let Some((previous_sequence_number, previous_running_hash)) = &self.previous {
+            let expected = previous_sequence_number
+                .checked_add(1)
+                .ok_or(TopicMessageVerifyError::SequenceNumberOverflow { sequence_number })?;
+
+            if sequence_number != expected {
+                return Err(TopicMessageVerifyError::SequenceGap {
+                    expected,
+                    actual: sequence_number,
+                });
+            }
+
+            let payer = payer.ok_or(TopicMessageVerifyError::UnknownPayer { sequence_number })?;
+
+            let computed = running_hash(
+                previous_running_hash,
+                payer,
+                self.topic_id,
+                header.consensus_timestamp,
+                sequence_number,
+                &header.message,
+            );
+
+            if computed != actual_running_hash {
+                return Err(TopicMessageVerifyError::RunningHashMismatch {
+                    sequence_number,
+                    expected: computed.to_vec(),
+                    actual: header.running_hash.clone(),
+                });
+            }
+        }
+
+        self.previous = Some((sequence_number, actual_running_hash));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+    use time::OffsetDateTime;
+
+    use super::{
+        running_hash,
+        RunningHashVerifier,
+    };
+    use crate::topic::topic_message::PbTopicMessageHeader;
+    use crate::{
+        AccountId,
+        TopicId,
+        TopicMessageVerifyError,
+    };
+
+    const PAYER: AccountId = AccountId::new(0, 0, 5006);
+    const TOPIC_ID: TopicId = TopicId::new(0, 0, 5007);
+
+    fn timestamp(sequence_number: u64) -> OffsetDateTime {
+        OffsetDateTime::from_unix_timestamp(1554158542 + sequence_number as i64).unwrap()
+    }
+
+    // builds a valid chain of messages starting after the topic's initial (all zero) running hash.
+    fn chain(len: u64) -> Vec<PbTopicMessageHeader> {
+        let mut previous = [0; 48];
+
+        (1..=len)
+            .map(|sequence_number| {
+                let message = format!("message {sequence_number}").into_bytes();
+
+                let hash = running_hash(
+                    &previous,
+                    PAYER,
+                    TOPIC_ID,
+                    timestamp(sequence_number),
+                    sequence_number,
+                    &message,
+                );
+
+                previous = hash;
+
+                PbTopicMessageHeader {
+                    consensus_timestamp: timestamp(sequence_number),
+                    sequence_number,
+                    running_hash: hash.to_vec(),
+                    running_hash_version: 3,
+                    message,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn known_hash() {
+        // a regression value for the block layout; the hashes are checked against the network's
+        // by the `topic::message::verify_running_hash` end to end test.
+        let previous: [u8; 48] = std::array::from_fn(|it| it as u8);
+
+        let hash = running_hash(
+            &previous,
+            AccountId::new(0, 0, 4537915),
+            TopicId::new(0, 0, 4538211),
+            OffsetDateTime::from_unix_timestamp(1718023654)
+                .unwrap()
+                .replace_nanosecond(283118003)
+                .unwrap(),
+            2,
+            b"hello, world",
+        );
+
+        expect![[r#"afa6603648a14a68dc26f0ab808fc47f7294364587a10e077a135b34cf0a5537977e907b0fdc44a84d540773c2de18cd"#]]
+            .assert_eq(&hex::encode(hash));
+    }
+
+    #[test]
+    fn valid_chain() {
+        let mut verifier = RunningHashVerifier::new(TOPIC_ID, Some((0, [0; 48])));
+
+        for header in chain(5) {
+            verifier.verify(&header, Some(PAYER)).unwrap();
+        }
+    }
+
+    #[test]
+    fn first_message_is_trusted_without_previous() {
+        let mut verifier = RunningHashVerifier::new(TOPIC_ID, None);
+
+        for header in chain(5).iter().skip(2) {
+            verifier.verify(header, Some(PAYER)).unwrap();
+        }
+    }
+
+    #[test]
+    fn sequence_gap() {
+        let mut verifier = RunningHashVerifier::new(TOPIC_ID, None);
+
+        let chain = chain(5);
+
+        verifier.verify(&chain[0], Some(PAYER)).unwrap();
+
+        assert!(matches!(
+            verifier.verify(&chain[2], Some(PAYER)),
+            Err(TopicMessageVerifyError::SequenceGap { expected: 2, actual: 3 })
+        ));
+    }
+
+    #[test]
+    fn tampered_message() {
+        let mut verifier = RunningHashVerifier::new(TOPIC_ID, None);
+
+        let mut chain = chain(2);
+
+        chain[1].message = b"something else".to_vec();
+
+        verifier.verify(&chain[0], Some(PAYER)).unwrap();
+
+        assert!(matches!(
+            verifier.verify(&chain[1], Some(PAYER)),
+            Err(TopicMessageVerifyError::RunningHashMismatch { sequence_number: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn wrong_payer() {
+        let mut verifier = RunningHashVerifier::new(TOPIC_ID, None);
+
+        let chain = chain(2);
+
+        verifier.verify(&chain[0], Some(PAYER)).unwrap();
+
+        assert!(matches!(
+            verifier.verify(&chain[1], Some(AccountId::new(0, 0, 2))),
+            Err(TopicMessageVerifyError::RunningHashMismatch { .. })
+        ));
+
+        assert!(matches!(
+            verifier.verify(&chain[1], None),
+            Err(TopicMessageVerifyError::UnknownPayer { sequence_number: 2 })
+        ));
+    }
+
+    #[test]
+    fn invalid_running_hash_length() {
+        let mut verifier = RunningHashVerifier::new(TOPIC_ID, None);
+
+        let mut chain = chain(2);
+
+        // even the first message, which is otherwise trusted, must have a running hash of the right length.
+        chain[0].running_hash = vec![0; 100];
+
+        assert!(matches!(
+            verifier.verify(&chain[0], Some(PAYER)),
+            Err(TopicMessageVerifyError::InvalidRunningHash { sequence_number: 1, length: 100 })
+        ));
+    }
+
+    #[test]
+    fn sequence_number_overflow() {
+        let mut verifier = RunningHashVerifier::new(TOPIC_ID, Some((u64::MAX, [0; 48])));
+
+        let chain = chain(1);
+
+        assert!(matches!(
+            verifier.verify(&chain[0], Some(PAYER)),
+            Err(TopicMessageVerifyError::SequenceNumberOverflow { sequence_number: 1 })
+        ));
+    }
+}
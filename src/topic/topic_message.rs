@@ -22,19 +22,26 @@ use std::iter;
 
 use time::OffsetDateTime;
 
-use crate::TransactionId;
+use crate::{
+    TopicMessageCheckpoint,
+    TransactionId,
+};
 
 /// Metadata for an individual chunk
 #[non_exhaustive]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TopicMessageChunk {
     /// The consensus timestamp for this chunk.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::timestamp"))]
     pub consensus_timestamp: OffsetDateTime,
 
     /// How large the content of this specific chunk was.
     pub content_size: usize,
 
     /// The new running hash of the topic that received the message.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub running_hash: Vec<u8>,
 
     /// Sequence number for this chunk.
@@ -44,18 +51,23 @@ pub struct TopicMessageChunk {
 /// Topic message records.
 #[non_exhaustive]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TopicMessage {
     /// The consensus timestamp of the message.
     ///
     /// If there are multiple chunks, this is taken from the *last* chunk.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::timestamp"))]
     pub consensus_timestamp: OffsetDateTime,
 
     /// The content of the message.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub contents: Vec<u8>,
 
     /// The new running hash of the topic that received the message.
     ///
     /// If there are multiple chunks, this is taken from the *last* chunk.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub running_hash: Vec<u8>,
 
     /// Version of the SHA-384 digest used to update the running hash.
@@ -74,6 +86,13 @@ pub struct TopicMessage {
 
     /// The [`TransactionId`] of the first chunk, gets copied to every subsequent chunk in the message.
     pub transaction: Option<TransactionId>,
+
+    /// The position of the subscription right after this message,
+    /// when the query has a [`checkpoint_store`](crate::TopicMessageQuery::checkpoint_store).
+    ///
+    /// Saving it to the store acknowledges this message, see [`TopicMessageQuery::manual_acknowledge`](crate::TopicMessageQuery::manual_acknowledge).
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub checkpoint: Option<TopicMessageCheckpoint>,
 }
 
 impl TopicMessage {
@@ -86,6 +105,7 @@ impl TopicMessage {
             sequence_number: pb.sequence_number,
             chunks: None,
             transaction: None,
+            checkpoint: None,
         }
     }
 
@@ -148,6 +168,7 @@ impl TopicMessage {
             sequence_number: last.header.sequence_number,
             chunks: Some(chunks),
             transaction: Some(last.initial_transaction_id),
+            checkpoint: None,
         }
     }
 }
@@ -0,0 +1,468 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+#[cfg(feature = "serde")]
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+use futures_core::future::BoxFuture;
+use parking_lot::Mutex;
+use time::OffsetDateTime;
+use triomphe::Arc;
+
+use super::topic_message::{
+    PbTopicMessageChunk,
+    PbTopicMessageHeader,
+};
+use crate::error::BoxStdError;
+use crate::{
+    Error,
+    TransactionId,
+};
+
+/// A chunk of a topic message that was received before the rest of the message.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct PendingTopicMessageChunk {
+    /// The [`TransactionId`] of the first chunk of the message.
+    pub initial_transaction_id: TransactionId,
+
+    /// The number of this chunk, starting at `1`.
+    pub number: i32,
+
+    /// The total number of chunks in the message.
+    pub total: i32,
+
+    /// The consensus timestamp of this chunk.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::timestamp"))]
+    pub consensus_timestamp: OffsetDateTime,
+
+    /// The sequence number of this chunk.
+    pub sequence_number: u64,
+
+    /// The running hash of the topic after this chunk.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
+    pub running_hash: Vec<u8>,
+
+    /// Version of the SHA-384 digest used to update the running hash.
+    pub running_hash_version: u64,
+
+    /// The content of this chunk.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
+    pub contents: Vec<u8>,
+}
+
+impl From<&PbTopicMessageChunk> for PendingTopicMessageChunk {
+    fn from(chunk: &PbTopicMessageChunk) -> Self {
+        Self {
+            initial_transaction_id: chunk.initial_transaction_id,
+            number: chunk.number,
+            total: chunk.total,
+            consensus_timestamp: chunk.header.consensus_timestamp,
+            sequence_number: chunk.header.sequence_number,
+            running_hash: chunk.header.running_hash.clone(),
+            running_hash_version: chunk.header.running_hash_version,
+            contents: chunk.header.message.clone(),
+        }
+    }
+}
+
+impl From<PendingTopicMessageChunk> for PbTopicMessageChunk {
+    fn from(chunk: PendingTopicMessageChunk) -> Self {
+        Self {
+            header: PbTopicMessageHeader {
+                consensus_timestamp: chunk.consensus_timestamp,
+                sequence_number: chunk.sequence_number,
+                running_hash: chunk.running_hash,
+                running_hash_version: chunk.running_hash_version,
+                message: chunk.contents,
+            },
+            initial_transaction_id: chunk.initial_transaction_id,
+            number: chunk.number,
+            total: chunk.total,
+        }
+    }
+}
+
+/// The position of a topic subscription, which a [`TopicMessageQuery`](crate::TopicMessageQuery) resumes from.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct TopicMessageCheckpoint {
+    /// The consensus timestamp of the last message (or chunk) that was handled.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::timestamp"))]
+    pub consensus_timestamp: OffsetDateTime,
+
+    /// The sequence number of the last message (or chunk) that was handled.
+    pub sequence_number: u64,
+
+    /// The running hash of the topic after the last message (or chunk) that was handled.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
+    pub running_hash: Vec<u8>,
+
+    /// Chunks of messages that haven't been completely received yet.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub pending_chunks: Vec<PendingTopicMessageChunk>,
+}
+
+/// Persists the [`TopicMessageCheckpoint`] of a topic subscription.
+///
+/// A store is set with [`TopicMessageQuery::checkpoint_store`](crate::TopicMessageQuery::checkpoint_store),
+/// and should only be used by one subscription at a time.
+///
+/// The subscription awaits the returned futures while its stream is polled,
+/// so a store that does blocking I/O should move it off the executor (for example with `tokio::fs`).
+pub trait TopicMessageCheckpointStore: Send + Sync {
+    /// Returns the last saved checkpoint, or `None` if nothing has been saved yet.
+    ///
+    /// # Errors
+    /// Any error is returned from the subscription as [`Error::TopicMessageCheckpoint`].
+    fn load(&self) -> BoxFuture<'_, Result<Option<TopicMessageCheckpoint>, BoxStdError>>;
+
+    /// Saves `checkpoint`, replacing the last saved checkpoint.
+    ///
+    /// # Errors
+    /// Any error is returned from the subscription as [`Error::TopicMessageCheckpoint`].
+    fn save<'a>(
+        &'a self,
+        checkpoint: &'a TopicMessageCheckpoint,
+    ) -> BoxFuture<'a, Result<(), BoxStdError>>;
+}
+
+/// A [`TopicMessageCheckpointStore`] that keeps the checkpoint in memory.
+///
+/// Clones share the same checkpoint.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryCheckpointStore {
+    checkpoint: Arc<Mutex<Option<TopicMessageCheckpoint>>>,
+}
+
+impl MemoryCheckpointStore {
+    /// Create a new, empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the last saved checkpoint.
+    #[must_use]
+    pub fn checkpoint(&self) -> Option<TopicMessageCheckpoint> {
+        self.checkpoint.lock().clone()
+    }
+
+    /// Removes the saved checkpoint, so that the next subscription starts over.
+    pub fn clear(&self) {
+        *self.checkpoint.lock() = None;
+    }
+}
+
+impl TopicMessageCheckpointStore for MemoryCheckpointStore {
+    fn load(&self) -> BoxFuture<'_, Result<Option<TopicMessageCheckpoint>, BoxStdError>> {
+        Box::pin(std::future::ready(Ok(self.checkpoint())))
+    }
+
+    fn save<'a>(
+        &'a self,
+        checkpoint: &'a TopicMessageCheckpoint,
+    ) -> BoxFuture<'a, Result<(), BoxStdError>> {
+        *self.checkpoint.lock() = Some(checkpoint.clone());
+        Box::pin(std::future::ready(Ok(())))
+    }
+}
+
+/// A [`TopicMessageCheckpointStore`] that keeps the checkpoint in a JSON file.
+///
+/// The file is replaced atomically on every save (by writing a temporary file next to it, then renaming it),
+/// so a crash never leaves a partially written checkpoint behind.
+///
+/// Every save waits for the file to be synced to disk, the file I/O runs on tokio's blocking thread pool.
+#[cfg(feature = "serde")]
+#[derive(Clone, Debug)]
+pub struct FileCheckpointStore {
+    path: PathBuf,
+}
+
+#[cfg(feature = "serde")]
+impl FileCheckpointStore {
+    /// Create a store for the file at `path`, which doesn't need to exist yet.
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Returns the path of the checkpoint file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn temporary_path(&self) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(".tmp");
+        path.into()
+    }
+
+    async fn load_file(&self) -> Result<Option<TopicMessageCheckpoint>, BoxStdError> {
+        let json = match tokio::fs::read_to_string(&self.path).await {
+            Ok(json) => json,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(format!("failed to read `{}`: {e}", self.path.display()).into()),
+        };
+
+        Ok(Some(serde_json::from_str(&json)?))
+    }
+
+    async fn save_file(&self, checkpoint: &TopicMessageCheckpoint) -> Result<(), BoxStdError> {
+        use tokio::io::AsyncWriteExt;
+
+        let json = serde_json::to_vec(checkpoint)?;
+
+        let temporary_path = self.temporary_path();
+
+        // the contents have to be on disk before the rename, or a crash could leave an empty file behind.
+        let write = async {
+            let mut file = tokio::fs::File::create(&temporary_path).await?;
+            file.write_all(&json).await?;
+            file.sync_all().await
+        };
+
+        write.await.map_err(|e| format!("failed to write `{}`: {e}", temporary_path.display()))?;
+
+        tokio::fs::rename(&temporary_path, &self.path)
+            .await
+            .map_err(|e| format!("failed to replace `{}`: {e}", self.path.display()))?;
+
+        // the rename itself is only durable once the directory is synced.
+        #[cfg(unix)]
+        {
+            let directory = match self.path.parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => parent,
+                _ => Path::new("."),
+            };
+
+            let sync = async { tokio::fs::File::open(directory).await?.sync_all().await };
+
+            sync.await.map_err(|e| format!("failed to sync `{}`: {e}", directory.display()))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TopicMessageCheckpointStore for FileCheckpointStore {
+    fn load(&self) -> BoxFuture<'_, Result<Option<TopicMessageCheckpoint>, BoxStdError>> {
+        Box::pin(self.load_file())
+    }
+
+    fn save<'a>(
+        &'a self,
+        checkpoint: &'a TopicMessageCheckpoint,
+    ) -> BoxFuture<'a, Result<(), BoxStdError>> {
+        Box::pin(self.save_file(checkpoint))
+    }
+}
+
+/// Tracks the last message a subscription handled, and saves it to a [`TopicMessageCheckpointStore`].
+pub(crate) struct Checkpointer {
+    store: Arc<dyn TopicMessageCheckpointStore>,
+    // the consensus timestamp, sequence number, and running hash of the last handled message.
+    last: Option<(OffsetDateTime, u64, Vec<u8>)>,
+    unsaved: bool,
+}
+
+impl Checkpointer {
+    pub(crate) fn new(
+        store: Arc<dyn TopicMessageCheckpointStore>,
+        checkpoint: Option<&TopicMessageCheckpoint>,
+    ) -> Self {
+        let last = checkpoint
+            .map(|it| (it.consensus_timestamp, it.sequence_number, it.running_hash.clone()));
+
+        Self { store, last, unsaved: false }
+    }
+
+    /// Returns `true` if the message with `sequence_number` was already handled.
+    pub(crate) fn is_handled(&self, sequence_number: u64) -> bool {
+        self.last.as_ref().is_some_and(|(_, last, _)| sequence_number <= *last)
+    }
+
+    pub(crate) fn handled(
+        &mut self,
+        consensus_timestamp: OffsetDateTime,
+        sequence_number: u64,
+        running_hash: Vec<u8>,
+    ) {
+        self.last = Some((consensus_timestamp, sequence_number, running_hash));
+        self.unsaved = true;
+    }
+
+    /// Returns the checkpoint after the last handled message.
+    pub(crate) fn checkpoint(
+        &self,
+        pending_chunks: impl FnOnce() -> Vec<PendingTopicMessageChunk>,
+    ) -> Option<TopicMessageCheckpoint> {
+        let (consensus_timestamp, sequence_number, running_hash) = self.last.as_ref()?;
+
+        Some(TopicMessageCheckpoint {
+            consensus_timestamp: *consensus_timestamp,
+            sequence_number: *sequence_number,
+            running_hash: running_hash.clone(),
+            pending_chunks: pending_chunks(),
+        })
+    }
+
+    /// Returns a future that saves the last handled message, if it hasn't been saved yet.
+    ///
+    /// If the future fails, [`save_failed`](Self::save_failed) has to be called so that it's saved again.
+    pub(crate) fn save(
+        &mut self,
+        pending_chunks: impl FnOnce() -> Vec<PendingTopicMessageChunk>,
+    ) -> Option<BoxFuture<'static, crate::Result<()>>> {
+        if !self.unsaved {
+            return None;
+        }
+
+        let checkpoint = self.checkpoint(pending_chunks)?;
+        let store = self.store.clone();
+
+        self.unsaved = false;
+
+        Some(Box::pin(async move {
+            store.save(&checkpoint).await.map_err(Error::topic_message_checkpoint)
+        }))
+    }
+
+    pub(crate) fn save_failed(&mut self) {
+        self.unsaved = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::OffsetDateTime;
+    use triomphe::Arc;
+    use unsize::{
+        CoerceUnsize,
+        Coercion,
+    };
+
+    use super::{
+        Checkpointer,
+        MemoryCheckpointStore,
+        PendingTopicMessageChunk,
+        TopicMessageCheckpoint,
+        TopicMessageCheckpointStore,
+    };
+    use crate::{
+        AccountId,
+        TransactionId,
+    };
+
+    fn chunk() -> PendingTopicMessageChunk {
+        PendingTopicMessageChunk {
+            initial_transaction_id: TransactionId {
+                account_id: AccountId::new(0, 0, 5005),
+                valid_start: OffsetDateTime::from_unix_timestamp(1_554_158_542).unwrap(),
+                nonce: None,
+                scheduled: false,
+            },
+            number: 1,
+            total: 2,
+            consensus_timestamp: OffsetDateTime::from_unix_timestamp(1_554_158_552).unwrap(),
+            sequence_number: 3,
+            running_hash: vec![1; 48],
+            running_hash_version: 3,
+            contents: b"hello".to_vec(),
+        }
+    }
+
+    #[tokio::test]
+    async fn checkpointer_saves_once() {
+        let store = MemoryCheckpointStore::new();
+        let mut checkpointer = Checkpointer::new(
+            Arc::new(store.clone()).unsize(Coercion!(to dyn TopicMessageCheckpointStore)),
+            None,
+        );
+
+        assert!(checkpointer.save(|| unreachable!()).is_none());
+        assert_eq!(store.checkpoint(), None);
+
+        let timestamp = OffsetDateTime::from_unix_timestamp(1_554_158_552).unwrap();
+        checkpointer.handled(timestamp, 3, vec![1; 48]);
+        checkpointer.save(|| vec![chunk()]).unwrap().await.unwrap();
+
+        let checkpoint = store.checkpoint().unwrap();
+
+        assert_eq!(checkpoint.consensus_timestamp, timestamp);
+        assert_eq!(checkpoint.sequence_number, 3);
+        assert_eq!(checkpoint.pending_chunks, [chunk()]);
+
+        // nothing changed, so nothing is saved.
+        assert!(checkpointer.save(|| unreachable!()).is_none());
+
+        // unless saving failed.
+        checkpointer.save_failed();
+        assert!(checkpointer.save(|| vec![chunk()]).is_some());
+
+        assert!(checkpointer.is_handled(3));
+        assert!(!checkpointer.is_handled(4));
+    }
+
+    #[test]
+    fn chunk_round_trip() {
+        let chunk = chunk();
+
+        let pb = super::PbTopicMessageChunk::from(chunk.clone());
+
+        assert_eq!(PendingTopicMessageChunk::from(&pb), chunk);
+    }
+
+    #[cfg(feature = "serde")]
+    #[tokio::test]
+    async fn file_store() {
+        let path = std::env::temp_dir()
+            .join(format!("hedera-topic-checkpoint-{}.json", std::process::id()));
+
+        let store = super::FileCheckpointStore::new(&path);
+
+        assert_eq!(store.load().await.unwrap(), None);
+
+        let checkpoint = TopicMessageCheckpoint {
+            consensus_timestamp: OffsetDateTime::from_unix_timestamp(1_554_158_552).unwrap(),
+            sequence_number: 3,
+            running_hash: vec![1; 48],
+            pending_chunks: vec![chunk()],
+        };
+
+        store.save(&checkpoint).await.unwrap();
+
+        let loaded = store.load().await.unwrap();
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, Some(checkpoint));
+    }
+}
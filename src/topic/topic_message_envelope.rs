@@ -0,0 +1,404 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use chacha20poly1305::aead::{
+    Aead,
+    Payload,
+};
+use chacha20poly1305::{
+    ChaCha20Poly1305,
+    KeyInit,
+};
+use hkdf::Hkdf;
+use rand::RngCore;
+use sha2::Sha256;
+
+use crate::{
+    Error,
+    PrivateKey,
+    PublicKey,
+};
+
+const MAGIC: &[u8; 4] = b"HCSE";
+const VERSION: u8 = 1;
+
+const KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+const WRAPPED_KEY_LEN: usize = KEY_LEN + TAG_LEN;
+
+const KDF_INFO: &[u8] = b"hedera topic message envelope v1";
+
+const KIND_ED25519: u8 = 0;
+const KIND_ECDSA: u8 = 1;
+
+/// The content key, wrapped for a single recipient.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Recipient {
+    kind: u8,
+    ephemeral_public_key: Vec<u8>,
+    wrapped_key: Vec<u8>,
+}
+
+/// A topic message that's encrypted for a set of recipients.
+///
+/// The message is encrypted with `ChaCha20-Poly1305` under a random content key,
+/// and the content key is wrapped for each recipient with a key agreed with an ephemeral key pair:
+/// ECDH on secp256k1 for ECDSA keys, and X25519 for Ed25519 keys (after converting them).
+///
+/// Most of the time there's no need to use this directly,
+/// instead use [`TopicMessageSubmitTransaction::encrypted_message`](crate::TopicMessageSubmitTransaction::encrypted_message)
+/// and [`TopicMessageQuery::decryption_key`](crate::TopicMessageQuery::decryption_key).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TopicMessageEnvelope {
+    recipients: Vec<Recipient>,
+    nonce: [u8; NONCE_LEN],
+    ciphertext: Vec<u8>,
+}
+
+impl TopicMessageEnvelope {
+    /// Encrypts `message` so that it can be decrypted by the holder of any of `recipients`' private keys.
+    ///
+    /// # Errors
+    /// - [`Error::TopicMessageEnvelope`] if there are no recipients, more than 255 recipients,
+    ///   or a recipient's key can't be used for key agreement.
+    pub fn seal(message: &[u8], recipients: &[PublicKey]) -> crate::Result<Self> {
+        if recipients.is_empty() {
+            return Err(Error::topic_message_envelope("no recipients"));
+        }
+
+        if recipients.len() > usize::from(u8::MAX) {
+            return Err(Error::topic_message_envelope(format!(
+                "too many recipients: {} (the maximum is {})",
+                recipients.len(),
+                u8::MAX
+            )));
+        }
+
+        let mut content_key = [0; KEY_LEN];
+        rand::thread_rng().fill_bytes(&mut content_key);
+
+        let recipients = recipients
+            .iter()
+            .map(|recipient| wrap_key(&content_key, recipient))
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        let mut nonce = [0; NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut nonce);
+
+        let mut envelope = Self { recipients, nonce, ciphertext: Vec::new() };
+
+        // the recipients are authenticated along with the message, so they can't be swapped out.
+        let aad = envelope.header();
+
+        envelope.ciphertext = ChaCha20Poly1305::new(&content_key.into())
+            .encrypt(&nonce.into(), Payload { msg: message, aad: &aad })
+            .map_err(|_| Error::topic_message_envelope("failed to encrypt message"))?;
+
+        Ok(envelope)
+    }
+
+    /// Decrypts this message with `key`.
+    ///
+    /// # Errors
+    /// - [`Error::TopicMessageEnvelope`] if the message wasn't encrypted for `key`, or was tampered with.
+    pub fn open(&self, key: &PrivateKey) -> crate::Result<Vec<u8>> {
+        self.open_for_recipient(key)?
+            .ok_or_else(|| Error::topic_message_envelope("message wasn't encrypted for this key"))
+    }
+
+    /// Decrypts this message with `key`, or returns `None` if it wasn't encrypted for `key`.
+    pub(crate) fn open_for_recipient(&self, key: &PrivateKey) -> crate::Result<Option<Vec<u8>>> {
+        let kind = key_kind(key.is_ecdsa());
+        let public_key = key.public_key().to_bytes_raw();
+
+        let Some(content_key) = self
+            .recipients
+            .iter()
+            .filter(|it| it.kind == kind)
+            .find_map(|it| unwrap_key(it, key, &public_key))
+        else {
+            return Ok(None);
+        };
+
+        let aad = self.header();
+
+        ChaCha20Poly1305::new(&content_key.into())
+            .decrypt(&self.nonce.into(), Payload { msg: &self.ciphertext, aad: &aad })
+            .map(Some)
+            .map_err(|_| Error::topic_message_envelope("message failed authentication"))
+    }
+
+    /// Returns the number of recipients this message was encrypted for.
+    #[must_use]
+    pub fn recipient_count(&self) -> usize {
+        self.recipients.len()
+    }
+
+    /// Returns `true` if `bytes` look like an encrypted message.
+    ///
+    /// This only checks the message's prefix, a plain message could start with the same bytes.
+    #[must_use]
+    pub fn is_envelope(bytes: &[u8]) -> bool {
+        bytes.len() > MAGIC.len() && bytes.starts_with(MAGIC) && bytes[MAGIC.len()] == VERSION
+    }
+
+    /// Parse an encrypted message from bytes.
+    ///
+    /// # Errors
+    /// - [`Error::TopicMessageEnvelope`] if `bytes` isn't an encrypted message.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        fn take<'a>(bytes: &mut &'a [u8], len: usize) -> crate::Result<&'a [u8]> {
+            if bytes.len() < len {
+                return Err(Error::topic_message_envelope("unexpected end of message"));
+            }
+
+            let (head, tail) = bytes.split_at(len);
+            *bytes = tail;
+
+            Ok(head)
+        }
+
+        if !Self::is_envelope(bytes) {
+            return Err(Error::topic_message_envelope("not an encrypted message"));
+        }
+
+        let mut bytes = &bytes[MAGIC.len() + 1..];
+
+        let count = take(&mut bytes, 1)?[0];
+
+        let mut recipients = Vec::with_capacity(usize::from(count));
+
+        for _ in 0..count {
+            let kind = take(&mut bytes, 1)?[0];
+
+            let ephemeral_public_key_len = match kind {
+                KIND_ED25519 => 32,
+                KIND_ECDSA => 33,
+                kind => {
+                    return Err(Error::topic_message_envelope(format!(
+                        "unknown recipient key kind: {kind}"
+                    )))
+                }
+            };
+
+            let ephemeral_public_key = take(&mut bytes, ephemeral_public_key_len)?.to_vec();
+            let wrapped_key = take(&mut bytes, WRAPPED_KEY_LEN)?.to_vec();
+
+            recipients.push(Recipient { kind, ephemeral_public_key, wrapped_key });
+        }
+
+        let mut nonce = [0; NONCE_LEN];
+        nonce.copy_from_slice(take(&mut bytes, NONCE_LEN)?);
+
+        if bytes.len() < TAG_LEN {
+            return Err(Error::topic_message_envelope("unexpected end of message"));
+        }
+
+        Ok(Self { recipients, nonce, ciphertext: bytes.to_vec() })
+    }
+
+    /// Convert this encrypted message to bytes, to be submitted to a topic.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.header();
+        bytes.extend_from_slice(&self.ciphertext);
+        bytes
+    }
+
+    /// Everything before the ciphertext.
+    fn header(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(VERSION);
+
+        // `seal` and `from_bytes` ensure there are at most 255 recipients.
+        bytes.push(self.recipients.len() as u8);
+
+        for recipient in &self.recipients {
+            bytes.push(recipient.kind);
+            bytes.extend_from_slice(&recipient.ephemeral_public_key);
+            bytes.extend_from_slice(&recipient.wrapped_key);
+        }
+
+        bytes.extend_from_slice(&self.nonce);
+
+        bytes
+    }
+}
+
+fn key_kind(is_ecdsa: bool) -> u8 {
+    match is_ecdsa {
+        true => KIND_ECDSA,
+        false => KIND_ED25519,
+    }
+}
+
+/// Derive the key that wraps the content key for `recipient_public_key`.
+fn key_encryption_key(
+    shared_secret: &[u8; 32],
+    ephemeral_public_key: &[u8],
+    recipient_public_key: &[u8],
+) -> ChaCha20Poly1305 {
+    let salt = [ephemeral_public_key, recipient_public_key].concat();
+
+    let mut key = [0; KEY_LEN];
+
+    // 32 bytes is always a valid length for HKDF-SHA256.
+    Hkdf::<Sha256>::new(Some(&salt), shared_secret).expand(KDF_INFO, &mut key).unwrap();
+
+    ChaCha20Poly1305::new(&key.into())
+}
+
+fn wrap_key(content_key: &[u8; KEY_LEN], recipient: &PublicKey) -> crate::Result<Recipient> {
+    let (ephemeral_public_key, shared_secret) =
+        recipient.ephemeral_key_agreement().ok_or_else(|| {
+            Error::topic_message_envelope(format!("`{recipient}` can't be used for encryption"))
+        })?;
+
+    let recipient_public_key = recipient.to_bytes_raw();
+
+    // every key encryption key is only used once, so a constant nonce is fine.
+    let wrapped_key =
+        key_encryption_key(&shared_secret, &ephemeral_public_key, &recipient_public_key)
+            .encrypt(&[0u8; NONCE_LEN].into(), content_key.as_slice())
+            .map_err(|_| Error::topic_message_envelope("failed to wrap content key"))?;
+
+    Ok(Recipient { kind: key_kind(recipient.is_ecdsa()), ephemeral_public_key, wrapped_key })
+}
+
+fn unwrap_key(recipient: &Recipient, key: &PrivateKey, public_key: &[u8]) -> Option<[u8; KEY_LEN]> {
+    let shared_secret = key.key_agreement(&recipient.ephemeral_public_key)?;
+
+    let content_key =
+        key_encryption_key(&shared_secret, &recipient.ephemeral_public_key, public_key)
+            .decrypt(&[0u8; NONCE_LEN].into(), recipient.wrapped_key.as_slice())
+            .ok()?;
+
+    content_key.try_into().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::TopicMessageEnvelope;
+    use crate::{
+        Error,
+        PrivateKey,
+    };
+
+    #[test]
+    fn round_trip_ed25519() {
+        let key = PrivateKey::generate_ed25519();
+
+        let envelope = TopicMessageEnvelope::seal(b"hello", &[key.public_key()]).unwrap();
+
+        assert_eq!(envelope.open(&key).unwrap(), b"hello");
+    }
+
+    #[test]
+    fn round_trip_ecdsa() {
+        let key = PrivateKey::generate_ecdsa();
+
+        let envelope = TopicMessageEnvelope::seal(b"hello", &[key.public_key()]).unwrap();
+
+        assert_eq!(envelope.open(&key).unwrap(), b"hello");
+    }
+
+    #[test]
+    fn many_recipients() {
+        let keys = [
+            PrivateKey::generate_ed25519(),
+            PrivateKey::generate_ecdsa(),
+            PrivateKey::generate_ed25519(),
+        ];
+
+        let public_keys: Vec<_> = keys.iter().map(PrivateKey::public_key).collect();
+
+        let bytes = TopicMessageEnvelope::seal(b"hello", &public_keys).unwrap().to_bytes();
+
+        assert!(TopicMessageEnvelope::is_envelope(&bytes));
+
+        let envelope = TopicMessageEnvelope::from_bytes(&bytes).unwrap();
+
+        assert_eq!(envelope.recipient_count(), 3);
+
+        for key in &keys {
+            assert_eq!(envelope.open(key).unwrap(), b"hello");
+        }
+    }
+
+    #[test]
+    fn wrong_key() {
+        let envelope =
+            TopicMessageEnvelope::seal(b"hello", &[PrivateKey::generate_ed25519().public_key()])
+                .unwrap();
+
+        assert_matches!(
+            envelope.open(&PrivateKey::generate_ed25519()),
+            Err(Error::TopicMessageEnvelope(_))
+        );
+        assert_matches!(
+            envelope.open(&PrivateKey::generate_ecdsa()),
+            Err(Error::TopicMessageEnvelope(_))
+        );
+    }
+
+    #[test]
+    fn tampered() {
+        let key = PrivateKey::generate_ecdsa();
+
+        let mut bytes =
+            TopicMessageEnvelope::seal(b"hello", &[key.public_key()]).unwrap().to_bytes();
+
+        *bytes.last_mut().unwrap() ^= 1;
+
+        let envelope = TopicMessageEnvelope::from_bytes(&bytes).unwrap();
+
+        assert_matches!(envelope.open(&key), Err(Error::TopicMessageEnvelope(_)));
+    }
+
+    #[test]
+    fn no_recipients() {
+        assert_matches!(
+            TopicMessageEnvelope::seal(b"hello", &[]),
+            Err(Error::TopicMessageEnvelope(_))
+        );
+    }
+
+    #[test]
+    fn from_bytes_invalid() {
+        let key = PrivateKey::generate_ed25519();
+
+        let bytes = TopicMessageEnvelope::seal(b"hello", &[key.public_key()]).unwrap().to_bytes();
+
+        assert!(!TopicMessageEnvelope::is_envelope(b"hello"));
+        assert_matches!(
+            TopicMessageEnvelope::from_bytes(b"hello"),
+            Err(Error::TopicMessageEnvelope(_))
+        );
+        assert_matches!(
+            TopicMessageEnvelope::from_bytes(&bytes[..bytes.len() - 20]),
+            Err(Error::TopicMessageEnvelope(_))
+        );
+    }
+}
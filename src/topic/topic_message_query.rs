@@ -19,7 +19,12 @@
  */
 
 use std::collections::HashMap;
+use std::future::{
+    Future,
+    Ready,
+};
 use std::{
+    fmt,
     mem,
     task,
 };
@@ -27,7 +32,12 @@ use std::{
 use futures_core::future::BoxFuture;
 use futures_core::stream::BoxStream;
 use futures_core::Stream;
-use futures_util::TryStreamExt;
+use futures_util::future::Either;
+use futures_util::stream::Once;
+use futures_util::{
+    StreamExt,
+    TryStreamExt,
+};
 use hedera_proto::mirror;
 use hedera_proto::mirror::consensus_service_client::ConsensusServiceClient;
 use hedera_proto::mirror::ConsensusTopicQuery;
@@ -37,11 +47,27 @@ use time::{
 };
 use tonic::transport::Channel;
 use tonic::Response;
+use triomphe::Arc;
+use unsize::{
+    CoerceUnsize,
+    Coercion,
+};
 
+use super::running_hash::{
+    running_hash_array,
+    RunningHashVerifier,
+    RUNNING_HASH_LEN,
+};
 use super::topic_message::{
     PbTopicMessageChunk,
     PbTopicMessageHeader,
 };
+use super::topic_message_checkpoint::{
+    Checkpointer,
+    PendingTopicMessageChunk,
+    TopicMessageCheckpoint,
+    TopicMessageCheckpointStore,
+};
 use crate::mirror_query::{
     AnyMirrorQueryData,
     AnyMirrorQueryMessage,
@@ -49,11 +75,16 @@ use crate::mirror_query::{
 };
 use crate::protobuf::FromProtobuf;
 use crate::{
+    AccountId,
     AnyMirrorQueryResponse,
+    Error,
     MirrorQuery,
+    PrivateKey,
     ToProtobuf,
     TopicId,
     TopicMessage,
+    TopicMessageEnvelope,
+    TopicMessageVerifyError,
     TransactionId,
 };
 
@@ -71,7 +102,7 @@ pub struct TopicMessageQueryContext {
 /// messages for an HCS Topic via a specific (possibly open-ended) time range.
 pub type TopicMessageQuery = MirrorQuery<TopicMessageQueryData>;
 
-#[derive(Debug, Default, Clone)]
+#[derive(Default, Clone)]
 pub struct TopicMessageQueryData {
     /// The topic ID to retrieve messages for.
     topic_id: Option<TopicId>,
@@ -85,14 +116,131 @@ pub struct TopicMessageQueryData {
 
     /// The maximum number of messages to receive before stopping.
     limit: u64,
+
+    /// Whether to check the running hash and sequence number of each message.
+    verify_running_hash: bool,
+
+    /// The sequence number and running hash of the message before the first message.
+    previous_running_hash: Option<(u64, [u8; RUNNING_HASH_LEN])>,
+
+    /// Where the position of the subscription is persisted.
+    checkpoint_store: Option<Arc<dyn TopicMessageCheckpointStore>>,
+
+    /// Whether the consumer saves the checkpoint of each message itself.
+    manual_acknowledge: bool,
+
+    /// The key to decrypt encrypted messages with.
+    decryption_key: Option<PrivateKey>,
+}
+
+impl fmt::Debug for TopicMessageQueryData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TopicMessageQueryData")
+            .field("topic_id", &self.topic_id)
+            .field("start_time", &self.start_time)
+            .field("end_time", &self.end_time)
+            .field("limit", &self.limit)
+            .field("verify_running_hash", &self.verify_running_hash)
+            .field("previous_running_hash", &self.previous_running_hash)
+            .field("checkpoint_store", &self.checkpoint_store.is_some())
+            .field("manual_acknowledge", &self.manual_acknowledge)
+            .field("decryption_key", &self.decryption_key.is_some())
+            .finish()
+    }
 }
 
 impl TopicMessageQueryData {
-    fn map_stream<'a, S>(stream: S) -> impl Stream<Item = crate::Result<TopicMessage>>
+    async fn load_checkpoint(&self) -> crate::Result<Option<TopicMessageCheckpoint>> {
+        match &self.checkpoint_store {
+            Some(store) => store.load().await.map_err(Error::topic_message_checkpoint),
+            None => Ok(None),
+        }
+    }
+
+    fn map_stream<'a, S>(
+        &self,
+        stream: S,
+    ) -> impl Stream<Item = crate::Result<TopicMessage>> + Send + 'a
     where
         S: Stream<Item = crate::Result<mirror::ConsensusTopicResponse>> + Send + 'a,
     {
-        MessagesMapStream { inner: stream, incomplete_messages: HashMap::new() }
+        let data = self.clone();
+
+        // the stream can only be set up once the checkpoint is loaded.
+        futures_util::stream::once(async move {
+            match data.load_checkpoint().await {
+                Ok(checkpoint) => data.messages_map_stream(stream, checkpoint),
+                Err(e) => Either::Left(futures_util::stream::once(std::future::ready(Err(e)))),
+            }
+        })
+        .flatten()
+    }
+
+    fn messages_map_stream<S>(
+        &self,
+        stream: S,
+        checkpoint: Option<TopicMessageCheckpoint>,
+    ) -> Either<Once<Ready<crate::Result<TopicMessage>>>, MessagesMapStream<S>>
+    where
+        S: Stream<Item = crate::Result<mirror::ConsensusTopicResponse>> + Send,
+    {
+        let verifier = match (self.verify_running_hash, self.topic_id) {
+            (true, Some(topic_id)) => {
+                let previous_running_hash = match (&self.previous_running_hash, &checkpoint) {
+                    (Some(previous), _) => Ok(Some(*previous)),
+                    (None, Some(checkpoint)) => {
+                        running_hash_array(checkpoint.sequence_number, &checkpoint.running_hash)
+                            .map(|hash| Some((checkpoint.sequence_number, hash)))
+                    }
+                    (None, None) => Ok(None),
+                };
+
+                match previous_running_hash {
+                    Ok(previous) => Some(RunningHashVerifier::new(topic_id, previous)),
+                    Err(e) => {
+                        return Either::Left(futures_util::stream::once(std::future::ready(Err(
+                            e.into(),
+                        ))));
+                    }
+                }
+            }
+            (true, None) => {
+                return Either::Left(futures_util::stream::once(std::future::ready(Err(
+                    TopicMessageVerifyError::MissingTopicId.into(),
+                ))));
+            }
+            (false, _) => None,
+        };
+
+        let checkpointer = self
+            .checkpoint_store
+            .clone()
+            .map(|store| Checkpointer::new(store, checkpoint.as_ref()));
+
+        // chunks of messages that were incomplete when the checkpoint was saved.
+        let mut incomplete_messages: HashMap<TransactionId, IncompleteMessage> = HashMap::new();
+
+        for chunk in checkpoint.into_iter().flat_map(|it| it.pending_chunks) {
+            let entry = incomplete_messages
+                .entry(chunk.initial_transaction_id)
+                .or_insert_with(IncompleteMessage::new);
+
+            if let IncompleteMessage::Partial(_, messages) = entry {
+                if let Err(index) = messages.binary_search_by_key(&chunk.number, |it| it.number) {
+                    messages.insert(index, chunk.into());
+                }
+            }
+        }
+
+        Either::Right(MessagesMapStream {
+            inner: stream,
+            incomplete_messages,
+            verifier,
+            checkpointer,
+            saving: None,
+            manual_acknowledge: self.manual_acknowledge,
+            decryption_key: self.decryption_key.clone(),
+        })
     }
 }
 
@@ -146,6 +294,130 @@ impl TopicMessageQuery {
         self.data.limit = limit;
         self
     }
+
+    /// Returns whether the running hash of each message will be verified.
+    #[must_use]
+    pub fn get_verify_running_hash(&self) -> bool {
+        self.data.verify_running_hash
+    }
+
+    /// Sets whether to verify the running hash and sequence number of each message.
+    ///
+    /// When enabled, each message's running hash is recomputed from the previous message's,
+    /// which proves that no message was dropped, reordered, or altered by the mirror node.
+    /// A message that fails verification is returned as [`Error::TopicMessageVerify`](crate::Error::TopicMessageVerify).
+    ///
+    /// Unless [`previous_running_hash`](Self::previous_running_hash) is set the first message is trusted as-is.
+    ///
+    /// Only messages that were submitted with chunk info can be verified, because the running hash includes the payer.
+    ///
+    /// The running hash includes the topic ID too, so if no [`topic_id`](Self::topic_id) is set
+    /// the stream fails with [`TopicMessageVerifyError::MissingTopicId`](crate::TopicMessageVerifyError::MissingTopicId).
+    ///
+    /// Defaults to `false`.
+    pub fn verify_running_hash(&mut self, verify: bool) -> &mut Self {
+        self.data.verify_running_hash = verify;
+        self
+    }
+
+    /// Returns the sequence number and running hash of the message before the first message.
+    #[must_use]
+    pub fn get_previous_running_hash(&self) -> Option<(u64, &[u8])> {
+        self.data.previous_running_hash.as_ref().map(|(seq, hash)| (*seq, hash.as_slice()))
+    }
+
+    /// Sets the sequence number and running hash of the message before the first message,
+    /// so that the first message can be verified too.
+    ///
+    /// # Errors
+    /// - [`Error::TopicMessageVerify`](crate::Error::TopicMessageVerify) with
+    ///   [`InvalidRunningHash`](crate::TopicMessageVerifyError::InvalidRunningHash) if `running_hash` isn't 48 bytes long.
+    pub fn previous_running_hash(
+        &mut self,
+        sequence_number: u64,
+        running_hash: impl AsRef<[u8]>,
+    ) -> crate::Result<&mut Self> {
+        let running_hash = running_hash_array(sequence_number, running_hash.as_ref())?;
+
+        self.data.previous_running_hash = Some((sequence_number, running_hash));
+
+        Ok(self)
+    }
+
+    /// Returns the checkpoint store for this query, if one was set.
+    #[must_use]
+    pub fn get_checkpoint_store(&self) -> Option<&dyn TopicMessageCheckpointStore> {
+        self.data.checkpoint_store.as_deref()
+    }
+
+    /// Sets where the position of the subscription is persisted, so that it can be resumed after a restart.
+    ///
+    /// When the store has a checkpoint, the subscription starts right after it (instead of at the [`start_time`](Self::start_time)),
+    /// any chunks of incomplete messages are restored, and the checkpoint's running hash is used to verify the first message
+    /// (unless [`previous_running_hash`](Self::previous_running_hash) is set).
+    ///
+    /// By default a message is saved to the checkpoint once the next message is requested from the stream,
+    /// so delivery is at-least-once: a message that was handled, but not followed by a request for the next one
+    /// before the process stopped, is delivered again. Use [`manual_acknowledge`](Self::manual_acknowledge)
+    /// to control when the checkpoint is saved instead.
+    pub fn checkpoint_store(
+        &mut self,
+        store: impl TopicMessageCheckpointStore + 'static,
+    ) -> &mut Self {
+        self.data.checkpoint_store =
+            Some(Arc::new(store).unsize(Coercion!(to dyn TopicMessageCheckpointStore)));
+        self
+    }
+
+    /// Clears the checkpoint store, so that the position of the subscription isn't persisted.
+    pub fn clear_checkpoint_store(&mut self) -> &mut Self {
+        self.data.checkpoint_store = None;
+        self
+    }
+
+    /// Returns whether the consumer saves the checkpoint of each message itself.
+    #[must_use]
+    pub fn get_manual_acknowledge(&self) -> bool {
+        self.data.manual_acknowledge
+    }
+
+    /// Sets whether the consumer saves the checkpoint of each message itself, instead of the subscription.
+    ///
+    /// When enabled, the subscription only loads the [`checkpoint_store`](Self::checkpoint_store),
+    /// and a message is acknowledged by saving its [`checkpoint`](TopicMessage::checkpoint) to the store.
+    /// Messages after the last acknowledged one are delivered again when the subscription is resumed.
+    ///
+    /// Delivery is exactly-once only if handling a message and saving its checkpoint happen atomically,
+    /// for example with a store that saves the checkpoint in the same database transaction as the message's effects.
+    /// Otherwise a message that was handled, but not yet acknowledged when the process stopped, is delivered again.
+    ///
+    /// Defaults to `false`.
+    pub fn manual_acknowledge(&mut self, manual: bool) -> &mut Self {
+        self.data.manual_acknowledge = manual;
+        self
+    }
+
+    /// Returns the key that encrypted messages are decrypted with.
+    #[must_use]
+    pub fn get_decryption_key(&self) -> Option<&PrivateKey> {
+        self.data.decryption_key.as_ref()
+    }
+
+    /// Sets the key to decrypt messages with, that were submitted with
+    /// [`TopicMessageSubmitTransaction::encrypted_message`](crate::TopicMessageSubmitTransaction::encrypted_message).
+    ///
+    /// Messages that aren't encrypted are returned as-is, and so are messages that only look encrypted
+    /// and messages that were encrypted for other recipients (a topic can carry messages for several),
+    /// which can be recognized with [`TopicMessageEnvelope::is_envelope`](crate::TopicMessageEnvelope::is_envelope).
+    /// Messages that were encrypted for `key` but were tampered with are returned as
+    /// [`Error::TopicMessageEnvelope`](crate::Error::TopicMessageEnvelope), and don't advance the checkpoint.
+    ///
+    /// Finding out whether a message is for `key` takes a key agreement per recipient,
+    /// so anyone who can submit to the topic can make each message cost up to 255 of them.
+    pub fn decryption_key(&mut self, key: PrivateKey) -> &mut Self {
+        self.data.decryption_key = Some(key);
+        self
+    }
 }
 
 impl From<TopicMessageQueryData> for AnyMirrorQueryData {
@@ -167,6 +439,14 @@ impl MirrorRequest for TopicMessageQueryData {
 
     type ItemStream<'a> = BoxStream<'a, crate::Result<TopicMessage>>;
 
+    fn initial_context(&self) -> BoxFuture<'_, crate::Result<Self::Context>> {
+        Box::pin(async {
+            let start_time = self.load_checkpoint().await?.map(|it| it.consensus_timestamp);
+
+            Ok(TopicMessageQueryContext { start_time })
+        })
+    }
+
     fn connect(
         &self,
         context: &Self::Context,
@@ -199,19 +479,19 @@ impl MirrorRequest for TopicMessageQueryData {
         })
     }
 
-    fn make_item_stream<'a, S>(stream: S) -> Self::ItemStream<'a>
+    fn make_item_stream<'a, S>(&self, stream: S) -> Self::ItemStream<'a>
     where
         S: Stream<Item = crate::Result<Self::GrpcItem>> + Send + 'a,
     {
-        Box::pin(Self::map_stream(stream))
+        Box::pin(self.map_stream(stream))
     }
 
-    fn try_collect<'a, S>(stream: S) -> BoxFuture<'a, crate::Result<Self::Response>>
+    fn try_collect<'a, S>(&self, stream: S) -> BoxFuture<'a, crate::Result<Self::Response>>
     where
         S: Stream<Item = crate::Result<Self::GrpcItem>> + Send + 'a,
     {
         // this doesn't reuse the work in `make_item_stream`
-        Box::pin(Self::map_stream(stream).try_collect())
+        Box::pin(self.map_stream(stream).try_collect())
     }
 
     fn update_context(context: &mut Self::Context, item: &Self::GrpcItem) {
@@ -239,6 +519,11 @@ enum IncompleteMessage {
 }
 
 impl IncompleteMessage {
+    fn new() -> Self {
+        // todo: configurable?
+        Self::Partial(OffsetDateTime::now_utc() + time::Duration::minutes(15), Vec::new())
+    }
+
     fn handle_expiry(&mut self) -> &mut Self {
         match self {
             IncompleteMessage::Partial(expiry, _) if *expiry < OffsetDateTime::now_utc() => {
@@ -256,7 +541,47 @@ pin_project_lite::pin_project! {
         #[pin]
         inner: S,
         incomplete_messages: HashMap<TransactionId, IncompleteMessage>,
+        verifier: Option<RunningHashVerifier>,
+        checkpointer: Option<Checkpointer>,
+        // the checkpoint that's being saved, which has to finish before the next message is delivered.
+        saving: Option<BoxFuture<'static, crate::Result<()>>>,
+        manual_acknowledge: bool,
+        decryption_key: Option<PrivateKey>,
+    }
+}
+
+fn decrypt(mut message: TopicMessage, key: &PrivateKey) -> crate::Result<TopicMessage> {
+    // a plain message can start with the same bytes as an encrypted one, so anything that doesn't parse is passed through.
+    let Ok(envelope) = TopicMessageEnvelope::from_bytes(&message.contents) else {
+        return Ok(message);
+    };
+
+    // a topic can carry messages for several recipients, the ones for other keys are passed through.
+    if let Some(contents) = envelope.open_for_recipient(key)? {
+        message.contents = contents;
     }
+
+    Ok(message)
+}
+
+fn pending_chunks(
+    incomplete_messages: &HashMap<TransactionId, IncompleteMessage>,
+) -> Vec<PendingTopicMessageChunk> {
+    let now = OffsetDateTime::now_utc();
+
+    let mut chunks: Vec<_> = incomplete_messages
+        .values()
+        .filter_map(|it| match it {
+            IncompleteMessage::Partial(expiry, chunks) if *expiry >= now => Some(chunks),
+            _ => None,
+        })
+        .flatten()
+        .map(PendingTopicMessageChunk::from)
+        .collect();
+
+    chunks.sort_by_key(|it| it.sequence_number);
+
+    chunks
 }
 
 impl<S> Stream for MessagesMapStream<S>
@@ -273,16 +598,75 @@ where
 
         let mut this = self.project();
 
+        // the next message was requested, so the last one has been handled.
+        if !*this.manual_acknowledge && this.saving.is_none() {
+            if let Some(checkpointer) = this.checkpointer.as_mut() {
+                *this.saving = checkpointer.save(|| pending_chunks(this.incomplete_messages));
+            }
+        }
+
         loop {
+            if let Some(saving) = this.saving.as_mut() {
+                let res = task::ready!(saving.as_mut().poll(cx));
+
+                *this.saving = None;
+
+                if let Err(e) = res {
+                    if let Some(checkpointer) = this.checkpointer.as_mut() {
+                        checkpointer.save_failed();
+                    }
+
+                    return Poll::Ready(Some(Err(e)));
+                }
+            }
+
             let item = match task::ready!(this.inner.as_mut().poll_next(cx)) {
                 Some(Ok(item)) => item,
                 Some(Err(e)) => return Poll::Ready(Some(Err(e))),
                 None => return Poll::Ready(None),
             };
 
-            match filter_map(item, this.incomplete_messages) {
-                Ok(Some(item)) => return Poll::Ready(Some(Ok(item))),
-                Ok(None) => {}
+            let position = match this.checkpointer.as_ref() {
+                // already delivered before the subscription was resumed.
+                Some(checkpointer) if checkpointer.is_handled(item.sequence_number) => continue,
+                Some(_) => item.consensus_timestamp.map(|it| {
+                    (OffsetDateTime::from(it), item.sequence_number, item.running_hash.clone())
+                }),
+                None => None,
+            };
+
+            let res = filter_map(item, this.incomplete_messages, this.verifier.as_mut()).and_then(
+                |item| match (item, this.decryption_key.as_ref()) {
+                    (Some(item), Some(key)) => decrypt(item, key).map(Some),
+                    (item, _) => Ok(item),
+                },
+            );
+
+            // errors don't move the checkpoint, so the message is retried on resume.
+            if let (Ok(_), Some(checkpointer), Some((timestamp, sequence_number, running_hash))) =
+                (&res, this.checkpointer.as_mut(), position)
+            {
+                checkpointer.handled(timestamp, sequence_number, running_hash);
+            }
+
+            match res {
+                Ok(Some(mut item)) => {
+                    if let Some(checkpointer) = this.checkpointer.as_ref() {
+                        item.checkpoint =
+                            checkpointer.checkpoint(|| pending_chunks(this.incomplete_messages));
+                    }
+
+                    return Poll::Ready(Some(Ok(item)));
+                }
+                Ok(None) => {
+                    // nothing was delivered, so the chunk can be saved right away.
+                    if let (false, Some(checkpointer)) =
+                        (*this.manual_acknowledge, this.checkpointer.as_mut())
+                    {
+                        *this.saving =
+                            checkpointer.save(|| pending_chunks(this.incomplete_messages));
+                    }
+                }
                 Err(e) => return Poll::Ready(Some(Err(e))),
             }
         }
@@ -292,6 +676,7 @@ where
 fn filter_map(
     mut item: mirror::ConsensusTopicResponse,
     incomplete_messages: &mut HashMap<TransactionId, IncompleteMessage>,
+    verifier: Option<&mut RunningHashVerifier>,
 ) -> crate::Result<Option<TopicMessage>> {
     let header = PbTopicMessageHeader {
         consensus_timestamp: pb_getf!(item, consensus_timestamp)?.into(),
@@ -301,28 +686,34 @@ fn filter_map(
         message: item.message,
     };
 
-    let item = match item.chunk_info.take() {
-        Some(chunk_info) if chunk_info.total > 1 => PbTopicMessageChunk {
-            header,
-            initial_transaction_id: TransactionId::from_protobuf(pb_getf!(
-                chunk_info,
-                initial_transaction_id
-            )?)?,
-            number: chunk_info.number,
-            total: chunk_info.total,
-        },
+    let chunk_info = item
+        .chunk_info
+        .take()
+        .map(|chunk_info| {
+            let initial_transaction_id =
+                TransactionId::from_protobuf(pb_getf!(chunk_info, initial_transaction_id)?)?;
+
+            crate::Result::Ok((initial_transaction_id, chunk_info.number, chunk_info.total))
+        })
+        .transpose()?;
+
+    if let Some(verifier) = verifier {
+        // every chunk is its own message as far as the running hash is concerned.
+        let payer: Option<AccountId> = chunk_info.as_ref().map(|it| it.0.account_id);
+
+        verifier.verify(&header, payer)?;
+    }
+
+    let item = match chunk_info {
+        Some((initial_transaction_id, number, total)) if total > 1 => {
+            PbTopicMessageChunk { header, initial_transaction_id, number, total }
+        }
         _ => return Ok(Some(TopicMessage::from_single(header))),
     };
 
     let tx_id = item.initial_transaction_id;
 
-    let entry = incomplete_messages.entry(tx_id).or_insert_with(|| {
-        IncompleteMessage::Partial(
-            // todo: configurable?
-            OffsetDateTime::now_utc() + time::Duration::minutes(15),
-            Vec::new(),
-        )
-    });
+    let entry = incomplete_messages.entry(tx_id).or_insert_with(IncompleteMessage::new);
 
     let IncompleteMessage::Partial(_, messages) = entry.handle_expiry() else { return Ok(None) };
 
@@ -351,11 +742,16 @@ fn filter_map(
 
 #[cfg(test)]
 mod tests {
+    use futures_util::StreamExt;
     use time::OffsetDateTime;
 
     use crate::{
+        Error,
+        MemoryCheckpointStore,
+        PrivateKey,
         TopicId,
         TopicMessageQuery,
+        TopicMessageVerifyError,
     };
 
     #[test]
@@ -390,4 +786,70 @@ mod tests {
 
         assert_eq!(query.get_limit(), 1415);
     }
+
+    #[test]
+    fn get_set_checkpoint_store() {
+        let mut query = TopicMessageQuery::new();
+        assert!(query.get_checkpoint_store().is_none());
+
+        query.checkpoint_store(MemoryCheckpointStore::new());
+
+        assert!(query.get_checkpoint_store().is_some());
+
+        query.clear_checkpoint_store();
+
+        assert!(query.get_checkpoint_store().is_none());
+    }
+
+    #[test]
+    fn get_set_manual_acknowledge() {
+        let mut query = TopicMessageQuery::new();
+        assert!(!query.get_manual_acknowledge());
+
+        query.manual_acknowledge(true);
+
+        assert!(query.get_manual_acknowledge());
+    }
+
+    #[test]
+    fn get_set_decryption_key() {
+        let key = PrivateKey::generate_ecdsa();
+
+        let mut query = TopicMessageQuery::new();
+        query.decryption_key(key.clone());
+
+        assert_eq!(query.get_decryption_key().map(PrivateKey::to_bytes), Some(key.to_bytes()));
+    }
+
+    #[test]
+    fn get_set_verify_running_hash() {
+        let mut query = TopicMessageQuery::new();
+        query.verify_running_hash(true).previous_running_hash(5, [1; 48]).unwrap();
+
+        assert!(query.get_verify_running_hash());
+        assert_eq!(query.get_previous_running_hash(), Some((5, &[1; 48][..])));
+
+        assert!(matches!(
+            query.previous_running_hash(6, [1; 100]),
+            Err(Error::TopicMessageVerify(TopicMessageVerifyError::InvalidRunningHash {
+                sequence_number: 6,
+                length: 100
+            }))
+        ));
+
+        assert_eq!(query.get_previous_running_hash(), Some((5, &[1; 48][..])));
+    }
+
+    #[tokio::test]
+    async fn verify_running_hash_without_topic_id() {
+        let mut query = TopicMessageQuery::new();
+        query.verify_running_hash(true);
+
+        let res: Vec<_> = query.data.map_stream(futures_util::stream::empty()).collect().await;
+
+        assert!(matches!(
+            res[..],
+            [Err(Error::TopicMessageVerify(TopicMessageVerifyError::MissingTopicId))]
+        ));
+    }
 }
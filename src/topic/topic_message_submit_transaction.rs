@@ -44,7 +44,9 @@ use crate::transaction::{
 use crate::{
     BoxGrpcFuture,
     Error,
+    PublicKey,
     TopicId,
+    TopicMessageEnvelope,
     Transaction,
     ValidateChecksums,
 };
@@ -92,6 +94,23 @@ impl TopicMessageSubmitTransaction {
         self.data_mut().chunk_data_mut().data = bytes.into();
         self
     }
+
+    /// Sets the message to be submitted, encrypted so that only the holders of `recipients`' private keys can read it.
+    ///
+    /// Subscribers decrypt the message by setting [`TopicMessageQuery::decryption_key`](crate::TopicMessageQuery::decryption_key).
+    ///
+    /// # Errors
+    /// - [`Error::TopicMessageEnvelope`] if the message can't be encrypted for `recipients`,
+    ///   see [`TopicMessageEnvelope::seal`].
+    pub fn encrypted_message(
+        &mut self,
+        message: impl AsRef<[u8]>,
+        recipients: &[PublicKey],
+    ) -> crate::Result<&mut Self> {
+        let envelope = TopicMessageEnvelope::seal(message.as_ref(), recipients)?;
+
+        Ok(self.message(envelope.to_bytes()))
+    }
 }
 
 impl TransactionData for TopicMessageSubmitTransactionData {
@@ -229,7 +248,9 @@ mod tests {
     };
     use crate::{
         AnyTransaction,
+        PrivateKey,
         TopicId,
+        TopicMessageEnvelope,
         TopicMessageSubmitTransaction,
     };
 
@@ -342,6 +363,18 @@ mod tests {
         assert_eq!(tx.get_message(), Some(MESSAGE));
     }
 
+    #[test]
+    fn set_encrypted_message() {
+        let key = PrivateKey::generate_ed25519();
+
+        let mut tx = TopicMessageSubmitTransaction::new();
+        tx.encrypted_message(MESSAGE, &[key.public_key()]).unwrap();
+
+        let envelope = TopicMessageEnvelope::from_bytes(tx.get_message().unwrap()).unwrap();
+
+        assert_eq!(envelope.open(&key).unwrap(), MESSAGE);
+    }
+
     #[test]
     #[should_panic]
     fn get_set_topic_id_frozen_panics() {
@@ -28,6 +28,7 @@ use super::{
 };
 use crate::downcast::DowncastOwned;
 use crate::entity_id::ValidateChecksums;
+use crate::execute_policy::ExecutePolicy;
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::FromProtobuf;
 use crate::transaction::{
@@ -51,6 +52,11 @@ mod data {
         AccountDeleteTransactionData as AccountDelete,
         AccountUpdateTransactionData as AccountUpdate,
     };
+    pub(super) use crate::address_book::{
+        NodeCreateTransactionData as NodeCreate,
+        NodeDeleteTransactionData as NodeDelete,
+        NodeUpdateTransactionData as NodeUpdate,
+    };
     pub(super) use crate::contract::{
         ContractCreateTransactionData as ContractCreate,
         ContractDeleteTransactionData as ContractDelete,
@@ -76,8 +82,11 @@ mod data {
         SystemUndeleteTransactionData as SystemUndelete,
     };
     pub(super) use crate::token::{
+        TokenAirdropTransactionData as TokenAirdrop,
         TokenAssociateTransactionData as TokenAssociate,
         TokenBurnTransactionData as TokenBurn,
+        TokenCancelAirdropTransactionData as TokenCancelAirdrop,
+        TokenClaimAirdropTransactionData as TokenClaimAirdrop,
         TokenCreateTransactionData as TokenCreate,
         TokenDeleteTransactionData as TokenDelete,
         TokenDissociateTransactionData as TokenDissociate,
@@ -86,6 +95,7 @@ mod data {
         TokenGrantKycTransactionData as TokenGrantKyc,
         TokenMintTransactionData as TokenMint,
         TokenPauseTransactionData as TokenPause,
+        TokenRejectTransactionData as TokenReject,
         TokenRevokeKycTransactionData as TokenRevokeKyc,
         TokenUnfreezeTransactionData as TokenUnfreeze,
         TokenUnpauseTransactionData as TokenUnpause,
@@ -150,6 +160,13 @@ pub enum AnyTransactionData {
     Freeze(data::Freeze),
     Ethereum(data::Ethereum),
     TokenUpdateNfts(data::TokenUpdateNfts),
+    TokenAirdrop(data::TokenAirdrop),
+    TokenClaimAirdrop(data::TokenClaimAirdrop),
+    TokenCancelAirdrop(data::TokenCancelAirdrop),
+    TokenReject(data::TokenReject),
+    NodeCreate(data::NodeCreate),
+    NodeUpdate(data::NodeUpdate),
+    NodeDelete(data::NodeDelete),
 }
 
 impl ToTransactionDataProtobuf for AnyTransactionData {
@@ -282,6 +299,17 @@ impl ToTransactionDataProtobuf for AnyTransactionData {
             Self::TokenUpdateNfts(transaction) => {
                 transaction.to_transaction_data_protobuf(chunk_info)
             }
+            Self::TokenAirdrop(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
+            Self::TokenClaimAirdrop(transaction) => {
+                transaction.to_transaction_data_protobuf(chunk_info)
+            }
+            Self::TokenCancelAirdrop(transaction) => {
+                transaction.to_transaction_data_protobuf(chunk_info)
+            }
+            Self::TokenReject(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
+            Self::NodeCreate(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
+            Self::NodeUpdate(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
+            Self::NodeDelete(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
         }
     }
 }
@@ -331,6 +359,13 @@ impl TransactionData for AnyTransactionData {
             Self::ScheduleDelete(transaction) => transaction.default_max_transaction_fee(),
             Self::Ethereum(transaction) => transaction.default_max_transaction_fee(),
             Self::TokenUpdateNfts(transaction) => transaction.default_max_transaction_fee(),
+            Self::TokenAirdrop(transaction) => transaction.default_max_transaction_fee(),
+            Self::TokenClaimAirdrop(transaction) => transaction.default_max_transaction_fee(),
+            Self::TokenCancelAirdrop(transaction) => transaction.default_max_transaction_fee(),
+            Self::TokenReject(transaction) => transaction.default_max_transaction_fee(),
+            Self::NodeCreate(transaction) => transaction.default_max_transaction_fee(),
+            Self::NodeUpdate(transaction) => transaction.default_max_transaction_fee(),
+            Self::NodeDelete(transaction) => transaction.default_max_transaction_fee(),
         }
     }
 
@@ -378,6 +413,13 @@ impl TransactionData for AnyTransactionData {
             Self::ScheduleDelete(it) => it.maybe_chunk_data(),
             Self::Ethereum(it) => it.maybe_chunk_data(),
             Self::TokenUpdateNfts(it) => it.maybe_chunk_data(),
+            Self::TokenAirdrop(it) => it.maybe_chunk_data(),
+            Self::TokenClaimAirdrop(it) => it.maybe_chunk_data(),
+            Self::TokenCancelAirdrop(it) => it.maybe_chunk_data(),
+            Self::TokenReject(it) => it.maybe_chunk_data(),
+            Self::NodeCreate(it) => it.maybe_chunk_data(),
+            Self::NodeUpdate(it) => it.maybe_chunk_data(),
+            Self::NodeDelete(it) => it.maybe_chunk_data(),
         }
     }
 
@@ -425,6 +467,13 @@ impl TransactionData for AnyTransactionData {
             Self::ScheduleDelete(it) => it.wait_for_receipt(),
             Self::Ethereum(it) => it.wait_for_receipt(),
             Self::TokenUpdateNfts(it) => it.wait_for_receipt(),
+            Self::TokenAirdrop(it) => it.wait_for_receipt(),
+            Self::TokenClaimAirdrop(it) => it.wait_for_receipt(),
+            Self::TokenCancelAirdrop(it) => it.wait_for_receipt(),
+            Self::TokenReject(it) => it.wait_for_receipt(),
+            Self::NodeCreate(it) => it.wait_for_receipt(),
+            Self::NodeUpdate(it) => it.wait_for_receipt(),
+            Self::NodeDelete(it) => it.wait_for_receipt(),
         }
     }
 }
@@ -478,6 +527,13 @@ impl TransactionExecute for AnyTransactionData {
             Self::ScheduleDelete(transaction) => transaction.execute(channel, request),
             Self::Ethereum(transaction) => transaction.execute(channel, request),
             Self::TokenUpdateNfts(transaction) => transaction.execute(channel, request),
+            Self::TokenAirdrop(transaction) => transaction.execute(channel, request),
+            Self::TokenClaimAirdrop(transaction) => transaction.execute(channel, request),
+            Self::TokenCancelAirdrop(transaction) => transaction.execute(channel, request),
+            Self::TokenReject(transaction) => transaction.execute(channel, request),
+            Self::NodeCreate(transaction) => transaction.execute(channel, request),
+            Self::NodeUpdate(transaction) => transaction.execute(channel, request),
+            Self::NodeDelete(transaction) => transaction.execute(channel, request),
         }
     }
 }
@@ -529,6 +585,13 @@ impl ValidateChecksums for AnyTransactionData {
             Self::Freeze(transaction) => transaction.validate_checksums(ledger_id),
             Self::Ethereum(transaction) => transaction.validate_checksums(ledger_id),
             Self::TokenUpdateNfts(transaction) => transaction.validate_checksums(ledger_id),
+            Self::TokenAirdrop(transaction) => transaction.validate_checksums(ledger_id),
+            Self::TokenClaimAirdrop(transaction) => transaction.validate_checksums(ledger_id),
+            Self::TokenCancelAirdrop(transaction) => transaction.validate_checksums(ledger_id),
+            Self::TokenReject(transaction) => transaction.validate_checksums(ledger_id),
+            Self::NodeCreate(transaction) => transaction.validate_checksums(ledger_id),
+            Self::NodeUpdate(transaction) => transaction.validate_checksums(ledger_id),
+            Self::NodeDelete(transaction) => transaction.validate_checksums(ledger_id),
         }
     }
 }
@@ -588,6 +651,13 @@ impl FromProtobuf<services::transaction_body::Data> for AnyTransactionData {
             Data::ScheduleDelete(pb) => data::ScheduleDelete::from_protobuf(pb)?.into(),
             Data::ScheduleSign(pb) => data::ScheduleSign::from_protobuf(pb)?.into(),
             Data::TokenUpdateNfts(pb) => data::TokenUpdateNfts::from_protobuf(pb)?.into(),
+            Data::TokenAirdrop(pb) => data::TokenAirdrop::from_protobuf(pb)?.into(),
+            Data::TokenClaimAirdrop(pb) => data::TokenClaimAirdrop::from_protobuf(pb)?.into(),
+            Data::TokenCancelAirdrop(pb) => data::TokenCancelAirdrop::from_protobuf(pb)?.into(),
+            Data::TokenReject(pb) => data::TokenReject::from_protobuf(pb)?.into(),
+            Data::NodeCreate(pb) => data::NodeCreate::from_protobuf(pb)?.into(),
+            Data::NodeUpdate(pb) => data::NodeUpdate::from_protobuf(pb)?.into(),
+            Data::NodeDelete(pb) => data::NodeDelete::from_protobuf(pb)?.into(),
             Data::CryptoAddLiveHash(_) => {
                 return Err(Error::from_protobuf(
                     "unsupported transaction `AddLiveHashTransaction`",
@@ -752,6 +822,27 @@ impl AnyTransactionData {
             ServicesTransactionDataList::TokenUpdateNfts(v) => {
                 data::TokenUpdateNfts::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            ServicesTransactionDataList::TokenAirdrop(v) => {
+                data::TokenAirdrop::from_protobuf(try_into_only_element(v)?)?.into()
+            }
+            ServicesTransactionDataList::TokenClaimAirdrop(v) => {
+                data::TokenClaimAirdrop::from_protobuf(try_into_only_element(v)?)?.into()
+            }
+            ServicesTransactionDataList::TokenCancelAirdrop(v) => {
+                data::TokenCancelAirdrop::from_protobuf(try_into_only_element(v)?)?.into()
+            }
+            ServicesTransactionDataList::TokenReject(v) => {
+                data::TokenReject::from_protobuf(try_into_only_element(v)?)?.into()
+            }
+            ServicesTransactionDataList::NodeCreate(v) => {
+                data::NodeCreate::from_protobuf(try_into_only_element(v)?)?.into()
+            }
+            ServicesTransactionDataList::NodeUpdate(v) => {
+                data::NodeUpdate::from_protobuf(try_into_only_element(v)?)?.into()
+            }
+            ServicesTransactionDataList::NodeDelete(v) => {
+                data::NodeDelete::from_protobuf(try_into_only_element(v)?)?.into()
+            }
         };
 
         Ok(data)
@@ -782,6 +873,7 @@ impl AnyTransaction {
             },
             signers: Vec::new(),
             sources: None,
+            policy: ExecutePolicy::default(),
         })
     }
 }
@@ -831,6 +923,13 @@ enum ServicesTransactionDataList {
     Ethereum(Vec<services::EthereumTransactionBody>),
     UtilPrng(Vec<services::UtilPrngTransactionBody>),
     TokenUpdateNfts(Vec<services::TokenUpdateNftsTransactionBody>),
+    TokenAirdrop(Vec<services::TokenAirdropTransactionBody>),
+    TokenClaimAirdrop(Vec<services::TokenClaimAirdropTransactionBody>),
+    TokenCancelAirdrop(Vec<services::TokenCancelAirdropTransactionBody>),
+    TokenReject(Vec<services::TokenRejectTransactionBody>),
+    NodeCreate(Vec<services::NodeCreateTransactionBody>),
+    NodeUpdate(Vec<services::NodeUpdateTransactionBody>),
+    NodeDelete(Vec<services::NodeDeleteTransactionBody>),
 }
 
 impl FromProtobuf<Vec<services::transaction_body::Data>> for ServicesTransactionDataList {
@@ -895,6 +994,13 @@ impl FromProtobuf<Vec<services::transaction_body::Data>> for ServicesTransaction
             Data::ScheduleSign(it) => Self::ScheduleSign(make_vec(it, len)),
             Data::UtilPrng(it) => Self::UtilPrng(make_vec(it, len)),
             Data::TokenUpdateNfts(it) => Self::TokenUpdateNfts(make_vec(it, len)),
+            Data::TokenAirdrop(it) => Self::TokenAirdrop(make_vec(it, len)),
+            Data::TokenClaimAirdrop(it) => Self::TokenClaimAirdrop(make_vec(it, len)),
+            Data::TokenCancelAirdrop(it) => Self::TokenCancelAirdrop(make_vec(it, len)),
+            Data::TokenReject(it) => Self::TokenReject(make_vec(it, len)),
+            Data::NodeCreate(it) => Self::NodeCreate(make_vec(it, len)),
+            Data::NodeUpdate(it) => Self::NodeUpdate(make_vec(it, len)),
+            Data::NodeDelete(it) => Self::NodeDelete(make_vec(it, len)),
             Data::CryptoAddLiveHash(_) => {
                 return Err(Error::from_protobuf(
                     "unsupported transaction `AddLiveHashTransaction`",
@@ -969,6 +1075,13 @@ impl FromProtobuf<Vec<services::transaction_body::Data>> for ServicesTransaction
                 (Self::ScheduleDelete(v), Data::ScheduleDelete(element)) => v.push(element),
                 (Self::Ethereum(v), Data::EthereumTransaction(element)) => v.push(element),
                 (Self::UtilPrng(v), Data::UtilPrng(element)) => v.push(element),
+                (Self::TokenAirdrop(v), Data::TokenAirdrop(element)) => v.push(element),
+                (Self::TokenClaimAirdrop(v), Data::TokenClaimAirdrop(element)) => v.push(element),
+                (Self::TokenCancelAirdrop(v), Data::TokenCancelAirdrop(element)) => v.push(element),
+                (Self::TokenReject(v), Data::TokenReject(element)) => v.push(element),
+                (Self::NodeCreate(v), Data::NodeCreate(element)) => v.push(element),
+                (Self::NodeUpdate(v), Data::NodeUpdate(element)) => v.push(element),
+                (Self::NodeDelete(v), Data::NodeDelete(element)) => v.push(element),
                 _ => return Err(Error::from_protobuf("mismatched transaction types")),
             }
         }
@@ -1065,4 +1178,11 @@ impl_downcast_any! {
     Freeze,
     Ethereum,
     TokenUpdateNfts,
+    TokenAirdrop,
+    TokenClaimAirdrop,
+    TokenCancelAirdrop,
+    TokenReject,
+    NodeCreate,
+    NodeUpdate,
+    NodeDelete,
 }
@@ -1,6 +1,7 @@
 use std::cmp;
 use std::num::NonZeroUsize;
 
+use futures_core::future::BoxFuture;
 use hedera_proto::services;
 use tonic::transport::Channel;
 
@@ -10,6 +11,7 @@ use super::{
 };
 use crate::entity_id::ValidateChecksums;
 use crate::execute::Execute;
+use crate::execute_policy::ExecutePolicy;
 use crate::ledger_id::RefLedgerId;
 use crate::{
     AccountId,
@@ -154,22 +156,30 @@ where
         self.transaction.regenerate_transaction_id()
     }
 
+    fn execute_policy(&self) -> Option<&ExecutePolicy> {
+        self.transaction.execute_policy()
+    }
+
     fn operator_account_id(&self) -> Option<&AccountId> {
         self.transaction.operator_account_id()
     }
 
-    fn make_request(
-        &self,
-        transaction_id: Option<&TransactionId>,
+    fn make_request<'b>(
+        &'b self,
+        transaction_id: Option<&'b TransactionId>,
         node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
+    ) -> BoxFuture<'b, crate::Result<(Self::GrpcRequest, Self::Context)>> {
         assert!(self.transaction.is_frozen());
 
-        Ok(self.transaction.make_request_inner(&ChunkInfo::initial(
-            self.total_chunks,
-            *transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?,
-            node_account_id,
-        )))
+        Box::pin(async move {
+            self.transaction
+                .make_request_inner(&ChunkInfo::initial(
+                    self.total_chunks,
+                    *transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?,
+                    node_account_id,
+                ))
+                .await
+        })
     }
 
     fn execute(
@@ -258,20 +268,29 @@ where
         self.transaction.regenerate_transaction_id()
     }
 
-    fn make_request(
-        &self,
-        transaction_id: Option<&TransactionId>,
+    fn execute_policy(&self) -> Option<&ExecutePolicy> {
+        self.transaction.execute_policy()
+    }
+
+    fn make_request<'b>(
+        &'b self,
+        transaction_id: Option<&'b TransactionId>,
         node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
+    ) -> BoxFuture<'b, crate::Result<(Self::GrpcRequest, Self::Context)>> {
         assert!(self.transaction.is_frozen());
 
-        Ok(self.transaction.make_request_inner(&ChunkInfo {
-            total: self.total_chunks,
-            current: self.current_chunk,
-            initial_transaction_id: self.initial_transaction_id,
-            node_account_id,
-            current_transaction_id: *transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?,
-        }))
+        Box::pin(async move {
+            self.transaction
+                .make_request_inner(&ChunkInfo {
+                    total: self.total_chunks,
+                    current: self.current_chunk,
+                    initial_transaction_id: self.initial_transaction_id,
+                    node_account_id,
+                    current_transaction_id: *transaction_id
+                        .ok_or(Error::NoPayerAccountOrTransactionId)?,
+                })
+                .await
+        })
     }
 
     fn execute(
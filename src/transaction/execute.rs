@@ -21,6 +21,7 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
 
+use futures_core::future::BoxFuture;
 use hedera_proto::services;
 use prost::Message;
 use tonic::transport::Channel;
@@ -32,6 +33,7 @@ use super::{
     TransactionSources,
 };
 use crate::execute::Execute;
+use crate::execute_policy::ExecutePolicy;
 use crate::ledger_id::RefLedgerId;
 use crate::transaction::any::AnyTransactionData;
 use crate::transaction::protobuf::ToTransactionDataProtobuf;
@@ -85,45 +87,72 @@ impl<D> Transaction<D>
 where
     D: TransactionData + ToTransactionDataProtobuf,
 {
-    pub(crate) fn make_request_inner(
+    pub(crate) async fn make_request_inner(
         &self,
         chunk_info: &ChunkInfo,
-    ) -> (services::Transaction, TransactionHash) {
+    ) -> crate::Result<(services::Transaction, TransactionHash)> {
+        let mut transactions = self.make_requests_inner(std::slice::from_ref(chunk_info)).await?;
+
+        Ok(transactions.swap_remove(0))
+    }
+
+    /// Signs the transaction body for each of `chunk_infos`, asking each signer for all of its signatures at once.
+    pub(crate) async fn make_requests_inner(
+        &self,
+        chunk_infos: &[ChunkInfo],
+    ) -> crate::Result<Vec<(services::Transaction, TransactionHash)>> {
         assert!(self.is_frozen());
 
-        let transaction_body = self.to_transaction_body_protobuf(chunk_info);
+        let bodies: Vec<_> = chunk_infos
+            .iter()
+            .map(|it| self.to_transaction_body_protobuf(it).encode_to_vec())
+            .collect();
 
-        let body_bytes = transaction_body.encode_to_vec();
+        let mut signatures: Vec<Vec<services::SignaturePair>> = vec![Vec::new(); bodies.len()];
 
-        let mut signatures = Vec::with_capacity(1 + self.signers.len());
+        let operator = self.body.operator.as_ref().map(|it| &it.signer);
 
-        if let Some(operator) = &self.body.operator {
-            let operator_signature = operator.sign(&body_bytes);
+        {
+            let messages: Vec<_> = bodies.iter().map(Vec::as_slice).collect();
 
-            signatures.push(SignaturePair::from(operator_signature).into_protobuf());
-        }
+            for signer in operator.into_iter().chain(&self.signers) {
+                let public_key = signer.public_key();
+                let public_key_bytes = public_key.to_bytes();
 
-        for signer in &self.signers {
-            let public_key = signer.public_key().to_bytes();
-            if !signatures.iter().any(|it| public_key.starts_with(&it.pub_key_prefix)) {
-                let signature = signer.sign(&body_bytes);
-                signatures.push(SignaturePair::from(signature).into_protobuf());
+                // every body has the same signatures, so checking the first is enough.
+                if signatures.first().is_some_and(|it| {
+                    it.iter().any(|it| public_key_bytes.starts_with(&it.pub_key_prefix))
+                }) {
+                    continue;
+                }
+
+                for (sig_pairs, signature) in
+                    signatures.iter_mut().zip(signer.sign_all(&messages).await?)
+                {
+                    sig_pairs.push(SignaturePair::from((public_key, signature)).into_protobuf());
+                }
             }
         }
 
-        let signed_transaction = services::SignedTransaction {
-            body_bytes,
-            sig_map: Some(services::SignatureMap { sig_pair: signatures }),
-        };
+        let transactions = bodies.into_iter().zip(signatures).map(|(body_bytes, sig_pair)| {
+            let signed_transaction = services::SignedTransaction {
+                body_bytes,
+                sig_map: Some(services::SignatureMap { sig_pair }),
+            };
+
+            let signed_transaction_bytes = signed_transaction.encode_to_vec();
 
-        let signed_transaction_bytes = signed_transaction.encode_to_vec();
+            let transaction_hash = TransactionHash::new(&signed_transaction_bytes);
 
-        let transaction_hash = TransactionHash::new(&signed_transaction_bytes);
+            let transaction = services::Transaction {
+                signed_transaction_bytes,
+                ..services::Transaction::default()
+            };
 
-        let transaction =
-            services::Transaction { signed_transaction_bytes, ..services::Transaction::default() };
+            (transaction, transaction_hash)
+        });
 
-        (transaction, transaction_hash)
+        Ok(transactions.collect())
     }
 }
 
@@ -191,17 +220,24 @@ where
         self.body.regenerate_transaction_id
     }
 
-    fn make_request(
-        &self,
-        transaction_id: Option<&TransactionId>,
+    fn execute_policy(&self) -> Option<&ExecutePolicy> {
+        Some(&self.policy)
+    }
+
+    fn make_request<'a>(
+        &'a self,
+        transaction_id: Option<&'a TransactionId>,
         node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
+    ) -> BoxFuture<'a, crate::Result<(Self::GrpcRequest, Self::Context)>> {
         assert!(self.is_frozen());
 
-        Ok(self.make_request_inner(&ChunkInfo::single(
-            *transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?,
-            node_account_id,
-        )))
+        Box::pin(async move {
+            self.make_request_inner(&ChunkInfo::single(
+                *transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?,
+                node_account_id,
+            ))
+            .await
+        })
     }
 
     fn execute(
@@ -298,11 +334,14 @@ pub(crate) struct SourceTransaction<'a, D> {
 }
 
 impl<'a, D> SourceTransaction<'a, D> {
-    pub(crate) fn new(transaction: &'a Transaction<D>, sources: &'a TransactionSources) -> Self {
+    pub(crate) async fn new(
+        transaction: &'a Transaction<D>,
+        sources: &'a TransactionSources,
+    ) -> crate::Result<Self> {
         // fixme: be way more lazy.
-        let sources = sources.sign_with(&transaction.signers);
+        let sources = sources.sign_with(&transaction.signers).await?;
 
-        Self { inner: transaction, sources }
+        Ok(Self { inner: transaction, sources })
     }
 
     pub(crate) async fn execute(
@@ -394,15 +433,22 @@ impl<'a, D: TransactionExecute> Execute for SourceTransactionExecuteView<'a, D>
         Some(false)
     }
 
-    fn make_request(
-        &self,
-        transaction_id: Option<&TransactionId>,
+    fn execute_policy(&self) -> Option<&ExecutePolicy> {
+        self.transaction.execute_policy()
+    }
+
+    fn make_request<'b>(
+        &'b self,
+        transaction_id: Option<&'b TransactionId>,
         node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
+    ) -> BoxFuture<'b, crate::Result<(Self::GrpcRequest, Self::Context)>> {
         debug_assert_eq!(transaction_id, self.transaction_id().as_ref());
 
         let index = *self.indecies_by_node_id.get(&node_account_id).unwrap();
-        Ok((self.chunk.transactions()[index].clone(), self.chunk.transaction_hashes()[index]))
+        let request =
+            (self.chunk.transactions()[index].clone(), self.chunk.transaction_hashes()[index]);
+
+        Box::pin(std::future::ready(Ok(request)))
     }
 
     fn execute(
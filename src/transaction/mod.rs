@@ -34,16 +34,23 @@ use triomphe::Arc;
 
 use crate::downcast::DowncastOwned;
 use crate::execute::execute;
-use crate::signer::AnySigner;
+use crate::execute_policy::ExecutePolicy;
+use crate::signer::{
+    sign_now,
+    AnySigner,
+};
 use crate::{
     AccountId,
     Client,
     Error,
     Hbar,
+    NodeSelector,
     Operator,
     PrivateKey,
     PublicKey,
+    RetryPolicy,
     ScheduleCreateTransaction,
+    Signer,
     TransactionHash,
     TransactionId,
     TransactionResponse,
@@ -54,6 +61,7 @@ mod any;
 mod chunked;
 mod execute;
 mod protobuf;
+mod signature_collection;
 mod source;
 #[cfg(test)]
 mod tests;
@@ -74,9 +82,10 @@ pub(crate) use protobuf::{
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
 };
+pub use signature_collection::SignatureCollection;
 pub(crate) use source::TransactionSources;
 
-const DEFAULT_TRANSACTION_VALID_DURATION: Duration = Duration::seconds(120);
+pub(crate) const DEFAULT_TRANSACTION_VALID_DURATION: Duration = Duration::seconds(120);
 
 /// A transaction that can be executed on the Hedera network.
 #[derive(Clone)]
@@ -86,6 +95,8 @@ pub struct Transaction<D> {
     signers: Vec<AnySigner>,
 
     sources: Option<TransactionSources>,
+
+    policy: ExecutePolicy,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -128,6 +139,7 @@ where
             },
             signers: Vec::new(),
             sources: None,
+            policy: ExecutePolicy::default(),
         }
     }
 }
@@ -157,7 +169,7 @@ where
 
 impl<D> Transaction<D> {
     pub(crate) fn from_parts(body: TransactionBody<D>, signers: Vec<AnySigner>) -> Self {
-        Self { body, signers, sources: None }
+        Self { body, signers, sources: None, policy: ExecutePolicy::default() }
     }
 
     pub(crate) fn is_frozen(&self) -> bool {
@@ -172,8 +184,8 @@ impl<D> Transaction<D> {
         self.sources.as_ref()
     }
 
-    fn signed_sources(&self) -> Option<Cow<'_, TransactionSources>> {
-        self.sources().map(|it| it.sign_with(&self.signers))
+    fn signed_sources(&self) -> crate::Result<Option<Cow<'_, TransactionSources>>> {
+        self.sources().map(|it| sign_now(it.sign_with(&self.signers))).transpose()
     }
 
     /// # Panics
@@ -305,6 +317,13 @@ impl<D> Transaction<D> {
         self.sign_signer(AnySigner::arbitrary(Box::new(public_key), signer))
     }
 
+    /// Sign the transaction with a [`Signer`].
+    ///
+    /// The signer is only asked for signatures when they're needed, such as when the transaction is executed.
+    pub fn sign_with_signer<S: Signer + 'static>(&mut self, signer: S) -> &mut Self {
+        self.sign_signer(AnySigner::signer(signer))
+    }
+
     pub(crate) fn sign_signer(&mut self, signer: AnySigner) -> &mut Self {
         // We're _supposed_ to require frozen here, but really there's no reason I can think of to do that.
 
@@ -316,6 +335,40 @@ impl<D> Transaction<D> {
         self.signers.push(signer);
         self
     }
+
+    /// Returns the retry policy for this transaction, if one was set.
+    ///
+    /// When `None`, the client's [retry policy](Client::set_retry_policy) is used.
+    #[must_use]
+    pub fn get_retry_policy(&self) -> Option<&dyn RetryPolicy> {
+        self.policy.retry_policy.as_deref()
+    }
+
+    /// Sets the retry policy for this transaction, overriding the client's.
+    ///
+    /// Unlike most settings, this can be changed after the transaction is frozen.
+    pub fn retry_policy(&mut self, policy: impl RetryPolicy + 'static) -> &mut Self {
+        self.policy.retry_policy = Some(ExecutePolicy::arc_retry_policy(policy));
+
+        self
+    }
+
+    /// Returns the node selector for this transaction, if one was set.
+    ///
+    /// When `None`, the client's [node selector](Client::set_node_selector) is used.
+    #[must_use]
+    pub fn get_node_selector(&self) -> Option<&dyn NodeSelector> {
+        self.policy.node_selector.as_deref()
+    }
+
+    /// Sets the node selector for this transaction, overriding the client's.
+    ///
+    /// Unlike most settings, this can be changed after the transaction is frozen.
+    pub fn node_selector(&mut self, selector: impl NodeSelector + 'static) -> &mut Self {
+        self.policy.node_selector = Some(ExecutePolicy::arc_node_selector(selector));
+
+        self
+    }
 }
 
 impl<D: ChunkedTransactionData> Transaction<D> {
@@ -396,6 +449,14 @@ impl<D: ValidateChecksums> Transaction<D> {
         if self.is_frozen() {
             return Ok(self);
         }
+
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!(
+            "freeze_with",
+            request_type = crate::request_hook::request_type_name(std::any::type_name::<D>()),
+        )
+        .entered();
+
         let client: Option<&Client> = client.into();
 
         let node_account_ids = match &self.body.node_account_ids {
@@ -487,7 +548,7 @@ impl<D: TransactionExecute> Transaction<D> {
         let used_chunks = self.data().maybe_chunk_data().map_or(1, ChunkData::used_chunks);
         let node_account_ids = self.body.node_account_ids.as_deref().unwrap();
 
-        let mut transaction_list = Vec::with_capacity(used_chunks * node_account_ids.len());
+        let mut chunk_infos = Vec::with_capacity(used_chunks * node_account_ids.len());
 
         // Note: This ordering is *important*,
         // there's no documentation for it but `TransactionList` is sorted by chunk number,
@@ -507,17 +568,19 @@ impl<D: TransactionExecute> Transaction<D> {
                     node_account_id,
                 };
 
-                transaction_list.push(self.make_request_inner(&chunk_info).0);
+                chunk_infos.push(chunk_info);
             }
         }
 
-        Ok(transaction_list)
+        let transaction_list = sign_now(self.make_requests_inner(&chunk_infos))?;
+
+        Ok(transaction_list.into_iter().map(|it| it.0).collect())
     }
 
     pub(crate) fn make_sources(&self) -> crate::Result<Cow<'_, TransactionSources>> {
         assert!(self.is_frozen());
 
-        if let Some(sources) = self.signed_sources() {
+        if let Some(sources) = self.signed_sources()? {
             return Ok(sources);
         }
 
@@ -528,6 +591,7 @@ impl<D: TransactionExecute> Transaction<D> {
     ///
     /// # Errors
     /// - If `freeze_with` wasn't called with an operator.
+    /// - [`Error::Signature`] if a signer fails, or can't sign synchronously.
     ///
     /// # Panics
     /// - If `!self.is_frozen()`.
@@ -535,13 +599,13 @@ impl<D: TransactionExecute> Transaction<D> {
         assert!(self.is_frozen(), "Transaction must be frozen to call `to_bytes`");
 
         let transaction_list = self
-            .signed_sources()
+            .signed_sources()?
             .map_or_else(|| self.make_transaction_list(), |it| Ok(it.transactions().to_vec()))?;
 
         Ok(hedera_proto::sdk::TransactionList { transaction_list }.encode_to_vec())
     }
 
-    pub(crate) fn add_signature_signer(&mut self, signer: &AnySigner) -> Vec<u8> {
+    pub(crate) fn add_signature_signer(&mut self, signer: &AnySigner) -> crate::Result<Vec<u8>> {
         assert!(self.is_frozen());
 
         // note: the following pair of cheecks are for more detailed panic messages
@@ -561,34 +625,53 @@ impl<D: TransactionExecute> Transaction<D> {
             );
         }
 
-        let sources = self.make_sources().unwrap();
+        let sources = self.make_sources()?;
 
         // this is the only check that is for correctness rather than debugability.
         assert!(sources.transactions().len() == 1);
 
-        let sources = sources.sign_with(std::slice::from_ref(signer));
+        let sources = sign_now(sources.sign_with(std::slice::from_ref(signer)))?;
 
         // hack: I don't care about perf here.
-        let ret = signer.sign(&sources.signed_transactions()[0].body_bytes);
+        let ret = sign_now(signer.sign(&sources.signed_transactions()[0].body_bytes))?;
 
         // if we have a `Cow::Borrowed` that'd mean there was no modification
         if let Cow::Owned(sources) = sources {
             self.sources = Some(sources);
         }
 
-        ret.1
+        Ok(ret.1)
     }
 
-    // todo: should this return `Result<&mut Self>`?
     /// Adds a signature directly to `self`.
     ///
     /// Only use this as a last resort.
     ///
     /// This forcibly disables transaction ID regeneration.
+    ///
+    /// # Panics
+    /// - If a [`Signer`] added to `self` fails, or can't sign synchronously;
+    ///   use [`try_add_signature`](Self::try_add_signature) to handle that instead.
     pub fn add_signature(&mut self, pk: PublicKey, signature: Vec<u8>) -> &mut Self {
-        self.add_signature_signer(&AnySigner::arbitrary(Box::new(pk), move |_| signature.clone()));
+        self.try_add_signature(pk, signature).unwrap()
+    }
 
-        self
+    /// Adds a signature directly to `self`.
+    ///
+    /// Only use this as a last resort.
+    ///
+    /// This forcibly disables transaction ID regeneration.
+    ///
+    /// # Errors
+    /// - [`Error::Signature`] if a [`Signer`] added to `self` fails, or can't sign synchronously.
+    pub fn try_add_signature(
+        &mut self,
+        pk: PublicKey,
+        signature: Vec<u8>,
+    ) -> crate::Result<&mut Self> {
+        self.add_signature_signer(&AnySigner::arbitrary(Box::new(pk), move |_| signature.clone()))?;
+
+        Ok(self)
     }
 
     /// # Panics
@@ -618,6 +701,7 @@ impl<D: TransactionExecute> Transaction<D> {
     /// # Errors
     /// - [`Error::NoPayerAccountOrTransactionId`]
     ///     if `freeze_with` wasn't called with an operator and no transaction ID was set.
+    /// - [`Error::Signature`] if a signer fails, or can't sign synchronously.
     ///
     /// # Panics
     /// - If `!self.is_frozen()`.
@@ -645,6 +729,7 @@ impl<D: TransactionExecute> Transaction<D> {
     /// # Errors
     /// - [`Error::NoPayerAccountOrTransactionId`]
     ///     if `freeze_with` wasn't called with an operator and no transaction ID was set.
+    /// - [`Error::Signature`] if a signer fails, or can't sign synchronously.
     ///
     /// # Panics
     /// - If `!self.is_frozen()`.
@@ -707,6 +792,7 @@ where
 
         if let Some(sources) = self.sources() {
             return self::execute::SourceTransaction::new(self, sources)
+                .await?
                 .execute(client, timeout)
                 .await;
         }
@@ -827,6 +913,7 @@ where
         // fixme: dedup this with `execute_with_optional_timeout`
         if let Some(sources) = self.sources() {
             return self::execute::SourceTransaction::new(self, sources)
+                .await?
                 .execute_all(client, timeout_per_chunk)
                 .await;
         }
@@ -1019,7 +1106,7 @@ where
     D: DowncastOwned<U>,
 {
     fn downcast_owned(self) -> Result<Transaction<U>, Self> {
-        let Self { body, signers, sources } = self;
+        let Self { body, signers, sources, policy } = self;
         let TransactionBody {
             data,
             node_account_ids,
@@ -1048,6 +1135,7 @@ where
                 },
                 signers,
                 sources,
+                policy,
             }),
 
             Err(data) => Err(Self {
@@ -1064,6 +1152,7 @@ where
                 },
                 signers,
                 sources,
+                policy,
             }),
         }
     }
@@ -1088,6 +1177,7 @@ pub(crate) mod test_helpers {
         TokenId,
         Transaction,
         TransactionId,
+        TransferTransaction,
     };
 
     impl<D: Default> Transaction<D> {
@@ -1160,6 +1250,19 @@ pub(crate) mod test_helpers {
         data.unwrap()
     }
 
+    /// Returns an unfrozen [`Transaction::new_for_tests`] transferring `amount` from `sender` to `receiver`.
+    pub(crate) fn make_transfer(
+        sender: AccountId,
+        receiver: AccountId,
+        amount: Hbar,
+    ) -> TransferTransaction {
+        let mut tx = TransferTransaction::new_for_tests();
+
+        tx.hbar_transfer(sender, -amount).hbar_transfer(receiver, amount);
+
+        tx
+    }
+
     pub(crate) fn unused_private_key() -> PrivateKey {
         "302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e10".parse().unwrap()
     }
@@ -0,0 +1,438 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::borrow::Cow;
+
+use hedera_proto::services;
+use prost::bytes::{
+    Buf,
+    BufMut,
+};
+use prost::encoding::{
+    message,
+    DecodeContext,
+    WireType,
+};
+use prost::{
+    DecodeError,
+    Message,
+};
+use time::OffsetDateTime;
+
+use super::{
+    TransactionExecute,
+    TransactionSources,
+    DEFAULT_TRANSACTION_VALID_DURATION,
+};
+use crate::protobuf::{
+    FromProtobuf,
+    ToProtobuf,
+};
+use crate::signer::{
+    sign_now,
+    AnySigner,
+};
+use crate::{
+    AccountId,
+    AnyTransaction,
+    Error,
+    Key,
+    KeySignatureReport,
+    PrivateKey,
+    PublicKey,
+    Signer,
+    Transaction,
+    TransactionId,
+};
+
+/// A frozen transaction with the signatures collected for it so far.
+///
+/// This is meant for signing a transaction with several parties, possibly on air-gapped machines:
+/// each party loads the collection [`from_bytes`](Self::from_bytes), signs it, and hands back [`to_bytes`](Self::to_bytes).
+/// The returned collections can be [`merge`](Self::merge)d in any order.
+///
+/// The bytes of a collection are also valid [`Transaction::from_bytes`] input.
+#[derive(Clone)]
+pub struct SignatureCollection {
+    sources: TransactionSources,
+    required_keys: Vec<Key>,
+    expires_at: OffsetDateTime,
+}
+
+impl SignatureCollection {
+    /// Creates a collection for a frozen transaction, including any signatures it already has.
+    ///
+    /// # Errors
+    /// - [`Error::Signature`] if a signer fails, or can't sign synchronously.
+    ///
+    /// # Panics
+    /// - If `!transaction.is_frozen()`.
+    pub fn new<D: TransactionExecute>(transaction: &Transaction<D>) -> crate::Result<Self> {
+        assert!(
+            transaction.is_frozen(),
+            "Transaction must be frozen to create a `SignatureCollection`"
+        );
+
+        Self::from_sources(transaction.make_sources()?.into_owned(), Vec::new())
+    }
+
+    fn from_sources(sources: TransactionSources, required_keys: Vec<Key>) -> crate::Result<Self> {
+        let body = services::TransactionBody::decode(&*sources.signed_transactions()[0].body_bytes)
+            .map_err(Error::from_protobuf)?;
+
+        let valid_duration =
+            body.transaction_valid_duration.map_or(DEFAULT_TRANSACTION_VALID_DURATION, Into::into);
+
+        // every chunk has its own transaction ID, the collection is only useful until the first of them expires.
+        let expires_at = sources
+            .transaction_ids()
+            .iter()
+            .map(|it| it.valid_start + valid_duration)
+            .min()
+            .expect("`TransactionSources` always has at least one transaction");
+
+        Ok(Self { sources, required_keys, expires_at })
+    }
+
+    /// Parses a collection from bytes produced by [`to_bytes`](Self::to_bytes).
+    ///
+    /// Plain transaction bytes (from [`Transaction::to_bytes`]) are also accepted, without any required keys.
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`] if a valid collection cannot be parsed from the bytes.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        // the transaction list is checked the same way as any other transaction bytes.
+        let sources = AnyTransaction::from_bytes(bytes)?
+            .sources
+            .expect("`Transaction::from_bytes` always sets sources");
+
+        let pb = SignatureCollectionProtobuf::decode(bytes).map_err(Error::from_protobuf)?;
+
+        let required_keys = Vec::<Key>::from_protobuf(pb.required_keys)?;
+
+        Self::from_sources(sources, required_keys)
+    }
+
+    /// Convert `self` to protobuf encoded bytes.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        SignatureCollectionProtobuf {
+            transaction_list: self.sources.transactions().to_vec(),
+            required_keys: self.required_keys.to_protobuf(),
+        }
+        .encode_to_vec()
+    }
+
+    /// Returns the transaction these signatures are for.
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`] if the transaction can't be parsed, which shouldn't happen for a valid collection.
+    pub fn to_transaction(&self) -> crate::Result<AnyTransaction> {
+        AnyTransaction::from_bytes(&self.to_bytes())
+    }
+
+    /// Returns the ID of the transaction (of the first chunk, for chunked transactions).
+    #[must_use]
+    pub fn transaction_id(&self) -> TransactionId {
+        self.sources.transaction_ids()[0]
+    }
+
+    /// Returns the account IDs of the nodes the transaction can be submitted to.
+    #[must_use]
+    pub fn node_account_ids(&self) -> &[AccountId] {
+        self.sources.node_ids()
+    }
+
+    /// Returns when the transaction stops being valid, after which collecting signatures is pointless.
+    #[must_use]
+    pub fn expires_at(&self) -> OffsetDateTime {
+        self.expires_at
+    }
+
+    /// Returns `true` if the transaction is no longer valid.
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        OffsetDateTime::now_utc() >= self.expires_at
+    }
+
+    /// Returns the public keys that have signed the transaction so far.
+    #[must_use]
+    pub fn signers(&self) -> Vec<PublicKey> {
+        use services::signature_pair::Signature;
+
+        // every signed transaction has the same signers (`TransactionSources` checks this), so the first is representative.
+        let sig_pairs = self.sources.signed_transactions()[0]
+            .sig_map
+            .as_ref()
+            .map_or_else(|| [].as_slice(), |it| &it.sig_pair);
+
+        sig_pairs
+            .iter()
+            .filter_map(|it| match &it.signature {
+                Some(Signature::Ed25519(_)) => {
+                    PublicKey::from_bytes_ed25519(&it.pub_key_prefix).ok()
+                }
+                Some(Signature::EcdsaSecp256k1(_)) => {
+                    PublicKey::from_bytes_ecdsa(&it.pub_key_prefix).ok()
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the keys that must sign the transaction.
+    #[must_use]
+    pub fn get_required_keys(&self) -> &[Key] {
+        &self.required_keys
+    }
+
+    /// Adds a key that must sign the transaction.
+    ///
+    /// The required keys travel with the collection, so that every party knows what's still missing.
+    pub fn required_key(&mut self, key: impl Into<Key>) -> &mut Self {
+        let key = key.into();
+
+        if !self.required_keys.contains(&key) {
+            self.required_keys.push(key);
+        }
+
+        self
+    }
+
+    /// Returns a report for each of the [required keys](Self::get_required_keys), in order.
+    #[must_use]
+    pub fn signature_reports(&self) -> Vec<KeySignatureReport> {
+        self.required_keys
+            .iter()
+            .map(|key| KeySignatureReport::from_sources(key, &self.sources))
+            .collect()
+    }
+
+    /// Returns `true` if every [required key](Self::get_required_keys) is satisfied.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.signature_reports().iter().all(|it| it.is_satisfied)
+    }
+
+    /// Sign the transaction.
+    pub fn sign(&mut self, private_key: PrivateKey) -> &mut Self {
+        // signing with a private key never fails, nor waits.
+        sign_now(self.sign_signer(AnySigner::PrivateKey(private_key))).unwrap();
+
+        self
+    }
+
+    /// Sign the transaction with a [`Signer`].
+    ///
+    /// # Errors
+    /// - [`Error::Signature`] if the signer fails.
+    pub async fn sign_with_signer<S: Signer + 'static>(
+        &mut self,
+        signer: S,
+    ) -> crate::Result<&mut Self> {
+        self.sign_signer(AnySigner::signer(signer)).await?;
+
+        Ok(self)
+    }
+
+    async fn sign_signer(&mut self, signer: AnySigner) -> crate::Result<()> {
+        let sources = self.sources.sign_with(std::slice::from_ref(&signer)).await?;
+
+        if let Cow::Owned(sources) = sources {
+            self.sources = sources;
+        }
+
+        Ok(())
+    }
+
+    /// Adds the signatures and required keys from `other` that `self` doesn't already have.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureMerge`] if `other` is for a different transaction.
+    /// - [`Error::SignatureMerge`] if a signature from `other` doesn't verify.
+    pub fn merge(&mut self, other: &Self) -> crate::Result<&mut Self> {
+        let sources = self.sources.merge_signatures(&other.sources)?;
+
+        if let Cow::Owned(sources) = sources {
+            self.sources = sources;
+        }
+
+        for key in &other.required_keys {
+            self.required_key(key.clone());
+        }
+
+        Ok(self)
+    }
+}
+
+/// The encoded form of a [`SignatureCollection`].
+///
+/// This is a `TransactionList` with an extra field, so that anything that can read transaction bytes can read it.
+#[derive(Debug, Default)]
+struct SignatureCollectionProtobuf {
+    transaction_list: Vec<services::Transaction>,
+    required_keys: Vec<services::Key>,
+}
+
+impl Message for SignatureCollectionProtobuf {
+    fn encode_raw<B: BufMut>(&self, buf: &mut B) {
+        message::encode_repeated(1, &self.transaction_list, buf);
+        message::encode_repeated(2, &self.required_keys, buf);
+    }
+
+    fn merge_field<B: Buf>(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError> {
+        match tag {
+            1 => message::merge_repeated(wire_type, &mut self.transaction_list, buf, ctx),
+            2 => message::merge_repeated(wire_type, &mut self.required_keys, buf, ctx),
+            _ => prost::encoding::skip_field(wire_type, tag, buf, ctx),
+        }
+    }
+
+    fn encoded_len(&self) -> usize {
+        message::encoded_len_repeated(1, &self.transaction_list)
+            + message::encoded_len_repeated(2, &self.required_keys)
+    }
+
+    fn clear(&mut self) {
+        self.transaction_list.clear();
+        self.required_keys.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use time::Duration;
+
+    use super::SignatureCollection;
+    use crate::transaction::test_helpers::{
+        TEST_NODE_ACCOUNT_IDS,
+        TEST_TX_ID,
+    };
+    use crate::{
+        AnyTransaction,
+        Error,
+        Key,
+        KeyList,
+        PrivateKey,
+        TransferTransaction,
+    };
+
+    fn make_transaction(memo: &str) -> TransferTransaction {
+        let mut tx = TransferTransaction::new();
+
+        tx.node_account_ids(TEST_NODE_ACCOUNT_IDS)
+            .transaction_id(TEST_TX_ID)
+            .transaction_valid_duration(Duration::seconds(60))
+            .transaction_memo(memo)
+            .freeze()
+            .unwrap();
+
+        tx
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let key = PrivateKey::generate_ed25519();
+
+        let mut collection = SignatureCollection::new(&make_transaction("")).unwrap();
+        collection.required_key(key.public_key()).sign(key.clone());
+
+        let collection = SignatureCollection::from_bytes(&collection.to_bytes()).unwrap();
+
+        assert_eq!(collection.transaction_id(), TEST_TX_ID);
+        assert_eq!(collection.node_account_ids(), TEST_NODE_ACCOUNT_IDS);
+        assert_eq!(collection.expires_at(), TEST_TX_ID.valid_start + Duration::seconds(60));
+        assert_eq!(collection.get_required_keys(), [Key::from(key.public_key())]);
+        assert_eq!(collection.signers(), [key.public_key()]);
+        assert!(collection.is_complete());
+    }
+
+    #[test]
+    fn bytes_are_transaction_bytes() {
+        let mut collection = SignatureCollection::new(&make_transaction("")).unwrap();
+        collection.required_key(PrivateKey::generate_ed25519().public_key());
+
+        let tx = AnyTransaction::from_bytes(&collection.to_bytes()).unwrap();
+
+        assert_eq!(tx.get_transaction_id(), Some(TEST_TX_ID));
+    }
+
+    #[test]
+    fn merge() {
+        let alice = PrivateKey::generate_ed25519();
+        let bob = PrivateKey::generate_ecdsa();
+
+        let mut collection = SignatureCollection::new(&make_transaction("")).unwrap();
+        collection.required_key(KeyList::from([alice.public_key(), bob.public_key()]));
+        assert!(!collection.is_complete());
+
+        let bytes = collection.to_bytes();
+
+        let mut from_alice = SignatureCollection::from_bytes(&bytes).unwrap();
+        from_alice.sign(alice.clone());
+
+        let mut from_bob = SignatureCollection::from_bytes(&bytes).unwrap();
+        from_bob.sign(bob.clone());
+
+        collection.merge(&from_bob).unwrap().merge(&from_alice).unwrap();
+
+        // merging the same signatures twice is harmless.
+        collection.merge(&from_alice).unwrap();
+
+        assert_eq!(collection.signers(), [bob.public_key(), alice.public_key()]);
+        assert!(collection.is_complete());
+
+        // every node's transaction ended up with both signatures.
+        for signed in collection.sources.signed_transactions() {
+            assert_eq!(signed.sig_map.as_ref().unwrap().sig_pair.len(), 2);
+        }
+    }
+
+    #[test]
+    fn merge_different_transaction() {
+        let mut collection = SignatureCollection::new(&make_transaction("")).unwrap();
+
+        let mut other = SignatureCollection::new(&make_transaction("other")).unwrap();
+        other.sign(PrivateKey::generate_ed25519());
+
+        assert_matches!(collection.merge(&other), Err(Error::SignatureMerge(_)));
+    }
+
+    #[test]
+    fn merge_invalid_signature() {
+        let key = PrivateKey::generate_ed25519();
+
+        let mut tx = make_transaction("");
+        tx.sign_with(key.public_key(), |_| vec![0; 64]);
+
+        let mut collection = SignatureCollection::new(&make_transaction("")).unwrap();
+        let other = SignatureCollection::new(&tx).unwrap();
+
+        assert_matches!(collection.merge(&other), Err(Error::SignatureMerge(_)));
+        assert!(collection.signers().is_empty());
+    }
+}
@@ -30,6 +30,7 @@ use crate::signer::AnySigner;
 use crate::{
     AccountId,
     Error,
+    PublicKey,
     TransactionHash,
     TransactionId,
 };
@@ -112,31 +113,8 @@ impl TransactionSources {
         // ensure all signers (if any) are consistent for all signed transactions.
         // this doesn't compare or validate the signatures,
         // instead it ensures that all signatures in the first signed transation exist in *all* transactions and none extra exist.
-        {
-            let mut iter = signed_transactions.iter().map(|it| {
-                it.sig_map
-                    .as_ref()
-                    .map(|it| {
-                        let mut tmp = it
-                            .sig_pair
-                            .iter()
-                            .map(|it| it.pub_key_prefix.as_slice())
-                            .collect::<Vec<_>>();
-
-                        // sort to be generous about signature ordering.
-                        tmp.sort();
-
-                        tmp
-                    })
-                    .unwrap_or_default()
-            });
-
-            // this should always be `Some`, buuuut, we lose nothing by doing it this way.
-            if let Some(first) = iter.next() {
-                if iter.any(|sigs| first != sigs.as_slice()) {
-                    return Err(Error::from_protobuf("Transaction has mismatched signatures"));
-                }
-            }
+        if !has_consistent_signers(&signed_transactions) {
+            return Err(Error::from_protobuf("Transaction has mismatched signatures"));
         }
 
         let transaction_info: Result<Vec<_>, _> = signed_transactions
@@ -227,9 +205,9 @@ impl TransactionSources {
         })
     }
 
-    pub(crate) fn sign_with(&self, signers: &[AnySigner]) -> Cow<'_, Self> {
+    pub(crate) async fn sign_with(&self, signers: &[AnySigner]) -> crate::Result<Cow<'_, Self>> {
         if signers.is_empty() {
-            return Cow::Borrowed(self);
+            return Ok(Cow::Borrowed(self));
         }
 
         let mut signed_transactions = Cow::Borrowed(&self.signed_transactions);
@@ -247,26 +225,96 @@ impl TransactionSources {
                 continue;
             }
 
-            for tx in signed_transactions.to_mut().iter_mut() {
+            let signatures = {
+                let messages: Vec<_> =
+                    signed_transactions.iter().map(|it| it.body_bytes.as_slice()).collect();
+
+                signer.sign_all(&messages).await?
+            };
+
+            let public_key = signer.public_key();
+
+            for (tx, signature) in signed_transactions.to_mut().iter_mut().zip(signatures) {
                 let sig_map = tx.sig_map.get_or_insert_with(services::SignatureMap::default);
                 // todo: reuse `pk_bytes` instead of re-serializing them.
-                let sig_pair = super::execute::SignaturePair::from(signer.sign(&tx.body_bytes));
+                let sig_pair = super::execute::SignaturePair::from((public_key, signature));
 
                 sig_map.sig_pair.push(sig_pair.into_protobuf());
             }
         }
 
-        match signed_transactions {
+        Ok(match signed_transactions {
             // if it's still borrowed then no signatures have been added (all signers are duplicates).
             Cow::Borrowed(_) => Cow::Borrowed(self),
-            Cow::Owned(signed_transactions) => Cow::Owned(Self {
-                signed_transactions,
-                transactions: OnceCell::new(),
-                chunks: self.chunks.clone(),
-                transaction_ids: self.transaction_ids.clone(),
-                node_ids: self.node_ids.clone(),
-                transaction_hashes: self.transaction_hashes.clone(),
-            }),
+            Cow::Owned(signed_transactions) => {
+                Cow::Owned(self.with_signed_transactions(signed_transactions))
+            }
+        })
+    }
+
+    /// Adds the signatures from `other` that `self` doesn't already have.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureMerge`] if `other` isn't made from the same transaction bodies, in the same order.
+    /// - [`Error::SignatureMerge`] if `other` doesn't have the same signers on every transaction body.
+    /// - [`Error::SignatureMerge`] if a signature being added doesn't verify against its transaction body.
+    pub(crate) fn merge_signatures(&self, other: &Self) -> crate::Result<Cow<'_, Self>> {
+        if self.signed_transactions.len() != other.signed_transactions.len()
+            || self
+                .signed_transactions
+                .iter()
+                .zip(other.signed_transactions.iter())
+                .any(|(lhs, rhs)| lhs.body_bytes != rhs.body_bytes)
+        {
+            return Err(Error::signature_merge("transaction bodies don't match"));
+        }
+
+        // `new` checks this, but `other` can't be trusted to have come from it unchanged.
+        if !has_consistent_signers(&other.signed_transactions) {
+            return Err(Error::signature_merge("signers differ between transaction bodies"));
+        }
+
+        let mut signed_transactions = Cow::Borrowed(&self.signed_transactions);
+
+        // each side has the same signers on all of its transactions,
+        // so adding the missing signatures transaction by transaction keeps that true for the result.
+        for (index, other) in other.signed_transactions.iter().enumerate() {
+            for sig_pair in other.sig_map.iter().flat_map(|it| &it.sig_pair) {
+                if signed_transactions[index].sig_map.as_ref().map_or(false, |it| {
+                    it.sig_pair.iter().any(|it| it.pub_key_prefix == sig_pair.pub_key_prefix)
+                }) {
+                    continue;
+                }
+
+                verify_sig_pair(sig_pair, &other.body_bytes)?;
+
+                signed_transactions.to_mut()[index]
+                    .sig_map
+                    .get_or_insert_with(services::SignatureMap::default)
+                    .sig_pair
+                    .push(sig_pair.clone());
+            }
+        }
+
+        Ok(match signed_transactions {
+            Cow::Borrowed(_) => Cow::Borrowed(self),
+            Cow::Owned(signed_transactions) => {
+                Cow::Owned(self.with_signed_transactions(signed_transactions))
+            }
+        })
+    }
+
+    fn with_signed_transactions(
+        &self,
+        signed_transactions: Box<[services::SignedTransaction]>,
+    ) -> Self {
+        Self {
+            signed_transactions,
+            transactions: OnceCell::new(),
+            chunks: self.chunks.clone(),
+            transaction_ids: self.transaction_ids.clone(),
+            node_ids: self.node_ids.clone(),
+            transaction_hashes: self.transaction_hashes.clone(),
         }
     }
 
@@ -290,11 +338,11 @@ impl TransactionSources {
         self.chunks.len()
     }
 
-    pub(super) fn chunks(&self) -> impl Iterator<Item = SourceChunk<'_>> {
+    pub(crate) fn chunks(&self) -> impl Iterator<Item = SourceChunk<'_>> {
         (0..self.chunks.len()).map(|index| SourceChunk { map: self, index })
     }
 
-    pub(super) fn _transaction_ids(&self) -> &[TransactionId] {
+    pub(super) fn transaction_ids(&self) -> &[TransactionId] {
         &self.transaction_ids
     }
 
@@ -308,3 +356,98 @@ impl TransactionSources {
         })
     }
 }
+
+/// Returns `true` if every signed transaction has signatures from the same public keys (by prefix).
+///
+/// Signature ordering isn't considered, and the signatures themselves aren't verified.
+fn has_consistent_signers(signed_transactions: &[services::SignedTransaction]) -> bool {
+    let mut iter = signed_transactions.iter().map(|it| {
+        let mut prefixes: Vec<_> = it
+            .sig_map
+            .iter()
+            .flat_map(|it| &it.sig_pair)
+            .map(|it| it.pub_key_prefix.as_slice())
+            .collect();
+
+        // sort to be generous about signature ordering.
+        prefixes.sort_unstable();
+
+        prefixes
+    });
+
+    // this should always be `Some`, buuuut, we lose nothing by doing it this way.
+    let Some(first) = iter.next() else {
+        return true;
+    };
+
+    iter.all(|prefixes| prefixes == first)
+}
+
+/// Checks that `sig_pair` has a full public key, and that its signature is valid for `body_bytes`.
+fn verify_sig_pair(sig_pair: &services::SignaturePair, body_bytes: &[u8]) -> crate::Result<()> {
+    use services::signature_pair::Signature;
+
+    let (public_key, signature) = match &sig_pair.signature {
+        Some(Signature::Ed25519(signature)) => {
+            (PublicKey::from_bytes_ed25519(&sig_pair.pub_key_prefix), signature)
+        }
+        Some(Signature::EcdsaSecp256k1(signature)) => {
+            (PublicKey::from_bytes_ecdsa(&sig_pair.pub_key_prefix), signature)
+        }
+        _ => return Err(Error::signature_merge("unsupported signature type")),
+    };
+
+    let public_key = public_key.map_err(|_| {
+        Error::signature_merge("signature doesn't have a full public key as its prefix")
+    })?;
+
+    public_key.verify(body_bytes, signature).map_err(|_| {
+        Error::signature_merge(format!(
+            "signature by `{public_key}` doesn't match the transaction body"
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use prost::Message;
+
+    use super::TransactionSources;
+    use crate::transaction::test_helpers::{
+        TEST_NODE_ACCOUNT_IDS,
+        TEST_TX_ID,
+    };
+    use crate::{
+        Error,
+        PrivateKey,
+        TransferTransaction,
+    };
+
+    fn make_sources(signer: Option<PrivateKey>) -> TransactionSources {
+        let mut tx = TransferTransaction::new();
+
+        tx.node_account_ids(TEST_NODE_ACCOUNT_IDS).transaction_id(TEST_TX_ID).freeze().unwrap();
+
+        if let Some(signer) = signer {
+            tx.sign(signer);
+        }
+
+        let list = hedera_proto::sdk::TransactionList::decode(&*tx.to_bytes().unwrap()).unwrap();
+
+        TransactionSources::new(list.transaction_list).unwrap()
+    }
+
+    #[test]
+    fn merge_signatures_inconsistent_signers() {
+        let sources = make_sources(None);
+        let other = make_sources(Some(PrivateKey::generate_ed25519()));
+
+        // drop the signature from one node's transaction only.
+        let mut signed_transactions = other.signed_transactions.clone();
+        signed_transactions[1].sig_map = None;
+        let other = other.with_signed_transactions(signed_transactions);
+
+        assert_matches!(sources.merge_signatures(&other), Err(Error::SignatureMerge(_)));
+    }
+}
@@ -1,15 +1,24 @@
 use std::collections::HashSet;
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
 
 use assert_matches::assert_matches;
+use futures_core::future::BoxFuture;
 use hex_literal::hex;
 use time::OffsetDateTime;
+use triomphe::Arc;
 
 use crate::transaction::AnyTransactionData;
 use crate::{
     AnyTransaction,
     Client,
+    Error,
     Hbar,
     PrivateKey,
+    PublicKey,
+    Signer,
     TopicMessageSubmitTransaction,
     TransactionId,
     TransferTransaction,
@@ -119,3 +128,135 @@ async fn chunked_to_from_bytes() -> crate::Result<()> {
 
     Ok(())
 }
+
+struct CountingSigner {
+    key: PrivateKey,
+    calls: Arc<AtomicUsize>,
+}
+
+impl Signer for CountingSigner {
+    fn public_key(&self) -> PublicKey {
+        self.key.public_key()
+    }
+
+    fn sign<'a>(&'a self, messages: &'a [&'a [u8]]) -> BoxFuture<'a, crate::Result<Vec<Vec<u8>>>> {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+
+        Box::pin(async move { Ok(messages.iter().map(|it| self.key.sign(it)).collect()) })
+    }
+}
+
+struct FailingSigner(PublicKey);
+
+impl Signer for FailingSigner {
+    fn public_key(&self) -> PublicKey {
+        self.0
+    }
+
+    fn sign<'a>(&'a self, _messages: &'a [&'a [u8]]) -> BoxFuture<'a, crate::Result<Vec<Vec<u8>>>> {
+        Box::pin(async { Err(Error::Signature("nope".into())) })
+    }
+}
+
+struct PendingSigner(PublicKey);
+
+impl Signer for PendingSigner {
+    fn public_key(&self) -> PublicKey {
+        self.0
+    }
+
+    fn sign<'a>(&'a self, _messages: &'a [&'a [u8]]) -> BoxFuture<'a, crate::Result<Vec<Vec<u8>>>> {
+        Box::pin(std::future::pending())
+    }
+}
+
+fn signer_test_transaction() -> TransferTransaction {
+    let mut tx = TransferTransaction::new();
+
+    tx.hbar_transfer(2.into(), Hbar::new(2))
+        .hbar_transfer(101.into(), Hbar::new(-2))
+        .transaction_id(TransactionId {
+            account_id: 101.into(),
+            valid_start: OffsetDateTime::now_utc(),
+            nonce: None,
+            scheduled: false,
+        })
+        .node_account_ids([6.into(), 7.into()]);
+
+    tx
+}
+
+#[test]
+fn signer_signs_every_node_in_one_batch() -> crate::Result<()> {
+    let key = PrivateKey::generate_ed25519();
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let mut tx = signer_test_transaction();
+
+    let bytes = tx
+        .sign_with_signer(CountingSigner { key: key.clone(), calls: Arc::clone(&calls) })
+        .freeze()?
+        .to_bytes()?;
+
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+
+    let tx2 = AnyTransaction::from_bytes(&bytes)?;
+
+    let signed_transactions = tx2.sources.as_ref().unwrap().signed_transactions();
+
+    assert_eq!(signed_transactions.len(), 2);
+
+    let public_key = key.public_key().to_bytes_raw();
+
+    for tx in signed_transactions {
+        let sig_pairs = &tx.sig_map.as_ref().unwrap().sig_pair;
+        assert!(sig_pairs.iter().any(|it| it.pub_key_prefix == public_key));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn signer_error_is_returned() -> crate::Result<()> {
+    let mut tx = signer_test_transaction();
+
+    let res = tx
+        .sign_with_signer(FailingSigner(PrivateKey::generate_ed25519().public_key()))
+        .freeze()?
+        .to_bytes();
+
+    assert_matches!(res, Err(Error::Signature(_)));
+
+    Ok(())
+}
+
+#[test]
+fn pending_signer_errors_synchronously() -> crate::Result<()> {
+    let mut tx = signer_test_transaction();
+
+    let res = tx
+        .sign_with_signer(PendingSigner(PrivateKey::generate_ed25519().public_key()))
+        .freeze()?
+        .to_bytes();
+
+    assert_matches!(res, Err(Error::Signature(_)));
+
+    Ok(())
+}
+
+#[test]
+fn try_add_signature_with_pending_signer() -> crate::Result<()> {
+    let key = PrivateKey::generate_ed25519();
+
+    let mut tx = signer_test_transaction();
+
+    tx.node_account_ids([6.into()])
+        .sign_with_signer(PendingSigner(PrivateKey::generate_ed25519().public_key()))
+        .freeze()?;
+
+    let res = tx.try_add_signature(key.public_key(), vec![0; 64]);
+
+    assert_matches!(res, Err(Error::Signature(_)));
+
+    Ok(())
+}
@@ -42,6 +42,8 @@ use crate::{
 /// Response from [`TransactionReceiptQuery`][crate::TransactionReceiptQuery].
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TransactionReceipt {
     // fixme(sr): better doc comment.
     /// The ID of the transaction that this is a receipt for.
@@ -49,6 +51,7 @@ pub struct TransactionReceipt {
 
     /// The consensus status of the transaction; is UNKNOWN if consensus has not been reached, or if
     /// the associated transaction did not have a valid payer signature.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::status"))]
     pub status: Status,
 
     /// In the receipt for an `AccountCreateTransaction`, the id of the newly created account.
@@ -72,6 +75,7 @@ pub struct TransactionReceipt {
     // TODO: use a hash type (for display/debug/serialize purposes)
     /// In the receipt for a `TopicMessageSubmitTransaction`, the new running hash of the
     /// topic that received the message.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::option_hex"))]
     pub topic_running_hash: Option<Vec<u8>>,
 
     /// In the receipt of a `TopicMessageSubmitTransaction`, the version of the SHA-384
@@ -99,6 +103,9 @@ pub struct TransactionReceipt {
     /// the serial numbers of the newly created NFTs.
     pub serials: Vec<i64>,
 
+    /// In the receipt of a `NodeCreateTransaction`, the id of the newly created node.
+    pub node_id: u64,
+
     /// The receipts of processing all transactions with the given id, in consensus time order.
     pub duplicates: Vec<TransactionReceipt>,
 
@@ -159,6 +166,7 @@ impl TransactionReceipt {
             status,
             total_supply: receipt.new_total_supply,
             serials: receipt.serial_numbers,
+            node_id: receipt.node_id,
             topic_running_hash_version: receipt.topic_running_hash_version,
             topic_sequence_number: receipt.topic_sequence_number,
             topic_running_hash: receipt
@@ -232,6 +240,7 @@ impl ToProtobuf for TransactionReceipt {
             schedule_id: self.schedule_id.to_protobuf(),
             scheduled_transaction_id: self.scheduled_transaction_id.to_protobuf(),
             serial_numbers: self.serials.clone(),
+            node_id: self.node_id,
         }
     }
 }
@@ -270,6 +279,7 @@ mod tests {
             schedule_id: Some(ScheduleId::new(1, 1, 1)),
             scheduled_transaction_id: Some(TEST_TX_ID),
             serials: Vec::from([1, 2, 3]),
+            node_id: 1,
             duplicates: Vec::new(),
             children: Vec::new(),
         }
@@ -381,6 +391,7 @@ mod tests {
                     2,
                     3,
                 ],
+                node_id: 1,
             }
         "#]]
         .assert_debug_eq(&make_receipt().to_protobuf())
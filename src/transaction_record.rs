@@ -23,14 +23,17 @@ use std::collections::HashMap;
 use hedera_proto::services;
 use time::OffsetDateTime;
 
+use crate::contract::event_topic;
 use crate::protobuf::ToProtobuf;
 use crate::{
     AccountId,
     AssessedCustomFee,
     ContractFunctionResult,
+    ContractLogInfo,
     EvmAddress,
     FromProtobuf,
     Hbar,
+    PendingAirdropRecord,
     PublicKey,
     ScheduleId,
     Tinybar,
@@ -45,6 +48,8 @@ use crate::{
 /// The complete record for a transaction on Hedera that has reached consensus.
 /// Response from [`TransactionRecordQuery`][crate::TransactionRecordQuery].
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TransactionRecord {
     /// The status (reach consensus, or failed, or is unknown) and the ID of
     /// any new account/file/instance created.
@@ -52,9 +57,11 @@ pub struct TransactionRecord {
 
     /// The hash of the Transaction that executed (not the hash of any Transaction that failed for
     /// having a duplicate TransactionID).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub transaction_hash: Vec<u8>,
 
     /// The consensus timestamp.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::timestamp"))]
     pub consensus_timestamp: OffsetDateTime,
 
     /// Record of the value returned by the smart contract function or constructor.
@@ -66,9 +73,11 @@ pub struct TransactionRecord {
     pub transfers: Vec<Transfer>,
 
     /// All fungible token transfers as a result of this transaction.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::sorted_nested_map"))]
     pub token_transfers: HashMap<TokenId, HashMap<AccountId, i64>>,
 
     /// All NFT Token transfers as a result of this transaction.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::sorted_map"))]
     pub token_nft_transfers: HashMap<TokenId, Vec<TokenNftTransfer>>,
 
     /// The ID of the transaction this record represents.
@@ -92,6 +101,7 @@ pub struct TransactionRecord {
 
     /// In the record of an internal transaction, the consensus timestamp of the user
     /// transaction that spawned it.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::option_timestamp"))]
     pub parent_consensus_timestamp: Option<OffsetDateTime>,
 
     /// In the record of an internal CryptoCreate transaction triggered by a user
@@ -108,14 +118,11 @@ pub struct TransactionRecord {
 
     /// The keccak256 hash of the ethereumData. This field will only be populated for
     /// `EthereumTransaction`.
-    #[cfg_attr(feature = "ffi", serde(with = "serde_with::As::<serde_with::base64::Base64>"))]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub ethereum_hash: Vec<u8>,
 
     /// In the record of a PRNG transaction with no output range, a pseudorandom 384-bit string.
-    #[cfg_attr(
-        feature = "ffi",
-        serde(with = "serde_with::As::<Option<serde_with::base64::Base64>>")
-    )]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::option_hex"))]
     pub prng_bytes: Option<Vec<u8>>,
 
     /// In the record of a PRNG transaction with an output range, the output of a PRNG
@@ -124,6 +131,12 @@ pub struct TransactionRecord {
 
     /// The last 20 bytes of the keccak-256 hash of a ECDSA_SECP256K1 primitive key.
     pub evm_address: Option<EvmAddress>,
+
+    /// The new pending airdrops created by the transaction, if any.
+    ///
+    /// Pending airdrops are created by a [`TokenAirdropTransaction`](crate::TokenAirdropTransaction)
+    /// when the receiver is not yet associated with the token.
+    pub pending_airdrop_records: Vec<PendingAirdropRecord>,
 }
 // TODO: paid_staking_rewards
 
@@ -143,6 +156,17 @@ impl TransactionRecord {
         ToProtobuf::to_bytes(self)
     }
 
+    /// Returns the logs of the [`contract_function_result`](Self::contract_function_result) emitted by
+    /// the (non-anonymous) event with the given `signature`, for example `Transfer(address,address,uint256)`.
+    pub fn logs_for_event(&self, signature: &str) -> impl Iterator<Item = &ContractLogInfo> {
+        let topic = event_topic(signature);
+
+        self.contract_function_result
+            .iter()
+            .flat_map(|it| &it.logs)
+            .filter(move |it| it.has_topic(&topic))
+    }
+
     fn from_protobuf(
         record: services::TransactionRecord,
         duplicates: Vec<Self>,
@@ -237,6 +261,7 @@ impl TransactionRecord {
             evm_address,
             prng_bytes,
             prng_number,
+            pending_airdrop_records: Vec::from_protobuf(record.new_pending_airdrops)?,
         })
     }
 }
@@ -320,6 +345,7 @@ impl ToProtobuf for TransactionRecord {
                 .evm_address
                 .as_ref()
                 .map_or_else(Vec::default, |it| it.to_bytes().to_vec()),
+            new_pending_airdrops: self.pending_airdrop_records.to_protobuf(),
             body: self
                 .contract_function_result
                 .as_ref()
@@ -346,6 +372,8 @@ mod tests {
         ContractFunctionResult,
         ContractId,
         Hbar,
+        PendingAirdropId,
+        PendingAirdropRecord,
         PrivateKey,
         ScheduleId,
         TokenAssociation,
@@ -422,6 +450,14 @@ mod tests {
             prng_bytes,
             prng_number,
             evm_address: Some(crate::EvmAddress([0; 20])),
+            pending_airdrop_records: Vec::from([PendingAirdropRecord {
+                pending_airdrop_id: PendingAirdropId::new_token_id(
+                    AccountId::new(1, 2, 3),
+                    AccountId::new(3, 2, 1),
+                    TokenId::new(4, 5, 6),
+                ),
+                pending_airdrop_value: Some(7),
+            }]),
         }
     }
 
@@ -0,0 +1,1235 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+};
+
+use hedera_proto::services;
+use prost::Message;
+use time::{
+    Duration,
+    OffsetDateTime,
+};
+
+use crate::node_address::service_endpoint_from_protobuf;
+use crate::protobuf::FromProtobuf;
+use crate::transaction::{
+    TransactionExecute,
+    DEFAULT_TRANSACTION_VALID_DURATION,
+};
+use crate::{
+    AccountId,
+    ContractId,
+    Error,
+    FileId,
+    Hbar,
+    Key,
+    KeyList,
+    NftId,
+    PendingAirdropId,
+    ScheduleId,
+    TokenId,
+    TokenNftTransfer,
+    TopicId,
+    Transaction,
+    TransactionHash,
+    TransactionId,
+};
+
+const NODE_ACCOUNT_IDS: &str = "node account IDs";
+
+/// A description of a transaction meant for people, such as in signing UIs and audit logs.
+///
+/// Created with [`Transaction::summary`], the [`Display`] impl renders one line per item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TransactionSummary {
+    /// The name of the transaction's type, for example `TransferTransaction`.
+    pub transaction_type: &'static str,
+
+    /// The transaction ID, which includes the payer and the start of the valid window.
+    pub transaction_id: Option<TransactionId>,
+
+    /// The maximum fee the payer is willing to pay.
+    pub max_transaction_fee: Hbar,
+
+    /// The transaction memo.
+    pub memo: String,
+
+    /// How long the transaction stays valid after its valid start.
+    pub valid_duration: Duration,
+
+    /// The nodes the transaction can be submitted to.
+    pub node_account_ids: Vec<AccountId>,
+
+    /// Hbar, token, and NFT transfers.
+    pub transfers: Vec<SummaryTransfer>,
+
+    /// Keys set by the transaction.
+    pub keys: Vec<SummaryKey>,
+
+    /// Everything else worth reviewing, specific to the transaction's type.
+    pub fields: Vec<SummaryField>,
+}
+
+/// A transfer listed in a [`TransactionSummary`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SummaryTransfer {
+    /// An hbar transfer, `amount` is negative for the sender.
+    Hbar {
+        /// The account being debited or credited.
+        account_id: AccountId,
+        /// The signed amount.
+        amount: Hbar,
+        /// Whether this is an approved (allowance) transfer.
+        is_approval: bool,
+    },
+
+    /// A fungible token transfer, `amount` is negative for the sender.
+    Token {
+        /// The token being transferred.
+        token_id: TokenId,
+        /// The account being debited or credited.
+        account_id: AccountId,
+        /// The signed amount, in the token's smallest denomination.
+        amount: i64,
+        /// The decimals the token is expected to have, if given.
+        expected_decimals: Option<u32>,
+        /// Whether this is an approved (allowance) transfer.
+        is_approval: bool,
+    },
+
+    /// An NFT transfer.
+    Nft(TokenNftTransfer),
+}
+
+/// A key set by a transaction, listed in a [`TransactionSummary`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SummaryKey {
+    /// Which key is being set, for example `admin key`.
+    pub name: &'static str,
+
+    /// The new key.
+    pub key: Key,
+}
+
+/// A named value listed in a [`TransactionSummary`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SummaryField {
+    /// What the value is, for example `token`.
+    pub name: &'static str,
+
+    /// The value, formatted for display.
+    pub value: String,
+}
+
+impl TransactionSummary {
+    /// Returns the account paying for the transaction.
+    #[must_use]
+    pub fn payer(&self) -> Option<AccountId> {
+        self.transaction_id.map(|it| it.account_id)
+    }
+
+    /// Returns when the transaction stops being valid.
+    #[must_use]
+    pub fn valid_until(&self) -> Option<OffsetDateTime> {
+        self.transaction_id.map(|it| it.valid_start + self.valid_duration)
+    }
+
+    /// Returns the `(name, value)` lines of the summary, in display order.
+    fn lines(&self) -> Vec<(&'static str, String)> {
+        let mut lines = Vec::new();
+
+        if let Some(transaction_id) = &self.transaction_id {
+            lines.push(("transaction ID", transaction_id.to_string()));
+            lines.push(("payer", transaction_id.account_id.to_string()));
+            lines.push(("valid start", transaction_id.valid_start.to_string()));
+        }
+
+        lines.push(("valid duration", format!("{}s", self.valid_duration.whole_seconds())));
+        lines.push(("max transaction fee", self.max_transaction_fee.to_string()));
+        lines.push(("memo", format!("{:?}", self.memo)));
+
+        let node_account_ids: Vec<_> =
+            self.node_account_ids.iter().map(ToString::to_string).collect();
+        lines.push((NODE_ACCOUNT_IDS, node_account_ids.join(", ")));
+
+        for transfer in &self.transfers {
+            lines.push(match transfer {
+                SummaryTransfer::Hbar { account_id, amount, is_approval } => (
+                    "hbar transfer",
+                    format!(
+                        "{account_id} {}{amount}{}",
+                        if amount.to_tinybars() > 0 { "+" } else { "" },
+                        approval(*is_approval)
+                    ),
+                ),
+                SummaryTransfer::Token {
+                    token_id,
+                    account_id,
+                    amount,
+                    expected_decimals,
+                    is_approval,
+                } => (
+                    "token transfer",
+                    format!(
+                        "{token_id} {account_id} {amount:+}{}{}",
+                        expected_decimals
+                            .map(|it| format!(" (decimals: {it})"))
+                            .unwrap_or_default(),
+                        approval(*is_approval)
+                    ),
+                ),
+                SummaryTransfer::Nft(nft) => (
+                    "NFT transfer",
+                    format!(
+                        "{}/{} {} -> {}{}",
+                        nft.token_id,
+                        nft.serial,
+                        nft.sender,
+                        nft.receiver,
+                        approval(nft.is_approved)
+                    ),
+                ),
+            });
+        }
+
+        for key in &self.keys {
+            lines.push((key.name, key_to_string(&key.key)));
+        }
+
+        for field in &self.fields {
+            lines.push((field.name, field.value.clone()));
+        }
+
+        lines
+    }
+}
+
+impl Display for TransactionSummary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.transaction_type)?;
+
+        for (name, value) in self.lines() {
+            write!(f, "\n  {:<20} {value}", format!("{name}:"))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn approval(is_approval: bool) -> &'static str {
+    if is_approval {
+        " (approved)"
+    } else {
+        ""
+    }
+}
+
+fn key_to_string(key: &Key) -> String {
+    match key {
+        Key::Single(public_key) => public_key.to_string_der(),
+        Key::ContractId(id) => format!("contract {id}"),
+        Key::DelegateContractId(id) => format!("delegate contract {id}"),
+        Key::KeyList(list) => {
+            let keys: Vec<_> = list.keys.iter().map(key_to_string).collect();
+
+            match list.threshold {
+                Some(threshold) => format!("{threshold} of [{}]", keys.join(", ")),
+                None => format!("all of [{}]", keys.join(", ")),
+            }
+        }
+    }
+}
+
+/// The differences between two transactions, created with [`Transaction::diff`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TransactionDiff {
+    /// Every line of the summaries that differs.
+    pub differences: Vec<SummaryDifference>,
+}
+
+/// A line of a [`TransactionSummary`] that differs between two transactions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SummaryDifference {
+    /// The name of the line, for example `memo`.
+    pub name: &'static str,
+
+    /// The values in the first transaction.
+    pub left: Vec<String>,
+
+    /// The values in the second transaction.
+    pub right: Vec<String>,
+}
+
+impl TransactionDiff {
+    /// Returns `true` if the transactions are identical.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.differences.is_empty()
+    }
+
+    /// Returns `true` if the transactions are identical apart from the nodes they're for.
+    #[must_use]
+    pub fn is_node_account_ids_only(&self) -> bool {
+        self.differences.iter().all(|it| it.name == NODE_ACCOUNT_IDS)
+    }
+}
+
+impl Display for TransactionDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+
+        for difference in &self.differences {
+            for (sign, values) in [('-', &difference.left), ('+', &difference.right)] {
+                for value in values {
+                    if !std::mem::take(&mut first) {
+                        writeln!(f)?;
+                    }
+
+                    write!(f, "{sign} {}: {value}", difference.name)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<D: TransactionExecute> Transaction<D> {
+    /// Returns a description of this transaction for review.
+    ///
+    /// Chunked transactions are described by their first chunk, along with the number of chunks.
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`] if the transaction body can't be decoded.
+    /// - If the transaction has no transaction ID, see [`Transaction::to_bytes`].
+    ///
+    /// # Panics
+    /// - If `!self.is_frozen()`.
+    pub fn summary(&self) -> crate::Result<TransactionSummary> {
+        assert!(self.is_frozen(), "Transaction must be frozen to get its summary");
+
+        let body = self.first_body()?;
+
+        let mut summary = TransactionSummary {
+            transaction_type: body.data.as_ref().map_or("Transaction", transaction_type),
+            transaction_id: Option::from_protobuf(body.transaction_id)?,
+            max_transaction_fee: Hbar::from_tinybars(body.transaction_fee as i64),
+            memo: body.memo,
+            valid_duration: body
+                .transaction_valid_duration
+                .map_or(DEFAULT_TRANSACTION_VALID_DURATION, Into::into),
+            node_account_ids: self.get_node_account_ids().unwrap_or_default().to_vec(),
+            transfers: Vec::new(),
+            keys: Vec::new(),
+            fields: Vec::new(),
+        };
+
+        if let Some(data) = body.data {
+            summary.data(data)?;
+        }
+
+        let chunks = self.make_sources()?.chunks().count();
+
+        if chunks > 1 {
+            summary.field("chunks", chunks);
+        }
+
+        Ok(summary)
+    }
+
+    /// Compares the [summaries](Self::summary) of this transaction and `other`.
+    ///
+    /// Differences in the bodies that the summaries don't show (including every chunk after the first)
+    /// are reported as a difference in `body hash`, unless the summaries already differ in more than their node account IDs.
+    ///
+    /// # Errors
+    /// - See [`Transaction::summary`].
+    ///
+    /// # Panics
+    /// - If either transaction isn't frozen.
+    pub fn diff<E: TransactionExecute>(
+        &self,
+        other: &Transaction<E>,
+    ) -> crate::Result<TransactionDiff> {
+        let lhs = self.summary()?.lines();
+        let rhs = other.summary()?.lines();
+
+        let mut names: Vec<&'static str> = Vec::new();
+
+        for name in lhs.iter().chain(&rhs).map(|it| it.0) {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+
+        let values = |lines: &[(&'static str, String)], name: &str| -> Vec<String> {
+            lines.iter().filter(|it| it.0 == name).map(|it| it.1.clone()).collect()
+        };
+
+        let mut differences: Vec<_> = names
+            .into_iter()
+            .filter_map(|name| {
+                let left = values(&lhs, name);
+                let right = values(&rhs, name);
+
+                (left != right).then_some(SummaryDifference { name, left, right })
+            })
+            .collect();
+
+        // the summary doesn't show everything, so make sure nothing else differs,
+        // otherwise a diff could look like it's only for different nodes.
+        if differences.iter().all(|it| it.name == NODE_ACCOUNT_IDS) {
+            let lhs = self.body_hash()?;
+            let rhs = other.body_hash()?;
+
+            if lhs.0 != rhs.0 {
+                differences.push(SummaryDifference {
+                    name: "body hash",
+                    left: vec![lhs.to_string()],
+                    right: vec![rhs.to_string()],
+                });
+            }
+        }
+
+        Ok(TransactionDiff { differences })
+    }
+
+    fn first_body(&self) -> crate::Result<services::TransactionBody> {
+        let sources = self.make_sources()?;
+
+        services::TransactionBody::decode(&*sources.signed_transactions()[0].body_bytes)
+            .map_err(Error::from_protobuf)
+    }
+
+    /// Hashes the bodies of every chunk, without their node account IDs.
+    fn body_hash(&self) -> crate::Result<TransactionHash> {
+        let sources = self.make_sources()?;
+
+        let mut hashes = Vec::new();
+
+        for chunk in sources.chunks() {
+            // the bodies of a chunk should only differ by node account ID,
+            // but transactions from elsewhere might not, so keep every distinct one.
+            let mut bodies: Vec<Vec<u8>> = Vec::new();
+
+            for signed_transaction in chunk.signed_transactions() {
+                let mut body = services::TransactionBody::decode(&*signed_transaction.body_bytes)
+                    .map_err(Error::from_protobuf)?;
+                body.node_account_id = None;
+
+                let body = body.encode_to_vec();
+
+                if !bodies.contains(&body) {
+                    bodies.push(body);
+                }
+            }
+
+            hashes.extend_from_slice(&(bodies.len() as u64).to_be_bytes());
+
+            for body in bodies {
+                hashes.extend_from_slice(&TransactionHash::new(&body).0);
+            }
+        }
+
+        Ok(TransactionHash::new(&hashes))
+    }
+}
+
+impl TransactionSummary {
+    fn field(&mut self, name: &'static str, value: impl Display) {
+        self.fields.push(SummaryField { name, value: value.to_string() });
+    }
+
+    fn entity<T, P>(&mut self, name: &'static str, id: Option<P>) -> crate::Result<()>
+    where
+        T: FromProtobuf<P> + Display,
+    {
+        if let Some(id) = id {
+            self.field(name, T::from_protobuf(id)?);
+        }
+
+        Ok(())
+    }
+
+    fn key(&mut self, name: &'static str, key: Option<services::Key>) -> crate::Result<()> {
+        if let Some(key) = Option::from_protobuf(key)? {
+            self.keys.push(SummaryKey { name, key });
+        }
+
+        Ok(())
+    }
+
+    fn key_list(
+        &mut self,
+        name: &'static str,
+        keys: Option<services::KeyList>,
+    ) -> crate::Result<()> {
+        if let Some(keys) = Option::<KeyList>::from_protobuf(keys)? {
+            self.keys.push(SummaryKey { name, key: Key::KeyList(keys) });
+        }
+
+        Ok(())
+    }
+
+    fn transfers(&mut self, transfers: Vec<services::AccountAmount>) -> crate::Result<()> {
+        for transfer in transfers {
+            self.transfers.push(SummaryTransfer::Hbar {
+                account_id: AccountId::from_protobuf(pb_getf!(transfer, account_id)?)?,
+                amount: Hbar::from_tinybars(transfer.amount),
+                is_approval: transfer.is_approval,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn token_transfers(
+        &mut self,
+        transfers: Vec<services::TokenTransferList>,
+    ) -> crate::Result<()> {
+        for transfer in transfers {
+            let token_id = TokenId::from_protobuf(pb_getf!(transfer, token)?)?;
+
+            for it in transfer.transfers {
+                self.transfers.push(SummaryTransfer::Token {
+                    token_id,
+                    account_id: AccountId::from_protobuf(pb_getf!(it, account_id)?)?,
+                    amount: it.amount,
+                    expected_decimals: transfer.expected_decimals,
+                    is_approval: it.is_approval,
+                });
+            }
+
+            for nft in transfer.nft_transfers {
+                self.transfers
+                    .push(SummaryTransfer::Nft(TokenNftTransfer::from_protobuf(nft, token_id)?));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn token_keys(&mut self, keys: [Option<services::Key>; 8]) -> crate::Result<()> {
+        const NAMES: [&str; 8] = [
+            "admin key",
+            "KYC key",
+            "freeze key",
+            "wipe key",
+            "supply key",
+            "fee schedule key",
+            "pause key",
+            "metadata key",
+        ];
+
+        for (name, key) in NAMES.into_iter().zip(keys) {
+            self.key(name, key)?;
+        }
+
+        Ok(())
+    }
+
+    fn endpoints(
+        &mut self,
+        name: &'static str,
+        endpoints: Vec<services::ServiceEndpoint>,
+    ) -> crate::Result<()> {
+        for endpoint in endpoints {
+            if endpoint.domain_name.is_empty() {
+                self.field(name, service_endpoint_from_protobuf(endpoint)?);
+            } else {
+                self.field(name, format!("{}:{}", endpoint.domain_name, endpoint.port));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn pending_airdrops(&mut self, ids: Vec<services::PendingAirdropId>) -> crate::Result<()> {
+        for id in ids {
+            let id = PendingAirdropId::from_protobuf(id)?;
+
+            let token = match (id.token_id, id.nft_id) {
+                (Some(token_id), _) => token_id.to_string(),
+                (None, Some(nft_id)) => nft_id.to_string(),
+                (None, None) => String::new(),
+            };
+
+            self.field(
+                "pending airdrop",
+                format!("{token} {} -> {}", id.sender_id, id.receiver_id),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn serials(&mut self, serials: &[i64]) {
+        if !serials.is_empty() {
+            self.field("serial numbers", join(serials));
+        }
+    }
+
+    // one arm per transaction type that has something worth showing.
+    #[allow(clippy::too_many_lines)]
+    fn data(&mut self, data: services::transaction_body::Data) -> crate::Result<()> {
+        use services::transaction_body::Data;
+
+        match data {
+            Data::CryptoTransfer(it) => {
+                self.transfers(it.transfers.map(|it| it.account_amounts).unwrap_or_default())?;
+                self.token_transfers(it.token_transfers)?;
+            }
+            Data::TokenAirdrop(it) => self.token_transfers(it.token_transfers)?,
+            Data::CryptoCreateAccount(it) => {
+                self.key("key", it.key)?;
+                self.field("initial balance", Hbar::from_tinybars(it.initial_balance as i64));
+            }
+            Data::CryptoUpdateAccount(it) => {
+                self.entity::<AccountId, _>("account", it.account_id_to_update)?;
+                self.key("key", it.key)?;
+            }
+            Data::CryptoDelete(it) => {
+                self.entity::<AccountId, _>("account", it.delete_account_id)?;
+                self.entity::<AccountId, _>("transfer account", it.transfer_account_id)?;
+            }
+            Data::FileCreate(it) => self.key_list("keys", it.keys)?,
+            Data::FileUpdate(it) => {
+                self.entity::<FileId, _>("file", it.file_id)?;
+                self.key_list("keys", it.keys)?;
+            }
+            Data::FileAppend(it) => {
+                self.entity::<FileId, _>("file", it.file_id)?;
+                self.field("contents", format!("{} bytes", it.contents.len()));
+            }
+            Data::FileDelete(it) => self.entity::<FileId, _>("file", it.file_id)?,
+            Data::ContractCreateInstance(it) => {
+                self.key("admin key", it.admin_key)?;
+                self.field("gas", it.gas);
+                self.field("initial balance", Hbar::from_tinybars(it.initial_balance));
+            }
+            Data::ContractUpdateInstance(it) => {
+                self.entity::<ContractId, _>("contract", it.contract_id)?;
+                self.key("admin key", it.admin_key)?;
+            }
+            Data::ContractCall(it) => {
+                self.entity::<ContractId, _>("contract", it.contract_id)?;
+                self.field("gas", it.gas);
+                self.field("amount", Hbar::from_tinybars(it.amount));
+                self.field(
+                    "function parameters",
+                    format!("{} bytes", it.function_parameters.len()),
+                );
+            }
+            Data::ContractDeleteInstance(it) => {
+                self.entity::<ContractId, _>("contract", it.contract_id)?;
+            }
+            Data::ConsensusCreateTopic(it) => {
+                self.key("admin key", it.admin_key)?;
+                self.key("submit key", it.submit_key)?;
+            }
+            Data::ConsensusUpdateTopic(it) => {
+                self.entity::<TopicId, _>("topic", it.topic_id)?;
+                self.key("admin key", it.admin_key)?;
+                self.key("submit key", it.submit_key)?;
+            }
+            Data::ConsensusDeleteTopic(it) => self.entity::<TopicId, _>("topic", it.topic_id)?,
+            Data::ConsensusSubmitMessage(it) => {
+                self.entity::<TopicId, _>("topic", it.topic_id)?;
+                self.field("message", format!("{} bytes", it.message.len()));
+            }
+            Data::TokenCreation(it) => {
+                self.field("name", format!("{:?}", it.name));
+                self.field("symbol", format!("{:?}", it.symbol));
+                self.field("decimals", it.decimals);
+                self.field("initial supply", it.initial_supply);
+                self.entity::<AccountId, _>("treasury", it.treasury)?;
+                self.token_keys([
+                    it.admin_key,
+                    it.kyc_key,
+                    it.freeze_key,
+                    it.wipe_key,
+                    it.supply_key,
+                    it.fee_schedule_key,
+                    it.pause_key,
+                    it.metadata_key,
+                ])?;
+            }
+            Data::TokenUpdate(it) => {
+                self.entity::<TokenId, _>("token", it.token)?;
+
+                // empty means unchanged.
+                if !it.name.is_empty() {
+                    self.field("name", format!("{:?}", it.name));
+                }
+
+                if !it.symbol.is_empty() {
+                    self.field("symbol", format!("{:?}", it.symbol));
+                }
+
+                self.entity::<AccountId, _>("treasury", it.treasury)?;
+                self.token_keys([
+                    it.admin_key,
+                    it.kyc_key,
+                    it.freeze_key,
+                    it.wipe_key,
+                    it.supply_key,
+                    it.fee_schedule_key,
+                    it.pause_key,
+                    it.metadata_key,
+                ])?;
+            }
+            Data::TokenMint(it) => {
+                self.entity::<TokenId, _>("token", it.token)?;
+
+                if it.amount != 0 {
+                    self.field("amount", it.amount);
+                }
+
+                if !it.metadata.is_empty() {
+                    self.field("NFTs", it.metadata.len());
+                }
+            }
+            Data::TokenBurn(it) => {
+                self.entity::<TokenId, _>("token", it.token)?;
+
+                if it.amount != 0 {
+                    self.field("amount", it.amount);
+                }
+
+                self.serials(&it.serial_numbers);
+            }
+            Data::TokenWipe(it) => {
+                self.entity::<TokenId, _>("token", it.token)?;
+                self.entity::<AccountId, _>("account", it.account)?;
+
+                if it.amount != 0 {
+                    self.field("amount", it.amount);
+                }
+
+                self.serials(&it.serial_numbers);
+            }
+            Data::TokenFreeze(it) => {
+                self.entity::<TokenId, _>("token", it.token)?;
+                self.entity::<AccountId, _>("account", it.account)?;
+            }
+            Data::TokenUnfreeze(it) => {
+                self.entity::<TokenId, _>("token", it.token)?;
+                self.entity::<AccountId, _>("account", it.account)?;
+            }
+            Data::TokenGrantKyc(it) => {
+                self.entity::<TokenId, _>("token", it.token)?;
+                self.entity::<AccountId, _>("account", it.account)?;
+            }
+            Data::TokenRevokeKyc(it) => {
+                self.entity::<TokenId, _>("token", it.token)?;
+                self.entity::<AccountId, _>("account", it.account)?;
+            }
+            Data::TokenAssociate(it) => {
+                self.entity::<AccountId, _>("account", it.account)?;
+
+                for token in it.tokens {
+                    self.field("token", TokenId::from_protobuf(token)?);
+                }
+            }
+            Data::TokenDissociate(it) => {
+                self.entity::<AccountId, _>("account", it.account)?;
+
+                for token in it.tokens {
+                    self.field("token", TokenId::from_protobuf(token)?);
+                }
+            }
+            Data::TokenDeletion(it) => self.entity::<TokenId, _>("token", it.token)?,
+            Data::TokenPause(it) => self.entity::<TokenId, _>("token", it.token)?,
+            Data::TokenUnpause(it) => self.entity::<TokenId, _>("token", it.token)?,
+            Data::TokenUpdateNfts(it) => {
+                self.entity::<TokenId, _>("token", it.token)?;
+                self.serials(&it.serial_numbers);
+            }
+            Data::TokenFeeScheduleUpdate(it) => {
+                self.entity::<TokenId, _>("token", it.token_id)?;
+            }
+            Data::ScheduleCreate(it) => {
+                self.key("admin key", it.admin_key)?;
+                self.entity::<AccountId, _>("schedule payer", it.payer_account_id)?;
+            }
+            Data::ScheduleSign(it) => self.entity::<ScheduleId, _>("schedule", it.schedule_id)?,
+            Data::ScheduleDelete(it) => {
+                self.entity::<ScheduleId, _>("schedule", it.schedule_id)?;
+            }
+            Data::CryptoApproveAllowance(it) => {
+                for allowance in it.crypto_allowances {
+                    let owner = allowance_owner(allowance.owner)?;
+                    let spender = AccountId::from_protobuf(pb_getf!(allowance, spender)?)?;
+
+                    self.field(
+                        "hbar allowance",
+                        format!("{owner} -> {spender} {}", Hbar::from_tinybars(allowance.amount)),
+                    );
+                }
+
+                for allowance in it.token_allowances {
+                    let token_id = TokenId::from_protobuf(pb_getf!(allowance, token_id)?)?;
+                    let owner = allowance_owner(allowance.owner)?;
+                    let spender = AccountId::from_protobuf(pb_getf!(allowance, spender)?)?;
+
+                    self.field(
+                        "token allowance",
+                        format!("{token_id} {owner} -> {spender} {}", allowance.amount),
+                    );
+                }
+
+                for allowance in it.nft_allowances {
+                    let token_id = TokenId::from_protobuf(pb_getf!(allowance, token_id)?)?;
+                    let owner = allowance_owner(allowance.owner)?;
+                    let spender = AccountId::from_protobuf(pb_getf!(allowance, spender)?)?;
+
+                    let mut value = format!("{token_id} {owner} -> {spender}");
+
+                    if !allowance.serial_numbers.is_empty() {
+                        value += &format!(" serials {}", join(&allowance.serial_numbers));
+                    }
+
+                    match allowance.approved_for_all {
+                        Some(true) => value += " all serials",
+                        Some(false) => value += " revoke all serials",
+                        None => {}
+                    }
+
+                    if let Some(delegating_spender) =
+                        Option::<AccountId>::from_protobuf(allowance.delegating_spender)?
+                    {
+                        value += &format!(" (delegated by {delegating_spender})");
+                    }
+
+                    self.field("NFT allowance", value);
+                }
+            }
+            Data::CryptoDeleteAllowance(it) => {
+                for allowance in it.nft_allowances {
+                    let token_id = TokenId::from_protobuf(pb_getf!(allowance, token_id)?)?;
+                    let owner = allowance_owner(allowance.owner)?;
+
+                    self.field(
+                        "NFT allowance removal",
+                        format!("{token_id} {owner} serials {}", join(&allowance.serial_numbers)),
+                    );
+                }
+            }
+            Data::TokenReject(it) => {
+                use services::token_reference::TokenIdentifier;
+
+                self.entity::<AccountId, _>("owner", it.owner)?;
+
+                for rejection in it.rejections {
+                    match pb_getf!(rejection, token_identifier)? {
+                        TokenIdentifier::FungibleToken(it) => {
+                            self.field("token", TokenId::from_protobuf(it)?);
+                        }
+                        TokenIdentifier::Nft(it) => self.field("NFT", NftId::from_protobuf(it)?),
+                    }
+                }
+            }
+            Data::TokenClaimAirdrop(it) => self.pending_airdrops(it.pending_airdrops)?,
+            Data::TokenCancelAirdrop(it) => self.pending_airdrops(it.pending_airdrops)?,
+            Data::NodeCreate(it) => {
+                self.entity::<AccountId, _>("node account", it.account_id)?;
+
+                if !it.description.is_empty() {
+                    self.field("description", format!("{:?}", it.description));
+                }
+
+                self.endpoints("gossip endpoint", it.gossip_endpoint)?;
+                self.endpoints("service endpoint", it.service_endpoint)?;
+                self.field(
+                    "gossip CA certificate",
+                    format!("{} bytes", it.gossip_ca_certificate.len()),
+                );
+
+                if !it.grpc_certificate_hash.is_empty() {
+                    self.field("gRPC certificate hash", hex::encode(it.grpc_certificate_hash));
+                }
+
+                self.key("admin key", it.admin_key)?;
+            }
+            Data::NodeUpdate(it) => {
+                self.field("node ID", it.node_id);
+                self.entity::<AccountId, _>("node account", it.account_id)?;
+
+                // unset means unchanged.
+                if let Some(description) = it.description {
+                    self.field("description", format!("{description:?}"));
+                }
+
+                self.endpoints("gossip endpoint", it.gossip_endpoint)?;
+                self.endpoints("service endpoint", it.service_endpoint)?;
+
+                if let Some(certificate) = it.gossip_ca_certificate {
+                    self.field("gossip CA certificate", format!("{} bytes", certificate.len()));
+                }
+
+                if let Some(hash) = it.grpc_certificate_hash {
+                    self.field("gRPC certificate hash", hex::encode(hash));
+                }
+
+                self.key("admin key", it.admin_key)?;
+            }
+            Data::EthereumTransaction(it) => {
+                self.field("ethereum data", format!("{} bytes", it.ethereum_data.len()));
+                self.entity::<FileId, _>("call data file", it.call_data)?;
+                self.field("max gas allowance", Hbar::from_tinybars(it.max_gas_allowance));
+            }
+            // the type (and the common fields) are all there is to show for everything else.
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+/// Allowances without an owner are for the payer.
+fn allowance_owner(owner: Option<services::AccountId>) -> crate::Result<String> {
+    Ok(Option::<AccountId>::from_protobuf(owner)?
+        .map_or_else(|| "payer".to_owned(), ToString::to_string))
+}
+
+fn join(serials: &[i64]) -> String {
+    serials.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+}
+
+fn transaction_type(data: &services::transaction_body::Data) -> &'static str {
+    use services::transaction_body::Data;
+
+    match data {
+        Data::ContractCall(_) => "ContractExecuteTransaction",
+        Data::ContractCreateInstance(_) => "ContractCreateTransaction",
+        Data::ContractUpdateInstance(_) => "ContractUpdateTransaction",
+        Data::ContractDeleteInstance(_) => "ContractDeleteTransaction",
+        Data::EthereumTransaction(_) => "EthereumTransaction",
+        Data::CryptoApproveAllowance(_) => "AccountAllowanceApproveTransaction",
+        Data::CryptoDeleteAllowance(_) => "AccountAllowanceDeleteTransaction",
+        Data::CryptoCreateAccount(_) => "AccountCreateTransaction",
+        Data::CryptoDelete(_) => "AccountDeleteTransaction",
+        Data::CryptoTransfer(_) => "TransferTransaction",
+        Data::CryptoUpdateAccount(_) => "AccountUpdateTransaction",
+        Data::FileAppend(_) => "FileAppendTransaction",
+        Data::FileCreate(_) => "FileCreateTransaction",
+        Data::FileDelete(_) => "FileDeleteTransaction",
+        Data::FileUpdate(_) => "FileUpdateTransaction",
+        Data::SystemDelete(_) => "SystemDeleteTransaction",
+        Data::SystemUndelete(_) => "SystemUndeleteTransaction",
+        Data::Freeze(_) => "FreezeTransaction",
+        Data::ConsensusCreateTopic(_) => "TopicCreateTransaction",
+        Data::ConsensusUpdateTopic(_) => "TopicUpdateTransaction",
+        Data::ConsensusDeleteTopic(_) => "TopicDeleteTransaction",
+        Data::ConsensusSubmitMessage(_) => "TopicMessageSubmitTransaction",
+        Data::TokenCreation(_) => "TokenCreateTransaction",
+        Data::TokenFreeze(_) => "TokenFreezeTransaction",
+        Data::TokenUnfreeze(_) => "TokenUnfreezeTransaction",
+        Data::TokenGrantKyc(_) => "TokenGrantKycTransaction",
+        Data::TokenRevokeKyc(_) => "TokenRevokeKycTransaction",
+        Data::TokenDeletion(_) => "TokenDeleteTransaction",
+        Data::TokenUpdate(_) => "TokenUpdateTransaction",
+        Data::TokenMint(_) => "TokenMintTransaction",
+        Data::TokenBurn(_) => "TokenBurnTransaction",
+        Data::TokenWipe(_) => "TokenWipeTransaction",
+        Data::TokenAssociate(_) => "TokenAssociateTransaction",
+        Data::TokenDissociate(_) => "TokenDissociateTransaction",
+        Data::TokenFeeScheduleUpdate(_) => "TokenFeeScheduleUpdateTransaction",
+        Data::TokenPause(_) => "TokenPauseTransaction",
+        Data::TokenUnpause(_) => "TokenUnpauseTransaction",
+        Data::ScheduleCreate(_) => "ScheduleCreateTransaction",
+        Data::ScheduleDelete(_) => "ScheduleDeleteTransaction",
+        Data::ScheduleSign(_) => "ScheduleSignTransaction",
+        Data::UtilPrng(_) => "PrngTransaction",
+        Data::TokenUpdateNfts(_) => "TokenUpdateNftsTransaction",
+        Data::TokenAirdrop(_) => "TokenAirdropTransaction",
+        Data::TokenClaimAirdrop(_) => "TokenClaimAirdropTransaction",
+        Data::TokenCancelAirdrop(_) => "TokenCancelAirdropTransaction",
+        Data::TokenReject(_) => "TokenRejectTransaction",
+        Data::NodeCreate(_) => "NodeCreateTransaction",
+        Data::NodeUpdate(_) => "NodeUpdateTransaction",
+        Data::NodeDelete(_) => "NodeDeleteTransaction",
+        Data::CryptoAddLiveHash(_) => "AddLiveHashTransaction",
+        Data::CryptoDeleteLiveHash(_) => "DeleteLiveHashTransaction",
+        Data::UncheckedSubmit(_) => "UncheckedSubmitTransaction",
+        Data::NodeStakeUpdate(_) => "NodeStakeUpdateTransaction",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hedera_proto::services;
+    use hedera_proto::services::transaction_body::Data;
+    use prost::Message;
+
+    use super::{
+        SummaryField,
+        SummaryKey,
+        SummaryTransfer,
+    };
+    use crate::protobuf::ToProtobuf;
+    use crate::transaction::test_helpers::{
+        self,
+        unused_private_key,
+        TEST_ACCOUNT_ID,
+        TEST_NODE_ACCOUNT_IDS,
+        TEST_TOKEN_ID,
+        TEST_TX_ID,
+    };
+    use crate::{
+        AccountAllowanceApproveTransaction,
+        AccountId,
+        AnyTransaction,
+        Client,
+        FileAppendTransaction,
+        FileId,
+        Hbar,
+        Key,
+        TokenCreateTransaction,
+        TransferTransaction,
+    };
+
+    const RECEIVER: AccountId = AccountId::new(0, 0, 1002);
+
+    /// Applies `f` to every body in the transaction list `bytes`, dropping the transactions it returns `false` for.
+    fn edit_bodies(
+        bytes: &[u8],
+        mut f: impl FnMut(&mut services::TransactionBody) -> bool,
+    ) -> AnyTransaction {
+        let mut list = hedera_proto::sdk::TransactionList::decode(bytes).unwrap();
+
+        list.transaction_list.retain_mut(|transaction| {
+            let mut signed =
+                services::SignedTransaction::decode(&*transaction.signed_transaction_bytes)
+                    .unwrap();
+
+            let mut body = services::TransactionBody::decode(&*signed.body_bytes).unwrap();
+            let keep = f(&mut body);
+
+            signed.body_bytes = body.encode_to_vec();
+            transaction.signed_transaction_bytes = signed.encode_to_vec();
+
+            keep
+        });
+
+        AnyTransaction::from_bytes(&list.encode_to_vec()).unwrap()
+    }
+
+    fn make_transfer(memo: &str) -> TransferTransaction {
+        make_transfer_for(memo, &TEST_NODE_ACCOUNT_IDS)
+    }
+
+    fn make_transfer_for(memo: &str, node_account_ids: &[AccountId]) -> TransferTransaction {
+        let mut tx = test_helpers::make_transfer(TEST_ACCOUNT_ID, RECEIVER, Hbar::new(5));
+
+        tx.node_account_ids(node_account_ids.iter().copied())
+            .token_transfer(TEST_TOKEN_ID, TEST_ACCOUNT_ID, -10)
+            .token_transfer(TEST_TOKEN_ID, RECEIVER, 10)
+            .transaction_memo(memo)
+            .freeze()
+            .unwrap();
+
+        tx
+    }
+
+    #[test]
+    fn transfer() {
+        let summary = make_transfer("hello").summary().unwrap();
+
+        assert_eq!(summary.transaction_type, "TransferTransaction");
+        assert_eq!(summary.transaction_id, Some(TEST_TX_ID));
+        assert_eq!(summary.payer(), Some(TEST_ACCOUNT_ID));
+        assert_eq!(summary.max_transaction_fee, Hbar::new(2));
+        assert_eq!(summary.memo, "hello");
+        assert_eq!(summary.node_account_ids, TEST_NODE_ACCOUNT_IDS);
+
+        for (account_id, amount) in [(TEST_ACCOUNT_ID, -5), (RECEIVER, 5)] {
+            assert!(summary.transfers.contains(&SummaryTransfer::Hbar {
+                account_id,
+                amount: Hbar::new(amount),
+                is_approval: false,
+            }));
+        }
+
+        for (account_id, amount) in [(TEST_ACCOUNT_ID, -10), (RECEIVER, 10)] {
+            assert!(summary.transfers.contains(&SummaryTransfer::Token {
+                token_id: TEST_TOKEN_ID,
+                account_id,
+                amount,
+                expected_decimals: None,
+                is_approval: false,
+            }));
+        }
+
+        let text = summary.to_string();
+
+        assert!(text.starts_with("TransferTransaction\n"));
+        assert!(text.contains("memo:                \"hello\""));
+        assert!(text.contains(&format!("token transfer:      {TEST_TOKEN_ID} {RECEIVER} +10")));
+    }
+
+    #[test]
+    fn token_create() {
+        let key = Key::from(unused_private_key().public_key());
+
+        let mut tx = TokenCreateTransaction::new_for_tests();
+        tx.name("Test")
+            .symbol("T")
+            .admin_key(key.clone())
+            .supply_key(key.clone())
+            .freeze()
+            .unwrap();
+
+        let summary = tx.summary().unwrap();
+
+        assert_eq!(summary.transaction_type, "TokenCreateTransaction");
+        assert_eq!(
+            summary.keys,
+            [
+                SummaryKey { name: "admin key", key: key.clone() },
+                SummaryKey { name: "supply key", key },
+            ]
+        );
+        assert!(summary
+            .fields
+            .contains(&SummaryField { name: "symbol", value: "\"T\"".to_owned() }));
+    }
+
+    #[test]
+    fn diff_node_account_ids() {
+        let tx = make_transfer("");
+        let other = make_transfer_for("", &TEST_NODE_ACCOUNT_IDS[..1]);
+
+        // round trip through bytes, like transactions handed over by someone else.
+        let tx = AnyTransaction::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+
+        assert!(tx.diff(&tx).unwrap().is_empty());
+
+        let diff = tx.diff(&other).unwrap();
+
+        assert!(!diff.is_empty());
+        assert!(diff.is_node_account_ids_only());
+    }
+
+    #[test]
+    fn diff_node_account_ids_and_unsummarized_field() {
+        let tx = make_transfer("");
+        let other = make_transfer_for("", &TEST_NODE_ACCOUNT_IDS[..1]);
+
+        // `generate_record` isn't part of the summary.
+        let other = edit_bodies(&other.to_bytes().unwrap(), |body| {
+            body.generate_record = true;
+            true
+        });
+
+        let diff = tx.diff(&other).unwrap();
+
+        assert!(!diff.is_node_account_ids_only());
+        assert_eq!(
+            diff.differences.iter().map(|it| it.name).collect::<Vec<_>>(),
+            ["node account IDs", "body hash"]
+        );
+    }
+
+    #[test]
+    fn diff_memo() {
+        let diff = make_transfer("a").diff(&make_transfer("b")).unwrap();
+
+        assert!(!diff.is_node_account_ids_only());
+        assert_eq!(diff.differences.len(), 1);
+        assert_eq!(diff.differences[0].name, "memo");
+        assert_eq!(diff.to_string(), "- memo: \"a\"\n+ memo: \"b\"");
+    }
+
+    #[tokio::test]
+    async fn diff_later_chunk() {
+        let client = Client::for_testnet();
+        client.set_operator(TEST_ACCOUNT_ID, unused_private_key());
+
+        let mut tx = FileAppendTransaction::new();
+        tx.file_id(FileId::new(0, 0, 10))
+            .contents(Vec::from([1; 10]))
+            .chunk_size(4)
+            .transaction_id(TEST_TX_ID)
+            .node_account_ids(TEST_NODE_ACCOUNT_IDS)
+            .freeze_with(&client)
+            .unwrap();
+
+        let bytes = tx.to_bytes().unwrap();
+        let tx = AnyTransaction::from_bytes(&bytes).unwrap();
+
+        let summary = tx.summary().unwrap();
+        assert!(summary.fields.contains(&SummaryField { name: "chunks", value: "3".to_owned() }));
+
+        let first_node = Some(TEST_NODE_ACCOUNT_IDS[0].to_protobuf());
+        let one_node = edit_bodies(&bytes, |body| body.node_account_id == first_node);
+
+        assert!(tx.diff(&tx).unwrap().is_empty());
+        assert!(tx.diff(&one_node).unwrap().is_node_account_ids_only());
+
+        // only the last chunk differs, so the summaries are the same.
+        let other = edit_bodies(&bytes, |body| {
+            if let Some(Data::FileAppend(it)) = &mut body.data {
+                if it.contents.len() < 4 {
+                    it.contents[0] = 2;
+                }
+            }
+
+            true
+        });
+
+        let diff = tx.diff(&other).unwrap();
+
+        assert!(!diff.is_node_account_ids_only());
+        assert_eq!(diff.differences.iter().map(|it| it.name).collect::<Vec<_>>(), ["body hash"]);
+    }
+
+    #[test]
+    fn allowance_approve() {
+        let mut tx = AccountAllowanceApproveTransaction::new_for_tests();
+        tx.approve_hbar_allowance(TEST_ACCOUNT_ID, RECEIVER, Hbar::new(3))
+            .approve_token_nft_allowance_all_serials(TEST_TOKEN_ID, TEST_ACCOUNT_ID, RECEIVER)
+            .freeze()
+            .unwrap();
+
+        let summary = tx.summary().unwrap();
+
+        assert_eq!(
+            summary.fields,
+            [
+                SummaryField {
+                    name: "hbar allowance",
+                    value: format!("{TEST_ACCOUNT_ID} -> {RECEIVER} {}", Hbar::new(3)),
+                },
+                SummaryField {
+                    name: "NFT allowance",
+                    value: format!("{TEST_TOKEN_ID} {TEST_ACCOUNT_ID} -> {RECEIVER} all serials"),
+                },
+            ]
+        );
+    }
+}
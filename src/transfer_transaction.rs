@@ -69,27 +69,27 @@ pub struct TransferTransactionData {
 
 #[derive(Debug, Clone)]
 #[cfg_attr(test, derive(Eq, PartialEq))]
-struct Transfer {
+pub(crate) struct Transfer {
     /// The account involved in the transfer.
-    account_id: AccountId,
+    pub(crate) account_id: AccountId,
 
     /// The value of the transfer.
-    amount: i64,
+    pub(crate) amount: i64,
 
     /// If this is an approved transfer.
-    is_approval: bool,
+    pub(crate) is_approval: bool,
 }
 
 #[derive(Debug, Clone)]
 #[cfg_attr(test, derive(Eq, PartialEq))]
-struct TokenTransfer {
-    token_id: TokenId,
+pub(crate) struct TokenTransfer {
+    pub(crate) token_id: TokenId,
 
-    transfers: Vec<Transfer>,
+    pub(crate) transfers: Vec<Transfer>,
 
-    nft_transfers: Vec<TokenNftTransfer>,
+    pub(crate) nft_transfers: Vec<TokenNftTransfer>,
 
-    expected_decimals: Option<u32>,
+    pub(crate) expected_decimals: Option<u32>,
 }
 
 impl TransferTransaction {
@@ -148,3 +148,59 @@ async fn large() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn verify_running_hash() -> anyhow::Result<()> {
+    let Some(TestEnvironment { config, client }) = setup_nonfree() else {
+        return Ok(());
+    };
+
+    // Skip if using local node.
+    // Note: Remove when multinode is supported
+    if config.is_local {
+        return Ok(());
+    }
+
+    let topic = Topic::create(&client).await?;
+
+    // a message with several chunks, since only chunks carry the payer that the running hash includes.
+    TopicMessageSubmitTransaction::new()
+        .topic_id(topic.id)
+        .message(resources::BIG_CONTENTS)
+        .execute_all(&client)
+        .await?;
+
+    let fut = async {
+        for _ in 0..20 {
+            // a new topic starts with an all zero running hash, so every chunk is checked against the network's.
+            let res = TopicMessageQuery::new()
+                .topic_id(topic.id)
+                .start_time(OffsetDateTime::UNIX_EPOCH)
+                .limit(14)
+                .verify_running_hash(true)
+                .previous_running_hash(0, [0; 48])?
+                .execute(&client)
+                .await;
+
+            // topic not found -> try again
+            if let Err(hedera::Error::GrpcStatus(status)) = &res {
+                if status.code() == tonic::Code::NotFound {
+                    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+                    continue;
+                }
+            }
+
+            return res.map_err(anyhow::Error::from);
+        }
+
+        anyhow::bail!("Couldn't get topic after 20 attempts")
+    };
+
+    let messages = tokio::time::timeout(std::time::Duration::from_secs(60), fut).await??;
+
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].contents, resources::BIG_CONTENTS.as_bytes());
+    topic.delete(&client).await?;
+
+    Ok(())
+}